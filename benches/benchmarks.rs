@@ -1,6 +1,6 @@
 use criterion::{Criterion, criterion_main, criterion_group};
 use rand::prelude::*;
-use string_iter::StringIterable;
+use string_iter::prelude::*;
 
 /// this generates a unicode char evenly across utf-8 lengths
 fn gen_char() -> char {
@@ -63,5 +63,60 @@ fn iter_benchmark(c: &mut Criterion){
 
 }
 
-criterion_group!(benches, iter_benchmark);
+fn search_benchmark(c: &mut Criterion){
+    let log: String = (0..1000).map(|i| format!(
+        "2023-01-01T00:00:{:02} INFO  [server::http] request {} took {}ms\r\n", i % 60, i, i * 7
+    )).collect();
+    c.bench_function("str::find(' ') words", |b|
+        b.iter(|| {
+            let mut s = log.as_str();
+            let mut count = 0;
+            while let Some(i) = s.find(' ') {
+                s = &s[i + 1..];
+                count += 1;
+            }
+            count
+        })
+    );
+    c.bench_function("next_slice(' ') words", |b|
+        b.iter(|| {
+            let mut iter = log.str_iter();
+            let mut count = 0;
+            while iter.next_slice(' '.sep_with(Sep::Split)).is_some() {
+                count += 1;
+            }
+            count
+        })
+    );
+    c.bench_function("next_slice(|c| c == ' ') words", |b|
+        b.iter(|| {
+            let mut iter = log.str_iter();
+            let mut count = 0;
+            while iter.next_slice((|c| c == ' ').sep_with(Sep::Split)).is_some() {
+                count += 1;
+            }
+            count
+        })
+    );
+    c.bench_function("str::split([' ', ':']) to Vec<&str>", |b|
+        b.iter(|| log.split([' ', ':']).collect::<Vec<_>>())
+    );
+    c.bench_function("into_splits([' ', ':']) to Vec<&str>", |b|
+        b.iter(|| log.str_iter().into_splits([' ', ':']).collect::<Vec<_>>())
+    );
+    c.bench_function("str::split(\"\\r\\n\") to Vec<&str>", |b|
+        b.iter(|| log.split("\r\n").collect::<Vec<_>>())
+    );
+    c.bench_function("into_splits(\"\\r\\n\") to Vec<&str>", |b|
+        b.iter(|| log.str_iter().into_splits("\r\n").collect::<Vec<_>>())
+    );
+    c.bench_function("str::rsplit(\"\\r\\n\") to Vec<&str>", |b|
+        b.iter(|| log.rsplit("\r\n").collect::<Vec<_>>())
+    );
+    c.bench_function("into_splits(\"\\r\\n\").rev() to Vec<&str>", |b|
+        b.iter(|| log.str_iter().into_splits("\r\n").rev().collect::<Vec<_>>())
+    );
+}
+
+criterion_group!(benches, iter_benchmark, search_benchmark);
 criterion_main!(benches);
\ No newline at end of file
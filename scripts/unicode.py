@@ -0,0 +1,182 @@
+#!/usr/bin/env python3
+"""Generate `src/unicode_tables.rs` from the Unicode Character Database.
+
+Usage: scripts/unicode.py <ucd directory> [version]
+
+The directory should contain the following files from
+https://www.unicode.org/Public/<version>/ucd/
+
+* auxiliary/GraphemeBreakProperty.txt
+* auxiliary/WordBreakProperty.txt
+* auxiliary/SentenceBreakProperty.txt
+* emoji/emoji-data.txt
+* DerivedCoreProperties.txt
+* EastAsianWidth.txt
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([\w ;]+?)\s*(?:#.*)?$")
+
+
+def find(ucd, name):
+    for root, _, files in os.walk(ucd):
+        if name in files:
+            return os.path.join(root, name)
+    raise FileNotFoundError(name)
+
+
+def parse(path):
+    """Yields `(lo, hi, value)` for each line of a UCD property file."""
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            m = LINE.match(line.strip())
+            if m:
+                lo = int(m.group(1), 16)
+                hi = int(m.group(2), 16) if m.group(2) else lo
+                yield lo, hi, m.group(3).replace(" ", "")
+
+
+def compress(table):
+    """Merge a `{codepoint: category}` dict into sorted ranges."""
+    ranges = []
+    for c in sorted(table):
+        cat = table[c]
+        if ranges and ranges[-1][1] == c - 1 and ranges[-1][2] == cat:
+            ranges[-1][1] = c
+        else:
+            ranges.append([c, c, cat])
+    return ranges
+
+
+def emit_table(out, name, enum, ranges):
+    out.write(f"pub(crate) const {name}: &[(u32, u32, {enum})] = &[\n")
+    for lo, hi, cat in ranges:
+        out.write(f"    (0x{lo:04x}, 0x{hi:04x}, {enum}::{cat}),\n")
+    out.write("];\n")
+
+
+def grapheme_table(ucd):
+    table = {}
+    for lo, hi, value in parse(find(ucd, "GraphemeBreakProperty.txt")):
+        cat = value.replace("_", "")
+        for c in range(lo, hi + 1):
+            table[c] = cat
+    for lo, hi, value in parse(find(ucd, "emoji-data.txt")):
+        if value == "Extended_Pictographic":
+            for c in range(lo, hi + 1):
+                assert c not in table, hex(c)
+                table[c] = "ExtendedPictographic"
+    # GB9c uses Indic_Conjunct_Break, which is folded into the table
+    for lo, hi, value in parse(find(ucd, "DerivedCoreProperties.txt")):
+        if not value.startswith("InCB;"):
+            continue
+        incb = value[len("InCB;"):]
+        for c in range(lo, hi + 1):
+            cat = table.get(c)
+            if incb == "Consonant":
+                assert cat is None, hex(c)
+                table[c] = "InCBConsonant"
+            elif incb == "Linker":
+                assert cat == "Extend", hex(c)
+                table[c] = "InCBLinker"
+            elif incb == "Extend":
+                assert cat in ("Extend", "ZWJ"), hex(c)
+                if cat == "Extend":
+                    table[c] = "InCBExtend"
+    return compress(table)
+
+
+def wide_table(ucd):
+    """East_Asian_Width `W` and `F` ranges."""
+    table = {}
+    for lo, hi, value in parse(find(ucd, "EastAsianWidth.txt")):
+        if value in ("W", "F"):
+            for c in range(lo, hi + 1):
+                table[c] = "W"
+    return [(lo, hi) for lo, hi, _ in compress(table)]
+
+
+def property_table(ucd, name):
+    table = {}
+    for lo, hi, value in parse(find(ucd, name)):
+        for c in range(lo, hi + 1):
+            table[c] = value.replace("_", "")
+    return compress(table)
+
+
+def emit_enum(out, doc, name, ranges):
+    out.write(f"/// {doc} property.\n")
+    out.write("#[allow(clippy::upper_case_acronyms)]\n")
+    out.write("#[derive(Clone, Copy, PartialEq, Eq, Debug)]\n")
+    out.write(f"pub(crate) enum {name} {{\n")
+    out.write("    Other,\n")
+    for cat in sorted({cat for _, _, cat in ranges}):
+        out.write(f"    {cat},\n")
+    out.write("}\n\n")
+
+
+GRAPHEME_ENUM = """\
+/// Grapheme_Cluster_Break property, with Extended_Pictographic
+/// and Indic_Conjunct_Break folded in.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum GraphemeCat {
+    Other,
+    CR,
+    LF,
+    Control,
+    Prepend,
+    /// Extend with Indic_Conjunct_Break=None
+    Extend,
+    /// Extend with Indic_Conjunct_Break=Extend
+    InCBExtend,
+    /// Extend with Indic_Conjunct_Break=Linker
+    InCBLinker,
+    /// Other with Indic_Conjunct_Break=Consonant
+    InCBConsonant,
+    /// Also Indic_Conjunct_Break=Extend
+    ZWJ,
+    RegionalIndicator,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+}
+"""
+
+
+def main():
+    ucd = sys.argv[1]
+    version = sys.argv[2] if len(sys.argv) > 2 else "17.0.0"
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "unicode_tables.rs")
+    with open(path, "w", encoding="utf-8") as out:
+        out.write(f"// Generated by scripts/unicode.py from the Unicode Character Database {version}.\n")
+        out.write("// Do not edit directly.\n\n")
+        out.write("/// The version of Unicode used by segmentation and display widths in this crate.\n")
+        out.write(f"pub const UNICODE_VERSION: (u8, u8, u8) = ({', '.join(version.split('.'))});\n\n")
+        out.write(GRAPHEME_ENUM)
+        out.write("\n")
+        emit_table(out, "GRAPHEME_TABLE", "GraphemeCat", grapheme_table(ucd))
+        for prop, name, file in [
+            ("Word_Break", "Word", "WordBreakProperty.txt"),
+            ("Sentence_Break", "Sentence", "SentenceBreakProperty.txt"),
+        ]:
+            ranges = property_table(ucd, file)
+            out.write("\n")
+            emit_enum(out, prop, f"{name}Cat", ranges)
+            emit_table(out, f"{name.upper()}_TABLE", f"{name}Cat", ranges)
+        out.write("\n/// East_Asian_Width `W` and `F` ranges.\n")
+        out.write("pub(crate) const WIDE: &[(u32, u32)] = &[\n")
+        for lo, hi in wide_table(ucd):
+            out.write(f"    (0x{lo:04x}, 0x{hi:04x}),\n")
+        out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
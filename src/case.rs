@@ -0,0 +1,176 @@
+use core::fmt::{Display, Formatter, Write};
+use core::iter::FusedIterator;
+
+use crate::StringIter;
+
+/// An identifier case style, see [`to_case()`](StringIter::to_case).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `kebab-case`
+    Kebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `Title Case`
+    Title,
+}
+
+/// How a word is cased.
+enum WordCase {
+    Lower,
+    Upper,
+    Capital,
+}
+
+impl Case {
+    fn separator(self) -> Option<char> {
+        match self {
+            Case::Snake | Case::ScreamingSnake => Some('_'),
+            Case::Kebab => Some('-'),
+            Case::Title => Some(' '),
+            Case::Camel | Case::Pascal => None,
+        }
+    }
+
+    fn word_case(self, index: usize) -> WordCase {
+        match self {
+            Case::Snake | Case::Kebab => WordCase::Lower,
+            Case::ScreamingSnake => WordCase::Upper,
+            Case::Camel if index == 0 => WordCase::Lower,
+            Case::Camel | Case::Pascal | Case::Title => WordCase::Capital,
+        }
+    }
+
+    /// Convert `s` into this case.
+    ///
+    /// This requires the `std` feature,
+    /// use [`to_case()`](StringIter::to_case) in `no_std`.
+    ///
+    /// ```
+    /// # use string_iter::Case;
+    /// assert_eq!(Case::Kebab.convert("HTTPServer"), "http-server");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn convert(self, s: &str) -> alloc::string::String {
+        use alloc::string::ToString;
+        StringIter::new(s).to_case(self).to_string()
+    }
+}
+
+impl<'t> StringIter<'t> {
+
+    /// Map the iterator into an `Iterator<Item = &str>`
+    /// that yields the words of an identifier.
+    ///
+    /// * Non-alphanumeric [`char`]s are separators and are discarded.
+    /// * An uppercase [`char`] after a lowercase [`char`] or a digit starts a new word.
+    /// * An uppercase [`char`] followed by a lowercase [`char`] ends an acronym.
+    /// * Digits do not start a new word.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let words: Vec<_> = "parseHTTPServer2Config__v2".str_iter().case_words().collect();
+    /// assert_eq!(words, ["parse", "HTTP", "Server2", "Config", "v2"]);
+    /// ```
+    pub fn case_words(self) -> CaseWords<'t> {
+        CaseWords(self)
+    }
+
+    /// Returns a [`Display`] adapter that writes the identifier in a [`Case`],
+    /// without allocating.
+    ///
+    /// Words are obtained by [`case_words()`](StringIter::case_words).
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, Case};
+    /// let iter = "XMLHttpRequest".str_iter();
+    /// assert_eq!(iter.clone().to_case(Case::Snake).to_string(), "xml_http_request");
+    /// assert_eq!(iter.clone().to_case(Case::Camel).to_string(), "xmlHttpRequest");
+    /// assert_eq!(iter.clone().to_case(Case::ScreamingSnake).to_string(), "XML_HTTP_REQUEST");
+    /// assert_eq!(iter.clone().to_case(Case::Title).to_string(), "Xml Http Request");
+    /// ```
+    pub fn to_case(self, case: Case) -> CaseDisplay<'t> {
+        CaseDisplay { iter: self, case }
+    }
+}
+
+/// An iterator that yields the words of an identifier,
+/// see [`case_words()`](StringIter::case_words).
+#[derive(Debug, Clone)]
+pub struct CaseWords<'t>(StringIter<'t>);
+
+impl<'t> CaseWords<'t> {
+    /// Returns the remaining [`str`] of this iterator.
+    pub fn as_str(&self) -> &'t str {
+        self.0.as_str()
+    }
+}
+
+impl<'t> Iterator for CaseWords<'t> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.trim_start_by(|c: char| !c.is_alphanumeric());
+        let s = self.0.as_str();
+        let mut chars = s.char_indices().peekable();
+        let (_, mut prev) = chars.next()?;
+        let mut end = s.len();
+        while let Some((index, c)) = chars.next() {
+            let boundary = if !c.is_alphanumeric() {
+                true
+            } else if c.is_uppercase() {
+                prev.is_lowercase()
+                    || prev.is_numeric()
+                    || prev.is_uppercase() && chars.peek().is_some_and(|(_, next)| next.is_lowercase())
+            } else {
+                false
+            };
+            if boundary {
+                end = index;
+                break;
+            }
+            prev = c;
+        }
+        let (result, rest) = s.split_at(end);
+        self.0 = StringIter::new(rest);
+        Some(result)
+    }
+}
+
+impl FusedIterator for CaseWords<'_> {}
+
+/// A [`Display`] adapter that writes an identifier in a [`Case`],
+/// see [`to_case()`](StringIter::to_case).
+#[derive(Debug, Clone)]
+pub struct CaseDisplay<'t> {
+    iter: StringIter<'t>,
+    case: Case,
+}
+
+impl Display for CaseDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (index, word) in self.iter.clone().case_words().enumerate() {
+            if index > 0 {
+                if let Some(sep) = self.case.separator() {
+                    f.write_char(sep)?;
+                }
+            }
+            let mut chars = word.chars();
+            if let WordCase::Capital = self.case.word_case(index) {
+                if let Some(c) = chars.next() {
+                    c.to_uppercase().try_for_each(|c| f.write_char(c))?;
+                }
+            }
+            match self.case.word_case(index) {
+                WordCase::Upper => chars.try_for_each(|c| c.to_uppercase().try_for_each(|c| f.write_char(c)))?,
+                _ => chars.try_for_each(|c| c.to_lowercase().try_for_each(|c| f.write_char(c)))?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{StringIter, merge::substr_range};
+
+/// A saved state of a [`StringIter`].
+///
+/// See [`checkpoint()`](StringIter::checkpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint<'t>(&'t str);
+
+impl<'t> Checkpoint<'t> {
+    /// Returns the underlying [`str`] at the time of the checkpoint.
+    pub const fn as_str(&self) -> &'t str {
+        self.0
+    }
+}
+
+impl<'t> StringIter<'t> {
+
+    /// Save the current state of the iterator,
+    /// which can be restored with [`rewind()`](StringIter::rewind).
+    #[inline]
+    pub const fn checkpoint(&self) -> Checkpoint<'t> {
+        Checkpoint(self.str)
+    }
+
+    /// Restore the iterator to a [`Checkpoint`].
+    #[inline]
+    pub fn rewind(&mut self, checkpoint: Checkpoint<'t>) {
+        self.str = checkpoint.0;
+    }
+
+    /// Returns the leading [`str`] consumed since a [`Checkpoint`].
+    ///
+    /// # Panics
+    ///
+    /// If the iterator is not a substring of the checkpoint.
+    pub fn consumed_since(&self, checkpoint: Checkpoint<'t>) -> &'t str {
+        assert!(substr_range(checkpoint.0, self.str).is_some(),
+            "checkpoint is not from this iterator");
+        // SAFETY: safe since self is a substring of checkpoint
+        unsafe {
+            StringIter::new(checkpoint.0).slice_front_ptr(self.str.as_ptr())
+        }
+    }
+
+    /// Returns the trailing [`str`] consumed since a [`Checkpoint`].
+    ///
+    /// # Panics
+    ///
+    /// If the iterator is not a substring of the checkpoint.
+    pub fn consumed_back_since(&self, checkpoint: Checkpoint<'t>) -> &'t str {
+        assert!(substr_range(checkpoint.0, self.str).is_some(),
+            "checkpoint is not from this iterator");
+        // SAFETY: safe since self is a substring of checkpoint
+        unsafe {
+            StringIter::new(checkpoint.0).slice_back_ptr(self.str.as_ptr().add(self.len()))
+        }
+    }
+
+    /// Run a function on the iterator,
+    /// and rewind the iterator if the function fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "foo=bar".str_iter();
+    /// let result: Result<_, char> = iter.transaction(|iter| {
+    ///     let key = iter.next_slice('='.sep_with(Sep::Split));
+    ///     let value = iter.try_next_slice(|c: char| match c {
+    ///         'a'..='z' => Ok(false),
+    ///         c => Err(c),
+    ///     })?;
+    ///     Ok((key, value))
+    /// });
+    /// assert_eq!(result, Ok((Some("foo"), Some("bar"))));
+    ///
+    /// let mut iter = "foo=BAR".str_iter();
+    /// let result: Result<_, char> = iter.transaction(|iter| {
+    ///     let key = iter.next_slice('='.sep_with(Sep::Split));
+    ///     let value = iter.try_next_slice(|c: char| match c {
+    ///         'a'..='z' => Ok(false),
+    ///         c => Err(c),
+    ///     })?;
+    ///     Ok((key, value))
+    /// });
+    /// assert_eq!(result, Err('B'));
+    /// assert_eq!(iter.as_str(), "foo=BAR");
+    /// ```
+    pub fn transaction<T, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, E>) -> Result<T, E> {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+        if result.is_err() {
+            self.rewind(checkpoint);
+        }
+        result
+    }
+}
@@ -0,0 +1,406 @@
+use core::num::NonZeroUsize;
+use core::ops::{Bound, RangeBounds};
+
+use crate::{StringIter, pattern::{Pattern, Sep, Search}};
+
+/// Truncate `s` to at most `len` [`char`]s,
+/// so a child pattern sees the same look-ahead as it would alone.
+#[inline]
+fn truncate(s: &str, len: NonZeroUsize) -> &str {
+    if len.get() == 1 {
+        return StringIter::new(s).peek().map(|(_, s)| s).unwrap_or(s);
+    }
+    match StringIter::new(s).peekn(len.get()) {
+        Ok(s) => s,
+        Err(s) => s,
+    }
+}
+
+/// Combinators for building new [`Pattern`]s out of existing ones.
+///
+/// Combined patterns look ahead for as many [`char`]s as their inputs require,
+/// each input only sees the look-ahead of its own [`len()`](Pattern::len).
+///
+/// Combined patterns use the [`Sep`] of `self`,
+/// use [`sep_with`](crate::SetSep::sep_with) to override it.
+///
+/// # Note
+///
+/// Combinators might call [`matches()`](Pattern::matches) on their inputs
+/// more or less than once per [`char`],
+/// stateful patterns like [`isize`] or [`Interval`](crate::patterns::Interval)
+/// are not expected to behave consistently.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::prelude::*;
+/// let ident = ('a'..='z').or('A'..='Z').or('_');
+/// let mut iter = "foo_Bar + baz".str_iter();
+/// assert_eq!(iter.next_slice(ident.not()), Some("foo_Bar"));
+///
+/// let mut iter = "a->b=>c".str_iter();
+/// let arrow = ['-', '='].followed_by('>');
+/// assert_eq!(iter.next_slice(arrow.sep_with(Sep::Split)), Some("a"));
+/// assert_eq!(iter.as_str(), "b=>c");
+/// ```
+pub trait PatternExt: Pattern + Sized {
+
+    /// Matches if either pattern matches.
+    ///
+    /// Both patterns are always called,
+    /// the longest [`match_len`](Pattern::match_len) is used if both match.
+    fn or<P: Pattern<Err = Self::Err>>(self, other: P) -> Or<Self, P> {
+        Or { left: self, right: other, matched: (false, false) }
+    }
+
+    /// Matches if both patterns match on the same [`char`].
+    ///
+    /// The longest [`match_len`](Pattern::match_len) is used.
+    fn and<P: Pattern<Err = Self::Err>>(self, other: P) -> And<Self, P> {
+        And { left: self, right: other }
+    }
+
+    /// Matches if this pattern does not match.
+    ///
+    /// The match length is always a single [`char`].
+    fn not(self) -> Not<Self> {
+        Not { pattern: self }
+    }
+
+    /// Matches if this pattern matches,
+    /// and `other` matches right after the end of this match.
+    ///
+    /// The match length is the sum of both match lengths.
+    fn followed_by<P: Pattern<Err = Self::Err>>(self, other: P) -> FollowedBy<Self, P> {
+        FollowedBy { left: self, right: other, matched: 0 }
+    }
+
+    /// Matches this pattern repeated consecutively,
+    /// as many times as possible within `range`, `range` can be unbounded.
+    ///
+    /// The look-ahead is that of this pattern times the maximum repetitions,
+    /// an unbounded `range` looks ahead to the end of the string.
+    /// Matching in reverse finds the right-most start of a match,
+    /// e.g. `'a'.repeated(2..)` matches `"aaa"` from the second `'a'`.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is empty or allows `0` repetitions.
+    fn repeated(self, range: impl RangeBounds<usize>) -> Repeated<Self> {
+        let min = match range.start_bound() {
+            Bound::Included(n) => *n,
+            Bound::Excluded(n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(n) => *n,
+            Bound::Excluded(n) => n.checked_sub(1)
+                .expect("repetition range cannot be empty"),
+            Bound::Unbounded => usize::MAX,
+        };
+        assert!(min != 0, "pattern cannot match an empty string");
+        assert!(min <= max, "repetition range cannot be empty");
+        Repeated { pattern: self, min, max, matched: 0 }
+    }
+
+    /// Matches this pattern only outside of quotes,
+    /// given as pairs of opening and closing quotes.
+    ///
+    /// Use [`escape()`](OutsideQuotes::escape) to also ignore
+    /// quotes and matches right after an escape [`char`].
+    ///
+    /// Quotes can only be recognized front to back,
+    /// so this pattern is intended for the [`search`](Pattern::search) fast paths,
+    /// and [`search_back`](Pattern::search_back) scans the whole string forward.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = r#"a,"b,c",d"#.str_iter().into_splits(','.outside_quotes(&[('"', '"')]));
+    /// assert_eq!(iter.next(), Some("a"));
+    /// assert_eq!(iter.next_back(), Some("d"));
+    /// assert_eq!(iter.next(), Some(r#""b,c""#));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn outside_quotes<'a>(self, quotes: &'a [(char, char)]) -> OutsideQuotes<'a, Self> {
+        OutsideQuotes { pattern: self, quotes, escape: None, quote: None, escaped: false }
+    }
+}
+
+impl<P> PatternExt for P where P: Pattern + Sized {}
+
+/// A pattern that matches if either pattern matches.
+///
+/// See [`PatternExt::or`]
+#[derive(Debug, Clone)]
+pub struct Or<A: Pattern, B: Pattern<Err = A::Err>> {
+    left: A,
+    right: B,
+    matched: (bool, bool),
+}
+
+impl<A: Pattern, B: Pattern<Err = A::Err>> Pattern for Or<A, B> {
+    type Err = A::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        let left = self.left.matches(c, truncate(s, self.left.len()))?;
+        let right = self.right.matches(c, truncate(s, self.right.len()))?;
+        self.matched = (left, right);
+        Ok(left || right)
+    }
+
+    fn len(&self) -> NonZeroUsize { self.left.len().max(self.right.len()) }
+
+    fn match_len(&self, c: char, s: &str) -> usize {
+        let left = match self.matched.0 {
+            true => self.left.match_len(c, truncate(s, self.left.len())),
+            false => 0,
+        };
+        let right = match self.matched.1 {
+            true => self.right.match_len(c, truncate(s, self.right.len())),
+            false => 0,
+        };
+        left.max(right)
+    }
+
+    fn sep(&self) -> Sep { self.left.sep() }
+}
+
+/// A pattern that matches if both patterns match.
+///
+/// See [`PatternExt::and`]
+#[derive(Debug, Clone)]
+pub struct And<A: Pattern, B: Pattern<Err = A::Err>> {
+    left: A,
+    right: B,
+}
+
+impl<A: Pattern, B: Pattern<Err = A::Err>> Pattern for And<A, B> {
+    type Err = A::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        let left = self.left.matches(c, truncate(s, self.left.len()))?;
+        let right = self.right.matches(c, truncate(s, self.right.len()))?;
+        Ok(left && right)
+    }
+
+    fn len(&self) -> NonZeroUsize { self.left.len().max(self.right.len()) }
+
+    fn match_len(&self, c: char, s: &str) -> usize {
+        self.left.match_len(c, truncate(s, self.left.len()))
+            .max(self.right.match_len(c, truncate(s, self.right.len())))
+    }
+
+    fn sep(&self) -> Sep { self.left.sep() }
+}
+
+/// A pattern that matches if the inner pattern does not match.
+///
+/// See [`PatternExt::not`]
+#[derive(Debug, Clone)]
+pub struct Not<P: Pattern> {
+    pattern: P,
+}
+
+impl<P: Pattern> Pattern for Not<P> {
+    type Err = P::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        Ok(!self.pattern.matches(c, s)?)
+    }
+
+    fn len(&self) -> NonZeroUsize { self.pattern.len() }
+
+    fn sep(&self) -> Sep { self.pattern.sep() }
+}
+
+/// A pattern that matches two patterns in sequence.
+///
+/// See [`PatternExt::followed_by`]
+#[derive(Debug, Clone)]
+pub struct FollowedBy<A: Pattern, B: Pattern<Err = A::Err>> {
+    left: A,
+    right: B,
+    matched: usize,
+}
+
+impl<A: Pattern, B: Pattern<Err = A::Err>> Pattern for FollowedBy<A, B> {
+    type Err = A::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        let first = truncate(s, self.left.len());
+        if !self.left.matches(c, first)? {
+            return Ok(false);
+        }
+        let len = self.left.match_len(c, first);
+        let rest = &s[len..];
+        let (c2, _) = match StringIter::new(rest).peek() {
+            Some(next) => next,
+            None => return Ok(false),
+        };
+        let second = truncate(rest, self.right.len());
+        if !self.right.matches(c2, second)? {
+            return Ok(false);
+        }
+        self.matched = len + self.right.match_len(c2, second);
+        Ok(true)
+    }
+
+    fn len(&self) -> NonZeroUsize { self.left.len().saturating_add(self.right.len().get()) }
+
+    fn match_len(&self, _: char, _: &str) -> usize { self.matched }
+
+    fn sep(&self) -> Sep { self.left.sep() }
+}
+
+/// A pattern that matches another pattern repeatedly.
+///
+/// See [`PatternExt::repeated`]
+#[derive(Debug, Clone)]
+pub struct Repeated<P: Pattern> {
+    pattern: P,
+    min: usize,
+    max: usize,
+    matched: usize,
+}
+
+impl<P: Pattern> Pattern for Repeated<P> {
+    type Err = P::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        let mut count = 0;
+        let mut len = 0;
+        let mut c = c;
+        while count < self.max {
+            let rest = truncate(&s[len..], self.pattern.len());
+            if !self.pattern.matches(c, rest)? {
+                break;
+            }
+            let match_len = self.pattern.match_len(c, rest);
+            if match_len == 0 {
+                break;
+            }
+            len += match_len;
+            count += 1;
+            match StringIter::new(&s[len..]).peek() {
+                Some((next, _)) => c = next,
+                None => break,
+            }
+        }
+        self.matched = len;
+        Ok(count >= self.min)
+    }
+
+    fn len(&self) -> NonZeroUsize {
+        self.pattern.len().saturating_mul(NonZeroUsize::new(self.max).unwrap())
+    }
+
+    fn match_len(&self, _: char, _: &str) -> usize { self.matched }
+
+    fn sep(&self) -> Sep { self.pattern.sep() }
+}
+
+/// A pattern that matches another pattern outside of quotes.
+///
+/// See [`PatternExt::outside_quotes`]
+#[derive(Debug, Clone)]
+pub struct OutsideQuotes<'a, P: Pattern> {
+    pattern: P,
+    quotes: &'a [(char, char)],
+    escape: Option<char>,
+    /// The closing quote if inside quotes.
+    quote: Option<char>,
+    escaped: bool,
+}
+
+impl<'a, P: Pattern> OutsideQuotes<'a, P> {
+    /// Ignore quotes and matches right after `escape`, inside or outside of quotes.
+    pub fn escape(self, escape: char) -> Self {
+        OutsideQuotes { escape: Some(escape), ..self }
+    }
+
+    /// Returns the pairs of opening and closing quotes.
+    pub fn quotes(&self) -> &'a [(char, char)] {
+        self.quotes
+    }
+
+    /// Returns the escape [`char`].
+    pub fn escape_char(&self) -> Option<char> {
+        self.escape
+    }
+
+    fn reset(&mut self) {
+        self.quote = None;
+        self.escaped = false;
+    }
+
+    /// Scan `s` from the front, calls `f` on each match until it returns `false`.
+    fn scan(&mut self, s: &str, mut f: impl FnMut(usize, usize) -> bool) -> Result<(), P::Err> {
+        self.reset();
+        for (index, c) in s.char_indices() {
+            let rest = truncate(&s[index..], self.len());
+            if self.matches(c, rest)? && !f(index, self.match_len(c, rest)) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P: Pattern> Pattern for OutsideQuotes<'_, P> {
+    type Err = P::Err;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        if self.escaped {
+            self.escaped = false;
+            return Ok(false);
+        }
+        if Some(c) == self.escape {
+            self.escaped = true;
+            return Ok(false);
+        }
+        match self.quote {
+            Some(close) => {
+                if c == close {
+                    self.quote = None;
+                }
+                Ok(false)
+            }
+            None => match self.quotes.iter().find(|(open, _)| *open == c) {
+                Some((_, close)) => {
+                    self.quote = Some(*close);
+                    Ok(false)
+                }
+                None => self.pattern.matches(c, s),
+            }
+        }
+    }
+
+    fn len(&self) -> NonZeroUsize { self.pattern.len() }
+
+    fn match_len(&self, c: char, s: &str) -> usize { self.pattern.match_len(c, s) }
+
+    fn sep(&self) -> Sep { self.pattern.sep() }
+
+    fn search(&mut self, s: &str) -> Search<Self::Err> {
+        let mut found = Search::NotFound;
+        match self.scan(s, |index, len| {
+            found = Search::Found(index, len);
+            false
+        }) {
+            Ok(()) => found,
+            Err(err) => Search::Error(err),
+        }
+    }
+
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+        let mut found = Search::NotFound;
+        match self.scan(s, |index, len| {
+            found = Search::Found(index, len);
+            true
+        }) {
+            Ok(()) => found,
+            Err(err) => Search::Error(err),
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use alloc::borrow::Cow;
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+use crate::{StringIter, Position, PatternExt, ParseError, Expected};
+use crate::split::QuotedSplitIter;
+use crate::search::find_bytes;
+
+const QUOTES: &[(char, char)] = &[('"', '"')];
+
+/// The reason a CSV record failed to parse, see [`CsvError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvErrorKind {
+    /// A quoted field is not closed.
+    UnterminatedQuote,
+    /// A `"` inside an unquoted field.
+    UnexpectedQuote,
+    /// A [`char`] other than a delimiter or a line break after a quoted field.
+    UnexpectedChar,
+    /// A record does not have the same number of fields as the first record,
+    /// this is not reported in [`flexible`](CsvReader::flexible) mode.
+    ColumnCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// An error returned by [`CsvReader`].
+///
+/// After a syntax error, the reader skips to the next line break after the error,
+/// after a [`ColumnCount`](CsvErrorKind::ColumnCount) error it skips the record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CsvError {
+    kind: CsvErrorKind,
+    position: Position,
+}
+
+impl CsvError {
+    /// Returns the reason of the error.
+    pub const fn kind(&self) -> CsvErrorKind {
+        self.kind
+    }
+
+    /// Returns the [`Position`] of the error,
+    /// or of the start of the record for [`ColumnCount`](CsvErrorKind::ColumnCount).
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Convert into a [`ParseError`] in `source`, the input of the [`CsvReader`],
+    /// expecting [`Named("a CSV record")`](Expected::Named).
+    ///
+    /// # Panics
+    ///
+    /// If the position is not a [`char`] boundary of `source`.
+    pub fn into_parse_error(self, source: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Named("a CSV record"), &source[self.position.byte..])
+    }
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            CsvErrorKind::UnterminatedQuote => f.write_str("unterminated quoted field")?,
+            CsvErrorKind::UnexpectedQuote => f.write_str("unexpected quote in unquoted field")?,
+            CsvErrorKind::UnexpectedChar => f.write_str("expected a delimiter after quoted field")?,
+            CsvErrorKind::ColumnCount { expected, found } => write!(f, "expected {} fields, found {}", expected, found)?,
+        }
+        write!(f, " at {}", self.position)
+    }
+}
+
+const _: () = {
+    extern crate std;
+    impl std::error::Error for CsvError {}
+};
+
+/// Returns the length of a line break at the start of `s`.
+fn line_break(s: &str) -> Option<usize> {
+    if s.starts_with('\n') {
+        Some(1)
+    } else if s.starts_with("\r\n") {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Advance `position` over `s`, with the line breaks of [`line_break`],
+/// so a lone `\r` is not a line break.
+fn advance(mut position: Position, s: &str) -> Position {
+    for c in s.chars() {
+        position.byte += c.len_utf8();
+        position.char += 1;
+        if c == '\n' {
+            position.line += 1;
+            position.column = 1;
+        } else {
+            position.column += 1;
+        }
+    }
+    position
+}
+
+/// Scan a record, returns the length of the record,
+/// the length of its line break and the number of fields.
+///
+/// On failure, returns the error and its byte offset.
+fn scan_record(s: &str, delimiter: char) -> Result<(usize, usize, usize), (CsvErrorKind, usize)> {
+    let bytes = s.as_bytes();
+    let mut index = 0;
+    let mut fields = 1;
+    loop {
+        if bytes.get(index) == Some(&b'"') {
+            let open = index;
+            index += 1;
+            loop {
+                match find_bytes(b"\"", &bytes[index..]) {
+                    Some(quote) => index += quote + 1,
+                    None => return Err((CsvErrorKind::UnterminatedQuote, open)),
+                }
+                // `""` is an escaped quote
+                if bytes.get(index) != Some(&b'"') {
+                    break;
+                }
+                index += 1;
+            }
+            let rest = &s[index..];
+            if !(rest.is_empty() || rest.starts_with(delimiter) || line_break(rest).is_some()) {
+                return Err((CsvErrorKind::UnexpectedChar, index));
+            }
+        } else {
+            index = s[index..].char_indices()
+                .find(|(offset, c)| *c == delimiter || *c == '"' || line_break(&s[index + offset..]).is_some())
+                .map_or(s.len(), |(offset, _)| index + offset);
+            if bytes.get(index) == Some(&b'"') {
+                return Err((CsvErrorKind::UnexpectedQuote, index));
+            }
+        }
+        let rest = &s[index..];
+        if rest.is_empty() {
+            return Ok((index, 0, fields));
+        }
+        if let Some(len) = line_break(rest) {
+            return Ok((index, len, fields));
+        }
+        index += delimiter.len_utf8();
+        fields += 1;
+    }
+}
+
+/// A zero-copy [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) CSV reader,
+/// that yields [`CsvRecord`]s.
+///
+/// * Records are separated by `"\n"` or `"\r\n"`, a blank line is a record
+///   with a single empty field, see [`skip_blank_lines()`](CsvReader::skip_blank_lines).
+/// * Fields can be quoted with `"`, quoted fields can contain delimiters,
+///   line breaks and `""` as an escaped quote.
+/// * By default, all records must have the same number of fields as the first record,
+///   see [`flexible()`](CsvReader::flexible).
+///
+/// After an error, the reader continues with the next line or record, see [`CsvError`].
+/// Positions count `"\n"` and `"\r\n"` as line breaks, but not a lone `"\r"`.
+///
+/// This requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{CsvReader, CsvErrorKind};
+/// let csv = "name,quote\r\nferris,\"says \"\"hi\"\"\"\r\ncorro,\"multi\nline\"\r\n";
+/// let mut reader = CsvReader::new(csv).has_headers(true);
+/// assert_eq!(reader.header_index("quote"), Some(1));
+///
+/// let record = reader.next().unwrap().unwrap();
+/// assert_eq!(record.collect::<Vec<_>>(), ["ferris", "says \"hi\""]);
+///
+/// let record = reader.next().unwrap().unwrap();
+/// assert_eq!(record.position().line, 3);
+/// assert_eq!(record.get(1).unwrap(), "multi\nline");
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvReader<'t> {
+    iter: StringIter<'t>,
+    /// The position of the front of `iter`.
+    position: Position,
+    delimiter: char,
+    has_headers: bool,
+    flexible: bool,
+    skip_blank_lines: bool,
+    headers: Option<CsvRecord<'t>>,
+    columns: Option<usize>,
+}
+
+impl<'t> CsvReader<'t> {
+    /// Construct a comma separated [`CsvReader`] without headers.
+    pub fn new(s: &'t str) -> Self {
+        CsvReader {
+            iter: StringIter::new(s),
+            position: Position::START,
+            delimiter: ',',
+            has_headers: false,
+            flexible: false,
+            skip_blank_lines: false,
+            headers: None,
+            columns: None,
+        }
+    }
+
+    /// Construct a tab separated [`CsvReader`] without headers.
+    pub fn tsv(s: &'t str) -> Self {
+        CsvReader::new(s).delimiter('\t')
+    }
+
+    /// Set the delimiter, by default `,`.
+    ///
+    /// # Panics
+    ///
+    /// If `delimiter` is `"`, `\r` or `\n`.
+    pub fn delimiter(self, delimiter: char) -> Self {
+        assert!(!matches!(delimiter, '"' | '\r' | '\n'), "invalid delimiter {:?}", delimiter);
+        CsvReader { delimiter, ..self }
+    }
+
+    /// If `true`, the first record is read as headers
+    /// and is not yielded by the iterator.
+    pub fn has_headers(self, has_headers: bool) -> Self {
+        CsvReader { has_headers, ..self }
+    }
+
+    /// If `true`, records can have different numbers of fields.
+    pub fn flexible(self, flexible: bool) -> Self {
+        CsvReader { flexible, ..self }
+    }
+
+    /// If `true`, blank lines are skipped instead of yielded
+    /// as records with a single empty field.
+    pub fn skip_blank_lines(self, skip_blank_lines: bool) -> Self {
+        CsvReader { skip_blank_lines, ..self }
+    }
+
+    /// Returns the header record, reading it if needed.
+    ///
+    /// Returns `Ok(None)` if the reader has no headers or the input is empty.
+    pub fn headers(&mut self) -> Result<Option<CsvRecord<'t>>, CsvError> {
+        if self.has_headers && self.headers.is_none() {
+            self.headers = self.read_record().transpose()?;
+        }
+        Ok(self.headers.clone())
+    }
+
+    /// Returns the index of the column with the header `name`.
+    pub fn header_index(&mut self, name: &str) -> Option<usize> {
+        let mut headers = self.headers().ok()??;
+        Iterator::position(&mut headers, |header| header == name)
+    }
+
+    fn read_record(&mut self) -> Option<Result<CsvRecord<'t>, CsvError>> {
+        loop {
+            let s = self.iter.as_str();
+            if s.is_empty() {
+                return None;
+            }
+            let (len, line_break, fields) = match scan_record(s, self.delimiter) {
+                Ok(result) => result,
+                Err((kind, offset)) => {
+                    let position = advance(self.position, &s[..offset]);
+                    // skip to the next line
+                    let next = find_bytes(b"\n", &s.as_bytes()[offset..])
+                        .map_or(s.len(), |index| offset + index + 1);
+                    self.position = advance(position, &s[offset..next]);
+                    self.iter.str = &s[next..];
+                    return Some(Err(CsvError { kind, position }));
+                }
+            };
+            let position = self.position;
+            let raw = &s[..len];
+            self.position = advance(position, &s[..len + line_break]);
+            self.iter.str = &s[len + line_break..];
+            if raw.is_empty() && self.skip_blank_lines {
+                continue;
+            }
+            let expected = *self.columns.get_or_insert(fields);
+            if !self.flexible && fields != expected {
+                let kind = CsvErrorKind::ColumnCount { expected, found: fields };
+                return Some(Err(CsvError { kind, position }));
+            }
+            return Some(Ok(CsvRecord {
+                raw,
+                fields: StringIter::new(raw).into_quoted_splits(self.delimiter.outside_quotes(QUOTES)),
+                remaining: fields,
+                position,
+            }));
+        }
+    }
+}
+
+impl<'t> Iterator for CsvReader<'t> {
+    type Item = Result<CsvRecord<'t>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(err) = self.headers() {
+            return Some(Err(err));
+        }
+        self.read_record()
+    }
+}
+
+impl FusedIterator for CsvReader<'_> {}
+
+/// A record of a CSV file, which is an iterator of its fields.
+///
+/// Unquoted fields and quoted fields without `""` are borrowed.
+///
+/// See [`CsvReader`].
+#[derive(Debug, Clone)]
+pub struct CsvRecord<'t> {
+    raw: &'t str,
+    fields: QuotedSplitIter<'t, 'static, char>,
+    remaining: usize,
+    position: Position,
+}
+
+impl<'t> CsvRecord<'t> {
+    /// Returns the record without its line break.
+    pub const fn as_str(&self) -> &'t str {
+        self.raw
+    }
+
+    /// Returns the [`Position`] of the start of the record.
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the remaining field at `index`.
+    pub fn get(&self, index: usize) -> Option<Cow<'t, str>> {
+        self.clone().nth(index)
+    }
+
+    /// Map the record into an iterator of raw fields, with quotes not removed.
+    pub fn into_raw_fields(self) -> QuotedSplitIter<'t, 'static, char> {
+        self.fields
+    }
+
+    /// Decode a field, `None` is an empty field at the end of the record
+    /// that is not yielded by [`into_quoted_splits`](StringIter::into_quoted_splits).
+    fn field(&mut self, field: Option<&'t str>) -> Option<Cow<'t, str>> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let field = field.unwrap_or("");
+        Some(match field.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            Some(quoted) if quoted.contains("\"\"") => Cow::Owned(quoted.replace("\"\"", "\"")),
+            Some(quoted) => Cow::Borrowed(quoted),
+            None => Cow::Borrowed(field),
+        })
+    }
+}
+
+impl<'t> Iterator for CsvRecord<'t> {
+    type Item = Cow<'t, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.fields.next();
+        self.field(field)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for CsvRecord<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let field = self.fields.next_back();
+        self.field(field)
+    }
+}
+
+impl ExactSizeIterator for CsvRecord<'_> {}
+
+impl FusedIterator for CsvRecord<'_> {}
+
+impl<'t> StringIter<'t> {
+    /// Convert into a comma separated [`CsvReader`], see [`CsvReader::new`].
+    pub fn into_csv(self) -> CsvReader<'t> {
+        CsvReader::new(self.str)
+    }
+}
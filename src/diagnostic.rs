@@ -0,0 +1,302 @@
+use core::fmt::{Display, Formatter, Write};
+use core::ops::Range;
+
+use crate::{StringIter, Span, Position};
+use crate::tracked::{is_line_break, line_start};
+use crate::merge::substr_range;
+use crate::unicode_tables::WIDE;
+
+/// Returns the display width of a grapheme cluster, or `None` for a tab.
+fn grapheme_width(s: &str) -> Option<usize> {
+    let c = s.chars().next().unwrap_or(' ');
+    if c == '\t' {
+        return None;
+    }
+    let wide = s.contains('\u{FE0F}') || WIDE.binary_search_by(|(lo, hi)| {
+        if *hi < c as u32 {
+            core::cmp::Ordering::Less
+        } else if *lo > c as u32 {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }).is_ok();
+    Some(match c {
+        _ if wide => 2,
+        c if c.is_control() => 0,
+        _ => 1,
+    })
+}
+
+/// The severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+        })
+    }
+}
+
+/// The part of the source a [`Label`] points to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LabelTarget<'a> {
+    /// A substring of the source, like one obtained from a [`StringIter`].
+    Str(&'a str),
+    /// A byte range of the source.
+    Span(Span),
+}
+
+impl<'a> From<&'a str> for LabelTarget<'a> {
+    fn from(value: &'a str) -> Self {
+        LabelTarget::Str(value)
+    }
+}
+
+impl From<Span> for LabelTarget<'_> {
+    fn from(value: Span) -> Self {
+        LabelTarget::Span(value)
+    }
+}
+
+impl From<Range<usize>> for LabelTarget<'_> {
+    fn from(value: Range<usize>) -> Self {
+        LabelTarget::Span(value.into())
+    }
+}
+
+/// A labelled part of the source in a [`Diagnostic`].
+///
+/// Primary labels are underlined with `^`, secondary labels with `-`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label<'a> {
+    target: LabelTarget<'a>,
+    message: &'a str,
+    primary: bool,
+}
+
+impl<'a> Label<'a> {
+    /// Construct a primary [`Label`], the first primary label
+    /// is the location of the [`Diagnostic`].
+    pub fn primary(target: impl Into<LabelTarget<'a>>, message: &'a str) -> Self {
+        Label { target: target.into(), message, primary: true }
+    }
+
+    /// Construct a secondary [`Label`].
+    pub fn secondary(target: impl Into<LabelTarget<'a>>, message: &'a str) -> Self {
+        Label { target: target.into(), message, primary: false }
+    }
+
+    /// Returns the byte range in `source`, if valid.
+    fn range(&self, source: &str) -> Option<Range<usize>> {
+        match &self.target {
+            LabelTarget::Str(s) => substr_range(source, s),
+            LabelTarget::Span(span) => span.as_str(source).map(|_| span.range()),
+        }
+    }
+}
+
+/// A rustc style diagnostic with source snippets, that can be rendered
+/// to any [`core::fmt::Write`] without allocating.
+///
+/// * Each labelled line is printed with tabs expanded,
+///   and each [`Label`] is underlined on its own row.
+///   A label spanning multiple lines is underlined to the end of its first line.
+/// * Underlines are aligned by display width,
+///   wide characters like CJK and emoji take 2 columns.
+/// * Gaps between labelled lines are shown as `...`.
+/// * Labels outside the source are ignored.
+///
+/// Lines and columns are computed like [`Position`], in [`char`]s starting from `1`.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, Diagnostic, Label, Level};
+/// let source = "let x = 1\nlet y = x +\n";
+/// let mut iter = source.str_iter();
+/// iter.skip_front(4);
+/// let x = iter.peekn(1).unwrap();
+/// iter.next_slice('+'.sep_with(Sep::Retain));
+/// let plus = iter.peekn(1).unwrap();
+///
+/// let labels = [
+///     Label::primary(plus, "expected an expression after this"),
+///     Label::secondary(x, "`x` defined here"),
+/// ];
+/// let diagnostic = Diagnostic::new(Level::Error, "unexpected end of line", source)
+///     .file("main.calc")
+///     .labels(&labels)
+///     .notes(&["expressions cannot end with an operator"]);
+/// assert_eq!(diagnostic.to_string(), "\
+/// error: unexpected end of line
+///  --> main.calc:2:11
+///   |
+/// 1 | let x = 1
+///   |     - `x` defined here
+/// 2 | let y = x +
+///   |           ^ expected an expression after this
+///   |
+///   = note: expressions cannot end with an operator
+/// ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Diagnostic<'a> {
+    level: Level,
+    message: &'a str,
+    source: &'a str,
+    file: Option<&'a str>,
+    labels: &'a [Label<'a>],
+    notes: &'a [&'a str],
+    tab_width: usize,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Construct a [`Diagnostic`] without labels in `source`.
+    pub const fn new(level: Level, message: &'a str, source: &'a str) -> Self {
+        Diagnostic {
+            level,
+            message,
+            source,
+            file: None,
+            labels: &[],
+            notes: &[],
+            tab_width: 4,
+        }
+    }
+
+    /// Set the file name shown before the location.
+    pub const fn file(self, file: &'a str) -> Self {
+        Diagnostic { file: Some(file), ..self }
+    }
+
+    /// Set the labels of this [`Diagnostic`].
+    pub const fn labels(self, labels: &'a [Label<'a>]) -> Self {
+        Diagnostic { labels, ..self }
+    }
+
+    /// Set the notes shown after the snippet.
+    pub const fn notes(self, notes: &'a [&'a str]) -> Self {
+        Diagnostic { notes, ..self }
+    }
+
+    /// Set the width of a tab stop, by default `4`.
+    ///
+    /// # Panics
+    ///
+    /// If `tab_width` is `0`.
+    pub const fn tab_width(self, tab_width: usize) -> Self {
+        assert!(tab_width != 0, "tab_width cannot be 0");
+        Diagnostic { tab_width, ..self }
+    }
+
+    /// Returns the display column after writing `s` from column `column`.
+    fn advance(&self, s: &str, column: usize) -> usize {
+        StringIter::new(s).graphemes().fold(column, |column, g| match grapheme_width(g) {
+            Some(width) => column + width,
+            None => (column / self.tab_width + 1) * self.tab_width,
+        })
+    }
+
+    /// Returns the range of the line containing byte offset `offset`, without its line break,
+    /// and the offset of the next line, past the end of `source` on the last line.
+    fn line_range(&self, offset: usize) -> (Range<usize>, usize) {
+        let bytes = self.source.as_bytes();
+        let start = line_start(bytes, offset);
+        let end = (offset..bytes.len()).find(|index| is_line_break(bytes, *index)).unwrap_or(bytes.len());
+        let next = end + 1;
+        let end = if self.source[start..end].ends_with('\r') { end - 1 } else { end };
+        (start..end, next)
+    }
+
+    /// Returns the line number of byte offset `offset`.
+    fn line_number(&self, offset: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        (0..offset).filter(|index| is_line_break(bytes, *index)).count() + 1
+    }
+
+    /// Write the diagnostic to `w`.
+    pub fn render(&self, w: &mut impl Write) -> core::fmt::Result {
+        writeln!(w, "{}: {}", self.level, self.message)?;
+        let source = self.source;
+        let labels = || self.labels.iter().filter_map(|label| Some((label, label.range(source)?)));
+        let location = labels().find(|(label, _)| label.primary).or_else(|| labels().next());
+        let gutter = labels()
+            .map(|(_, range)| range.start)
+            .max()
+            .map_or(0, |offset| self.line_number(offset).ilog10() as usize + 1);
+        if let Some((_, range)) = &location {
+            let position = Position::START.move_to(source, range.start);
+            write!(w, "{:gutter$}--> ", "")?;
+            if let Some(file) = self.file {
+                write!(w, "{}:", file)?;
+            }
+            writeln!(w, "{}", position)?;
+            writeln!(w, "{:gutter$} |", "")?;
+        }
+        // labelled lines in order, without allocating: `cursor` is the start of line `line`,
+        // and line numbers are counted forward from it so the source is scanned once
+        let bytes = source.as_bytes();
+        let mut cursor = 0;
+        let mut line = 1;
+        while let Some(offset) = labels().map(|(_, range)| range.start).filter(|start| *start >= cursor).min() {
+            let (bounds, next) = self.line_range(offset);
+            let skipped = (cursor..bounds.start).filter(|index| is_line_break(bytes, *index)).count();
+            if skipped > 0 && cursor > 0 {
+                writeln!(w, "...")?;
+            }
+            line += skipped;
+            write!(w, "{:>gutter$} | ", line)?;
+            let mut column = 0;
+            for g in StringIter::new(&source[bounds.clone()]).graphemes() {
+                let next = self.advance(g, column);
+                match g {
+                    "\t" => write!(w, "{:1$}", "", next - column)?,
+                    g => w.write_str(g)?,
+                }
+                column = next;
+            }
+            writeln!(w)?;
+            for (label, range) in labels().filter(|(_, range)| (bounds.start..next).contains(&range.start)) {
+                let start = range.start.min(bounds.end);
+                let end = range.end.clamp(start, bounds.end);
+                let pad = self.advance(&source[bounds.start..start], 0);
+                let len = (self.advance(&source[start..end], pad) - pad).max(1);
+                write!(w, "{:gutter$} | {:pad$}", "", "")?;
+                let mark = if label.primary { '^' } else { '-' };
+                (0..len).try_for_each(|_| w.write_char(mark))?;
+                match label.message {
+                    "" => writeln!(w)?,
+                    message => writeln!(w, " {}", message)?,
+                }
+            }
+            cursor = next;
+            line += 1;
+        }
+        if !self.notes.is_empty() {
+            if location.is_some() {
+                writeln!(w, "{:gutter$} |", "")?;
+            }
+            for note in self.notes {
+                writeln!(w, "{:gutter$} = note: {}", "", note)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.render(f)
+    }
+}
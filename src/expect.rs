@@ -0,0 +1,233 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{StringIter, Position, NumberErrorKind, QuoteErrorKind};
+use crate::merge::substr_range;
+use crate::pattern::{Pattern, Never, BalanceErrorKind};
+
+/// What a [`ParseError`] expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expected<'t> {
+    /// A [`char`], see [`expect_char()`](StringIter::expect_char).
+    Char(char),
+    /// A [`str`], see [`expect_str()`](StringIter::expect_str).
+    Str(&'t str),
+    /// A number of [`char`]s, see [`expect_chars()`](StringIter::expect_chars).
+    Chars(usize),
+    /// A match of a [`Pattern`], see [`expect()`](StringIter::expect).
+    Pattern,
+    /// A match of a named [`Pattern`], see [`expect_named()`](StringIter::expect_named).
+    Named(&'t str),
+    /// A number, see [`NumberError::into_parse_error()`](crate::NumberError::into_parse_error).
+    Number(NumberErrorKind),
+    /// A string literal, see [`QuoteError::into_parse_error()`](crate::QuoteError::into_parse_error).
+    Quoted(QuoteErrorKind),
+    /// Balanced delimiters, see [`BalanceError::into_parse_error()`](crate::patterns::BalanceError::into_parse_error).
+    Balanced(BalanceErrorKind),
+}
+
+impl Display for Expected<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "{:?}", c),
+            Expected::Str(s) => write!(f, "{:?}", s),
+            Expected::Chars(1) => f.write_str("1 char"),
+            Expected::Chars(n) => write!(f, "{} chars", n),
+            Expected::Pattern => f.write_str("a match of the pattern"),
+            Expected::Named(name) => f.write_str(name),
+            Expected::Number(_) => f.write_str("a number"),
+            Expected::Quoted(_) => f.write_str("a string literal"),
+            Expected::Balanced(_) => f.write_str("balanced delimiters"),
+        }
+    }
+}
+
+/// An error returned by the `expect_*` family of [`StringIter`],
+/// errors of other parsers can be converted with their `into_parse_error()`.
+///
+/// The error remembers the input at the error,
+/// which is a substring of the source and can be located with
+/// [`offset_in()`](ParseError::offset_in) or [`position_in()`](ParseError::position_in).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError<'t> {
+    expected: Expected<'t>,
+    at: &'t str,
+}
+
+impl<'t> ParseError<'t> {
+    /// Construct a [`ParseError`] at the front of `at`.
+    pub const fn new(expected: Expected<'t>, at: &'t str) -> Self {
+        ParseError { expected, at }
+    }
+
+    /// Returns what was expected.
+    pub const fn expected(&self) -> Expected<'t> {
+        self.expected
+    }
+
+    /// Returns the [`char`] found at the error, `None` at the end of the input.
+    pub fn found(&self) -> Option<char> {
+        self.at.chars().next()
+    }
+
+    /// Returns the remaining input at the error.
+    pub const fn as_str(&self) -> &'t str {
+        self.at
+    }
+
+    /// Returns the byte offset of the error in `source`,
+    /// the string that the failing [`StringIter`] was created from.
+    ///
+    /// Returns `None` if the error is not in `source`.
+    pub fn offset_in(&self, source: &str) -> Option<usize> {
+        substr_range(source, self.at).map(|range| range.start)
+    }
+
+    /// Returns the [`Position`] of the error in `source`,
+    /// which contains the byte and [`char`] offsets.
+    ///
+    /// Returns `None` if the error is not in `source`.
+    pub fn position_in(&self, source: &str) -> Option<Position> {
+        self.offset_in(source).map(|offset| Position::START.move_to(source, offset))
+    }
+}
+
+impl Display for ParseError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected {}, found ", self.expected)?;
+        match (self.expected, self.found()) {
+            (Expected::Chars(_), _) => match self.at.chars().count() {
+                1 => f.write_str("1 char"),
+                n => write!(f, "{} chars", n),
+            },
+            (_, Some(c)) => write!(f, "{:?}", c),
+            (_, None) => f.write_str("end of input"),
+        }
+    }
+}
+
+#[cfg(feature="std")]
+const _: () = {
+    extern crate std;
+    impl std::error::Error for ParseError<'_> {}
+};
+
+impl<'t> StringIter<'t> {
+
+    /// Consume a leading [`char`] `c`, and returns it as a [`&str`](str).
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, Expected};
+    /// let source = "(a]";
+    /// let mut iter = source.str_iter();
+    /// assert_eq!(iter.expect_char('('), Ok("("));
+    /// iter.next();
+    /// let err = iter.expect_char(')').unwrap_err();
+    /// assert_eq!(err.expected(), Expected::Char(')'));
+    /// assert_eq!(err.found(), Some(']'));
+    /// assert_eq!(err.offset_in(source), Some(2));
+    /// assert_eq!(err.to_string(), "expected ')', found ']'");
+    /// ```
+    pub fn expect_char(&mut self, c: char) -> Result<&'t str, ParseError<'t>> {
+        match self.str.strip_prefix(c) {
+            Some(rest) => {
+                let result = &self.str[..c.len_utf8()];
+                self.str = rest;
+                Ok(result)
+            }
+            None => Err(ParseError::new(Expected::Char(c), self.str)),
+        }
+    }
+
+    /// Consume a leading [`str`] `s`, and returns it as a [`&str`](str) of the iterator.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "let x".str_iter();
+    /// assert_eq!(iter.expect_str("let"), Ok("let"));
+    /// assert_eq!(iter.expect_str("x").unwrap_err().to_string(), "expected \"x\", found ' '");
+    /// ```
+    pub fn expect_str<'a>(&mut self, s: &'a str) -> Result<&'t str, ParseError<'a>> where 't: 'a {
+        match self.str.strip_prefix(s) {
+            Some(rest) => {
+                let result = &self.str[..s.len()];
+                self.str = rest;
+                Ok(result)
+            }
+            None => Err(ParseError::new(Expected::Str(s), self.str)),
+        }
+    }
+
+    /// Consume exactly `n` [`char`]s.
+    ///
+    /// This is the fallible version of [`peekn()`](StringIter::peekn) that advances the iterator.
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "🦀ab".str_iter();
+    /// assert_eq!(iter.expect_chars(2), Ok("🦀a"));
+    /// assert_eq!(iter.expect_chars(2).unwrap_err().to_string(), "expected 2 chars, found 1 char");
+    /// ```
+    pub fn expect_chars(&mut self, n: usize) -> Result<&'t str, ParseError<'t>> {
+        match self.peekn(n) {
+            Ok(s) => {
+                self.str = &self.str[s.len()..];
+                Ok(s)
+            }
+            Err(_) => Err(ParseError::new(Expected::Chars(n), self.str)),
+        }
+    }
+
+    /// Consume a match of a [`Pattern`] at the front of the iterator,
+    /// the length of the match is determined by [`match_len`](Pattern::match_len).
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "3+x".str_iter();
+    /// assert_eq!(iter.expect('0'..='9'), Ok("3"));
+    /// assert_eq!(iter.expect(['+', '-']), Ok("+"));
+    /// assert!(iter.expect('0'..='9').is_err());
+    /// assert_eq!(iter.as_str(), "x");
+    /// ```
+    pub fn expect<P: Pattern<Err = Never>>(&mut self, mut pat: P) -> Result<&'t str, ParseError<'t>> {
+        let err = ParseError::new(Expected::Pattern, self.str);
+        let (c, _) = self.peek().ok_or(err)?;
+        let s = match self.peekn(pat.len().get()) {
+            Ok(s) => s,
+            Err(s) => s,
+        };
+        match pat.matches(c, s) {
+            Ok(true) => (),
+            Ok(false) => return Err(err),
+            Err(never) => match never {},
+        }
+        let len = pat.match_len(c, s);
+        assert!(s.is_char_boundary(len), "match_len() is not a char boundary of the matched str");
+        let (result, rest) = self.str.split_at(len);
+        self.str = rest;
+        Ok(result)
+    }
+
+    /// Consume a match of a [`Pattern`] at the front of the iterator like [`expect()`](StringIter::expect),
+    /// the error describes the pattern with `name`.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, Expected};
+    /// let mut iter = "x".str_iter();
+    /// let err = iter.expect_named('0'..='9', "a digit").unwrap_err();
+    /// assert_eq!(err.expected(), Expected::Named("a digit"));
+    /// assert_eq!(err.to_string(), "expected a digit, found 'x'");
+    /// ```
+    pub fn expect_named<'a, P: Pattern<Err = Never>>(&mut self, pat: P, name: &'a str)
+            -> Result<&'t str, ParseError<'a>> where 't: 'a {
+        self.expect(pat).map_err(|err| ParseError { expected: Expected::Named(name), ..err })
+    }
+}
@@ -0,0 +1,232 @@
+use core::cmp::Ordering;
+
+use crate::StringIter;
+use crate::unicode_tables::{GraphemeCat, GRAPHEME_TABLE};
+
+/// Binary search a range table generated by `scripts/unicode.py`.
+pub(crate) fn lookup<T: Copy>(c: char, table: &[(u32, u32, T)], default: T) -> T {
+    let c = c as u32;
+    match table.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            Ordering::Less
+        } else if lo > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(index) => table[index].2,
+        Err(_) => default,
+    }
+}
+
+fn grapheme_cat(c: char) -> GraphemeCat {
+    match c {
+        '\r' => GraphemeCat::CR,
+        '\n' => GraphemeCat::LF,
+        '\0'..='\x1f' | '\x7f' => GraphemeCat::Control,
+        '\x20'..='\x7e' => GraphemeCat::Other,
+        _ => lookup(c, GRAPHEME_TABLE, GraphemeCat::Other),
+    }
+}
+
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    !c.is_ascii() && grapheme_cat(c) == GraphemeCat::ExtendedPictographic
+}
+
+/// Returns `true` if the [`GraphemeCat`] has `Grapheme_Cluster_Break=Extend`.
+fn is_extend(cat: GraphemeCat) -> bool {
+    matches!(cat, GraphemeCat::Extend | GraphemeCat::InCBExtend | GraphemeCat::InCBLinker)
+}
+
+/// Returns `true` if there is a grapheme cluster boundary between `before` and `next`,
+/// following the rules in [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules).
+///
+/// `before` is all of the text preceding `next` that is known,
+/// it is scanned backwards for the context of GB9c, GB11 and GB12/GB13,
+/// so it must start on a grapheme cluster boundary, e.g. the start of a `StringIter`.
+fn is_boundary(before: &str, next: char) -> bool {
+    use GraphemeCat::*;
+    let Some(prev) = before.chars().next_back() else {
+        return true;
+    };
+    // fast path: ascii only breaks between CR LF
+    if prev.is_ascii() && next.is_ascii() {
+        return !(prev == '\r' && next == '\n');
+    }
+    let (prev_cat, next_cat) = (grapheme_cat(prev), grapheme_cat(next));
+    match (prev_cat, next_cat) {
+        // GB3
+        (CR, LF) => false,
+        // GB4, GB5
+        (CR | LF | Control, _) | (_, CR | LF | Control) => true,
+        // GB6, GB7, GB8
+        (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+        // GB9, GB9a, GB9b
+        (_, Extend | InCBExtend | InCBLinker | ZWJ | SpacingMark) | (Prepend, _) => false,
+        // GB9c
+        (InCBExtend | InCBLinker | ZWJ, InCBConsonant) => {
+            let mut linker = false;
+            for c in before.chars().rev() {
+                match grapheme_cat(c) {
+                    InCBLinker => linker = true,
+                    InCBExtend | ZWJ => (),
+                    InCBConsonant => return !linker,
+                    _ => return true,
+                }
+            }
+            true
+        }
+        // GB11
+        (ZWJ, ExtendedPictographic) => {
+            let mut chars = before.chars().rev().skip(1).map(grapheme_cat);
+            !matches!(chars.find(|cat| !is_extend(*cat)), Some(ExtendedPictographic))
+        }
+        // GB12, GB13
+        (RegionalIndicator, RegionalIndicator) => {
+            let count = before.chars().rev()
+                .take_while(|c| grapheme_cat(*c) == RegionalIndicator)
+                .count();
+            count % 2 == 0
+        }
+        // GB999
+        _ => true,
+    }
+}
+
+/// Returns the byte length of the first grapheme cluster in `s`.
+///
+/// `s` must start on a grapheme cluster boundary.
+fn grapheme_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    chars.next();
+    for (index, c) in chars {
+        // SAFETY: safe since index is a char boundary
+        if is_boundary(unsafe { s.get_unchecked(..index) }, c) {
+            return index;
+        }
+    }
+    s.len()
+}
+
+/// Returns the byte offset of the last grapheme cluster in `s`.
+///
+/// `s` must start on a grapheme cluster boundary,
+/// so each prefix of `s` passed to [`is_boundary`] does.
+fn grapheme_start_back(s: &str) -> usize {
+    for (index, c) in s.char_indices().rev() {
+        // SAFETY: safe since index is a char boundary,
+        // the whole prefix is needed as context
+        if is_boundary(unsafe { s.get_unchecked(..index) }, c) {
+            return index;
+        }
+    }
+    0
+}
+
+impl<'t> StringIter<'t> {
+
+    /// Returns a leading extended grapheme cluster
+    /// from the `StringIter` and advances it.
+    ///
+    /// The iterator is assumed to start and end on grapheme cluster boundaries.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "e\u{301}👨‍👩‍👧!".str_iter();
+    /// assert_eq!(iter.next_grapheme(), Some("e\u{301}"));
+    /// assert_eq!(iter.next_grapheme(), Some("👨‍👩‍👧"));
+    /// assert_eq!(iter.next_grapheme(), Some("!"));
+    /// assert_eq!(iter.next_grapheme(), None);
+    /// ```
+    pub fn next_grapheme(&mut self) -> Option<&'t str> {
+        let result = self.peek_grapheme()?;
+        // SAFETY: safe since result is a prefix of self
+        self.str = unsafe { self.str.get_unchecked(result.len()..) };
+        Some(result)
+    }
+
+    /// Returns a trailing extended grapheme cluster
+    /// from the `StringIter` and advances it.
+    pub fn next_grapheme_back(&mut self) -> Option<&'t str> {
+        let result = self.peek_grapheme_back()?;
+        // SAFETY: safe since result is a suffix of self
+        self.str = unsafe { self.str.get_unchecked(..self.len() - result.len()) };
+        Some(result)
+    }
+
+    /// Returns a leading extended grapheme cluster
+    /// from the `StringIter` without advancing it.
+    pub fn peek_grapheme(&self) -> Option<&'t str> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: safe since grapheme_len is a char boundary
+        Some(unsafe { self.str.get_unchecked(..grapheme_len(self.str)) })
+    }
+
+    /// Returns a trailing extended grapheme cluster
+    /// from the `StringIter` without advancing it.
+    pub fn peek_grapheme_back(&self) -> Option<&'t str> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: safe since grapheme_start_back is a char boundary
+        Some(unsafe { self.str.get_unchecked(grapheme_start_back(self.str)..) })
+    }
+
+    /// Returns leading [`&str`](str)s with maximum count `n` grapheme clusters
+    /// from the `StringIter` without advancing it.
+    ///
+    /// Returns OK if n grapheme clusters found, Err if less than n grapheme clusters found.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let iter = "🇯🇵🇫🇷🇺🇸".str_iter();
+    /// assert_eq!(iter.peekn_graphemes(2), Ok("🇯🇵🇫🇷"));
+    /// assert_eq!(iter.peekn(2), Ok("🇯🇵"));
+    /// ```
+    pub fn peekn_graphemes(&self, n: usize) -> Result<&'t str, &'t str> {
+        let mut iter = self.clone();
+        for _ in 0..n {
+            if iter.next_grapheme().is_none() {
+                return Err(self.str);
+            }
+        }
+        // SAFETY: safe since iter is a suffix of self
+        Ok(unsafe { self.slice_front_ptr(iter.str.as_ptr()) })
+    }
+
+    /// Returns trailing [`&str`](str)s with maximum count `n` grapheme clusters
+    /// from the `StringIter` without advancing it.
+    ///
+    /// Returns OK if n grapheme clusters found, Err if less than n grapheme clusters found.
+    pub fn peekn_graphemes_back(&self, n: usize) -> Result<&'t str, &'t str> {
+        let mut iter = self.clone();
+        for _ in 0..n {
+            if iter.next_grapheme_back().is_none() {
+                return Err(self.str);
+            }
+        }
+        // SAFETY: safe since iter is a prefix of self
+        Ok(unsafe { self.str.get_unchecked(iter.len()..) })
+    }
+
+    /// Skip `n` leading grapheme clusters from this [`StringIter`],
+    /// returns `true` if the string is empty afterwards.
+    pub fn skip_front_graphemes(&mut self, n: usize) -> bool {
+        for _ in 0..n {
+            self.next_grapheme();
+        }
+        self.is_empty()
+    }
+
+    /// Skip `n` trailing grapheme clusters from this [`StringIter`],
+    /// returns `true` if the string is empty afterwards.
+    pub fn skip_back_graphemes(&mut self, n: usize) -> bool {
+        for _ in 0..n {
+            self.next_grapheme_back();
+        }
+        self.is_empty()
+    }
+}
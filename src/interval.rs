@@ -90,7 +90,7 @@ impl<const N: usize> Pattern for Interval<N> {
         if self.cursor.is_negative() {
             return Ok(false);
         }
-        self.cursor = self.cursor % self.len();
+        self.cursor %= self.len();
         Ok(self.cursor == 0 || 
             self.interval.iter().any(|x| x.get() == self.cursor as usize))
     }
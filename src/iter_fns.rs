@@ -61,7 +61,7 @@ impl<'t> StringIter<'t> {
     /// but available to mapped versions of `StringIter`.
     #[inline]
     pub fn next_char(&mut self) -> Option<(char, &'t str)> {
-        let x = *self.str.as_bytes().get(0)?;
+        let x = *self.str.as_bytes().first()?;
         // SAFETY: safe since self.str is valid utf-8
         unsafe{
             if x < 0x80 {
@@ -118,7 +118,7 @@ impl<'t> StringIter<'t> {
             index -= 1;
             let result = self.str.get_unchecked(index..);
             self.str = self.str.get_unchecked(..index);
-            return Some((s2c4(result), result))
+            Some((s2c4(result), result))
         }
     }
 
@@ -172,7 +172,7 @@ impl<'t> StringIter<'t> {
             }
             index -= 1;
             let result = self.str.get_unchecked(index..);
-            return Some((s2c4(result), result))
+            Some((s2c4(result), result))
         }
     }
 
@@ -182,6 +182,10 @@ impl<'t> StringIter<'t> {
     ///
     /// Returns OK if n [`char`]s found, Err if less than n [`char`]s found.
     pub fn peekn(&self, n: usize) -> Result<&'t str, &'t str> {
+        // there are at most as many chars as bytes
+        if n > self.str.len() {
+            return Err(self.str);
+        }
         let mut index = 0usize;
         for _ in 0..n {
             let x = *self.str.as_bytes().get(index)
@@ -243,7 +247,70 @@ impl<'t> StringIter<'t> {
     }
 
     /// Removes leading [`char`]s that matches a `Pattern` from the `StringIter`.
-    pub fn trim_start_by(&mut self, mut f: impl Pattern<Err = Never>){
+    pub fn trim_start_by(&mut self, f: impl Pattern<Err = Never>){
+        match self.try_trim_start_by(f) {
+            Ok(()) => (),
+            Err(never) => match never {},
+        }
+    }
+
+    /// Removes trailing [`char`]s that matches a `Pattern` from the `StringIter`.
+    pub fn trim_end_by(&mut self, f: impl Pattern<Err = Never>){
+        match self.try_trim_end_by(f) {
+            Ok(()) => (),
+            Err(never) => match never {},
+        }
+    }
+
+    /// Removes leading and trailing [`char`]s that matches a fallible `Pattern` from the `StringIter`.
+    ///
+    /// The iterator will not be changed if the pattern fails.
+    pub fn try_trim_by<P: Pattern + Clone>(&mut self, f: P) -> Result<(), P::Err> {
+        let mut iter = self.clone();
+        iter.try_trim_start_by(f.clone())?;
+        iter.try_trim_end_by(f)?;
+        *self = iter;
+        Ok(())
+    }
+
+    /// Removes leading [`char`]s that matches a fallible `Pattern` from the `StringIter`.
+    ///
+    /// Patterns that look ahead more than one [`char`] remove whole matches,
+    /// see [`match_len`](Pattern::match_len).
+    ///
+    /// The iterator will not be changed if the pattern fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "  12x".str_iter();
+    /// let digits = |c: char| match c {
+    ///     ' ' | '0'..='9' => Ok(true),
+    ///     'x' => Err("unexpected x"),
+    ///     _ => Ok(false),
+    /// };
+    /// assert_eq!(iter.try_trim_start_by(digits), Err("unexpected x"));
+    /// assert_eq!(iter.as_str(), "  12x");
+    /// ```
+    pub fn try_trim_start_by<P: Pattern>(&mut self, mut f: P) -> Result<(), P::Err> {
+        let look_ahead = f.len().get();
+        if look_ahead > 1 {
+            let mut iter = self.clone();
+            while let Some((c, _)) = iter.peek() {
+                let s = match iter.peekn(look_ahead) {
+                    Ok(s) => s,
+                    Err(s) => s,
+                };
+                if !f.matches(c, s)? {
+                    break;
+                }
+                let len = f.match_len(c, s).max(c.len_utf8());
+                assert!(s.is_char_boundary(len), "match_len() is not a char boundary of the matched str");
+                // SAFETY: safe since len is a char boundary of a prefix of iter
+                iter.str = unsafe { iter.str.get_unchecked(len..) };
+            }
+            *self = iter;
+            return Ok(());
+        }
         let bytes = self.as_bytes();
         let mut index = 0;
         while let Some(x) = bytes.get(index) {
@@ -264,7 +331,7 @@ impl<'t> StringIter<'t> {
                     (s2c4(result), result, 4)
                 }
             };
-            if f.matches(c, s).unwrap() {
+            if f.matches(c, s)? {
                 index += len;
             } else {
                 break;
@@ -273,58 +340,40 @@ impl<'t> StringIter<'t> {
         unsafe {
             self.str = self.str.get_unchecked(index..)
         }
+        Ok(())
     }
 
-    /// Removes trailing [`char`]s that matches a `Pattern` from the `StringIter`.
-    pub fn trim_end_by(&mut self, mut f: impl Pattern<Err = Never>){
+    /// Removes trailing [`char`]s that matches a fallible `Pattern` from the `StringIter`.
+    ///
+    /// The iterator will not be changed if the pattern fails.
+    pub fn try_trim_end_by<P: Pattern>(&mut self, mut f: P) -> Result<(), P::Err> {
         let bytes = self.as_bytes();
-        let mut index = self.len() - 1;
-        while let Some(x) = bytes.get(index) {
-            let mut i = index;
-            // SAFETY: safe since self.str is valid utf-8
-            unsafe {
-                i -= 1;
-                if *x < 0x80 {
-                    let s = self.str.get_unchecked(i..index);
-                    if !f.matches(s2c1(s), s).unwrap() {
-                        break;
-                    } else {
-                        index = i;
-                        continue;
-                    }
-                }
-                i -= 1;
-                if *bytes.get_unchecked(i) & 0b1100_0000 == 0b1100_0000 {
-                    let s = self.str.get_unchecked(i..index);
-                    if !f.matches(s2c2(s), s).unwrap() {
-                        break;
-                    } else {
-                        index = i;
-                        continue;
-                    }
-                }
-                i -= 1;
-                if *bytes.get_unchecked(i) & 0b1110_0000 == 0b1110_0000  {
-                    let s = self.str.get_unchecked(i..index);
-                    if !f.matches(s2c3(s), s).unwrap() {
-                        break;
-                    } else {
-                        index = i;
-                        continue;
-                    }
-                }
+        let mut index = self.len();
+        while index > 0 {
+            let mut i = index - 1;
+            while bytes[i] & 0b1100_0000 == 0b1000_0000 {
                 i -= 1;
+            }
+            // SAFETY: safe since self.str is valid utf-8
+            // and i..index is a single code point
+            let (c, s) = unsafe {
                 let s = self.str.get_unchecked(i..index);
-                if !f.matches(s2c4(s), s).unwrap() {
-                    break;
-                } else {
-                    index = i;
-                    continue;
-                }
+                let c = match index - i {
+                    1 => s2c1(s),
+                    2 => s2c2(s),
+                    3 => s2c3(s),
+                    _ => s2c4(s),
+                };
+                (c, s)
+            };
+            if !f.matches(c, s)? {
+                break;
             }
+            index = i;
         }
         unsafe {
             self.str = self.str.get_unchecked(..index)
         }
+        Ok(())
     }
 }
\ No newline at end of file
@@ -61,6 +61,51 @@ impl<'t> StringIter<'t> {
         AsciiStrIter(self)
     }
 
+    /// Map the iterator into an `Iterator<Item = &str>`
+    /// that yields extended grapheme clusters.
+    /// 
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "e\u{301}🇫🇷".str_iter().graphemes();
+    /// assert_eq!(iter.next(), Some("e\u{301}"));
+    /// assert_eq!(iter.next(), Some("🇫🇷"));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn graphemes(self) -> GraphemeIter<'t> {
+        GraphemeIter(self)
+    }
+
+    /// Map the iterator into an `Iterator<Item = &str>`
+    /// that yields word segments, see [`next_word`](StringIter::next_word).
+    /// 
+    /// Every segment is yielded, including whitespace and punctuation,
+    /// filter the output to obtain words only.
+    /// 
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let words: Vec<_> = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+    ///     .str_iter()
+    ///     .words()
+    ///     .filter(|s| s.chars().any(char::is_alphanumeric))
+    ///     .collect();
+    /// assert_eq!(words, ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]);
+    /// ```
+    pub fn words(self) -> WordIter<'t> {
+        WordIter(self)
+    }
+
+    /// Map the iterator into an `Iterator<Item = &str>`
+    /// that yields sentences, see [`next_sentence`](StringIter::next_sentence).
+    /// 
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let sentences: Vec<_> = "Hello world. (How are you?) I'm fine.".str_iter().sentences().collect();
+    /// assert_eq!(sentences, ["Hello world. ", "(How are you?) ", "I'm fine."]);
+    /// ```
+    pub fn sentences(self) -> SentenceIter<'t> {
+        SentenceIter(self)
+    }
+
     /// Make the iterator peek for `len`.
     pub fn look_ahead(self, len: usize) -> LookAhead<'t> {
         assert!(len != 0, "look_ahead cannot be 0");
@@ -140,6 +185,70 @@ alt_iter!(AsciiIter, StringIter, u8, |(_, s)| unsafe {*s.as_bytes().get_unchecke
 alt_iter!(AsciiStrIter, StringIter, (u8, &'t str), |(_, s)| (unsafe {*s.as_bytes().get_unchecked(0)}, s),
     "A mapped [`StringIter`] that yields `(u8, &str)`s.");
 
+macro_rules! segment_iter {
+    ($name: ident, $next: ident, $next_back: ident, $doc: literal) => {
+
+        #[doc = $doc]
+        #[repr(transparent)]
+        #[derive(Debug, Clone)]
+        pub struct $name<'t>(StringIter<'t>);
+
+        impl<'t> Deref for $name<'t> {
+            type Target = StringIter<'t>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<'t> DerefMut for $name<'t> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl core::borrow::Borrow<str> for $name<'_> {
+            fn borrow(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl AsRef<str> for $name<'_> {
+            fn as_ref(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl<'t> Iterator for $name<'t> {
+            type Item = &'t str;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.$next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::from(!self.0.is_empty()), Some(self.0.len()))
+            }
+        }
+
+        impl<'t> DoubleEndedIterator for $name<'t> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.$next_back()
+            }
+        }
+
+        impl<'t> FusedIterator for $name<'t> {}
+    };
+}
+
+segment_iter!(GraphemeIter, next_grapheme, next_grapheme_back,
+    "A mapped [`StringIter`] that yields extended grapheme clusters as [`&str`]s.");
+segment_iter!(WordIter, next_word, next_word_back,
+    "A mapped [`StringIter`] that yields word segments as [`&str`]s.");
+segment_iter!(SentenceIter, next_sentence, next_sentence_back,
+    "A mapped [`StringIter`] that yields sentences as [`&str`]s.");
 
 /// A mapped StringIter that yields longer [`&str`]s by looking ahead.
 #[derive(Debug, Clone)]
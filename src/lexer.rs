@@ -0,0 +1,398 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+use core::iter::FusedIterator;
+
+use crate::{StringIter, Span, Trivia, ParseError, Expected};
+use crate::pattern::{Pattern, Never};
+
+/// A token yielded by a [`Lexer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token<'t, K> {
+    /// The kind of the [`Rule`] that matched.
+    pub kind: K,
+    /// The matched slice of the source.
+    pub text: &'t str,
+    /// The byte range of the token in the source.
+    pub span: Span,
+}
+
+/// An error token yielded by a [`Lexer`] for input no [`Rule`] matches.
+///
+/// The [`Lexer`] can continue after the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LexError<'t> {
+    text: &'t str,
+    span: Span,
+}
+
+impl<'t> LexError<'t> {
+    /// Returns the unmatched slice of the source.
+    pub const fn as_str(&self) -> &'t str {
+        self.text
+    }
+
+    /// Returns the byte range of the unmatched input in the source.
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Convert into a [`ParseError`] in `source`, the input of the [`Lexer`],
+    /// expecting [`Named("a token")`](Expected::Named).
+    ///
+    /// # Panics
+    ///
+    /// If the span does not start on a [`char`] boundary of `source`.
+    pub fn into_parse_error(self, source: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Named("a token"), &source[self.span.start..])
+    }
+}
+
+impl Display for LexError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unexpected {:?} at byte {}", self.text, self.span.start)
+    }
+}
+
+const _: () = {
+    extern crate std;
+    impl std::error::Error for LexError<'_> {}
+};
+
+/// What a [`Rule`] does to the mode stack of a [`Lexer`] after it matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ModeAction {
+    None,
+    Push(usize),
+    Pop,
+}
+
+/// A rule of a [`Lexer`] that matches a token of kind `K`.
+///
+/// Rules belong to mode `0` unless changed by [`in_mode()`](Rule::in_mode).
+pub struct Rule<'t, K> {
+    kind: K,
+    matcher: Box<dyn FnMut(&mut StringIter<'t>) -> bool + 't>,
+    priority: i32,
+    mode: usize,
+    action: ModeAction,
+    skip: bool,
+}
+
+impl<'t, K> Rule<'t, K> {
+    /// Construct a [`Rule`] from a function that advances the [`StringIter`]
+    /// past a token and returns `true`, or returns `false` if there is no token.
+    ///
+    /// The function is called with a copy of the [`Lexer`]'s iterator,
+    /// matches of zero length are ignored.
+    ///
+    /// ```
+    /// # use string_iter::{Lexer, Rule, QuoteStyle};
+    /// // a quoted string with escapes
+    /// let rule = Rule::new(|iter| iter.next_quoted(QuoteStyle::Double).is_ok(), "string");
+    /// let mut lexer = Lexer::new(r#""a\"b"c"#).rule(rule);
+    /// assert_eq!(lexer.next().unwrap().unwrap().text, r#""a\"b""#);
+    /// ```
+    pub fn new(matcher: impl FnMut(&mut StringIter<'t>) -> bool + 't, kind: K) -> Self {
+        Rule {
+            kind,
+            matcher: Box::new(matcher),
+            priority: 0,
+            mode: 0,
+            action: ModeAction::None,
+            skip: false,
+        }
+    }
+
+    /// Construct a [`Rule`] that matches a literal [`str`].
+    pub fn literal(literal: &'t str, kind: K) -> Self {
+        Rule::new(move |iter| iter.eat_str(literal), kind)
+    }
+
+    /// Construct a [`Rule`] that matches a run of one or more matches of a [`Pattern`],
+    /// like `char::is_alphanumeric` or [`pat!()`](crate::pat!).
+    pub fn pattern<P: Pattern<Err = Never> + Clone + 't>(pat: P, kind: K) -> Self {
+        Rule::new(move |iter| {
+            let mut matched = false;
+            while iter.expect(pat.clone()).is_ok_and(|s| !s.is_empty()) {
+                matched = true;
+            }
+            matched
+        }, kind)
+    }
+
+    /// Set the priority of this [`Rule`], default `0`.
+    ///
+    /// If rules match the same length, the one with the highest priority wins,
+    /// then the one added first.
+    pub fn priority(self, priority: i32) -> Self {
+        Rule { priority, ..self }
+    }
+
+    /// Make this [`Rule`] active in `mode` instead of mode `0`.
+    pub fn in_mode(self, mode: usize) -> Self {
+        Rule { mode, ..self }
+    }
+
+    /// Push `mode` to the mode stack after this [`Rule`] matches.
+    pub fn push_mode(self, mode: usize) -> Self {
+        Rule { action: ModeAction::Push(mode), ..self }
+    }
+
+    /// Pop the mode stack after this [`Rule`] matches.
+    pub fn pop_mode(self) -> Self {
+        Rule { action: ModeAction::Pop, ..self }
+    }
+
+    /// Discard the tokens of this [`Rule`] instead of yielding them.
+    pub fn skip(self) -> Self {
+        Rule { skip: true, ..self }
+    }
+}
+
+impl<K: Debug> Debug for Rule<'_, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Rule")
+            .field("kind", &self.kind)
+            .field("priority", &self.priority)
+            .field("mode", &self.mode)
+            .field("action", &self.action)
+            .field("skip", &self.skip)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A token kind that knows the [`Rule`]s of its [`Lexer`].
+///
+/// With the `derive` feature, this can be derived for an enum of unit variants
+/// with the `#[token("...")]`, `#[regex_like("...")]` and `#[pattern(...)]` attributes,
+/// see the `StringIterToken` derive macro.
+///
+/// ```
+/// # use string_iter::{prelude::*, Rule, StringIterToken};
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Kind { Word, Space }
+///
+/// impl StringIterToken for Kind {
+///     fn rules<'t>() -> Vec<Rule<'t, Self>> {
+///         vec![
+///             Rule::pattern(char::is_alphabetic, Kind::Word),
+///             Rule::pattern(' ', Kind::Space).skip(),
+///         ]
+///     }
+/// }
+///
+/// let words: Vec<_> = Kind::lexer("foo bar").map(|token| token.unwrap().text).collect();
+/// assert_eq!(words, ["foo", "bar"]);
+/// ```
+pub trait StringIterToken: Clone + Sized {
+    /// Returns the [`Rule`]s of this token kind, in order.
+    fn rules<'t>() -> Vec<Rule<'t, Self>>;
+
+    /// Construct a [`Lexer`] of `source` with the [`rules()`](StringIterToken::rules).
+    fn lexer(source: &str) -> Lexer<'_, Self> {
+        Self::rules().into_iter().fold(Lexer::new(source), Lexer::rule)
+    }
+}
+
+/// A lexer that yields [`Token`]s of kind `K` matched by [`Rule`]s.
+///
+/// At each position, the [`Rule`] of the current mode with the longest match wins,
+/// ties are broken by [`priority`](Rule::priority), then by the order rules are added.
+///
+/// A [`char`] no rule matches is yielded as a [`LexError`],
+/// and lexing continues after it.
+///
+/// The mode stack starts with mode `0`, and can be changed by rules
+/// or by [`push_mode()`](Lexer::push_mode) and [`pop_mode()`](Lexer::pop_mode),
+/// for things like string interpolation or heredocs.
+///
+/// This requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, Lexer, Rule, Trivia};
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Kind { Let, Ident, Number, Eq, EqEq }
+///
+/// let lexer = Lexer::new("let x = 1 == 2 // done\n?y")
+///     .trivia(Trivia::C)
+///     .rule(Rule::pattern(pat!('a'..='z'), Kind::Ident))
+///     .rule(Rule::literal("let", Kind::Let).priority(1))
+///     .rule(Rule::pattern('0'..='9', Kind::Number))
+///     .rule(Rule::literal("=", Kind::Eq))
+///     .rule(Rule::literal("==", Kind::EqEq));
+///
+/// let tokens: Vec<_> = lexer.map(|token| token.map(|token| (token.kind, token.text))).collect();
+/// assert_eq!(tokens[..6], [
+///     Ok((Kind::Let, "let")),
+///     Ok((Kind::Ident, "x")),
+///     Ok((Kind::Eq, "=")),
+///     Ok((Kind::Number, "1")),
+///     Ok((Kind::EqEq, "==")),
+///     Ok((Kind::Number, "2")),
+/// ]);
+/// assert_eq!(tokens[6].unwrap_err().to_string(), "unexpected \"?\" at byte 23");
+/// assert_eq!(tokens[7], Ok((Kind::Ident, "y")));
+/// ```
+pub struct Lexer<'t, K> {
+    source: &'t str,
+    iter: StringIter<'t>,
+    rules: Vec<Rule<'t, K>>,
+    modes: Vec<usize>,
+    trivia: Vec<(usize, Trivia<'t>)>,
+}
+
+impl<'t, K> Lexer<'t, K> {
+    /// Construct a [`Lexer`] of `source` with no rules.
+    pub fn new(source: &'t str) -> Self {
+        Lexer {
+            source,
+            iter: StringIter::new(source),
+            rules: Vec::new(),
+            modes: vec![0],
+            trivia: Vec::new(),
+        }
+    }
+
+    /// Add a [`Rule`].
+    pub fn rule(mut self, rule: Rule<'t, K>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Skip [`Trivia`] before each token in mode `0`.
+    ///
+    /// An unterminated block comment is yielded as a [`LexError`]
+    /// that extends to the end of the input.
+    pub fn trivia(self, trivia: Trivia<'t>) -> Self {
+        self.trivia_in_mode(0, trivia)
+    }
+
+    /// Skip [`Trivia`] before each token in `mode`, replacing any previous one.
+    ///
+    /// Modes without trivia, like the inside of a string, keep everything.
+    pub fn trivia_in_mode(mut self, mode: usize, trivia: Trivia<'t>) -> Self {
+        match self.trivia.iter_mut().find(|(m, _)| *m == mode) {
+            Some((_, t)) => *t = trivia,
+            None => self.trivia.push((mode, trivia)),
+        }
+        self
+    }
+
+    /// Returns the remaining [`str`] of the source.
+    pub fn as_str(&self) -> &'t str {
+        self.iter.as_str()
+    }
+
+    /// Returns the byte offset of the next token in the source.
+    pub fn offset(&self) -> usize {
+        self.source.len() - self.iter.len()
+    }
+
+    /// Returns the current mode.
+    pub fn mode(&self) -> usize {
+        self.modes[self.modes.len() - 1]
+    }
+
+    /// Push `mode` to the mode stack.
+    pub fn push_mode(&mut self, mode: usize) {
+        self.modes.push(mode);
+    }
+
+    /// Pop the mode stack and returns the popped mode.
+    ///
+    /// Returns `None` and keeps the mode if there is only one mode on the stack.
+    pub fn pop_mode(&mut self) -> Option<usize> {
+        match self.modes.len() {
+            1 => None,
+            _ => self.modes.pop(),
+        }
+    }
+
+    /// Returns the index and length of the winning [`Rule`] at the front of `iter`.
+    fn longest_match(&mut self, iter: &StringIter<'t>) -> Option<(usize, usize)> {
+        let mode = self.mode();
+        let mut best: Option<(usize, usize, i32)> = None;
+        for (index, rule) in self.rules.iter_mut().enumerate() {
+            if rule.mode != mode {
+                continue;
+            }
+            let mut matched = iter.clone();
+            if !(rule.matcher)(&mut matched) {
+                continue;
+            }
+            let len = iter.len() - matched.len();
+            if len > 0 && !matches!(best, Some((_, best, priority)) if (best, priority) >= (len, rule.priority)) {
+                best = Some((index, len, rule.priority));
+            }
+        }
+        best.map(|(index, len, _)| (index, len))
+    }
+
+    fn split_front(&mut self, len: usize) -> (&'t str, Span) {
+        let start = self.offset();
+        let s = self.iter.as_str();
+        let (text, rest) = s.split_at(len);
+        self.iter = StringIter::new(rest);
+        (text, Span::new(start, start + len))
+    }
+
+    /// Consume the unmatched [`char`] at the front.
+    fn error(&mut self) -> LexError<'t> {
+        let len = self.iter.peek().map_or(0, |(_, s)| s.len());
+        let (text, span) = self.split_front(len);
+        LexError { text, span }
+    }
+}
+
+impl<K: Debug> Debug for Lexer<'_, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Lexer")
+            .field("iter", &self.iter)
+            .field("rules", &self.rules)
+            .field("modes", &self.modes)
+            .field("trivia", &self.trivia)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'t, K: Clone> Iterator for Lexer<'t, K> {
+    type Item = Result<Token<'t, K>, LexError<'t>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mode = self.mode();
+            if let Some(&(_, trivia)) = self.trivia.iter().find(|(m, _)| *m == mode) {
+                if let Err(err) = self.iter.skip_trivia(&trivia) {
+                    let len = self.iter.len() - err.as_str().len();
+                    self.split_front(len);
+                    let (text, span) = self.split_front(self.iter.len());
+                    return Some(Err(LexError { text, span }));
+                }
+            }
+            if self.iter.is_empty() {
+                return None;
+            }
+            let iter = self.iter.clone();
+            let Some((index, len)) = self.longest_match(&iter) else {
+                return Some(Err(self.error()));
+            };
+            let (text, span) = self.split_front(len);
+            let rule = &self.rules[index];
+            let (kind, skip) = (rule.kind.clone(), rule.skip);
+            match rule.action {
+                ModeAction::None => (),
+                ModeAction::Push(mode) => self.push_mode(mode),
+                ModeAction::Pop => { self.pop_mode(); },
+            }
+            if !skip {
+                return Some(Ok(Token { kind, text, span }));
+            }
+        }
+    }
+}
+
+impl<K: Clone> FusedIterator for Lexer<'_, K> {}
@@ -46,6 +46,17 @@
 //! }
 //! ```
 //! 
+//! * Grapheme clusters
+//! 
+//! ```
+//! # use string_iter::prelude::*;
+//! let mut iter = "🇯🇵e\u{301}👨‍👩‍👧".str_iter().graphemes();
+//! assert_eq!(iter.next(), Some("🇯🇵"));
+//! assert_eq!(iter.next(), Some("e\u{301}"));
+//! assert_eq!(iter.next(), Some("👨‍👩‍👧"));
+//! assert_eq!(iter.next(), None);
+//! ```
+//! 
 //! * Look-ahead
 //! 
 //! ```
@@ -77,6 +88,15 @@
 //! assert_eq!(iter.as_str(), "baz");
 //! ```
 //! 
+//! The same with [`Balanced`](patterns::Balanced), which also checks for mismatched delimiters:
+//! ```
+//! # use string_iter::{prelude::*, patterns::Balanced};
+//! let mut iter = "{{foo}bar}baz".str_iter();
+//! let s = iter.try_next_slice(Balanced::brackets().sep_with(Sep::Yield));
+//! assert_eq!(s, Ok(Some("{{foo}bar}")));
+//! assert_eq!(iter.as_str(), "baz");
+//! ```
+//! 
 //! * Splitting
 //! 
 //! ```
@@ -91,6 +111,24 @@
 //! assert_eq!(iter.next(), None);
 //! ```
 //! 
+//! * Number scanning
+//! 
+//! ```
+//! # use string_iter::prelude::*;
+//! let mut iter = "0x1F + 2.5e3".str_iter();
+//! assert_eq!(iter.next_int_prefixed::<u32>(), Ok((31, "0x1F")));
+//! iter.skip_front(3);
+//! assert_eq!(iter.next_float::<f64>(), Ok((2500.0, "2.5e3")));
+//! ```
+//! 
+//! * Case conversion
+//! 
+//! ```
+//! # use string_iter::{prelude::*, Case};
+//! let iter = "thisIsCamelCase".str_iter();
+//! assert_eq!(iter.to_case(Case::Snake).to_string(), "this_is_camel_case");
+//! ```
+//! 
 //! # Patterns
 //! 
 //! We use [`Patterns`](Pattern) in [`trim`](StringIter::trim_by), 
@@ -123,6 +161,8 @@
 //! 
 //! Matching an `&str` by looking ahead.
 //! 
+//! The entire `&str` is yielded or discarded according to [`Sep`].
+//! 
 //! * `&[char]` or `[char;N]`
 //! 
 //! Matches any char in the set.
@@ -154,10 +194,22 @@
 //! 
 //! Match repeatedly by an interval.
 //! 
+//! * [`LiteralSet`](patterns::LiteralSet)
+//! 
+//! Matches the longest of many literal [`&str`]s, requires the `std` feature.
+//! 
+//! * [`WordBounds`](patterns::WordBounds) and [`SentenceBounds`](patterns::SentenceBounds)
+//! 
+//! Matches the start of each Unicode word or sentence.
+//! 
 //! * [`pat!()`](pat!)
 //! 
 //! A macro that turns `match` patterns into [`Pattern`]s.
 //! 
+//! * [`PatternExt`] combinators
+//! 
+//! Combine patterns with `or()`, `and()`, `not()`, `followed_by()` and `repeated()`.
+//! 
 //! * Custom implementations of [`Pattern`]
 //! 
 //! You can write your own pattern types!
@@ -201,6 +253,18 @@
 //! assert_eq!(iter.as_str(), "  bar ");
 //! ```
 //! 
+//! The above does not handle escapes like `\"`,
+//! use [`next_quoted()`](StringIter::next_quoted) for that:
+//! ```
+//! # use string_iter::{prelude::*, QuoteStyle};
+//! let foo = r#""f\"oo"  bar "#;
+//! let mut iter = foo.str_iter();
+//! let quoted = iter.next_quoted(QuoteStyle::Double).unwrap();
+//! assert_eq!(quoted.raw(), r#""f\"oo""#);
+//! assert_eq!(quoted.decoded(), "f\"oo");
+//! assert_eq!(iter.as_str(), "  bar ");
+//! ```
+//! 
 //! # Performance
 //! 
 //! This crate is comparable in speed to [`str::chars()`].
@@ -226,6 +290,34 @@ mod split;
 mod iter_fns;
 mod interval;
 mod pattern;
+mod combinator;
+mod tracked;
+mod span;
+mod checkpoint;
+mod search;
+mod grapheme;
+mod segment;
+mod case;
+mod number;
+mod quoted;
+mod lines;
+mod diagnostic;
+mod expect;
+mod parse;
+mod trivia;
+mod unicode_tables;
+#[cfg(feature="std")]
+extern crate alloc;
+#[cfg(feature="std")]
+mod literal;
+#[cfg(feature="std")]
+mod csv;
+#[cfg(feature="std")]
+mod shell;
+#[cfg(feature="std")]
+mod line_index;
+#[cfg(feature="std")]
+mod lexer;
 mod iterators;
 mod string_ext;
 
@@ -234,12 +326,35 @@ pub use string_ext::{StringExt, StringIndex};
 
 pub use pattern::{
     Pattern,
+    Search,
     Sep, 
     SetSep,
     Never,
     FallibleBool,
     CharStrPredicate, StrPredicate
 };
+pub use combinator::PatternExt;
+pub use tracked::{TrackedIter, Position};
+pub use span::Span;
+pub use checkpoint::Checkpoint;
+pub use unicode_tables::UNICODE_VERSION;
+pub use case::{Case, CaseDisplay};
+pub use number::{Integer, Float, NumberError, NumberErrorKind};
+pub use quoted::{QuoteStyle, Quoted, QuoteError, QuoteErrorKind};
+pub use lines::Line;
+pub use diagnostic::{Diagnostic, Label, LabelTarget, Level};
+pub use expect::{ParseError, Expected};
+pub use trivia::Trivia;
+#[cfg(feature="std")]
+pub use csv::{CsvReader, CsvRecord, CsvError, CsvErrorKind};
+#[cfg(feature="std")]
+pub use shell::{ShellError, ShellErrorKind};
+#[cfg(feature="std")]
+pub use line_index::{LineIndex, LineColumn, ColumnUnit};
+#[cfg(feature="std")]
+pub use lexer::{Lexer, Rule, Token, LexError, StringIterToken};
+#[cfg(feature="derive")]
+pub use string_iter_derive::StringIterToken;
 
 pub mod iter {
     //! Misallenious iterators used in this crate.
@@ -248,7 +363,14 @@ pub mod iter {
     //! and are functionally identical.
     pub use crate::iterators::*;
     pub use crate::merge::MergeIter;
-    pub use crate::split::SplitIter;
+    pub use crate::split::{SplitIter, QuotedSplitIter};
+    #[cfg(feature="std")]
+    pub use crate::split::DecodedSplitIter;
+    #[cfg(feature="std")]
+    pub use crate::shell::ShellWords;
+    pub use crate::case::CaseWords;
+    pub use crate::lines::LineIter;
+    pub use crate::trivia::Comments;
 }
 pub mod patterns {
     //! Misallenious patterns used in this crate.
@@ -256,8 +378,15 @@ pub mod patterns {
         SizedCharStrPredicate, 
         SizedStrPredicate,
         SepConfig,
+        Balanced,
+        BalanceError,
+        BalanceErrorKind,
     };
     pub use crate::interval::Interval;
+    pub use crate::combinator::{Or, And, Not, FollowedBy, Repeated, OutsideQuotes};
+    pub use crate::segment::{WordBounds, SentenceBounds};
+    #[cfg(feature="std")]
+    pub use crate::literal::{LiteralSet, LiteralMatch};
 }
 
 
@@ -273,6 +402,8 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use crate::pattern::{Sep, SetSep, CharStrPredicate, StrPredicate};
     #[doc(no_inline)]
+    pub use crate::combinator::PatternExt;
+    #[doc(no_inline)]
     pub use crate::merge::Merge;
     pub use crate::interval;
     pub use crate::pat;
@@ -405,9 +536,9 @@ impl<'t> From<&'t str> for StringIter<'t> {
     }
 }
 
-impl<'t> Into<&'t str> for StringIter<'t> {
-    fn into(self) -> &'t str {
-        self.str
+impl<'t> From<StringIter<'t>> for &'t str {
+    fn from(value: StringIter<'t>) -> Self {
+        value.str
     }
 }
 
@@ -451,33 +582,33 @@ const _: () = {
     use alloc::borrow::Cow;
     use alloc::sync::Arc;
 
-    impl<'t> Into<String> for StringIter<'t> {
-        fn into(self) -> String {
-            self.str.into()
+    impl<'t> From<StringIter<'t>> for String {
+        fn from(value: StringIter<'t>) -> Self {
+            value.str.into()
         }
     }
 
-    impl<'t> Into<Box<str>> for StringIter<'t> {
-        fn into(self) -> Box<str> {
-            self.str.into()
+    impl<'t> From<StringIter<'t>> for Box<str> {
+        fn from(value: StringIter<'t>) -> Self {
+            value.str.into()
         }
     }
 
-    impl<'t> Into<Rc<str>> for StringIter<'t> {
-        fn into(self) -> Rc<str> {
-            self.str.into()
+    impl<'t> From<StringIter<'t>> for Rc<str> {
+        fn from(value: StringIter<'t>) -> Self {
+            value.str.into()
         }
     }
 
-    impl<'t> Into<Arc<str>> for StringIter<'t> {
-        fn into(self) -> Arc<str> {
-            self.str.into()
+    impl<'t> From<StringIter<'t>> for Arc<str> {
+        fn from(value: StringIter<'t>) -> Self {
+            value.str.into()
         }
     }
 
-    impl<'t> Into<Cow<'t, str>> for StringIter<'t> {
-        fn into(self) -> Cow<'t, str> {
-            Cow::Borrowed(self.str)
+    impl<'t> From<StringIter<'t>> for Cow<'t, str> {
+        fn from(value: StringIter<'t>) -> Self {
+            Cow::Borrowed(value.str)
         }
     }
 
@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::ops::Range;
+
+use crate::Position;
+use crate::merge::substr_range;
+use crate::tracked::is_line_break;
+
+/// The unit columns are counted in, see [`LineIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// UTF-8 bytes.
+    Byte,
+    /// [`char`]s, or unicode scalar values.
+    Char,
+    /// UTF-16 code units, as used by the language server protocol.
+    Utf16,
+}
+
+impl ColumnUnit {
+    fn len(self, c: char) -> usize {
+        match self {
+            ColumnUnit::Byte => c.len_utf8(),
+            ColumnUnit::Char => 1,
+            ColumnUnit::Utf16 => c.len_utf16(),
+        }
+    }
+}
+
+/// A line and column obtained from a [`LineIndex`].
+///
+/// `line` and `column` start from `1`, like [`Position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineColumn {
+    /// Line number, starting from `1`.
+    pub line: usize,
+    /// Column number in a [`ColumnUnit`], starting from `1`.
+    pub column: usize,
+}
+
+impl LineColumn {
+    /// Construct a new [`LineColumn`].
+    pub const fn new(line: usize, column: usize) -> Self {
+        LineColumn { line, column }
+    }
+}
+
+/// Converts with the column in [`ColumnUnit::Char`].
+impl From<Position> for LineColumn {
+    fn from(position: Position) -> Self {
+        LineColumn { line: position.line, column: position.column }
+    }
+}
+
+/// Displays as `line:column`.
+impl Display for LineColumn {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// An index of the line starts of a source [`str`],
+/// for `O(log n)` conversions between byte offsets and [`LineColumn`]s.
+///
+/// Line breaks are `"\n"`, `"\r\n"` and a lone `"\r"`,
+/// like [`lines()`](crate::StringIter::lines) and [`Position`].
+///
+/// Converting to or from a column costs `O(column)`
+/// for [`ColumnUnit::Char`] and [`ColumnUnit::Utf16`].
+///
+/// This requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, LineIndex, LineColumn, ColumnUnit};
+/// let source = "let crab = '🦀';\nlet x = crab;";
+/// let index = LineIndex::new(source);
+///
+/// let mut iter = source.str_iter();
+/// iter.next_slice('\n'.sep_with(Sep::Split));
+/// iter.skip_front(8);
+/// let crab = iter.next_slice(';').unwrap();
+///
+/// let range = index.range_of(crab, ColumnUnit::Utf16).unwrap();
+/// assert_eq!(range, LineColumn::new(2, 9)..LineColumn::new(2, 13));
+/// assert_eq!(index.position_to_offset(range.start, ColumnUnit::Utf16), Some(27));
+///
+/// // the crab is 1 char, but 2 UTF-16 code units
+/// let semicolon = source.find(';').unwrap();
+/// assert_eq!(index.offset_to_position(semicolon, ColumnUnit::Char), Some(LineColumn::new(1, 15)));
+/// assert_eq!(index.offset_to_position(semicolon, ColumnUnit::Utf16), Some(LineColumn::new(1, 16)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex<'t> {
+    source: &'t str,
+    /// Byte offset and content length of each line.
+    lines: Vec<(usize, usize)>,
+}
+
+impl<'t> LineIndex<'t> {
+    /// Build a [`LineIndex`] of `source` in `O(n)`.
+    pub fn new(source: &'t str) -> Self {
+        let bytes = source.as_bytes();
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for index in 0..bytes.len() {
+            if is_line_break(bytes, index) {
+                let crlf = bytes[index] == b'\n' && index > start && bytes[index - 1] == b'\r';
+                lines.push((start, index - usize::from(crlf) - start));
+                start = index + 1;
+            }
+        }
+        // the position after a final line break is on an empty line
+        lines.push((start, source.len() - start));
+        LineIndex { source, lines }
+    }
+
+    /// Returns the source [`str`] of this [`LineIndex`].
+    pub const fn source(&self) -> &'t str {
+        self.source
+    }
+
+    /// Returns the number of lines,
+    /// including an empty line after a final line break.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the content of a line without its terminator,
+    /// `line` starts from `1`.
+    pub fn line(&self, line: usize) -> Option<&'t str> {
+        let (start, len) = *self.lines.get(line.checked_sub(1)?)?;
+        Some(&self.source[start..start + len])
+    }
+
+    /// Returns the [`LineColumn`] of the byte `offset`.
+    ///
+    /// Returns `None` if `offset` is out of bounds or not on a [`char`] boundary.
+    pub fn offset_to_position(&self, offset: usize, unit: ColumnUnit) -> Option<LineColumn> {
+        if !self.source.is_char_boundary(offset) {
+            return None;
+        }
+        let line = self.lines.partition_point(|(start, _)| *start <= offset);
+        let (start, _) = self.lines[line - 1];
+        let column = match unit {
+            ColumnUnit::Byte => offset - start,
+            _ => self.source[start..offset].chars().map(|c| unit.len(c)).sum(),
+        };
+        Some(LineColumn { line, column: column + 1 })
+    }
+
+    /// Returns the byte offset of a [`LineColumn`].
+    ///
+    /// The column can be at most one past the end of the line content.
+    /// Returns `None` if the position is out of bounds,
+    /// or not on a [`char`] boundary, like between a UTF-16 surrogate pair.
+    pub fn position_to_offset(&self, position: LineColumn, unit: ColumnUnit) -> Option<usize> {
+        let (start, len) = *self.lines.get(position.line.checked_sub(1)?)?;
+        let column = position.column.checked_sub(1)?;
+        let content = &self.source[start..start + len];
+        let offset = match unit {
+            ColumnUnit::Byte => column,
+            _ => {
+                let mut units = 0;
+                let mut chars = content.char_indices();
+                loop {
+                    if units == column {
+                        break chars.offset();
+                    }
+                    units += unit.len(chars.next()?.1);
+                    if units > column {
+                        return None;
+                    }
+                }
+            }
+        };
+        content.is_char_boundary(offset).then_some(start + offset)
+    }
+
+    /// Returns the [`LineColumn`] range of a substring of the source,
+    /// like one obtained from a [`StringIter`](crate::StringIter).
+    ///
+    /// Returns `None` if `slice` is not a substring of the source.
+    pub fn range_of(&self, slice: &str, unit: ColumnUnit) -> Option<Range<LineColumn>> {
+        let range = substr_range(self.source, slice)?;
+        Some(self.offset_to_position(range.start, unit)?..self.offset_to_position(range.end, unit)?)
+    }
+}
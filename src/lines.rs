@@ -0,0 +1,285 @@
+use core::iter::FusedIterator;
+
+use crate::StringIter;
+use crate::search::{find_bytes, rfind_bytes};
+use crate::tracked::is_line_break;
+
+/// A line obtained from [`lines()`](StringIter::lines)
+/// or [`lines_with_continuations()`](StringIter::lines_with_continuations).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<'t> {
+    raw: &'t str,
+    content: usize,
+    number: usize,
+    byte_offset: usize,
+    continuation: Option<char>,
+}
+
+impl<'t> Line<'t> {
+    /// Returns the line including its terminator.
+    pub const fn as_str(&self) -> &'t str {
+        self.raw
+    }
+
+    /// Returns the line without its terminator.
+    ///
+    /// For a continued line, this contains all physical lines
+    /// and the line breaks between them.
+    pub fn content(&self) -> &'t str {
+        &self.raw[..self.content]
+    }
+
+    /// Returns the line terminator, `"\n"`, `"\r\n"`, `"\r"`,
+    /// or `""` at the end of the [`str`].
+    pub fn terminator(&self) -> &'t str {
+        &self.raw[self.content..]
+    }
+
+    /// Returns the line number of the first physical line, starting from `1`.
+    pub const fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Returns the byte offset of the line, from the start of the iterator.
+    pub const fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Returns an iterator of the physical lines of this line,
+    /// with the line numbers and byte offsets of the original [`str`].
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "a \\\n  b\nc".str_iter().lines_with_continuations('\\');
+    /// let line = iter.next().unwrap();
+    /// let physical: Vec<_> = line.physical_lines().map(|line| (line.number(), line.content())).collect();
+    /// assert_eq!(physical, [(1, "a \\"), (2, "  b")]);
+    /// ```
+    pub fn physical_lines(&self) -> LineIter<'t> {
+        LineIter {
+            origin: self.raw,
+            offset: self.byte_offset,
+            iter: StringIter::new(self.raw),
+            front: self.number,
+            back: None,
+            continuation: None,
+        }
+    }
+
+    /// Returns the content of the line with continuations joined,
+    /// by removing the continuation [`char`]s and the line breaks after them.
+    ///
+    /// This borrows if the line is not continued,
+    /// and requires the `std` feature.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let line = "a \\\n  b\n".str_iter().lines_with_continuations('\\').next().unwrap();
+    /// assert_eq!(line.content(), "a \\\n  b");
+    /// assert_eq!(line.joined(), "a   b");
+    /// ```
+    #[cfg(feature="std")]
+    pub fn joined(&self) -> alloc::borrow::Cow<'t, str> {
+        use alloc::borrow::Cow;
+        let Some(continuation) = self.continuation else {
+            return Cow::Borrowed(self.content());
+        };
+        let mut lines = self.physical_lines();
+        match lines.clone().nth(1) {
+            None => Cow::Borrowed(self.content()),
+            Some(_) => {
+                let mut result = alloc::string::String::with_capacity(self.content);
+                while let Some(line) = lines.next() {
+                    match lines.is_empty() {
+                        true => result.push_str(line.content()),
+                        false => result.push_str(line.content().strip_suffix(continuation).unwrap_or(line.content())),
+                    }
+                }
+                Cow::Owned(result)
+            }
+        }
+    }
+}
+
+/// Returns the length of the first line and its terminator.
+fn first_line(s: &str) -> (usize, usize) {
+    let bytes = s.as_bytes();
+    match find_bytes(b"\r\n", bytes) {
+        None => (s.len(), 0),
+        Some(index) if bytes[index] == b'\r' && bytes.get(index + 1) == Some(&b'\n') => (index, 2),
+        Some(index) => (index, 1),
+    }
+}
+
+/// Returns the start of the last line and the length of its terminator.
+fn last_line(s: &str) -> (usize, usize) {
+    let bytes = s.as_bytes();
+    let terminator = if s.ends_with("\r\n") {
+        2
+    } else if s.ends_with(['\n', '\r']) {
+        1
+    } else {
+        0
+    };
+    let start = rfind_bytes(b"\r\n", &bytes[..s.len() - terminator]).map_or(0, |i| i + 1);
+    (start, terminator)
+}
+
+/// Count the lines in `s`.
+fn count_lines(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let breaks = (0..bytes.len()).filter(|index| is_line_break(bytes, *index)).count();
+    breaks + usize::from(!s.is_empty() && !s.ends_with(['\n', '\r']))
+}
+
+/// An iterator that yields [`Line`]s, see [`lines()`](StringIter::lines).
+#[derive(Debug, Clone)]
+pub struct LineIter<'t> {
+    origin: &'t str,
+    offset: usize,
+    iter: StringIter<'t>,
+    front: usize,
+    back: Option<usize>,
+    continuation: Option<char>,
+}
+
+impl<'t> LineIter<'t> {
+    /// Returns the remaining [`str`] of this iterator.
+    pub fn as_str(&self) -> &'t str {
+        self.iter.as_str()
+    }
+
+    /// Returns `true` if there are no more lines.
+    pub fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+
+    fn is_continued(&self, content: &str) -> bool {
+        self.continuation.is_some_and(|c| content.ends_with(c))
+    }
+
+    fn line(&self, raw: &'t str, content: usize, number: usize) -> Line<'t> {
+        Line {
+            raw,
+            content,
+            number,
+            byte_offset: self.offset + (raw.as_ptr() as usize - self.origin.as_ptr() as usize),
+            continuation: self.continuation,
+        }
+    }
+}
+
+impl<'t> Iterator for LineIter<'t> {
+    type Item = Line<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.iter.as_str();
+        if s.is_empty() {
+            return None;
+        }
+        let number = self.front;
+        let (mut content, mut terminator) = first_line(s);
+        self.front += 1;
+        while terminator > 0 && content + terminator < s.len() && self.is_continued(&s[..content]) {
+            let start = content + terminator;
+            let (len, next) = first_line(&s[start..]);
+            content = start + len;
+            terminator = next;
+            self.front += 1;
+        }
+        let (raw, rest) = s.split_at(content + terminator);
+        self.iter = StringIter::new(rest);
+        Some(self.line(raw, content, number))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.iter.is_empty()), Some(self.iter.len()))
+    }
+}
+
+impl<'t> DoubleEndedIterator for LineIter<'t> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let s = self.iter.as_str();
+        if s.is_empty() {
+            return None;
+        }
+        let back = *self.back.get_or_insert_with(|| self.front + count_lines(s) - 1);
+        let (mut start, terminator) = last_line(s);
+        let mut lines = 1;
+        while start > 0 {
+            let (prev, _) = last_line(&s[..start]);
+            let (len, _) = first_line(&s[prev..start]);
+            if !self.is_continued(&s[prev..prev + len]) {
+                break;
+            }
+            start = prev;
+            lines += 1;
+        }
+        let (rest, raw) = s.split_at(start);
+        self.iter = StringIter::new(rest);
+        self.back = Some(back - lines);
+        Some(self.line(raw, raw.len() - terminator, back + 1 - lines))
+    }
+}
+
+impl FusedIterator for LineIter<'_> {}
+
+impl<'t> StringIter<'t> {
+
+    /// Map the iterator into an iterator of [`Line`]s.
+    ///
+    /// Lines are terminated by `"\n"`, `"\r\n"`, a lone `"\r"` or the end of the [`str`],
+    /// the terminator of each line is kept.
+    /// Like [`str::lines`], there is no empty line after a final line break.
+    ///
+    /// The first call to [`next_back()`](DoubleEndedIterator::next_back) counts the
+    /// remaining lines in `O(remaining length)` to number lines from the back.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let lines: Vec<_> = "foo\r\nbar\rbaz\n\nqux".str_iter().lines()
+    ///     .map(|line| (line.number(), line.byte_offset(), line.content(), line.terminator()))
+    ///     .collect();
+    /// assert_eq!(lines, [
+    ///     (1, 0, "foo", "\r\n"),
+    ///     (2, 5, "bar", "\r"),
+    ///     (3, 9, "baz", "\n"),
+    ///     (4, 13, "", "\n"),
+    ///     (5, 14, "qux", ""),
+    /// ]);
+    ///
+    /// let last = "foo\nbar\n".str_iter().lines().next_back().unwrap();
+    /// assert_eq!((last.number(), last.as_str()), (2, "bar\n"));
+    /// ```
+    pub fn lines(self) -> LineIter<'t> {
+        LineIter {
+            origin: self.str,
+            offset: 0,
+            iter: self,
+            front: 1,
+            back: None,
+            continuation: None,
+        }
+    }
+
+    /// Map the iterator into an iterator of [`Line`]s,
+    /// where a line ending with `continuation` is joined with the next line.
+    ///
+    /// The yielded [`Line`] spans all its physical lines,
+    /// see [`Line::physical_lines`] and [`Line::joined`].
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let lines: Vec<_> = "CFLAGS = -O2 \\\n\t-Wall\nLIBS = -lm".str_iter()
+    ///     .lines_with_continuations('\\')
+    ///     .map(|line| (line.number(), line.content()))
+    ///     .collect();
+    /// assert_eq!(lines, [(1, "CFLAGS = -O2 \\\n\t-Wall"), (3, "LIBS = -lm")]);
+    /// ```
+    pub fn lines_with_continuations(self, continuation: char) -> LineIter<'t> {
+        LineIter {
+            continuation: Some(continuation),
+            ..self.lines()
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use alloc::{vec, vec::Vec, string::String};
+use core::{cell::Cell, num::NonZeroUsize};
+
+use crate::{StringIter, pattern::{Pattern, Never, Search}};
+
+const DEAD: u32 = u32::MAX;
+
+/// An Aho-Corasick automaton compiled into a dense DFA over bytes.
+#[derive(Debug, Clone)]
+struct Automaton {
+    /// `states * 256` transitions, failure links are resolved at build time.
+    table: Vec<u32>,
+    /// Length of the trie path to each state.
+    depth: Vec<usize>,
+    /// Literal that ends exactly at this state.
+    terminal: Vec<Option<u32>>,
+    /// Longest literal that is a suffix of this state.
+    output: Vec<Option<u32>>,
+}
+
+impl Automaton {
+    fn new<'a>(literals: impl Iterator<Item = impl Iterator<Item = &'a u8>>) -> Self {
+        let mut auto = Automaton {
+            table: vec![DEAD; 256],
+            depth: vec![0],
+            terminal: vec![None],
+            output: vec![None],
+        };
+        // build the trie
+        for (index, literal) in literals.enumerate() {
+            let mut state = 0;
+            for &b in literal {
+                let next = auto.table[state * 256 + b as usize];
+                state = if next == DEAD {
+                    let next = auto.depth.len();
+                    auto.table[state * 256 + b as usize] = next as u32;
+                    auto.table.extend_from_slice(&[DEAD; 256]);
+                    auto.depth.push(auto.depth[state] + 1);
+                    auto.terminal.push(None);
+                    auto.output.push(None);
+                    next
+                } else {
+                    next as usize
+                };
+            }
+            // duplicated literals report the first occurance
+            auto.terminal[state].get_or_insert(index as u32);
+        }
+        // resolve failure links in breadth first order,
+        // states are created in depth first order so sort them by depth
+        let mut order: Vec<usize> = (0..auto.depth.len()).collect();
+        order.sort_by_key(|s| auto.depth[*s]);
+        let mut fail = vec![0usize; auto.depth.len()];
+        for state in order {
+            let f = fail[state];
+            auto.output[state] = auto.terminal[state].or(auto.output[f]);
+            for b in 0..256 {
+                let next = auto.table[state * 256 + b];
+                if next == DEAD {
+                    auto.table[state * 256 + b] = if state == 0 { 0 } else { auto.table[f * 256 + b] };
+                } else if state != 0 {
+                    fail[next as usize] = auto.table[f * 256 + b] as usize;
+                }
+            }
+        }
+        auto
+    }
+
+    #[inline]
+    fn next(&self, state: usize, b: u8) -> usize {
+        self.table[state * 256 + b as usize] as usize
+    }
+
+    /// Follow trie edges only, transitions that decrease depth are failure links.
+    #[inline]
+    fn child(&self, state: usize, b: u8) -> Option<usize> {
+        let next = self.next(state, b);
+        (self.depth[next] == self.depth[state] + 1).then_some(next)
+    }
+}
+
+/// Which literal in a [`LiteralSet`] matched, and the length of the match in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LiteralMatch {
+    /// Index of the literal in the [`LiteralSet`].
+    pub literal: usize,
+    /// Length of the literal in bytes.
+    pub len: usize,
+}
+
+/// A pattern that matches any one of many literal [`str`]s,
+/// preferring the longest literal at the earliest position.
+///
+/// The literals are compiled into an Aho-Corasick automaton,
+/// so searching does not depend on the number of literals.
+///
+/// After a successful match, the matched literal
+/// can be obtained with [`last_match()`](LiteralSet::last_match).
+/// Use `&LiteralSet` as the pattern to keep access to it.
+///
+/// This requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, patterns::LiteralSet};
+/// let ops = LiteralSet::new(["=", "==", "!=", "<", "<="]);
+/// let mut iter = "a <= b".str_iter();
+/// assert_eq!(iter.next_slice(&ops), Some("a "));
+/// assert_eq!(ops.last_match().map(|m| &ops[m.literal]), Some("<="));
+/// assert_eq!(iter.as_str(), "<= b");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralSet {
+    literals: Vec<String>,
+    max_chars: NonZeroUsize,
+    forward: Automaton,
+    backward: Automaton,
+    last: Cell<Option<LiteralMatch>>,
+}
+
+impl LiteralSet {
+    /// Compile a [`LiteralSet`] from a list of literals.
+    ///
+    /// # Panics
+    ///
+    /// If the list is empty or contains `""`.
+    pub fn new<S: AsRef<str>>(literals: impl IntoIterator<Item = S>) -> Self {
+        let literals: Vec<String> = literals.into_iter()
+            .map(|s| s.as_ref().into())
+            .collect();
+        assert!(literals.iter().all(|s| !s.is_empty()), "\"\" is not a valid pattern");
+        let max_chars = literals.iter()
+            .map(|s| s.chars().count())
+            .max()
+            .and_then(NonZeroUsize::new)
+            .expect("pattern cannot have 0 literals");
+        LiteralSet {
+            forward: Automaton::new(literals.iter().map(|s| s.as_bytes().iter())),
+            backward: Automaton::new(literals.iter().map(|s| s.as_bytes().iter().rev())),
+            literals,
+            max_chars,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Returns the number of literals in the set.
+    pub fn len(&self) -> usize {
+        self.literals.len()
+    }
+
+    /// Returns `true` if the set contains no literals, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.literals.is_empty()
+    }
+
+    /// Returns the literal at `index`.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.literals.get(index).map(|s| s.as_str())
+    }
+
+    /// Returns the literal matched by the last successful
+    /// [`matches`](Pattern::matches), [`search`](Pattern::search)
+    /// or [`search_back`](Pattern::search_back) call.
+    pub fn last_match(&self) -> Option<LiteralMatch> {
+        self.last.get()
+    }
+
+    fn literal_match(&self, literal: u32) -> LiteralMatch {
+        let literal = literal as usize;
+        LiteralMatch { literal, len: self.literals[literal].len() }
+    }
+
+    /// Find the longest literal that is a prefix of `s`.
+    pub fn prefix_of(&self, s: &str) -> Option<LiteralMatch> {
+        let auto = &self.forward;
+        let mut state = 0;
+        let mut longest = None;
+        for &b in s.as_bytes() {
+            state = match auto.child(state, b) {
+                Some(state) => state,
+                None => break,
+            };
+            longest = auto.terminal[state].or(longest);
+        }
+        longest.map(|l| self.literal_match(l))
+    }
+
+    /// Find the first literal in `s`, returns its byte offset and the [`LiteralMatch`].
+    ///
+    /// If multiple literals start at the same position, the longest one is returned.
+    pub fn find(&self, s: &str) -> Option<(usize, LiteralMatch)> {
+        let auto = &self.forward;
+        let mut state = 0;
+        let mut first: Option<usize> = None;
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            state = auto.next(state, b);
+            // no later match can start before `first`
+            if first.is_some_and(|first| i + 1 - auto.depth[state] > first) {
+                break;
+            }
+            if let Some(literal) = auto.output[state] {
+                let start = i + 1 - self.literals[literal as usize].len();
+                first = Some(first.map_or(start, |first| first.min(start)));
+            }
+        }
+        let first = first?;
+        Some((first, self.prefix_of(&s[first..])?))
+    }
+
+    /// Find the last literal in `s`, returns its byte offset and the [`LiteralMatch`].
+    ///
+    /// If multiple literals start at the same position, the longest one is returned.
+    pub fn rfind(&self, s: &str) -> Option<(usize, LiteralMatch)> {
+        let auto = &self.backward;
+        let mut state = 0;
+        for (i, &b) in s.as_bytes().iter().enumerate().rev() {
+            state = auto.next(state, b);
+            if auto.output[state].is_some() {
+                return Some((i, self.prefix_of(&s[i..])?));
+            }
+        }
+        None
+    }
+
+    fn search_result(&self, found: Option<(usize, LiteralMatch)>) -> Search {
+        match found {
+            Some((index, m)) => {
+                self.last.set(Some(m));
+                Search::Found(index, m.len)
+            }
+            None => Search::NotFound,
+        }
+    }
+}
+
+impl core::ops::Index<usize> for LiteralSet {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        &self.literals[index]
+    }
+}
+
+impl Pattern for &LiteralSet {
+    type Err = Never;
+
+    fn matches(&mut self, _: char, s: &str) -> Result<bool, Self::Err> {
+        let m = self.prefix_of(s);
+        if m.is_some() {
+            self.last.set(m);
+        }
+        Ok(m.is_some())
+    }
+
+    fn len(&self) -> NonZeroUsize { self.max_chars }
+
+    fn match_len(&self, _: char, _: &str) -> usize {
+        self.last.get().map_or(0, |m| m.len)
+    }
+
+    fn search(&mut self, s: &str) -> Search {
+        self.search_result(self.find(s))
+    }
+
+    fn search_back(&mut self, s: &str) -> Search {
+        self.search_result(self.rfind(s))
+    }
+}
+
+impl Pattern for LiteralSet {
+    type Err = Never;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> { (&*self).matches(c, s) }
+
+    fn len(&self) -> NonZeroUsize { self.max_chars }
+
+    fn match_len(&self, c: char, s: &str) -> usize { (&self).match_len(c, s) }
+
+    fn search(&mut self, s: &str) -> Search { (&*self).search(s) }
+
+    fn search_back(&mut self, s: &str) -> Search { (&*self).search_back(s) }
+}
+
+impl<'t> StringIter<'t> {
+
+    /// Consume the longest literal in a [`LiteralSet`] at the start of the iterator,
+    /// returns its index and the matched [`str`].
+    ///
+    /// The iterator will not be changed if no literal matches.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, patterns::LiteralSet};
+    /// let keywords = LiteralSet::new(["let", "fn", "for"]);
+    /// let mut iter = "fn main".str_iter();
+    /// assert_eq!(iter.next_literal(&keywords), Some((1, "fn")));
+    /// assert_eq!(iter.next_literal(&keywords), None);
+    /// assert_eq!(iter.as_str(), " main");
+    /// ```
+    pub fn next_literal(&mut self, set: &LiteralSet) -> Option<(usize, &'t str)> {
+        let m = set.prefix_of(self.str)?;
+        let (result, rest) = self.str.split_at(m.len);
+        self.str = rest;
+        Some((m.literal, result))
+    }
+}
@@ -1,22 +1,29 @@
 use core::iter::Peekable;
+use core::ops::Range;
+
+/// Returns the byte range of `child` in `parent`,
+/// or `None` if `child` is not a substring of `parent`.
+pub(crate) fn substr_range(parent: &str, child: &str) -> Option<Range<usize>> {
+    let start = (child.as_ptr() as usize).checked_sub(parent.as_ptr() as usize)?;
+    let end = start + child.len();
+    if end > parent.len() {
+        return None;
+    }
+    Some(start..end)
+}
 
 /// See documentation in [`StringExt`]
 pub(crate) fn merge<'t>(parent: &'t str, first: &str, second: &str) -> Option<&'t str> {
-    let st = first.as_ptr() as usize;
-    if st as usize + first.len() == second.as_ptr() as usize {
-        let start = st.checked_sub(parent.as_ptr() as usize)?;
-        let end = start + first.len() + second.len();
-        if end > parent.len() {
-            return None;
-        }
-        // SAFETY: safe, since start and end are inside from.len()
-        // and enclose valid utf-8 strings first and second
-        Some(unsafe {
-            &parent.get_unchecked(start..end)
-        })
-    } else {
-        None
+    let first = substr_range(parent, first)?;
+    let second = substr_range(parent, second)?;
+    if first.end != second.start {
+        return None;
     }
+    // SAFETY: safe, since start and end are inside from.len()
+    // and enclose valid utf-8 strings first and second
+    Some(unsafe {
+        parent.get_unchecked(first.start..second.end)
+    })
 }
 
 /// Iterators for merging substrings.
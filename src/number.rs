@@ -0,0 +1,409 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{StringIter, ParseError, Expected};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type that can be scanned by [`next_int()`](StringIter::next_int).
+pub trait Integer: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    /// Computes `self * radix + digit`, or `self * radix - digit` if `negative`.
+    #[doc(hidden)]
+    fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($ty: ty),*) => {$(
+        impl sealed::Sealed for $ty {}
+        impl Integer for $ty {
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                let value = self.checked_mul(radix as $ty)?;
+                if negative {
+                    value.checked_sub(digit as $ty)
+                } else {
+                    value.checked_add(digit as $ty)
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A primitive floating point type that can be scanned by [`next_float()`](StringIter::next_float).
+pub trait Float: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn parse_lexeme(s: &str) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($($ty: ty),*) => {$(
+        impl sealed::Sealed for $ty {}
+        impl Float for $ty {
+            fn parse_lexeme(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+
+            fn is_infinite(self) -> bool {
+                <$ty>::is_infinite(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+/// The reason a number failed to scan, see [`NumberError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberErrorKind {
+    /// The iterator does not start with a number.
+    Empty,
+    /// A radix prefix is not followed by a valid digit.
+    InvalidDigit,
+    /// The number is too large to fit in the target type.
+    PosOverflow,
+    /// The number is too small to fit in the target type.
+    NegOverflow,
+}
+
+/// An error returned by the number scanning methods of [`StringIter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberError {
+    kind: NumberErrorKind,
+    offset: usize,
+}
+
+impl NumberError {
+    /// Returns the reason of the error.
+    pub const fn kind(&self) -> NumberErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the error from the start of the iterator.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Convert into a [`ParseError`] in `input`, the input of the iterator,
+    /// which is not changed on failure.
+    ///
+    /// # Panics
+    ///
+    /// If the offset is not a [`char`] boundary of `input`.
+    pub fn into_parse_error(self, input: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Number(self.kind), &input[self.offset..])
+    }
+}
+
+impl Display for NumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let reason = match self.kind {
+            NumberErrorKind::Empty => "expected a number",
+            NumberErrorKind::InvalidDigit => "invalid digit after radix prefix",
+            NumberErrorKind::PosOverflow => "number too large to fit in target type",
+            NumberErrorKind::NegOverflow => "number too small to fit in target type",
+        };
+        write!(f, "{} at byte {}", reason, self.offset)
+    }
+}
+
+#[cfg(feature="std")]
+const _: () = {
+    extern crate std;
+    impl std::error::Error for NumberError {}
+};
+
+/// Returns the length of a leading `+` or `-` and whether the number is negative.
+fn sign(bytes: &[u8]) -> (usize, bool) {
+    match bytes.first() {
+        Some(b'-') => (1, true),
+        Some(b'+') => (1, false),
+        _ => (0, false),
+    }
+}
+
+/// Returns the end of a run of `[0-9][0-9_]*` starting at `start`, if any.
+fn decimal_digits(bytes: &[u8], start: usize) -> Option<usize> {
+    if !bytes.get(start)?.is_ascii_digit() {
+        return None;
+    }
+    Some(bytes[start..].iter()
+        .position(|b| !b.is_ascii_digit() && *b != b'_')
+        .map_or(bytes.len(), |i| start + i))
+}
+
+/// Scans an integer, returns the value and its byte length.
+///
+/// If `radix` is `None`, a `0x`, `0o` or `0b` prefix is detected.
+fn scan_int<T: Integer>(s: &str, signed: bool, radix: Option<u32>) -> Result<(T, usize), NumberError> {
+    let bytes = s.as_bytes();
+    let (mut i, negative) = if signed { sign(bytes) } else { (0, false) };
+    let (radix, prefixed) = match radix {
+        Some(radix) => (radix, false),
+        None => match bytes.get(i..i + 2) {
+            Some(b"0x" | b"0X") => (16, true),
+            Some(b"0o" | b"0O") => (8, true),
+            Some(b"0b" | b"0B") => (2, true),
+            _ => (10, false),
+        }
+    };
+    if prefixed {
+        i += 2;
+    }
+    let mut value = T::ZERO;
+    let mut digits = 0;
+    while let Some(&b) = bytes.get(i) {
+        // separators are allowed after a radix prefix or the first digit
+        if b == b'_' && (prefixed || digits > 0) {
+            i += 1;
+            continue;
+        }
+        let Some(digit) = (b as char).to_digit(radix) else {
+            break;
+        };
+        value = value.push_digit(radix, digit, negative).ok_or(NumberError {
+            kind: if negative { NumberErrorKind::NegOverflow } else { NumberErrorKind::PosOverflow },
+            offset: i,
+        })?;
+        digits += 1;
+        i += 1;
+    }
+    if digits == 0 {
+        let kind = if prefixed { NumberErrorKind::InvalidDigit } else { NumberErrorKind::Empty };
+        return Err(NumberError { kind, offset: i });
+    }
+    Ok((value, i))
+}
+
+/// Scans a float, returns the value and its byte length.
+fn scan_float<T: Float>(s: &str) -> Result<(T, usize), NumberError> {
+    let bytes = s.as_bytes();
+    let (start, negative) = sign(bytes);
+    let mut end = decimal_digits(bytes, start)
+        .ok_or(NumberError { kind: NumberErrorKind::Empty, offset: start })?;
+    // `1.` and `1e` are not consumed, as in `1..2` and `1em`.
+    if bytes.get(end) == Some(&b'.') {
+        if let Some(fraction) = decimal_digits(bytes, end + 1) {
+            end = fraction;
+        }
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let (sign_len, _) = sign(&bytes[end + 1..]);
+        if let Some(exponent) = decimal_digits(bytes, end + 1 + sign_len) {
+            end = exponent;
+        }
+    }
+    let lexeme = &s[..end];
+    let value = if lexeme.contains('_') {
+        parse_without_separators(lexeme)
+    } else {
+        T::parse_lexeme(lexeme)
+    };
+    // the lexeme is always valid for `str::parse`
+    match value {
+        Some(value) if !value.is_infinite() => Ok((value, end)),
+        _ => Err(NumberError {
+            kind: if negative { NumberErrorKind::NegOverflow } else { NumberErrorKind::PosOverflow },
+            offset: 0,
+        }),
+    }
+}
+
+/// The significant digits kept by [`parse_without_separators()`],
+/// more than the 767 needed to round any `f64` correctly.
+const MAX_DIGITS: usize = 800;
+
+/// Parses a float lexeme with `_` separators without allocating,
+/// by rewriting it on the stack as its significant digits and an exponent.
+///
+/// Digits after [`MAX_DIGITS`] are replaced by a single `1` if any is not `0`,
+/// which keeps the rounding of [`str::parse`].
+fn parse_without_separators<T: Float>(lexeme: &str) -> Option<T> {
+    let bytes = lexeme.as_bytes();
+    let mut buffer = [0u8; MAX_DIGITS + 24];
+    let (mut i, negative) = sign(bytes);
+    let mut len = usize::from(negative);
+    buffer[0] = b'-';
+    let mut digits = 0;
+    // the power of 10 of the last written digit
+    let mut scale: i64 = 0;
+    let mut fraction = false;
+    let mut sticky = false;
+    while let Some(&b) = bytes.get(i) {
+        i += 1;
+        match b {
+            b'_' => (),
+            b'.' => fraction = true,
+            b'e' | b'E' => break,
+            b'0' if digits == 0 => scale -= i64::from(fraction),
+            _ if digits < MAX_DIGITS => {
+                buffer[len] = b;
+                len += 1;
+                digits += 1;
+                scale -= i64::from(fraction);
+            }
+            _ => {
+                sticky |= b != b'0';
+                scale += i64::from(!fraction);
+            }
+        }
+    }
+    if sticky {
+        buffer[len] = b'1';
+        len += 1;
+        scale -= 1;
+    }
+    if digits == 0 {
+        buffer[len] = b'0';
+        len += 1;
+    }
+    // large exponents are clamped, they overflow or underflow anyway
+    let (sign_len, negative_exponent) = sign(&bytes[i..]);
+    let mut exponent: i64 = 0;
+    for &b in bytes[i + sign_len..].iter().filter(|b| **b != b'_') {
+        exponent = (exponent * 10 + i64::from(b - b'0')).min(u32::MAX.into());
+    }
+    let exponent = scale + if negative_exponent { -exponent } else { exponent };
+    buffer[len] = b'e';
+    len += 1;
+    if exponent < 0 {
+        buffer[len] = b'-';
+        len += 1;
+    }
+    let start = len;
+    let mut n = exponent.unsigned_abs();
+    loop {
+        buffer[len] = b'0' + (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    buffer[start..len].reverse();
+    T::parse_lexeme(core::str::from_utf8(&buffer[..len]).ok()?)
+}
+
+impl<'t> StringIter<'t> {
+
+    fn take_number<T>(&mut self, scanned: Result<(T, usize), NumberError>) -> Result<(T, &'t str), NumberError> {
+        let (value, len) = scanned?;
+        let (result, rest) = self.str.split_at(len);
+        self.str = rest;
+        Ok((value, result))
+    }
+
+    /// Consume a decimal integer with an optional leading `+` or `-`,
+    /// returns the value and the consumed [`str`].
+    ///
+    /// `_` is accepted as a digit separator after the first digit.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, NumberErrorKind};
+    /// let mut iter = "-1_024px".str_iter();
+    /// assert_eq!(iter.next_int::<i32>(), Ok((-1024, "-1_024")));
+    /// assert_eq!(iter.as_str(), "px");
+    ///
+    /// let mut iter = "300".str_iter();
+    /// assert_eq!(iter.next_int::<u8>().unwrap_err().kind(), NumberErrorKind::PosOverflow);
+    /// assert_eq!(iter.as_str(), "300");
+    /// ```
+    pub fn next_int<T: Integer>(&mut self) -> Result<(T, &'t str), NumberError> {
+        self.take_number(scan_int(self.str, true, Some(10)))
+    }
+
+    /// Consume a decimal integer without a sign,
+    /// returns the value and the consumed [`str`].
+    ///
+    /// `_` is accepted as a digit separator after the first digit.
+    ///
+    /// The iterator will not be changed if this fails.
+    pub fn next_uint<T: Integer>(&mut self) -> Result<(T, &'t str), NumberError> {
+        self.take_number(scan_int(self.str, false, Some(10)))
+    }
+
+    /// Consume an integer in a given radix with an optional leading `+` or `-`,
+    /// returns the value and the consumed [`str`].
+    ///
+    /// `_` is accepted as a digit separator after the first digit.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "ff_ff;".str_iter();
+    /// assert_eq!(iter.next_int_radix::<u16>(16), Ok((0xffff, "ff_ff")));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in the range `2..=36`.
+    pub fn next_int_radix<T: Integer>(&mut self, radix: u32) -> Result<(T, &'t str), NumberError> {
+        assert!((2..=36).contains(&radix), "radix must be in the range 2..=36, found {}", radix);
+        self.take_number(scan_int(self.str, true, Some(radix)))
+    }
+
+    /// Consume an integer with an optional leading `+` or `-`,
+    /// and an optional `0x`, `0o` or `0b` radix prefix,
+    /// returns the value and the consumed [`str`].
+    ///
+    /// `_` is accepted as a digit separator after the radix prefix or the first digit.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, NumberErrorKind};
+    /// let mut iter = "0xFF_FF 0b1010 -0o17 42".str_iter();
+    /// assert_eq!(iter.next_int_prefixed::<i32>(), Ok((0xffff, "0xFF_FF")));
+    /// iter.trim_start();
+    /// assert_eq!(iter.next_int_prefixed::<i32>(), Ok((10, "0b1010")));
+    /// iter.trim_start();
+    /// assert_eq!(iter.next_int_prefixed::<i32>(), Ok((-15, "-0o17")));
+    /// iter.trim_start();
+    /// assert_eq!(iter.next_int_prefixed::<i32>(), Ok((42, "42")));
+    ///
+    /// let mut iter = "0xg".str_iter();
+    /// let err = iter.next_int_prefixed::<i32>().unwrap_err();
+    /// assert_eq!((err.kind(), err.offset()), (NumberErrorKind::InvalidDigit, 2));
+    /// ```
+    pub fn next_int_prefixed<T: Integer>(&mut self) -> Result<(T, &'t str), NumberError> {
+        self.take_number(scan_int(self.str, true, None))
+    }
+
+    /// Consume a decimal floating point number with an optional leading `+` or `-`,
+    /// a fraction and an exponent, returns the value and the consumed [`str`].
+    ///
+    /// `_` is accepted as a digit separator after the first digit of each part.
+    /// A `.` or `e` not followed by a digit is not consumed,
+    /// `inf` and `nan` are not accepted.
+    ///
+    /// Values that round to infinity are reported as overflow.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "-1_000.5e-3, 2em, 1..2".str_iter();
+    /// assert_eq!(iter.next_float::<f64>(), Ok((-1.0005, "-1_000.5e-3")));
+    /// iter.skip_front(2);
+    /// assert_eq!(iter.next_float::<f64>(), Ok((2.0, "2")));
+    /// assert_eq!(iter.as_str(), "em, 1..2");
+    /// iter.skip_front(4);
+    /// assert_eq!(iter.next_float::<f32>(), Ok((1.0, "1")));
+    /// ```
+    pub fn next_float<T: Float>(&mut self) -> Result<(T, &'t str), NumberError> {
+        self.take_number(scan_float(self.str))
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{StringIter, ParseError};
+use crate::pattern::{Pattern, Never};
+
+impl<'t> StringIter<'t> {
+
+    /// Consume a match of a [`Pattern`] at the front of the iterator,
+    /// returns `true` if matched.
+    ///
+    /// See [`expect()`](StringIter::expect).
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "-1".str_iter();
+    /// assert!(iter.eat('-'));
+    /// assert!(!iter.eat('-'));
+    /// assert_eq!(iter.as_str(), "1");
+    /// ```
+    pub fn eat<P: Pattern<Err = Never>>(&mut self, pat: P) -> bool {
+        self.expect(pat).is_ok()
+    }
+
+    /// Consume a leading [`str`] `s`, returns `true` if matched.
+    ///
+    /// See [`expect_str()`](StringIter::expect_str).
+    pub fn eat_str(&mut self, s: &str) -> bool {
+        self.expect_str(s).is_ok()
+    }
+
+    /// Run a parser, returns `None` and rewinds the iterator if it fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "-x".str_iter();
+    /// assert_eq!(iter.optional(|iter| iter.expect_str("-1")), None);
+    /// assert_eq!(iter.optional(|iter| iter.expect('-')), Some("-"));
+    /// assert_eq!(iter.as_str(), "x");
+    /// ```
+    pub fn optional<T, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, E>) -> Option<T> {
+        self.transaction(f).ok()
+    }
+
+    /// Run a parser repeatedly, until it fails or does not consume anything,
+    /// and collect the results.
+    ///
+    /// The failed or empty run is discarded and the iterator is rewound to before it.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "aab".str_iter();
+    /// let a: Vec<_> = iter.many0(|iter| iter.expect('a'));
+    /// assert_eq!(a, ["a", "a"]);
+    /// let c: Vec<_> = iter.many0(|iter| iter.expect('c'));
+    /// assert!(c.is_empty());
+    /// assert_eq!(iter.as_str(), "b");
+    /// ```
+    pub fn many0<C: Default + Extend<T>, T, E>(&mut self, f: impl FnMut(&mut Self) -> Result<T, E>) -> C {
+        let mut result = C::default();
+        self.many_into(&mut result, f);
+        result
+    }
+
+    fn many_into<C: Extend<T>, T, E>(&mut self, result: &mut C, mut f: impl FnMut(&mut Self) -> Result<T, E>) {
+        loop {
+            let checkpoint = self.checkpoint();
+            match f(self) {
+                Ok(item) if self.len() < checkpoint.as_str().len() => result.extend(Some(item)),
+                _ => return self.rewind(checkpoint),
+            }
+        }
+    }
+
+    /// Run a parser repeatedly like [`many0()`](StringIter::many0),
+    /// but fails if the first run fails.
+    ///
+    /// If the first run does not consume anything, its result is returned alone
+    /// without running the parser again.
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "12x".str_iter();
+    /// let digits: Result<String, _> = iter.many1(|iter| iter.expect('0'..='9'));
+    /// assert_eq!(digits.unwrap(), "12");
+    /// let digits: Result<String, _> = iter.many1(|iter| iter.expect('0'..='9'));
+    /// assert_eq!(digits.unwrap_err().to_string(), "expected a match of the pattern, found 'x'");
+    /// ```
+    pub fn many1<C: Default + Extend<T>, T, E>(&mut self, mut f: impl FnMut(&mut Self) -> Result<T, E>) -> Result<C, E> {
+        let mut result = C::default();
+        let len = self.len();
+        result.extend(Some(self.transaction(&mut f)?));
+        if self.len() < len {
+            self.many_into(&mut result, f);
+        }
+        Ok(result)
+    }
+
+    /// Run a parser repeatedly, separated by a [`Pattern`], and collect the results.
+    ///
+    /// Stops if the separator or the item after a separator fails,
+    /// in which case the iterator is rewound to before the separator.
+    /// Trailing separators are not consumed.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "a, b,c, ".str_iter();
+    /// let items: Vec<_> = iter.separated_list(|iter| iter.expect(char::is_alphabetic), ", ");
+    /// assert_eq!(items, ["a", "b"]);
+    /// assert_eq!(iter.as_str(), ",c, ");
+    /// ```
+    pub fn separated_list<C: Default + Extend<T>, T, E>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, E>,
+        sep: impl Pattern<Err = Never> + Clone,
+    ) -> C {
+        let mut result = C::default();
+        match self.transaction(&mut item) {
+            Ok(first) => result.extend(Some(first)),
+            Err(_) => return result,
+        }
+        while let Ok(next) = self.transaction(|iter| {
+            iter.expect(sep.clone()).map_err(|_| None)?;
+            item(iter).map_err(Some)
+        }) {
+            result.extend(Some(next));
+        }
+        result
+    }
+
+    /// Run a parser between two [`Pattern`]s.
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, ParseError};
+    /// let mut iter = "[1,2](3".str_iter();
+    /// let list: Result<Vec<_>, ParseError> = iter.delimited('[', |iter| {
+    ///     Ok(iter.separated_list(|iter| iter.expect('0'..='9'), ','))
+    /// }, ']');
+    /// assert_eq!(list.unwrap(), ["1", "2"]);
+    ///
+    /// let group: Result<_, ParseError> = iter.delimited('(', |iter| iter.expect('0'..='9'), ')');
+    /// assert_eq!(group.unwrap_err().to_string(), "expected a match of the pattern, found end of input");
+    /// assert_eq!(iter.as_str(), "(3");
+    /// ```
+    pub fn delimited<T, E: From<ParseError<'t>>>(
+        &mut self,
+        open: impl Pattern<Err = Never>,
+        f: impl FnOnce(&mut Self) -> Result<T, E>,
+        close: impl Pattern<Err = Never>,
+    ) -> Result<T, E> {
+        self.transaction(|iter| {
+            iter.expect(open)?;
+            let result = f(iter)?;
+            iter.expect(close)?;
+            Ok(result)
+        })
+    }
+}
@@ -1,6 +1,8 @@
 use core::{fmt::Debug, num::NonZeroUsize};
 use core::ops::{RangeTo, RangeInclusive};
 
+use crate::{search, ParseError, Expected};
+
 /// A never type that cannot be instanciated.
 #[derive(Debug)]
 pub enum Never{}
@@ -22,11 +24,44 @@ pub enum Sep{
     Conjoin,
 }
 
+/// The result of a [`Pattern`] search fast path,
+/// see [`search`](Pattern::search).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Search<E = Never> {
+    /// The pattern has no fast path, match [`char`] by [`char`] instead.
+    Unsupported,
+    /// The pattern is not found.
+    NotFound,
+    /// The pattern is found at a byte offset, with a match length in bytes.
+    Found(usize, usize),
+    /// The pattern failed before a match was found.
+    Error(E),
+}
+
+impl<E> Search<E> {
+    #[inline]
+    pub(crate) fn from_index(index: Option<usize>, len: usize) -> Self {
+        match index {
+            Some(index) => Search::Found(index, len),
+            None => Search::NotFound,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        match self {
+            Search::Found(index, len) => Search::Found(index + offset, len),
+            search => search,
+        }
+    }
+}
+
 /// A pattern for use in 
 /// [`slice`](crate::StringIter::try_next_slice), 
 /// [`split`](crate::StringIter::into_substrs) and 
 /// [`trim`](crate::StringIter::trim_by)
 /// functions.
+#[allow(clippy::len_without_is_empty)]
 pub trait Pattern{
     type Err: Debug;
     /// Try matching a char in a pattern
@@ -50,10 +85,34 @@ pub trait Pattern{
     /// 
     /// The iterator will not stop prematurely because of look-ahead.
     fn len(&self) -> NonZeroUsize { NonZeroUsize::new(1).unwrap() }
+    /// Returns the length of a successful match in bytes,
+    /// called after [`matches`](Pattern::matches) returned `true` on the same `c` and `s`.
+    /// 
+    /// By default this is the length of `c`.
+    /// 
+    /// This determines how many bytes are yielded or discarded
+    /// according to [`sep`](Pattern::sep). 
+    /// The result must be on a [`char`] boundary of `s`.
+    fn match_len(&self, c: char, _s: &str) -> usize { c.len_utf8() }
     /// Determines what to do with the matched [char] on separation.
     /// 
     /// See also [`sep_with`](SetSep::sep_with)
     fn sep(&self) -> Sep { Sep::Retain }
+    /// An optional fast path for finding the first match in `s`,
+    /// by default [`Unsupported`](Search::Unsupported).
+    /// 
+    /// The result must be identical to calling [`matches`](Pattern::matches)
+    /// on each [`char`] in `s` and [`match_len`](Pattern::match_len) on the first match,
+    /// an error is returned as [`Error`](Search::Error).
+    /// 
+    /// This is implemented for [`char`], `&[char]`, `[char; N]` and [`&str`],
+    /// using word-at-a-time byte searching.
+    fn search(&mut self, _s: &str) -> Search<Self::Err> { Search::Unsupported }
+    /// An optional fast path for finding the last match in `s`,
+    /// by default [`Unsupported`](Search::Unsupported).
+    /// 
+    /// See [`search`](Pattern::search).
+    fn search_back(&mut self, _s: &str) -> Search<Self::Err> { Search::Unsupported }
 }
 
 impl Pattern for isize {
@@ -85,6 +144,12 @@ impl Pattern for char {
     fn matches(&mut self, c: char, _: &str) -> Result<bool, Self::Err> {
         Ok(c == *self)
     }
+    fn search(&mut self, s: &str) -> Search {
+        Search::from_index(search::find_char(*self, s.as_bytes()), self.len_utf8())
+    }
+    fn search_back(&mut self, s: &str) -> Search {
+        Search::from_index(search::rfind_char(*self, s.as_bytes()), self.len_utf8())
+    }
 }
 
 impl Pattern for &str {
@@ -96,6 +161,15 @@ impl Pattern for &str {
         NonZeroUsize::new(self.chars().count())
             .expect("\"\" is not a valid pattern")
     }
+    fn match_len(&self, _: char, _: &str) -> usize {
+        str::len(self)
+    }
+    fn search(&mut self, s: &str) -> Search {
+        Search::from_index(search::find_str(self.as_bytes(), s.as_bytes()), str::len(self))
+    }
+    fn search_back(&mut self, s: &str) -> Search {
+        Search::from_index(search::rfind_str(self.as_bytes(), s.as_bytes()), str::len(self))
+    }
 }
 
 impl Pattern for &[char] {
@@ -103,6 +177,18 @@ impl Pattern for &[char] {
     fn matches(&mut self, c: char, _: &str) -> Result<bool, Self::Err> {
         Ok(self.contains(&c))
     }
+    fn search(&mut self, s: &str) -> Search {
+        match search::find_chars(self, s.as_bytes(), false) {
+            Some(index) => Search::from_index(index, 1),
+            None => Search::Unsupported,
+        }
+    }
+    fn search_back(&mut self, s: &str) -> Search {
+        match search::find_chars(self, s.as_bytes(), true) {
+            Some(index) => Search::from_index(index, 1),
+            None => Search::Unsupported,
+        }
+    }
 }
 
 impl<const N: usize> Pattern for [char; N] {
@@ -110,6 +196,18 @@ impl<const N: usize> Pattern for [char; N] {
     fn matches(&mut self, c: char, _: &str) -> Result<bool, Self::Err> {
         Ok(self.contains(&c))
     }
+    fn search(&mut self, s: &str) -> Search {
+        match search::find_chars(self, s.as_bytes(), false) {
+            Some(index) => Search::from_index(index, 1),
+            None => Search::Unsupported,
+        }
+    }
+    fn search_back(&mut self, s: &str) -> Search {
+        match search::find_chars(self, s.as_bytes(), true) {
+            Some(index) => Search::from_index(index, 1),
+            None => Search::Unsupported,
+        }
+    }
 }
 
 mod private{
@@ -174,7 +272,7 @@ pub trait StrPredicate<B: FallibleBool>: FnMut(&str) -> B + Sized{
     /// 
     /// * All chars above position zero are obtained through peeking
     /// * Do not expect all incoming strings to be length `len`,
-    /// the user is expected to handle edge cases.
+    ///   the user is expected to handle edge cases.
     /// 
     /// # Panics
     /// 
@@ -186,12 +284,16 @@ pub trait StrPredicate<B: FallibleBool>: FnMut(&str) -> B + Sized{
     }
 }
 
+impl<P: FnMut(&str) -> B, B: FallibleBool> StrPredicate<B> for P {}
+
 impl<P: FnMut(&str) -> B, B: FallibleBool> Pattern for SizedStrPredicate<P, B> {
     type Err = B::Err;
     fn matches(&mut self, _: char, s: &str) -> Result<bool, Self::Err> {
         (self.pattern)(s).get()
     }
     fn len(&self) -> NonZeroUsize { self.len }
+    /// The entire look-ahead is considered matched.
+    fn match_len(&self, _: char, s: &str) -> usize { s.len() }
 }
 
 
@@ -210,7 +312,7 @@ pub trait CharStrPredicate<B: FallibleBool>: FnMut(char, &str) -> B + Sized{
     /// 
     /// * All chars above position zero are obtained through peeking
     /// * Do not expect all incoming strings to be length `len`,
-    /// the user is expected to handle edge cases.
+    ///   the user is expected to handle edge cases.
     /// 
     /// # Panics
     /// 
@@ -222,6 +324,8 @@ pub trait CharStrPredicate<B: FallibleBool>: FnMut(char, &str) -> B + Sized{
     }
 }
 
+impl<P: FnMut(char, &str) -> B, B: FallibleBool> CharStrPredicate<B> for P {}
+
 impl<P: FnMut(char, &str) -> B, B: FallibleBool> Pattern for SizedCharStrPredicate<P, B> {
     type Err = B::Err;
     
@@ -229,6 +333,8 @@ impl<P: FnMut(char, &str) -> B, B: FallibleBool> Pattern for SizedCharStrPredica
         (self.pattern)(c, s).get()
     }
     fn len(&self) -> NonZeroUsize { self.len }
+    /// The entire look-ahead is considered matched.
+    fn match_len(&self, _: char, s: &str) -> usize { s.len() }
 }
 
 
@@ -242,9 +348,18 @@ impl<'t, T: Pattern> Pattern for PatRef<'t, T> {
     fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
         self.0.matches(c, s)
     }
+    fn match_len(&self, c: char, s: &str) -> usize {
+        self.0.match_len(c, s)
+    }
     fn sep(&self) -> Sep {
         self.0.sep()
     }
+    fn search(&mut self, s: &str) -> Search<Self::Err> {
+        self.0.search(s)
+    }
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+        self.0.search_back(s)
+    }
 }
 
 #[cfg(feature="std")]
@@ -263,9 +378,21 @@ const _:() = {
             self.as_mut().matches(c, s)
         }
         #[doc(hidden)]
+        fn match_len(&self, c: char, s: &str) -> usize {
+            self.as_ref().match_len(c, s)
+        }
+        #[doc(hidden)]
         fn sep(&self) -> Sep {
             self.as_ref().sep()
         }
+        #[doc(hidden)]
+        fn search(&mut self, s: &str) -> Search<Self::Err> {
+            self.as_mut().search(s)
+        }
+        #[doc(hidden)]
+        fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+            self.as_mut().search_back(s)
+        }
     }
 };
 
@@ -278,12 +405,21 @@ const _: () = {
     impl Pattern for String {
         type Err = Never;
         fn matches(&mut self, _: char, s: &str) -> Result<bool, Self::Err> {
-            Ok(self == &s)
+            Ok(self == s)
         }
         fn len(&self) -> NonZeroUsize {
             NonZeroUsize::new(self.chars().count())
                 .expect("\"\" is not a valid pattern")
         }
+        fn match_len(&self, _: char, _: &str) -> usize {
+            str::len(self)
+        }
+        fn search(&mut self, s: &str) -> Search {
+            Search::from_index(search::find_str(self.as_bytes(), s.as_bytes()), str::len(self))
+        }
+        fn search_back(&mut self, s: &str) -> Search {
+            Search::from_index(search::rfind_str(self.as_bytes(), s.as_bytes()), str::len(self))
+        }
     }
 
     impl Pattern for &String {
@@ -295,10 +431,19 @@ const _: () = {
             NonZeroUsize::new(self.chars().count())
                 .expect("\"\" is not a valid pattern")
         }
+        fn match_len(&self, _: char, _: &str) -> usize {
+            str::len(self)
+        }
+        fn search(&mut self, s: &str) -> Search {
+            Search::from_index(search::find_str(self.as_bytes(), s.as_bytes()), str::len(self))
+        }
+        fn search_back(&mut self, s: &str) -> Search {
+            Search::from_index(search::rfind_str(self.as_bytes(), s.as_bytes()), str::len(self))
+        }
     }
 };
 
-
+#[derive(Debug, Clone)]
 pub struct SepConfig<P: SetSep> {
     pattern: P,
     config: Sep,
@@ -313,9 +458,15 @@ impl<P: SetSep> Pattern for SepConfig<P> {
 
     fn len(&self) -> NonZeroUsize { self.pattern.len() }
 
+    fn match_len(&self, c: char, s: &str) -> usize { self.pattern.match_len(c, s) }
+
     fn sep(&self) -> Sep {
         self.config
     }
+
+    fn search(&mut self, s: &str) -> Search<Self::Err> { self.pattern.search(s) }
+
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> { self.pattern.search_back(s) }
 }
 
 /// Allows a pattern to edit its [`sep_method`](crate::Pattern::sep_method).
@@ -359,3 +510,334 @@ macro_rules! pat {
         (|s: str| !matches!(c, $p)).expecting($e)
     };
 }
+
+/// The reason a [`Balanced`] pattern failed, see [`BalanceError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BalanceErrorKind {
+    /// A closing delimiter without an opening delimiter.
+    Unmatched(char),
+    /// A closing delimiter that does not match the last opening delimiter.
+    Mismatched {
+        expected: char,
+        found: char,
+    },
+    /// The end of the string is reached before the closing delimiter.
+    Unclosed {
+        expected: char,
+    },
+    /// Delimiters are nested deeper than [`Balanced::MAX_DEPTH`].
+    TooDeep,
+}
+
+/// The error of a [`Balanced`] pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BalanceError {
+    kind: BalanceErrorKind,
+    offset: usize,
+}
+
+impl BalanceError {
+    /// Returns the reason of the error.
+    pub const fn kind(&self) -> BalanceErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the error from the start of the iterator.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Convert into a [`ParseError`] in `input`, the input of the iterator,
+    /// which is not changed on failure.
+    ///
+    /// # Panics
+    ///
+    /// If the offset is not a [`char`] boundary of `input`.
+    pub fn into_parse_error(self, input: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Balanced(self.kind), &input[self.offset..])
+    }
+}
+
+impl core::fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            BalanceErrorKind::Unmatched(c) => write!(f, "unmatched {:?}", c)?,
+            BalanceErrorKind::Mismatched { expected, found } => write!(f, "expected {:?}, found {:?}", expected, found)?,
+            BalanceErrorKind::Unclosed { expected } => write!(f, "unclosed delimiter, expected {:?}", expected)?,
+            BalanceErrorKind::TooDeep => f.write_str("delimiters nested too deep")?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+#[cfg(feature="std")]
+const _: () = {
+    extern crate std;
+    impl std::error::Error for BalanceError {}
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BalanceState {
+    Code,
+    Quote(char),
+    Escape(char),
+    Comment(usize),
+}
+
+/// A pattern that matches the closing delimiter of a balanced group,
+/// like `)` in `(a, (b, c))`.
+///
+/// Delimiters are tracked with a stack,
+/// mismatched or unmatched closing delimiters are reported as [`Pattern::Err`],
+/// so this is used with [`try_next_slice`](crate::StringIter::try_next_slice)
+/// and [`try_next_slice_back`](crate::StringIter::try_next_slice_back).
+///
+/// Delimiters inside quoted strings and comments can be ignored with
+/// [`quotes()`](Balanced::quotes) and [`comments()`](Balanced::comments).
+/// [`stop_at()`](Balanced::stop_at) matches [`char`]s outside of any group instead.
+///
+/// # Direction
+///
+/// Quotes and comments can only be recognized front to back,
+/// so the [`search_back`](Pattern::search_back) fast path scans the whole string forward,
+/// and reports unbalanced delimiters anywhere in the string.
+/// In reverse, the match is the opening delimiter of the last group,
+/// or the last stop [`char`] outside of any group with [`stop_at()`](Balanced::stop_at).
+///
+/// Unclosed groups are reported at the end of the string,
+/// so [`len()`](Pattern::len) looks ahead to the end of the string.
+/// When used in a [combinator](crate::PatternExt),
+/// a [`char`] by [`char`] match can only run front to back.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, patterns::Balanced};
+/// let mut iter = "{{foo}bar}baz".str_iter();
+/// let s = iter.try_next_slice(Balanced::brackets().sep_with(Sep::Yield));
+/// assert_eq!(s, Ok(Some("{{foo}bar}")));
+/// assert_eq!(iter.as_str(), "baz");
+///
+/// let mut iter = r#"f(")", g(x)) // )"#.str_iter();
+/// let call = Balanced::brackets().quotes(&['"']).comments(&[("//", "\n")]);
+/// assert_eq!(iter.try_next_slice(call.sep_with(Sep::Yield)), Ok(Some(r#"f(")", g(x))"#)));
+///
+/// // split attribute arguments at top level commas
+/// let mut iter = "a, b(c, d), e".str_iter();
+/// let arg = || Balanced::brackets().stop_at(&[',']).sep_with(Sep::Split);
+/// assert_eq!(iter.try_next_slice(arg()), Ok(Some("a")));
+/// assert_eq!(iter.try_next_slice(arg()), Ok(Some(" b(c, d)")));
+/// assert_eq!(iter.try_next_slice(arg()), Ok(Some(" e")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Balanced<'a> {
+    pairs: &'a [(char, char)],
+    quotes: &'a [char],
+    comments: &'a [(&'a str, &'a str)],
+    stops: &'a [char],
+    stack: [u8; Balanced::MAX_DEPTH],
+    depth: usize,
+    state: BalanceState,
+    /// [`char`]s of a comment delimiter left to skip.
+    skip: usize,
+    /// Bytes seen by [`matches`](Pattern::matches).
+    offset: usize,
+}
+
+impl<'a> Balanced<'a> {
+    /// Maximum nesting depth of delimiters.
+    pub const MAX_DEPTH: usize = 64;
+
+    /// `()`, `[]` and `{}`.
+    pub const BRACKETS: &'static [(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}')];
+
+    /// `()`, `[]`, `{}` and `<>`.
+    pub const ALL_BRACKETS: &'static [(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+    /// Construct a [`Balanced`] pattern from pairs of opening and closing delimiters.
+    ///
+    /// # Panics
+    ///
+    /// If there are more than 256 pairs,
+    /// or if a pair has the same opening and closing delimiter.
+    pub fn new(pairs: &'a [(char, char)]) -> Self {
+        assert!(pairs.len() <= 256, "too many delimiter pairs");
+        assert!(pairs.iter().all(|(open, close)| open != close), "delimiters must be distinct");
+        Balanced {
+            pairs,
+            quotes: &[],
+            comments: &[],
+            stops: &[],
+            stack: [0; Balanced::MAX_DEPTH],
+            depth: 0,
+            state: BalanceState::Code,
+            skip: 0,
+            offset: 0,
+        }
+    }
+
+    /// Construct a [`Balanced`] pattern from [`BRACKETS`](Balanced::BRACKETS).
+    pub fn brackets() -> Self {
+        Balanced::new(Balanced::BRACKETS)
+    }
+
+    /// Ignore delimiters inside strings quoted by these [`char`]s,
+    /// `\` escapes the next [`char`] inside a string.
+    pub fn quotes(self, quotes: &'a [char]) -> Self {
+        Balanced { quotes, ..self }
+    }
+
+    /// Ignore delimiters inside comments, given as pairs of start and end delimiters.
+    ///
+    /// Use `("//", "\n")` for line comments.
+    ///
+    /// # Panics
+    ///
+    /// If a delimiter is `""`.
+    pub fn comments(self, comments: &'a [(&'a str, &'a str)]) -> Self {
+        assert!(comments.iter().all(|(start, end)| !start.is_empty() && !end.is_empty()),
+            "\"\" is not a valid comment delimiter");
+        Balanced { comments, ..self }
+    }
+
+    /// Match these [`char`]s outside of any group instead of closing delimiters,
+    /// like `,` in an argument list.
+    pub fn stop_at(self, stops: &'a [char]) -> Self {
+        Balanced { stops, ..self }
+    }
+
+    fn reset(&mut self) {
+        self.depth = 0;
+        self.state = BalanceState::Code;
+        self.skip = 0;
+        self.offset = 0;
+    }
+
+    fn unclosed(&self, offset: usize) -> Option<BalanceError> {
+        let open = *self.stack[..self.depth].last()?;
+        Some(BalanceError {
+            kind: BalanceErrorKind::Unclosed { expected: self.pairs[open as usize].1 },
+            offset,
+        })
+    }
+
+    /// Advance the state by `c`, the first [`char`] of `s` at byte `offset`.
+    fn step(&mut self, c: char, s: &str, offset: usize) -> Result<bool, BalanceError> {
+        if self.skip > 0 {
+            self.skip -= 1;
+            return Ok(false);
+        }
+        match self.state {
+            BalanceState::Quote(q) => {
+                if c == '\\' {
+                    self.state = BalanceState::Escape(q);
+                } else if c == q {
+                    self.state = BalanceState::Code;
+                }
+                return Ok(false);
+            }
+            BalanceState::Escape(q) => {
+                self.state = BalanceState::Quote(q);
+                return Ok(false);
+            }
+            BalanceState::Comment(index) => {
+                let end = self.comments[index].1;
+                if s.starts_with(end) {
+                    self.skip = end.chars().count() - 1;
+                    self.state = BalanceState::Code;
+                }
+                return Ok(false);
+            }
+            BalanceState::Code => (),
+        }
+        if let Some(index) = self.comments.iter().position(|(start, _)| s.starts_with(start)) {
+            self.skip = self.comments[index].0.chars().count() - 1;
+            self.state = BalanceState::Comment(index);
+            return Ok(false);
+        }
+        if self.quotes.contains(&c) {
+            self.state = BalanceState::Quote(c);
+            return Ok(false);
+        }
+        if let Some(index) = self.pairs.iter().position(|(open, _)| *open == c) {
+            if self.depth == Balanced::MAX_DEPTH {
+                return Err(BalanceError { kind: BalanceErrorKind::TooDeep, offset });
+            }
+            self.stack[self.depth] = index as u8;
+            self.depth += 1;
+            return Ok(false);
+        }
+        if let Some(index) = self.pairs.iter().position(|(_, close)| *close == c) {
+            let kind = match self.depth.checked_sub(1) {
+                None => BalanceErrorKind::Unmatched(c),
+                Some(top) if self.stack[top] as usize == index => {
+                    self.depth = top;
+                    return Ok(top == 0 && self.stops.is_empty());
+                }
+                Some(top) => BalanceErrorKind::Mismatched {
+                    expected: self.pairs[self.stack[top] as usize].1,
+                    found: c,
+                },
+            };
+            return Err(BalanceError { kind, offset });
+        }
+        Ok(self.depth == 0 && self.stops.contains(&c))
+    }
+}
+
+impl Pattern for Balanced<'_> {
+    type Err = BalanceError;
+
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
+        let offset = self.offset;
+        self.offset += c.len_utf8();
+        let matched = self.step(c, s, offset)?;
+        // `s` is the rest of the string, this is the last char
+        if !matched && s.len() == c.len_utf8() {
+            if let Some(error) = self.unclosed(self.offset) {
+                return Err(error);
+            }
+        }
+        Ok(matched)
+    }
+
+    fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::MAX
+    }
+
+    fn search(&mut self, s: &str) -> Search<Self::Err> {
+        self.reset();
+        for (offset, c) in s.char_indices() {
+            match self.step(c, &s[offset..], offset) {
+                Ok(true) => return Search::Found(offset, c.len_utf8()),
+                Ok(false) => (),
+                Err(error) => return Search::Error(error),
+            }
+        }
+        match self.unclosed(s.len()) {
+            Some(error) => Search::Error(error),
+            None => Search::NotFound,
+        }
+    }
+
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+        self.reset();
+        // the opening delimiter of the last group, or the last stop
+        let mut last = None;
+        for (offset, c) in s.char_indices() {
+            let depth = self.depth;
+            match self.step(c, &s[offset..], offset) {
+                Ok(true) if depth == 0 => last = Some((offset, c.len_utf8())),
+                Ok(_) => if depth == 0 && self.depth == 1 && self.stops.is_empty() {
+                    last = Some((offset, c.len_utf8()));
+                },
+                Err(error) => return Search::Error(error),
+            }
+        }
+        match (self.unclosed(s.len()), last) {
+            (Some(error), _) => Search::Error(error),
+            (None, Some((offset, len))) => Search::Found(offset, len),
+            (None, None) => Search::NotFound,
+        }
+    }
+}
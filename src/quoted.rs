@@ -0,0 +1,305 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{StringIter, ParseError, Expected};
+use crate::search::find_bytes;
+
+/// The quoting style of a string literal, see [`next_quoted()`](StringIter::next_quoted).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// `"foo"`, with escapes.
+    Double,
+    /// `'foo'`, with escapes.
+    Single,
+    /// `` `foo` ``, with escapes.
+    Backtick,
+    /// `r"foo"` or `r#"foo"#` with any number of `#`, without escapes.
+    Raw,
+}
+
+impl QuoteStyle {
+    fn quote(self) -> u8 {
+        match self {
+            QuoteStyle::Double | QuoteStyle::Raw => b'"',
+            QuoteStyle::Single => b'\'',
+            QuoteStyle::Backtick => b'`',
+        }
+    }
+}
+
+/// The reason a string literal failed to scan, see [`QuoteError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteErrorKind {
+    /// The iterator does not start with the opening quote.
+    NotQuoted,
+    /// The closing quote is missing.
+    Unterminated,
+    /// An unknown escape sequence.
+    InvalidEscape,
+    /// A `\x` escape is not 2 hex digits in the range `00..=7F`.
+    InvalidHexEscape,
+    /// A `\u{..}` or `\uXXXX` escape is malformed or not a unicode scalar value.
+    InvalidUnicodeEscape,
+    /// A `\uXXXX` surrogate is not part of a valid surrogate pair.
+    LoneSurrogate,
+}
+
+/// An error returned by [`next_quoted()`](StringIter::next_quoted).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuoteError {
+    kind: QuoteErrorKind,
+    offset: usize,
+}
+
+impl QuoteError {
+    /// Returns the reason of the error.
+    pub const fn kind(&self) -> QuoteErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the error from the start of the iterator.
+    ///
+    /// This is the offset of the `\` for escape errors,
+    /// and the offset of the opening quote otherwise.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Convert into a [`ParseError`] in `input`, the input of the iterator,
+    /// which is not changed on failure.
+    ///
+    /// # Panics
+    ///
+    /// If the offset is not a [`char`] boundary of `input`.
+    pub fn into_parse_error(self, input: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Quoted(self.kind), &input[self.offset..])
+    }
+}
+
+impl Display for QuoteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let reason = match self.kind {
+            QuoteErrorKind::NotQuoted => "expected a string literal",
+            QuoteErrorKind::Unterminated => "unterminated string literal",
+            QuoteErrorKind::InvalidEscape => "unknown escape sequence",
+            QuoteErrorKind::InvalidHexEscape => "invalid hex escape",
+            QuoteErrorKind::InvalidUnicodeEscape => "invalid unicode escape",
+            QuoteErrorKind::LoneSurrogate => "unpaired surrogate in unicode escape",
+        };
+        write!(f, "{} at byte {}", reason, self.offset)
+    }
+}
+
+#[cfg(feature="std")]
+const _: () = {
+    extern crate std;
+    impl std::error::Error for QuoteError {}
+};
+
+/// A string literal obtained from [`next_quoted()`](StringIter::next_quoted).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Quoted<'t> {
+    raw: &'t str,
+    content: &'t str,
+    style: QuoteStyle,
+    escaped: bool,
+}
+
+impl<'t> Quoted<'t> {
+    /// Returns the literal including its quotes.
+    pub const fn raw(&self) -> &'t str {
+        self.raw
+    }
+
+    /// Returns the literal between its quotes, with escapes not decoded.
+    pub const fn content(&self) -> &'t str {
+        self.content
+    }
+
+    /// Returns the [`QuoteStyle`] of the literal.
+    pub const fn style(&self) -> QuoteStyle {
+        self.style
+    }
+
+    /// Returns `true` if the literal contains escape sequences.
+    pub const fn has_escapes(&self) -> bool {
+        self.escaped
+    }
+
+    /// Returns the literal with escapes decoded.
+    ///
+    /// This borrows if the literal contains no escapes,
+    /// and requires the `std` feature.
+    #[cfg(feature="std")]
+    pub fn decoded(&self) -> alloc::borrow::Cow<'t, str> {
+        use alloc::borrow::Cow;
+        if !self.escaped {
+            return Cow::Borrowed(self.content);
+        }
+        let mut result = alloc::string::String::with_capacity(self.content.len());
+        let bytes = self.content.as_bytes();
+        let mut last = 0;
+        while let Some(index) = find_bytes(b"\\", &bytes[last..]) {
+            let index = last + index;
+            result.push_str(&self.content[last..index]);
+            // escapes are validated by `next_quoted`
+            let (c, len) = escape(self.content, index).unwrap_or(('\u{FFFD}', 1));
+            result.push(c);
+            last = index + len;
+        }
+        result.push_str(&self.content[last..]);
+        Cow::Owned(result)
+    }
+}
+
+/// Parse exactly `len` hex digits at `s[start..]`.
+fn hex(s: &str, start: usize, len: usize) -> Option<u32> {
+    let digits = s.get(start..start + len)?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Decode the escape sequence starting with the `\` at `s[start]`,
+/// returns the decoded [`char`] and the length of the escape sequence.
+fn escape(s: &str, start: usize) -> Result<(char, usize), QuoteErrorKind> {
+    let c = match s.as_bytes().get(start + 1) {
+        Some(b'n') => '\n',
+        Some(b'r') => '\r',
+        Some(b't') => '\t',
+        Some(b'0') => '\0',
+        Some(b'\\') => '\\',
+        Some(b'"') => '"',
+        Some(b'\'') => '\'',
+        Some(b'`') => '`',
+        Some(b'x') => {
+            return match hex(s, start + 2, 2) {
+                Some(value @ 0..=0x7f) => Ok((value as u8 as char, 4)),
+                _ => Err(QuoteErrorKind::InvalidHexEscape),
+            };
+        }
+        Some(b'u') if s.as_bytes().get(start + 2) == Some(&b'{') => {
+            let digits = start + 3;
+            let len = s[digits..].bytes()
+                .position(|b| b == b'}')
+                .ok_or(QuoteErrorKind::InvalidUnicodeEscape)?;
+            if !(1..=6).contains(&len) {
+                return Err(QuoteErrorKind::InvalidUnicodeEscape);
+            }
+            return hex(s, digits, len)
+                .and_then(char::from_u32)
+                .map(|c| (c, len + 4))
+                .ok_or(QuoteErrorKind::InvalidUnicodeEscape);
+        }
+        Some(b'u') => {
+            let high = hex(s, start + 2, 4).ok_or(QuoteErrorKind::InvalidUnicodeEscape)?;
+            return match high {
+                0xD800..=0xDBFF => {
+                    if s.get(start + 6..start + 8) != Some("\\u") {
+                        return Err(QuoteErrorKind::LoneSurrogate);
+                    }
+                    match hex(s, start + 8, 4) {
+                        Some(low @ 0xDC00..=0xDFFF) => {
+                            let c = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                            char::from_u32(c).map(|c| (c, 12)).ok_or(QuoteErrorKind::LoneSurrogate)
+                        }
+                        Some(_) => Err(QuoteErrorKind::LoneSurrogate),
+                        None => Err(QuoteErrorKind::InvalidUnicodeEscape),
+                    }
+                }
+                0xDC00..=0xDFFF => Err(QuoteErrorKind::LoneSurrogate),
+                // SAFETY: safe since high is not a surrogate and less than 0x10000
+                _ => Ok((unsafe { char::from_u32_unchecked(high) }, 6)),
+            };
+        }
+        _ => return Err(QuoteErrorKind::InvalidEscape),
+    };
+    Ok((c, 2))
+}
+
+/// Scan a raw string literal, returns the length of the literal and the range of its content.
+fn scan_raw(s: &str) -> Result<(usize, usize, usize), QuoteError> {
+    let bytes = s.as_bytes();
+    let not_quoted = QuoteError { kind: QuoteErrorKind::NotQuoted, offset: 0 };
+    if bytes.first() != Some(&b'r') {
+        return Err(not_quoted);
+    }
+    let hashes = bytes[1..].iter().take_while(|b| **b == b'#').count();
+    let start = hashes + 2;
+    if bytes.get(start - 1) != Some(&b'"') {
+        return Err(not_quoted);
+    }
+    let mut index = start;
+    while let Some(quote) = find_bytes(b"\"", &bytes[index..]) {
+        let end = index + quote;
+        let closing = end + 1 + hashes;
+        if bytes.get(end + 1..closing).is_some_and(|h| h.iter().all(|b| *b == b'#')) {
+            return Ok((closing, start, end));
+        }
+        index = end + 1;
+    }
+    Err(QuoteError { kind: QuoteErrorKind::Unterminated, offset: 0 })
+}
+
+/// Scan an escaped string literal, returns the length of the literal,
+/// the range of its content and whether it contains escapes.
+fn scan_escaped(s: &str, quote: u8) -> Result<(usize, usize, usize, bool), QuoteError> {
+    let bytes = s.as_bytes();
+    if bytes.first() != Some(&quote) {
+        return Err(QuoteError { kind: QuoteErrorKind::NotQuoted, offset: 0 });
+    }
+    let mut index = 1;
+    let mut escaped = false;
+    while let Some(found) = find_bytes(&[quote, b'\\'], &bytes[index..]) {
+        let found = index + found;
+        if bytes[found] == quote {
+            return Ok((found + 1, 1, found, escaped));
+        }
+        let (_, len) = escape(s, found).map_err(|kind| QuoteError { kind, offset: found })?;
+        escaped = true;
+        index = found + len;
+    }
+    Err(QuoteError { kind: QuoteErrorKind::Unterminated, offset: 0 })
+}
+
+impl<'t> StringIter<'t> {
+
+    /// Consume a string literal in a [`QuoteStyle`].
+    ///
+    /// Supported escapes are `\n`, `\r`, `\t`, `\0`, `\\`, `\"`, `\'`, `` \` ``,
+    /// `\xNN` up to `\x7F`, `\u{N..}` with 1 to 6 hex digits,
+    /// and `\uXXXX` including surrogate pairs like `\uD83E\uDD80`.
+    /// All escapes are validated, but only decoded by [`Quoted::decoded()`].
+    ///
+    /// The iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, QuoteStyle};
+    /// let mut iter = r#""say \"hi\"\u{1F980}" rest"#.str_iter();
+    /// let quoted = iter.next_quoted(QuoteStyle::Double).unwrap();
+    /// assert_eq!(quoted.raw(), r#""say \"hi\"\u{1F980}""#);
+    /// assert_eq!(quoted.decoded(), "say \"hi\"🦀");
+    /// assert_eq!(iter.as_str(), " rest");
+    ///
+    /// let mut iter = r###"r#"C:\path "quoted""#"###.str_iter();
+    /// let quoted = iter.next_quoted(QuoteStyle::Raw).unwrap();
+    /// assert_eq!(quoted.content(), r#"C:\path "quoted""#);
+    /// ```
+    pub fn next_quoted(&mut self, style: QuoteStyle) -> Result<Quoted<'t>, QuoteError> {
+        let (len, start, end, escaped) = match style {
+            QuoteStyle::Raw => {
+                let (len, start, end) = scan_raw(self.str)?;
+                (len, start, end, false)
+            }
+            _ => scan_escaped(self.str, style.quote())?,
+        };
+        let quoted = Quoted {
+            raw: &self.str[..len],
+            content: &self.str[start..end],
+            style,
+            escaped,
+        };
+        self.str = &self.str[len..];
+        Ok(quoted)
+    }
+}
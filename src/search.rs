@@ -0,0 +1,349 @@
+//! Portable word-at-a-time byte searching, used by [`Pattern`](crate::Pattern) fast paths.
+
+use core::mem::size_of;
+
+const WORD: usize = size_of::<usize>();
+const LO: usize = usize::MAX / 255;
+const HI: usize = LO * 0x80;
+
+/// Returns `true` if any byte in `x` is zero.
+#[inline(always)]
+const fn has_zero(x: usize) -> bool {
+    x.wrapping_sub(LO) & !x & HI != 0
+}
+
+#[inline(always)]
+fn word(chunk: &[u8]) -> usize {
+    let mut bytes = [0u8; WORD];
+    bytes.copy_from_slice(chunk);
+    usize::from_ne_bytes(bytes)
+}
+
+/// Returns `true` if any byte in `w` is in `needles`.
+#[inline(always)]
+fn has_any<const N: usize>(w: usize, needles: &[usize; N]) -> bool {
+    needles.iter().any(|n| has_zero(w ^ n))
+}
+
+#[inline(always)]
+fn repeat<const N: usize>(needles: &[u8; N]) -> [usize; N] {
+    let mut repeated = [0usize; N];
+    for (r, n) in repeated.iter_mut().zip(needles) {
+        *r = LO * *n as usize;
+    }
+    repeated
+}
+
+/// Find the first byte in `haystack` that is in `needles`.
+///
+/// Scans a word at a time, intended for a small number of needles.
+pub(crate) fn find_bytes<const N: usize>(needles: &[u8; N], haystack: &[u8]) -> Option<usize> {
+    // matches are often close, check the first word byte by byte
+    let prefix = haystack.len().min(WORD);
+    if let Some(i) = haystack[..prefix].iter().position(|b| needles.contains(b)) {
+        return Some(i);
+    }
+    let repeated = repeat(needles);
+    let mut offset = prefix;
+    for chunk in haystack[prefix..].chunks_exact(WORD) {
+        if has_any(word(chunk), &repeated) {
+            break;
+        }
+        offset += WORD;
+    }
+    haystack[offset..].iter()
+        .position(|b| needles.contains(b))
+        .map(|i| i + offset)
+}
+
+/// Find the last byte in `haystack` that is in `needles`.
+///
+/// Scans a word at a time, intended for a small number of needles.
+pub(crate) fn rfind_bytes<const N: usize>(needles: &[u8; N], haystack: &[u8]) -> Option<usize> {
+    let prefix = haystack.len() - haystack.len().min(WORD);
+    if let Some(i) = haystack[prefix..].iter().rposition(|b| needles.contains(b)) {
+        return Some(i + prefix);
+    }
+    let repeated = repeat(needles);
+    let mut end = prefix;
+    for chunk in haystack[..prefix].rchunks_exact(WORD) {
+        if has_any(word(chunk), &repeated) {
+            break;
+        }
+        end -= WORD;
+    }
+    haystack[..end].iter().rposition(|b| needles.contains(b))
+}
+
+/// Find the first byte in `haystack` in an ASCII set.
+pub(crate) fn find_ascii_set(set: &[bool; 128], haystack: &[u8]) -> Option<usize> {
+    haystack.iter().position(|b| *b < 0x80 && set[*b as usize])
+}
+
+/// Find the last byte in `haystack` in an ASCII set.
+pub(crate) fn rfind_ascii_set(set: &[bool; 128], haystack: &[u8]) -> Option<usize> {
+    haystack.iter().rposition(|b| *b < 0x80 && set[*b as usize])
+}
+
+/// Find the first occurance of `needle` in `haystack`.
+pub(crate) fn find_str(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+    match needle.len() {
+        0 => Some(0),
+        1 => find_bytes(&[needle[0]], haystack),
+        _ => TwoWaySearcher::new(needle).find(needle, haystack),
+    }
+}
+
+/// Find the last occurance of `needle` in `haystack`.
+pub(crate) fn rfind_str(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+    match needle.len() {
+        0 => Some(haystack.len()),
+        1 => rfind_bytes(&[needle[0]], haystack),
+        _ => TwoWaySearcher::new(needle).rfind(needle, haystack),
+    }
+}
+
+/// The Crochemore-Perrin two-way string matching algorithm,
+/// which runs in `O(n + m)` time and `O(1)` space.
+///
+/// The needle is split at a critical factorization `needle[..crit_pos]`, `needle[crit_pos..]`.
+/// The right half is matched first, on mismatch we can skip by the mismatch position,
+/// then the left half is matched, on mismatch we can skip by the period of the needle.
+///
+/// If the needle has a short period, a `memory` of the already matched prefix
+/// is kept between shifts to avoid quadratic behavior.
+///
+/// This follows the implementation of `StrSearcher` in `core`.
+struct TwoWaySearcher {
+    /// Critical factorization index for searching forward.
+    crit_pos: usize,
+    /// Critical factorization index for searching backward.
+    crit_pos_back: usize,
+    period: usize,
+    /// A bitmask of `byte & 63` in the needle, used as a quick rejection.
+    byteset: u64,
+    /// `false` if the needle has a long period and memory is not used.
+    short_period: bool,
+}
+
+impl TwoWaySearcher {
+    fn new(needle: &[u8]) -> Self {
+        let (crit_pos_false, period_false) = maximal_suffix(needle, false);
+        let (crit_pos_true, period_true) = maximal_suffix(needle, true);
+        let (crit_pos, period) = if crit_pos_false > crit_pos_true {
+            (crit_pos_false, period_false)
+        } else {
+            (crit_pos_true, period_true)
+        };
+        let byteset = needle.iter().fold(0, |a, b| (1 << (b & 0x3f)) | a);
+        if needle[..crit_pos] == needle[period..period + crit_pos] {
+            let crit_pos_back = needle.len() - usize::max(
+                reverse_maximal_suffix(needle, period, false),
+                reverse_maximal_suffix(needle, period, true),
+            );
+            TwoWaySearcher { crit_pos, crit_pos_back, period, byteset, short_period: true }
+        } else {
+            TwoWaySearcher {
+                crit_pos,
+                crit_pos_back: crit_pos,
+                period: usize::max(crit_pos, needle.len() - crit_pos) + 1,
+                byteset,
+                short_period: false,
+            }
+        }
+    }
+
+    #[inline]
+    fn byteset_contains(&self, b: u8) -> bool {
+        (self.byteset >> (b & 0x3f)) & 1 != 0
+    }
+
+    fn find(&self, needle: &[u8], haystack: &[u8]) -> Option<usize> {
+        let len = needle.len();
+        let mut position = 0;
+        let mut memory = 0;
+        'search: loop {
+            let tail = *haystack.get(position + len - 1)?;
+            if !self.byteset_contains(tail) {
+                position += len;
+                memory = 0;
+                continue 'search;
+            }
+            // match the right half
+            let start = if self.short_period { self.crit_pos.max(memory) } else { self.crit_pos };
+            for i in start..len {
+                if needle[i] != haystack[position + i] {
+                    position += i - self.crit_pos + 1;
+                    memory = 0;
+                    continue 'search;
+                }
+            }
+            // match the left half
+            let start = if self.short_period { memory } else { 0 };
+            for i in (start..self.crit_pos).rev() {
+                if needle[i] != haystack[position + i] {
+                    position += self.period;
+                    if self.short_period {
+                        memory = len - self.period;
+                    }
+                    continue 'search;
+                }
+            }
+            return Some(position);
+        }
+    }
+
+    fn rfind(&self, needle: &[u8], haystack: &[u8]) -> Option<usize> {
+        let len = needle.len();
+        let mut end = haystack.len();
+        let mut memory = len;
+        'search: loop {
+            let start = end.checked_sub(len)?;
+            if !self.byteset_contains(haystack[start]) {
+                end -= len;
+                memory = len;
+                continue 'search;
+            }
+            // match the left half
+            let crit = if self.short_period { self.crit_pos_back.min(memory) } else { self.crit_pos_back };
+            for i in (0..crit).rev() {
+                if needle[i] != haystack[start + i] {
+                    end -= self.crit_pos_back - i;
+                    memory = len;
+                    continue 'search;
+                }
+            }
+            // match the right half
+            let needle_end = if self.short_period { memory } else { len };
+            for i in self.crit_pos_back..needle_end {
+                if needle[i] != haystack[start + i] {
+                    end -= self.period;
+                    if self.short_period {
+                        memory = self.period;
+                    }
+                    continue 'search;
+                }
+            }
+            return Some(start);
+        }
+    }
+}
+
+/// Compute the maximal suffix of `arr` and its period,
+/// under `<` if `order_greater` is `false`, and `>` otherwise.
+fn maximal_suffix(arr: &[u8], order_greater: bool) -> (usize, usize) {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+    while let Some(&a) = arr.get(right + offset) {
+        let b = arr[left + offset];
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+/// Compute the maximal suffix of the reverse of `arr`,
+/// stopping once `known_period` is reached.
+fn reverse_maximal_suffix(arr: &[u8], known_period: usize, order_greater: bool) -> usize {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+    let n = arr.len();
+    while right + offset < n {
+        let a = arr[n - (1 + right + offset)];
+        let b = arr[n - (1 + left + offset)];
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+        if period == known_period {
+            break;
+        }
+    }
+    left
+}
+
+/// Find the first occurance of `c` in `haystack`.
+pub(crate) fn find_char(c: char, haystack: &[u8]) -> Option<usize> {
+    if c.is_ascii() {
+        find_bytes(&[c as u8], haystack)
+    } else {
+        find_str(c.encode_utf8(&mut [0; 4]).as_bytes(), haystack)
+    }
+}
+
+/// Find the last occurance of `c` in `haystack`.
+pub(crate) fn rfind_char(c: char, haystack: &[u8]) -> Option<usize> {
+    if c.is_ascii() {
+        rfind_bytes(&[c as u8], haystack)
+    } else {
+        rfind_str(c.encode_utf8(&mut [0; 4]).as_bytes(), haystack)
+    }
+}
+
+/// Find the first or last occurance of any char in `chars` in `haystack`,
+/// returns `None` if `chars` is not an ASCII set.
+pub(crate) fn find_chars(chars: &[char], haystack: &[u8], rev: bool) -> Option<Option<usize>> {
+    if !chars.iter().all(char::is_ascii) {
+        return None;
+    }
+    macro_rules! find {
+        ($($n: literal),*) => {
+            match chars.len() {
+                $($n => {
+                    let mut bytes = [0u8; $n];
+                    for (b, c) in bytes.iter_mut().zip(chars) {
+                        *b = *c as u8;
+                    }
+                    return Some(if rev {
+                        rfind_bytes(&bytes, haystack)
+                    } else {
+                        find_bytes(&bytes, haystack)
+                    });
+                })*
+                _ => (),
+            }
+        };
+    }
+    find!(0, 1, 2, 3, 4);
+    let mut set = [false; 128];
+    for c in chars {
+        set[*c as usize] = true;
+    }
+    Some(if rev {
+        rfind_ascii_set(&set, haystack)
+    } else {
+        find_ascii_set(&set, haystack)
+    })
+}
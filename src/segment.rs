@@ -0,0 +1,337 @@
+use crate::{StringIter, merge::substr_range, pattern::{Pattern, Never, Search}};
+use crate::grapheme::{lookup, is_extended_pictographic};
+use crate::unicode_tables::{WordCat, SentenceCat, WORD_TABLE, SENTENCE_TABLE};
+
+fn word_cat(c: char) -> WordCat {
+    match c {
+        'a'..='z' | 'A'..='Z' => WordCat::ALetter,
+        '0'..='9' => WordCat::Numeric,
+        ' ' => WordCat::WSegSpace,
+        _ => lookup(c, WORD_TABLE, WordCat::Other),
+    }
+}
+
+fn sentence_cat(c: char) -> SentenceCat {
+    match c {
+        'a'..='z' => SentenceCat::Lower,
+        'A'..='Z' => SentenceCat::Upper,
+        '0'..='9' => SentenceCat::Numeric,
+        ' ' => SentenceCat::Sp,
+        '.' => SentenceCat::ATerm,
+        _ => lookup(c, SENTENCE_TABLE, SentenceCat::Other),
+    }
+}
+
+/// Returns `true` if there is a word boundary between `before` and `after`,
+/// following the rules in [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundary_Rules).
+///
+/// `before` must start on a word boundary.
+pub(crate) fn is_word_boundary(before: &str, after: &str) -> bool {
+    use WordCat::*;
+    let (Some(prev), Some(next)) = (before.chars().next_back(), after.chars().next()) else {
+        return true;
+    };
+    let (prev_cat, next_cat) = (word_cat(prev), word_cat(next));
+    match (prev_cat, next_cat) {
+        // WB3
+        (CR, LF) => return false,
+        // WB3a, WB3b
+        (CR | LF | Newline, _) | (_, CR | LF | Newline) => return true,
+        // WB3c
+        (ZWJ, _) if is_extended_pictographic(next) => return false,
+        // WB3d
+        (WSegSpace, WSegSpace) => return false,
+        // WB4
+        (_, Extend | Format | ZWJ) => return false,
+        _ => (),
+    }
+    // WB4: ignore Extend, Format and ZWJ, except after sot, CR, LF and Newline
+    let ignored = |cat: &WordCat| !matches!(cat, Extend | Format | ZWJ);
+    let mut back = before.chars().rev().map(word_cat).filter(ignored);
+    let prev = match back.next() {
+        Some(CR | LF | Newline) | None => return true,
+        Some(cat) => cat,
+    };
+    let is_mid = |cat| matches!(cat, MidLetter | MidNum | MidNumLet | SingleQuote | DoubleQuote);
+    let is_ah = |cat: WordCat| matches!(cat, ALetter | HebrewLetter);
+    let is_mid_letter = |cat| matches!(cat, MidLetter | MidNumLet | SingleQuote);
+    let is_mid_num = |cat| matches!(cat, MidNum | MidNumLet | SingleQuote);
+    let next2 = if is_mid(next_cat) {
+        after.chars().skip(1).map(word_cat).find(ignored)
+    } else {
+        None
+    };
+    let prev2 = if is_mid(prev) { back.next() } else { None };
+    match (prev, next_cat) {
+        // WB5
+        (p, n) if is_ah(p) && is_ah(n) => false,
+        // WB6
+        (p, n) if is_ah(p) && is_mid_letter(n) && next2.is_some_and(is_ah) => false,
+        // WB7
+        (p, n) if is_mid_letter(p) && is_ah(n) && prev2.is_some_and(is_ah) => false,
+        // WB7a
+        (HebrewLetter, SingleQuote) => false,
+        // WB7b
+        (HebrewLetter, DoubleQuote) if next2 == Some(HebrewLetter) => false,
+        // WB7c
+        (DoubleQuote, HebrewLetter) if prev2 == Some(HebrewLetter) => false,
+        // WB8, WB9, WB10
+        (Numeric, Numeric) => false,
+        (p, Numeric) if is_ah(p) => false,
+        (Numeric, n) if is_ah(n) => false,
+        // WB11
+        (p, Numeric) if is_mid_num(p) && prev2 == Some(Numeric) => false,
+        // WB12
+        (Numeric, n) if is_mid_num(n) && next2 == Some(Numeric) => false,
+        // WB13
+        (Katakana, Katakana) => false,
+        // WB13a
+        (ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet, ExtendNumLet) => false,
+        // WB13b
+        (ExtendNumLet, ALetter | HebrewLetter | Numeric | Katakana) => false,
+        // WB15, WB16
+        (RegionalIndicator, RegionalIndicator) => {
+            let count = 1 + back.take_while(|cat| *cat == RegionalIndicator).count();
+            count % 2 == 0
+        }
+        // WB999
+        _ => true,
+    }
+}
+
+/// Returns `true` if there is a sentence boundary between `before` and `after`,
+/// following the rules in [UAX #29](https://www.unicode.org/reports/tr29/#Sentence_Boundary_Rules).
+///
+/// `before` must start on a sentence boundary.
+pub(crate) fn is_sentence_boundary(before: &str, after: &str) -> bool {
+    use SentenceCat::*;
+    let (Some(prev), Some(next)) = (before.chars().next_back(), after.chars().next()) else {
+        return true;
+    };
+    let (prev_cat, next_cat) = (sentence_cat(prev), sentence_cat(next));
+    match (prev_cat, next_cat) {
+        // SB3
+        (CR, LF) => return false,
+        // SB4
+        (Sep | CR | LF, _) => return true,
+        // SB5
+        (_, Extend | Format) => return false,
+        _ => (),
+    }
+    // SB5: ignore Extend and Format, except after sot and ParaSep
+    let ignored = |cat: &SentenceCat| !matches!(cat, Extend | Format);
+    let mut back = before.chars().rev().map(sentence_cat).filter(ignored).peekable();
+    let prev = match back.peek() {
+        Some(Sep | CR | LF) | None => return false,
+        Some(cat) => *cat,
+    };
+    // SB6
+    if prev == ATerm && next_cat == Numeric {
+        return false;
+    }
+    // SB7
+    if prev == ATerm && next_cat == Upper && matches!(back.clone().nth(1), Some(Upper | Lower)) {
+        return false;
+    }
+    // match SATerm Close* Sp* in reverse
+    let mut sp = false;
+    let mut close = false;
+    let term = loop {
+        match back.next() {
+            Some(Sp) if !close => sp = true,
+            Some(Close) => close = true,
+            Some(term @ (ATerm | STerm)) => break term,
+            // SB998
+            _ => return false,
+        }
+    };
+    // SB8
+    if term == ATerm {
+        let lower = after.chars()
+            .map(sentence_cat)
+            .filter(ignored)
+            .find(|cat| matches!(cat, OLetter | Upper | Lower | Sep | CR | LF | ATerm | STerm));
+        if lower == Some(Lower) {
+            return false;
+        }
+    }
+    match next_cat {
+        // SB8a
+        SContinue | STerm | ATerm => false,
+        // SB9
+        Close if !sp => false,
+        // SB9, SB10
+        Sp | Sep | CR | LF => false,
+        // SB11
+        _ => true,
+    }
+}
+
+/// Returns the byte offset of the first boundary after the first [`char`] in `s`.
+fn next_boundary(s: &str, is_boundary: fn(&str, &str) -> bool) -> usize {
+    for (index, _) in s.char_indices().skip(1) {
+        if is_boundary(&s[..index], &s[index..]) {
+            return index;
+        }
+    }
+    s.len()
+}
+
+/// Returns the byte offset of the last boundary before the last [`char`] in `s`.
+fn next_boundary_back(s: &str, is_boundary: fn(&str, &str) -> bool) -> usize {
+    for (index, _) in s.char_indices().rev() {
+        if is_boundary(&s[..index], &s[index..]) {
+            return index;
+        }
+    }
+    0
+}
+
+impl<'t> StringIter<'t> {
+
+    fn next_segment(&mut self, is_boundary: fn(&str, &str) -> bool) -> Option<&'t str> {
+        if self.is_empty() {
+            return None;
+        }
+        let (result, rest) = self.str.split_at(next_boundary(self.str, is_boundary));
+        self.str = rest;
+        Some(result)
+    }
+
+    fn next_segment_back(&mut self, is_boundary: fn(&str, &str) -> bool) -> Option<&'t str> {
+        if self.is_empty() {
+            return None;
+        }
+        let (rest, result) = self.str.split_at(next_boundary_back(self.str, is_boundary));
+        self.str = rest;
+        Some(result)
+    }
+
+    /// Returns a leading word segment from the `StringIter` and advances it.
+    ///
+    /// Word segments follow the [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries)
+    /// default word boundaries, whitespace and punctuation are yielded as their own segments.
+    /// The iterator is assumed to start and end on word boundaries.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "can't stop 3.14".str_iter();
+    /// assert_eq!(iter.next_word(), Some("can't"));
+    /// assert_eq!(iter.next_word(), Some(" "));
+    /// assert_eq!(iter.next_word_back(), Some("3.14"));
+    /// assert_eq!(iter.as_str(), "stop ");
+    /// ```
+    pub fn next_word(&mut self) -> Option<&'t str> {
+        self.next_segment(is_word_boundary)
+    }
+
+    /// Returns a trailing word segment from the `StringIter` and advances it.
+    ///
+    /// See [`next_word`](StringIter::next_word).
+    pub fn next_word_back(&mut self) -> Option<&'t str> {
+        self.next_segment_back(is_word_boundary)
+    }
+
+    /// Returns a leading sentence from the `StringIter` and advances it.
+    ///
+    /// Sentences follow the [UAX #29](https://www.unicode.org/reports/tr29/#Sentence_Boundaries)
+    /// default sentence boundaries, trailing whitespace is included in the sentence.
+    /// The iterator is assumed to start and end on sentence boundaries.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let mut iter = "It is here, e.g. now. Hi!".str_iter();
+    /// assert_eq!(iter.next_sentence(), Some("It is here, e.g. now. "));
+    /// assert_eq!(iter.next_sentence(), Some("Hi!"));
+    /// ```
+    pub fn next_sentence(&mut self) -> Option<&'t str> {
+        self.next_segment(is_sentence_boundary)
+    }
+
+    /// Returns a trailing sentence from the `StringIter` and advances it.
+    ///
+    /// See [`next_sentence`](StringIter::next_sentence).
+    pub fn next_sentence_back(&mut self) -> Option<&'t str> {
+        self.next_segment_back(is_sentence_boundary)
+    }
+}
+
+macro_rules! boundary_pattern {
+    ($name: ident, $func: ident, $doc: literal) => {
+
+        #[doc = $doc]
+        /// 
+        /// Boundaries depend on the surrounding text,
+        /// so the pattern is constructed from the source [`str`].
+        /// Strings passed to the pattern should be substrings of the source,
+        /// otherwise they are treated as the start of the text.
+        /// 
+        /// This is a [`Retain`](crate::Sep::Retain) pattern that matches the first [`char`]
+        /// after each boundary, intended for use with [`into_substrs`](StringIter::into_substrs).
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<'t> {
+            text: &'t str,
+        }
+
+        impl<'t> $name<'t> {
+            /// Construct the pattern from the source text.
+            pub const fn new(text: &'t str) -> Self {
+                Self { text }
+            }
+
+            /// Obtain `s` and its offset in the source text.
+            fn context<'s>(&self, s: &'s str) -> (&'s str, usize) where 't: 's {
+                match substr_range(self.text, s) {
+                    Some(range) => (self.text, range.start),
+                    None => (s, 0),
+                }
+            }
+
+            fn is_boundary(text: &str, index: usize) -> bool {
+                $func(&text[..index], &text[index..])
+            }
+        }
+
+        impl Pattern for $name<'_> {
+            type Err = Never;
+
+            fn matches(&mut self, _: char, s: &str) -> Result<bool, Self::Err> {
+                let (text, offset) = self.context(s);
+                Ok(Self::is_boundary(text, offset))
+            }
+
+            fn search(&mut self, s: &str) -> Search {
+                let (text, offset) = self.context(s);
+                for (index, c) in s.char_indices() {
+                    if Self::is_boundary(text, offset + index) {
+                        return Search::Found(index, c.len_utf8());
+                    }
+                }
+                Search::NotFound
+            }
+
+            fn search_back(&mut self, s: &str) -> Search {
+                let (text, offset) = self.context(s);
+                for (index, c) in s.char_indices().rev() {
+                    if Self::is_boundary(text, offset + index) {
+                        return Search::Found(index, c.len_utf8());
+                    }
+                }
+                Search::NotFound
+            }
+        }
+    };
+}
+
+boundary_pattern!(WordBounds, is_word_boundary,
+    "A [`Pattern`] that matches the start of each word segment, see [`next_word`](StringIter::next_word).\n\n\
+    ```\n\
+    # use string_iter::{prelude::*, patterns::WordBounds};\n\
+    let text = \"don't panic\";\n\
+    let mut iter = text.str_iter().into_substrs(WordBounds::new(text));\n\
+    assert_eq!(iter.next(), Some(\"don't\"));\n\
+    assert_eq!(iter.next(), Some(\" \"));\n\
+    assert_eq!(iter.next(), Some(\"panic\"));\n\
+    ```");
+boundary_pattern!(SentenceBounds, is_sentence_boundary,
+    "A [`Pattern`] that matches the start of each sentence, see [`next_sentence`](StringIter::next_sentence).");
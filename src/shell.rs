@@ -0,0 +1,240 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+use crate::{StringIter, ParseError, Expected};
+
+/// The reason a command line failed to split, see [`ShellError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShellErrorKind {
+    /// A `'` is not closed.
+    UnterminatedSingleQuote,
+    /// A `"` is not closed.
+    UnterminatedDoubleQuote,
+    /// A `\` at the end of the string.
+    TrailingBackslash,
+}
+
+/// An error returned by [`shell_words()`](StringIter::shell_words).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShellError {
+    kind: ShellErrorKind,
+    offset: usize,
+}
+
+impl ShellError {
+    /// Returns the reason of the error.
+    pub const fn kind(&self) -> ShellErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the opening quote or the `\`,
+    /// from the start of the command line.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Convert into a [`ParseError`] in `command`, the command line that was split,
+    /// expecting [`Named("a shell word")`](Expected::Named).
+    ///
+    /// # Panics
+    ///
+    /// If the offset is not a [`char`] boundary of `command`.
+    pub fn into_parse_error(self, command: &str) -> ParseError<'_> {
+        ParseError::new(Expected::Named("a shell word"), &command[self.offset..])
+    }
+}
+
+impl Display for ShellError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let reason = match self.kind {
+            ShellErrorKind::UnterminatedSingleQuote => "unterminated single quote",
+            ShellErrorKind::UnterminatedDoubleQuote => "unterminated double quote",
+            ShellErrorKind::TrailingBackslash => "trailing backslash",
+        };
+        write!(f, "{} at byte {}", reason, self.offset)
+    }
+}
+
+const _: () = {
+    extern crate std;
+    impl std::error::Error for ShellError {}
+};
+
+fn is_blank(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n')
+}
+
+/// Returns `true` if `c` does not need quoting.
+fn is_safe(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ',' | ':' | '=' | '+' | '@' | '%')
+}
+
+/// An iterator that yields the words of a command line,
+/// see [`shell_words()`](StringIter::shell_words).
+#[derive(Debug, Clone)]
+pub struct ShellWords<'t> {
+    origin: &'t str,
+    iter: StringIter<'t>,
+}
+
+impl<'t> ShellWords<'t> {
+    /// Returns the remaining [`str`] of this iterator.
+    pub fn as_str(&self) -> &'t str {
+        self.iter.as_str()
+    }
+
+    fn error(&mut self, kind: ShellErrorKind, s: &str) -> ShellError {
+        self.iter.drain();
+        ShellError { kind, offset: s.as_ptr() as usize - self.origin.as_ptr() as usize }
+    }
+
+    /// Skip blanks, line continuations and comments.
+    fn skip_blanks(&mut self) {
+        loop {
+            self.iter.trim_start_by(is_blank);
+            let s = self.iter.as_str();
+            if let Some(rest) = s.strip_prefix("\\\n") {
+                self.iter = StringIter::new(rest);
+            } else if s.starts_with('#') {
+                let end = s.find('\n').unwrap_or(s.len());
+                self.iter = StringIter::new(&s[end..]);
+            } else {
+                return;
+            }
+        }
+    }
+}
+
+impl<'t> Iterator for ShellWords<'t> {
+    type Item = Result<Cow<'t, str>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_blanks();
+        let s = self.iter.as_str();
+        if s.is_empty() {
+            return None;
+        }
+        // words without quotes or escapes are borrowed
+        let special = s.find(|c| is_blank(c) || matches!(c, '\\' | '\'' | '"'));
+        let end = special.unwrap_or(s.len());
+        if !matches!(special, Some(i) if matches!(s.as_bytes()[i], b'\\' | b'\'' | b'"')) {
+            self.iter = StringIter::new(&s[end..]);
+            return Some(Ok(Cow::Borrowed(&s[..end])));
+        }
+        let mut word = String::from(&s[..end]);
+        let mut rest = &s[end..];
+        while let Some(c) = rest.chars().next() {
+            match c {
+                c if is_blank(c) => break,
+                '\\' => match rest[1..].chars().next() {
+                    Some('\n') => rest = &rest[2..],
+                    Some(next) => {
+                        word.push(next);
+                        rest = &rest[1 + next.len_utf8()..];
+                    }
+                    None => return Some(Err(self.error(ShellErrorKind::TrailingBackslash, rest))),
+                },
+                '\'' => match rest[1..].find('\'') {
+                    Some(close) => {
+                        word.push_str(&rest[1..close + 1]);
+                        rest = &rest[close + 2..];
+                    }
+                    None => return Some(Err(self.error(ShellErrorKind::UnterminatedSingleQuote, rest))),
+                },
+                '"' => {
+                    let open = rest;
+                    rest = &rest[1..];
+                    loop {
+                        let Some(index) = rest.find(['"', '\\']) else {
+                            return Some(Err(self.error(ShellErrorKind::UnterminatedDoubleQuote, open)));
+                        };
+                        word.push_str(&rest[..index]);
+                        if rest.as_bytes()[index] == b'"' {
+                            rest = &rest[index + 1..];
+                            break;
+                        }
+                        // inside double quotes, `\` only escapes these
+                        let c = match rest[index + 1..].chars().next() {
+                            Some(c) => c,
+                            None => return Some(Err(self.error(ShellErrorKind::UnterminatedDoubleQuote, open))),
+                        };
+                        match c {
+                            '\n' => (),
+                            '$' | '`' | '"' | '\\' => word.push(c),
+                            c => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                        }
+                        rest = &rest[index + 1 + c.len_utf8()..];
+                    }
+                }
+                c => {
+                    word.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        let raw = &s[..s.len() - rest.len()];
+        self.iter = StringIter::new(rest);
+        // a single quoted word can also be borrowed
+        match raw.strip_prefix('\'').and_then(|r| r.strip_suffix('\'')) {
+            Some(inner) if inner == word => Some(Ok(Cow::Borrowed(inner))),
+            _ => Some(Ok(Cow::Owned(word))),
+        }
+    }
+}
+
+impl FusedIterator for ShellWords<'_> {}
+
+impl<'t> StringIter<'t> {
+
+    /// Map the iterator into an iterator of words,
+    /// split the way a POSIX shell does without expansions.
+    ///
+    /// * Words are separated by spaces, tabs and newlines.
+    /// * `'` quotes everything until the next `'`.
+    /// * `"` quotes until the next `"`, where `\` only escapes `$`, `` ` ``, `"`, `\` and newlines.
+    /// * `\` outside quotes escapes any [`char`], `\` followed by a newline is removed.
+    /// * `#` at the start of a word starts a comment until the end of the line.
+    ///
+    /// Words without quotes or escapes are borrowed.
+    /// After an error, the iterator is empty.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let cmd = r#"git commit -m "fix \"quotes\"" --author='Ferris <ferris@crab.io>' # comment"#;
+    /// let words: Result<Vec<_>, _> = cmd.str_iter().shell_words().collect();
+    /// assert_eq!(words.unwrap(), [
+    ///     "git", "commit", "-m", r#"fix "quotes""#, "--author=Ferris <ferris@crab.io>"
+    /// ]);
+    /// ```
+    pub fn shell_words(self) -> ShellWords<'t> {
+        ShellWords { origin: self.str, iter: self }
+    }
+}
+
+/// Quote `s` for a POSIX shell, so that [`shell_words()`](StringIter::shell_words)
+/// or a shell reads it back as a single word.
+///
+/// Borrows if `s` does not need quoting,
+/// otherwise `s` is quoted with `'`, with `'` written as `'\''`.
+pub(crate) fn shell_quote(s: &str) -> Cow<'_, str> {
+    if !s.is_empty() && s.chars().all(is_safe) {
+        return Cow::Borrowed(s);
+    }
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        match c {
+            '\'' => quoted.push_str("'\\''"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    Cow::Owned(quoted)
+}
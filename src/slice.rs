@@ -1,5 +1,5 @@
 
-use crate::{StringIter, pattern::{Pattern, Never, Sep}};
+use crate::{StringIter, pattern::{Pattern, Never, Sep, Search}};
 
 
 
@@ -15,6 +15,13 @@ impl Sep {
     }
 }
 
+/// Obtain [`match_len`](Pattern::match_len) and make sure it does not escape `s`.
+#[inline]
+fn checked_match_len<P: Pattern>(pat: &P, c: char, s: &str) -> usize {
+    let len = pat.match_len(c, s);
+    assert!(s.is_char_boundary(len), "match_len() is not a char boundary of the matched str");
+    len
+}
 
 impl<'t> StringIter<'t> {
 
@@ -43,38 +50,50 @@ impl<'t> StringIter<'t> {
     /// 
     /// see [`SetSep`](crate::SetSep)
     pub fn try_next_slice<P: Pattern>(&mut self, mut pat: P) -> Result<Option<&'t str>, P::Err> {
-        if self.len() == 0{
+        if self.is_empty(){
             return Ok(None);
         }
         let mut index = self.len();
-        let mut char_len = 0;
-        if pat.len().get() == 1{
-            for (c, s) in self.clone(){
-                if pat.matches(c, s)? {
-                    index = s.as_ptr() as usize - self.str.as_ptr() as usize;
-                    char_len = s.len();
-                    break;
-                }
+        let mut match_len = 0;
+        match pat.search(self.str) {
+            Search::Found(i, len) => {
+                index = i;
+                match_len = len;
+                assert!(self.str.is_char_boundary(index) && self.str.is_char_boundary(index + match_len),
+                    "search() is not on a char boundary");
             }
-        } else {
-            for (c, s) in self.clone().look_ahead(pat.len().get()){
-                if pat.matches(c, s)? {
-                    index = s.as_ptr() as usize - self.str.as_ptr() as usize;
-                    char_len = c.len_utf8();
-                    break;
+            Search::NotFound => (),
+            Search::Error(err) => return Err(err),
+            Search::Unsupported => if pat.len().get() == 1{
+                for (c, s) in self.clone(){
+                    if pat.matches(c, s)? {
+                        index = s.as_ptr() as usize - self.str.as_ptr() as usize;
+                        match_len = checked_match_len(&pat, c, s);
+                        break;
+                    }
+                }
+            } else {
+                for (c, s) in self.clone().look_ahead(pat.len().get()){
+                    if pat.matches(c, s)? {
+                        index = s.as_ptr() as usize - self.str.as_ptr() as usize;
+                        match_len = checked_match_len(&pat, c, s);
+                        break;
+                    }
                 }
             }
         }
+        // SAFETY: safe since index is a char boundary
+        // and match_len is a char boundary of a substring starting at index
         unsafe{
             let result = if pat.sep().is_yielded() {
-                self.str.get_unchecked(..index + char_len)
+                self.str.get_unchecked(..index + match_len)
             } else {
                 self.str.get_unchecked(..index)
             };
             if pat.sep().is_retained() {
                 self.str = self.str.get_unchecked(index..);
             } else {
-                self.str = self.str.get_unchecked(index + char_len..);
+                self.str = self.str.get_unchecked(index + match_len..);
             }
             Ok(Some(result))
         }
@@ -90,43 +109,52 @@ impl<'t> StringIter<'t> {
     /// 
     /// See [try_next_slice](crate::StringIter::try_next_slice)
     pub fn try_next_slice_back<P: Pattern>(&mut self, mut pat: P) -> Result<Option<&'t str>, P::Err> {
-        if self.len() == 0{
+        if self.is_empty(){
             return Ok(None);
         }
-        let mut index = self.len();
-        let mut char_len = 0;
-        if pat.len().get() == 1{
-            for (c, s) in self.clone().rev(){
-                if pat.matches(c, s)? {
-                    index = s.as_ptr() as usize - self.str.as_ptr() as usize;
-                    char_len = s.len();
-                    break;
-                }
+        let mut index = 0;
+        let mut match_len = 0;
+        match pat.search_back(self.str) {
+            Search::Found(i, len) => {
+                index = i;
+                match_len = len;
+                assert!(self.str.is_char_boundary(index) && self.str.is_char_boundary(index + match_len),
+                    "search_back() is not on a char boundary");
             }
-        } else {
-            for (c, s) in self.clone().look_ahead(pat.len().get()).rev(){
-                if pat.matches(c, s)? {
-                    index = s.as_ptr() as usize - self.str.as_ptr() as usize;
-                    char_len = c.len_utf8();
-                    break;
+            Search::NotFound => (),
+            Search::Error(err) => return Err(err),
+            Search::Unsupported => {
+                let len = pat.len().get();
+                let mut iter = self.clone();
+                while let Some((c, s)) = iter.next_back() {
+                    // in reverse, the look-ahead still starts from the current char
+                    let s = if len == 1 {
+                        s
+                    } else {
+                        // SAFETY: safe since iter is a prefix of self
+                        match unsafe { StringIter::new(self.str.get_unchecked(iter.len()..)) }.peekn(len) {
+                            Ok(s) => s,
+                            Err(s) => s,
+                        }
+                    };
+                    if pat.matches(c, s)? {
+                        index = iter.len();
+                        match_len = checked_match_len(&pat, c, s);
+                        break;
+                    }
                 }
             }
         }
-        for (c, s) in self.clone().rev(){
-            if pat.matches(c, s)? {
-                index = s.as_ptr() as usize - self.str.as_ptr() as usize;
-                char_len = s.len();
-                break;
-            }
-        }
+        // SAFETY: safe since index is a char boundary
+        // and match_len is a char boundary of a substring starting at index
         unsafe{
             let result = if pat.sep().is_yielded() {
                 self.str.get_unchecked(index..)
             } else {
-                self.str.get_unchecked(index + char_len..)
+                self.str.get_unchecked(index + match_len..)
             };
             if pat.sep().is_retained() {
-                self.str = self.str.get_unchecked(..index + char_len);
+                self.str = self.str.get_unchecked(..index + match_len);
             } else {
                 self.str = self.str.get_unchecked(..index);
             }
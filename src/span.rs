@@ -0,0 +1,148 @@
+use core::ops::Range;
+
+use crate::merge::substr_range;
+
+/// A byte range in a source [`str`], with an optional source id.
+///
+/// A [`Span`] is a lightweight alternative to a substring,
+/// that can be stored without borrowing the source.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::{prelude::*, Span};
+/// let source = "let foo = bar;";
+/// let mut iter = source.str_iter();
+/// iter.next_slice(' '.sep_with(Sep::Split));
+/// let foo = iter.next_slice(' ').unwrap();
+///
+/// let span = Span::of(source, foo).unwrap();
+/// assert_eq!(span, Span::new(4, 7));
+/// assert_eq!(span.as_str(source), Some("foo"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    /// Start of the span in bytes, inclusive.
+    pub start: usize,
+    /// End of the span in bytes, exclusive.
+    pub end: usize,
+    /// An optional id for the source [`str`].
+    pub source: Option<usize>,
+}
+
+impl Span {
+    /// Construct a new [`Span`] without a source id.
+    ///
+    /// # Panics
+    ///
+    /// If `start > end`
+    pub const fn new(start: usize, end: usize) -> Self {
+        assert!(start <= end, "span cannot end before it starts");
+        Span { start, end, source: None }
+    }
+
+    /// Set the source id of this [`Span`].
+    pub const fn with_source(self, source: usize) -> Self {
+        Span { source: Some(source), ..self }
+    }
+
+    /// Obtain the [`Span`] of `child` in `parent`.
+    ///
+    /// Returns `None` if `child` is not a substring of `parent`.
+    ///
+    /// ```
+    /// # use string_iter::Span;
+    /// let parent = "foobar";
+    /// assert_eq!(Span::of(parent, &parent[3..]), Some(Span::new(3, 6)));
+    /// assert_eq!(Span::of(parent, "bar"), None);
+    /// ```
+    pub fn of(parent: &str, child: &str) -> Option<Self> {
+        let range = substr_range(parent, child)?;
+        Some(Span::new(range.start, range.end))
+    }
+
+    /// Returns the length of the [`Span`] in bytes.
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the [`Span`] has a length of zero bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the byte range of this [`Span`].
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Obtain the substring of `parent` this [`Span`] refers to.
+    ///
+    /// Returns `None` if the [`Span`] is out of bounds
+    /// or not on [`char`] boundaries.
+    pub fn as_str<'t>(&self, parent: &'t str) -> Option<&'t str> {
+        parent.get(self.range())
+    }
+
+    /// Returns `true` if `other` is inside this [`Span`] and has the same source.
+    pub fn contains(&self, other: &Span) -> bool {
+        self.source == other.source
+            && self.start <= other.start
+            && other.end <= self.end
+    }
+
+    /// Returns `true` if byte offset `offset` is inside this [`Span`].
+    pub const fn contains_offset(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Returns the smallest [`Span`] that contains both spans.
+    ///
+    /// Returns `None` if sources are different.
+    ///
+    /// ```
+    /// # use string_iter::Span;
+    /// assert_eq!(Span::new(1, 3).join(Span::new(5, 6)), Some(Span::new(1, 6)));
+    /// ```
+    pub fn join(self, other: Span) -> Option<Span> {
+        if self.source != other.source {
+            return None;
+        }
+        Some(Span {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+            source: self.source,
+        })
+    }
+
+    /// Concatenate adjacent spans.
+    ///
+    /// Returns `None` if `self` does not end where `other` starts,
+    /// or if sources are different.
+    ///
+    /// This is the [`Span`] equivalent of [`StringExt::merge`](crate::StringExt::merge).
+    ///
+    /// ```
+    /// # use string_iter::Span;
+    /// assert_eq!(Span::new(1, 3).merge(Span::new(3, 6)), Some(Span::new(1, 6)));
+    /// assert_eq!(Span::new(3, 6).merge(Span::new(1, 3)), None);
+    /// ```
+    pub fn merge(self, other: Span) -> Option<Span> {
+        if self.source != other.source || self.end != other.start {
+            return None;
+        }
+        Some(Span { end: other.end, ..self })
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(value: Range<usize>) -> Self {
+        Span::new(value.start, value.end)
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(value: Span) -> Self {
+        value.range()
+    }
+}
@@ -1,8 +1,27 @@
-use crate::{StringIter, pattern::{Pattern, Never, PatRef, Sep}, prelude::SetSep};
+use crate::{StringIter, pattern::{Pattern, Never, PatRef, Sep, Search, SepConfig}, prelude::SetSep};
+use crate::combinator::OutsideQuotes;
+
+/// In reverse, a retained match at the end of the string
+/// is the previous separator and has to be ignored.
+fn matches_back<P: Pattern>(pat: &mut P, end: usize, c: char, s: &str) -> Result<bool, P::Err> {
+    let matched = pat.matches(c, s)?;
+    Ok(matched && s.as_ptr() as usize + pat.match_len(c, s) != end)
+}
+
+/// In reverse, a retained match at the end of the string
+/// is the previous separator and has to be ignored.
+fn search_back<P: Pattern>(pat: &mut P, end: Option<usize>, s: &str) -> Search<P::Err> {
+    match pat.search_back(s) {
+        // fallback to matching char by char.
+        Search::Found(index, len) if end.is_some() && index + len == s.len() => Search::Unsupported,
+        result => result,
+    }
+}
 
 /// If matches are retained, ignore the result on the first element.
 struct SplitGuardFirst<P: Pattern>{
     flag: bool,
+    end: Option<usize>,
     pat: P,
 }
 
@@ -10,6 +29,15 @@ impl<P: Pattern> SplitGuardFirst<P> {
     fn new(pat: P) -> Self{
         Self {
             flag: !pat.sep().is_retained(),
+            end: None,
+            pat
+        }
+    }
+
+    fn new_back(pat: P, s: &str) -> Self{
+        Self {
+            flag: true,
+            end: pat.sep().is_retained().then(|| s.as_ptr() as usize + s.len()),
             pat
         }
     }
@@ -19,7 +47,9 @@ impl<P: Pattern> Pattern for SplitGuardFirst<P>  {
     type Err = P::Err;
 
     fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
-        if self.flag == false {
+        if let Some(end) = self.end {
+            matches_back(&mut self.pat, end, c, s)
+        } else if !self.flag {
             // discard the first result
             let _ = self.pat.matches(c, s);
             self.flag = true;
@@ -31,11 +61,35 @@ impl<P: Pattern> Pattern for SplitGuardFirst<P>  {
 
     fn len(&self) -> core::num::NonZeroUsize { self.pat.len() }
 
+    fn match_len(&self, c: char, s: &str) -> usize { self.pat.match_len(c, s) }
+
     fn sep(&self) -> crate::pattern::Sep { self.pat.sep() }
+
+    fn search(&mut self, s: &str) -> Search<Self::Err> {
+        if self.flag {
+            return self.pat.search(s);
+        }
+        // stateless patterns can skip the first char instead
+        let first = match StringIter::new(s).peek() {
+            Some((_, first)) => first.len(),
+            None => return Search::NotFound,
+        };
+        let result = self.pat.search(&s[first..]).offset(first);
+        if !matches!(result, Search::Unsupported) {
+            self.flag = true;
+        }
+        result
+    }
+
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+        search_back(&mut self.pat, self.end, s)
+    }
 }
+
 /// If matches are retained, ignore the result on the first element.
 struct SplitGuard<P: Pattern>{
     flag: bool,
+    end: Option<usize>,
     pat: P,
 }
 
@@ -43,6 +97,15 @@ impl<P: Pattern> SplitGuard<P> {
     fn new(pat: P) -> Self{
         Self {
             flag: !pat.sep().is_retained(),
+            end: None,
+            pat
+        }
+    }
+
+    fn new_back(pat: P, s: &str) -> Self{
+        Self {
+            flag: true,
+            end: pat.sep().is_retained().then(|| s.as_ptr() as usize + s.len()),
             pat
         }
     }
@@ -53,7 +116,9 @@ impl<P: Pattern> Pattern for SplitGuard<P>  {
     type Err = P::Err;
 
     fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> {
-        if self.flag == false {
+        if let Some(end) = self.end {
+            matches_back(&mut self.pat, end, c, s)
+        } else if !self.flag {
             // skip the first result
             self.flag = true;
             Ok(false)
@@ -64,7 +129,29 @@ impl<P: Pattern> Pattern for SplitGuard<P>  {
 
     fn len(&self) -> core::num::NonZeroUsize { self.pat.len() }
 
+    fn match_len(&self, c: char, s: &str) -> usize { self.pat.match_len(c, s) }
+
     fn sep(&self) -> crate::pattern::Sep { self.pat.sep() }
+
+    fn search(&mut self, s: &str) -> Search<Self::Err> {
+        if self.flag {
+            return self.pat.search(s);
+        }
+        // stateless patterns can skip the first char instead
+        let first = match StringIter::new(s).peek() {
+            Some((_, first)) => first.len(),
+            None => return Search::NotFound,
+        };
+        let result = self.pat.search(&s[first..]).offset(first);
+        if !matches!(result, Search::Unsupported) {
+            self.flag = true;
+        }
+        result
+    }
+
+    fn search_back(&mut self, s: &str) -> Search<Self::Err> {
+        search_back(&mut self.pat, self.end, s)
+    }
 }
 
 impl<'t> StringIter<'t> {
@@ -107,6 +194,28 @@ impl<'t> StringIter<'t> {
     pub fn into_splits(self, pat: impl Pattern<Err = Never>) -> SplitIter<'t, impl Pattern<Err = Never>>{
         SplitIter { str: self, pat: pat.sep_with(Sep::Split), count: 0 }
     }
+
+    /// Split the string into fields like [`into_splits`](crate::StringIter::into_splits),
+    /// ignoring separators inside quotes or after an escape [`char`].
+    ///
+    /// Fields are yielded with their quotes and escapes,
+    /// use [`decoded()`](QuotedSplitIter::decoded) to remove them.
+    ///
+    /// Iterating in reverse scans the remaining string from the front on each call.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let sep = ','.outside_quotes(&[('"', '"')]).escape('\\');
+    /// let fields: Vec<_> = r#"a,"b,c",d\,e"#.str_iter().into_quoted_splits(sep).collect();
+    /// assert_eq!(fields, ["a", r#""b,c""#, r"d\,e"]);
+    /// ```
+    pub fn into_quoted_splits<'a, P: Pattern<Err = Never>>(self, pat: OutsideQuotes<'a, P>) -> QuotedSplitIter<'t, 'a, P> {
+        QuotedSplitIter {
+            quotes: pat.quotes(),
+            escape: pat.escape_char(),
+            iter: SplitIter { str: self, pat: pat.sep_with(Sep::Split), count: 0 },
+        }
+    }
 }
 
 /// An iterator that yields [`&str`]s 
@@ -136,12 +245,133 @@ impl<'t, F> Iterator for SplitIter<'t, F> where F: Pattern<Err = Never>{
 impl<'t, F> DoubleEndedIterator for SplitIter<'t, F> where F: Pattern<Err = Never>{
     fn next_back(&mut self) -> Option<Self::Item> {
         let pat = PatRef(&mut self.pat);
+        let s = self.str.as_str();
         if self.count == 0 {
             self.count += 1;
-            self.str.next_slice_back(SplitGuardFirst::new(pat))
+            self.str.next_slice_back(SplitGuardFirst::new_back(pat, s))
         } else {
             self.count += 1;
-            self.str.next_slice_back(SplitGuard::new(pat))
+            self.str.next_slice_back(SplitGuard::new_back(pat, s))
+        }
+    }
+}
+
+/// An iterator that yields fields by splitting a [`StringIter`],
+/// ignoring separators inside quotes.
+///
+/// See [`into_quoted_splits()`](StringIter::into_quoted_splits).
+#[derive(Debug, Clone)]
+pub struct QuotedSplitIter<'t, 'a, P: Pattern<Err = Never>>{
+    iter: SplitIter<'t, SepConfig<OutsideQuotes<'a, P>>>,
+    /// Used by [`decoded()`](QuotedSplitIter::decoded).
+    #[cfg_attr(not(feature="std"), allow(dead_code))]
+    quotes: &'a [(char, char)],
+    #[cfg_attr(not(feature="std"), allow(dead_code))]
+    escape: Option<char>,
+}
+
+impl<'t, 'a, P: Pattern<Err = Never>> QuotedSplitIter<'t, 'a, P> {
+    /// Returns the remaining [`str`] of this iterator.
+    pub fn as_str(&self) -> &'t str {
+        self.iter.str.as_str()
+    }
+
+    /// Map the iterator into an iterator of fields with quotes and escapes removed.
+    ///
+    /// An escape [`char`] makes the next [`char`] literal.
+    /// Fields are borrowed if nothing is removed,
+    /// or if the field is a single quoted string without escapes.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// let sep = ' '.outside_quotes(&[('"', '"'), ('\'', '\'')]).escape('\\');
+    /// let args: Vec<_> = r#"echo "hello world" it\'s 'a "b"'"#.str_iter()
+    ///     .into_quoted_splits(sep)
+    ///     .decoded()
+    ///     .collect();
+    /// assert_eq!(args, ["echo", "hello world", "it's", "a \"b\""]);
+    /// ```
+    #[cfg(feature="std")]
+    pub fn decoded(self) -> DecodedSplitIter<'t, 'a, P> {
+        DecodedSplitIter(self)
+    }
+}
+
+impl<'t, P: Pattern<Err = Never>> Iterator for QuotedSplitIter<'t, '_, P> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<P: Pattern<Err = Never>> DoubleEndedIterator for QuotedSplitIter<'_, '_, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+/// An iterator that yields fields with quotes and escapes removed.
+///
+/// See [`decoded()`](QuotedSplitIter::decoded).
+#[cfg(feature="std")]
+#[derive(Debug, Clone)]
+pub struct DecodedSplitIter<'t, 'a, P: Pattern<Err = Never>>(QuotedSplitIter<'t, 'a, P>);
+
+#[cfg(feature="std")]
+const _: () = {
+    extern crate alloc;
+    use alloc::borrow::Cow;
+
+    /// Remove quotes and escapes from a field.
+    fn decode<'t>(field: &'t str, quotes: &[(char, char)], escape: Option<char>) -> Cow<'t, str> {
+        let is_special = |c: char| Some(c) == escape || quotes.iter().any(|(open, close)| c == *open || c == *close);
+        if !field.contains(is_special) {
+            return Cow::Borrowed(field);
+        }
+        let mut iter = StringIter::new(field);
+        if let Some((_, close)) = iter.next().and_then(|(c, _)| quotes.iter().find(|(open, _)| *open == c)) {
+            if iter.next_back().is_some_and(|(c, _)| c == *close) && !iter.as_str().contains(is_special) {
+                return Cow::Borrowed(iter.as_str());
+            }
+        }
+        let mut result = alloc::string::String::with_capacity(field.len());
+        let mut quote = None;
+        let mut chars = field.chars();
+        while let Some(c) = chars.next() {
+            if Some(c) == escape {
+                if let Some(c) = chars.next() {
+                    result.push(c);
+                }
+                continue;
+            }
+            match quote {
+                Some(close) if c == close => quote = None,
+                Some(_) => result.push(c),
+                None => match quotes.iter().find(|(open, _)| *open == c) {
+                    Some((_, close)) => quote = Some(*close),
+                    None => result.push(c),
+                }
+            }
+        }
+        Cow::Owned(result)
+    }
+
+    impl<'t, P: Pattern<Err = Never>> Iterator for DecodedSplitIter<'t, '_, P> {
+        type Item = Cow<'t, str>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let field = self.0.next()?;
+            Some(decode(field, self.0.quotes, self.0.escape))
+        }
+    }
+
+    impl<P: Pattern<Err = Never>> DoubleEndedIterator for DecodedSplitIter<'_, '_, P> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            let field = self.0.next_back()?;
+            Some(decode(field, self.0.quotes, self.0.escape))
         }
     }
-}
\ No newline at end of file
+};
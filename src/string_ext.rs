@@ -12,6 +12,7 @@ use crate::StringIterable;
 /// A `usize` or a range representing a slice of chars in a string.
 /// 
 /// This is similar to [`SliceIndex`](core::slice::SliceIndex).
+#[allow(clippy::len_without_is_empty)]
 pub trait StringIndex {
     fn start(&self) -> usize;
     fn len(&self) -> Option<usize>;
@@ -95,10 +96,10 @@ pub trait StringExt {
     /// Returns `Ok(&str)` if the exact substring is found.
     /// 
     /// Returns `Err(&str)` if [`len`](StringIndex::len) is bound and less than `len` [`char`]s found.
-    fn try_substr<'t>(&'t self, idx: impl StringIndex) -> Result<&'t str, &'t str>;
+    fn try_substr(&self, idx: impl StringIndex) -> Result<&str, &str>;
 
     /// Obtain a substring with a given index or range.
-    fn substr<'t>(&'t self, idx: impl StringIndex) -> &'t str{
+    fn substr(&self, idx: impl StringIndex) -> &str{
         match self.try_substr(idx) {
             Ok(s) => s,
             Err(s) => s,
@@ -110,16 +111,56 @@ pub trait StringExt {
     /// Returns `Ok(&str)` if the exact substring is found.
     /// 
     /// Returns `Err(&str)` if [`len`](StringIndex::len) is bound and less than `len` [`char`]s found.
-    fn try_substr_back<'t>(&'t self, idx: impl StringIndex) -> Result<&'t str, &'t str>;
+    fn try_substr_back(&self, idx: impl StringIndex) -> Result<&str, &str>;
     
     /// Obtain a substring with a given index or range in reverse.
-    fn substr_back<'t>(&'t self, idx: impl StringIndex) -> &'t str{
+    fn substr_back(&self, idx: impl StringIndex) -> &str{
         match self.try_substr_back(idx) {
             Ok(s) => s,
             Err(s) => s,
         }
     }
 
+    /// Try obtain a substring with a given index or range of extended grapheme clusters.
+    /// 
+    /// Returns `Ok(&str)` if the exact substring is found.
+    /// 
+    /// Returns `Err(&str)` if [`len`](StringIndex::len) is bound and less than `len` grapheme clusters found.
+    fn try_substr_graphemes(&self, idx: impl StringIndex) -> Result<&str, &str>;
+
+    /// Obtain a substring with a given index or range of extended grapheme clusters.
+    /// 
+    /// Unlike [`substr`](StringExt::substr), this never splits a user-visible character.
+    /// 
+    /// ```
+    /// # use string_iter::StringExt;
+    /// let name = "Zoe\u{308}👩‍🔬";
+    /// assert_eq!(name.substr(..3), "Zoe");
+    /// assert_eq!(name.substr_graphemes(..3), "Zoe\u{308}");
+    /// assert_eq!(name.substr_graphemes(3), "👩‍🔬");
+    /// ```
+    fn substr_graphemes(&self, idx: impl StringIndex) -> &str{
+        match self.try_substr_graphemes(idx) {
+            Ok(s) => s,
+            Err(s) => s,
+        }
+    }
+
+    /// Try obtain a substring with a given index or range of extended grapheme clusters in reverse.
+    /// 
+    /// Returns `Ok(&str)` if the exact substring is found.
+    /// 
+    /// Returns `Err(&str)` if [`len`](StringIndex::len) is bound and less than `len` grapheme clusters found.
+    fn try_substr_graphemes_back(&self, idx: impl StringIndex) -> Result<&str, &str>;
+
+    /// Obtain a substring with a given index or range of extended grapheme clusters in reverse.
+    fn substr_graphemes_back(&self, idx: impl StringIndex) -> &str{
+        match self.try_substr_graphemes_back(idx) {
+            Ok(s) => s,
+            Err(s) => s,
+        }
+    }
+
     /// Concatenate adjacent substrings.
     /// 
     /// Returns `None` if `first` and `second` are not adjacent or 
@@ -166,11 +207,32 @@ pub trait StringExt {
     /// assert_eq!(parent.merge(foob, obar), None);
     /// ```
     fn merge<'t>(&'t self, first: &str, second: &str) -> Option<&'t str>;
+
+    /// Quote a string for a POSIX shell, so that it is read back as a single word
+    /// by a shell or by [`shell_words()`](crate::StringIter::shell_words).
+    ///
+    /// Borrows if no quoting is needed, otherwise the string is wrapped in `'`,
+    /// with `'` written as `'\''`.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// ```
+    /// # use string_iter::prelude::*;
+    /// assert_eq!("src/main.rs".shell_quote(), "src/main.rs");
+    /// assert_eq!("it's here".shell_quote(), r"'it'\''s here'");
+    /// assert_eq!("".shell_quote(), "''");
+    ///
+    /// let quoted = "it's here".shell_quote();
+    /// let words: Vec<_> = quoted.str_iter().shell_words().collect();
+    /// assert_eq!(words, [Ok("it's here".into())]);
+    /// ```
+    #[cfg(feature="std")]
+    fn shell_quote(&self) -> alloc::borrow::Cow<'_, str>;
 }
 
 impl<T> StringExt for T where T: AsRef<str> {
     
-    fn try_substr<'t>(&'t self, idx: impl StringIndex) -> Result<&'t str, &'t str> {
+    fn try_substr(&self, idx: impl StringIndex) -> Result<&str, &str> {
         let mut iter = self.str_iter();
         iter.skip_front(idx.start());
         match idx.len() {
@@ -179,7 +241,7 @@ impl<T> StringExt for T where T: AsRef<str> {
         }
     }
 
-    fn try_substr_back<'t>(&'t self, idx: impl StringIndex) -> Result<&'t str, &'t str> {
+    fn try_substr_back(&self, idx: impl StringIndex) -> Result<&str, &str> {
         let mut iter = self.str_iter();
         iter.skip_back(idx.start());
         match idx.len() {
@@ -188,7 +250,30 @@ impl<T> StringExt for T where T: AsRef<str> {
         }
     }
 
+    fn try_substr_graphemes(&self, idx: impl StringIndex) -> Result<&str, &str> {
+        let mut iter = self.str_iter();
+        iter.skip_front_graphemes(idx.start());
+        match idx.len() {
+            Some(n) => iter.peekn_graphemes(n),
+            None => Ok(iter.as_str()),
+        }
+    }
+
+    fn try_substr_graphemes_back(&self, idx: impl StringIndex) -> Result<&str, &str> {
+        let mut iter = self.str_iter();
+        iter.skip_back_graphemes(idx.start());
+        match idx.len() {
+            Some(n) => iter.peekn_graphemes_back(n),
+            None => Ok(iter.as_str()),
+        }
+    }
+
     fn merge<'t>(&'t self, first: &str, second: &str) -> Option<&'t str> {
         crate::merge::merge(self.as_ref(), first, second)
     }
+
+    #[cfg(feature="std")]
+    fn shell_quote(&self) -> alloc::borrow::Cow<'_, str> {
+        crate::shell::shell_quote(self.as_ref())
+    }
 }
@@ -0,0 +1,277 @@
+use core::cell::Cell;
+use core::fmt::Display;
+use core::iter::FusedIterator;
+use core::ops::{Deref, DerefMut};
+
+use crate::{StringIter, Span, merge::substr_range};
+
+/// A position in a [`str`].
+///
+/// `line` and `column` start from `1`, `column` is counted in [`char`]s.
+///
+/// Line breaks are `"\n"`, `"\r\n"` and a lone `"\r"`, like [`lines()`](StringIter::lines).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// Offset in bytes, starting from `0`.
+    pub byte: usize,
+    /// Offset in [`char`]s, starting from `0`.
+    pub char: usize,
+    /// Line number, starting from `1`.
+    pub line: usize,
+    /// Column number in [`char`]s, starting from `1`.
+    pub column: usize,
+}
+
+impl Position {
+    /// The position of the start of a [`str`].
+    pub const START: Self = Position { byte: 0, char: 0, line: 1, column: 1 };
+
+    /// Move this position to byte offset `offset` of `origin`.
+    ///
+    /// This runs in `O(distance)`, the column is only recounted
+    /// when moving backward across a line break, in `O(distance + column)`.
+    pub(crate) fn move_to(self, origin: &str, offset: usize) -> Self {
+        let bytes = origin.as_bytes();
+        let mut pos = self;
+        if offset >= self.byte {
+            for index in self.byte..offset {
+                if is_continuation(bytes[index]) {
+                    continue;
+                }
+                pos.char += 1;
+                if is_line_break(bytes, index) {
+                    pos.line += 1;
+                    pos.column = 1;
+                } else {
+                    pos.column += 1;
+                }
+            }
+        } else {
+            for index in offset..self.byte {
+                if is_continuation(bytes[index]) {
+                    continue;
+                }
+                pos.char -= 1;
+                if is_line_break(bytes, index) {
+                    pos.line -= 1;
+                } else {
+                    pos.column -= 1;
+                }
+            }
+            if pos.line != self.line {
+                pos.column = count_chars(&bytes[line_start(bytes, offset)..offset]) + 1;
+            }
+        }
+        pos.byte = offset;
+        pos
+    }
+}
+
+const fn is_continuation(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
+}
+
+fn count_chars(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|b| !is_continuation(**b)).count()
+}
+
+/// Returns `true` if `bytes[index]` ends a line break,
+/// a `'\n'` or a `'\r'` not followed by `'\n'`.
+pub(crate) fn is_line_break(bytes: &[u8], index: usize) -> bool {
+    match bytes[index] {
+        b'\n' => true,
+        b'\r' => bytes.get(index + 1) != Some(&b'\n'),
+        _ => false,
+    }
+}
+
+/// Returns the byte offset of the start of the line containing `offset`.
+pub(crate) fn line_start(bytes: &[u8], offset: usize) -> usize {
+    (0..offset).rev().find(|index| is_line_break(bytes, *index)).map_or(0, |index| index + 1)
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::START
+    }
+}
+
+/// Displays as `line:column`.
+impl Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A [`StringIter`] that remembers its origin, and tracks
+/// the [`Position`] of its front and back in the origin.
+///
+/// All methods of [`StringIter`] are available through [`Deref`].
+///
+/// Positions are computed lazily and cached,
+/// so tracking costs amortized `O(consumed bytes)`.
+/// The first call to [`back_position()`](TrackedIter::back_position) also walks
+/// the remaining bytes once.
+///
+/// # Example
+///
+/// ```
+/// # use string_iter::prelude::*;
+/// let mut iter = "fn main() {\n    let x;\n}".str_iter().tracked();
+/// iter.next_slice('\n'.sep_with(Sep::Split));
+/// iter.trim_start();
+/// assert_eq!(iter.next_slice(' '), Some("let"));
+/// assert_eq!(iter.position().to_string(), "2:8");
+/// assert_eq!(iter.back_position().to_string(), "3:2");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackedIter<'t> {
+    origin: &'t str,
+    iter: StringIter<'t>,
+    front: Cell<Position>,
+    back: Cell<Option<Position>>,
+}
+
+impl<'t> StringIter<'t> {
+    /// Convert into a [`TrackedIter`], using the current [`str`] as its origin.
+    pub fn tracked(self) -> TrackedIter<'t> {
+        TrackedIter::new(self.str)
+    }
+}
+
+impl<'t> TrackedIter<'t> {
+    /// Construct a new [`TrackedIter`] from a [`&str`](str).
+    pub const fn new(s: &'t str) -> Self {
+        TrackedIter {
+            origin: s,
+            iter: StringIter::new(s),
+            front: Cell::new(Position::START),
+            back: Cell::new(None),
+        }
+    }
+
+    /// Returns the original [`str`] of this [`TrackedIter`].
+    pub const fn origin(&self) -> &'t str {
+        self.origin
+    }
+
+    /// Returns the underlying [`StringIter`].
+    pub const fn as_iter(&self) -> &StringIter<'t> {
+        &self.iter
+    }
+
+    /// Returns the byte offset of the front of the iterator.
+    pub fn offset(&self) -> usize {
+        self.offset_of(self.iter.as_str().as_ptr())
+    }
+
+    /// Returns the byte offset of the back of the iterator.
+    pub fn offset_back(&self) -> usize {
+        self.offset() + self.iter.len()
+    }
+
+    /// Returns the [`Position`] of the front of the iterator.
+    pub fn position(&self) -> Position {
+        let offset = self.offset();
+        let pos = self.front.get().move_to(self.origin, offset);
+        self.front.set(pos);
+        pos
+    }
+
+    /// Returns the [`Position`] of the back of the iterator.
+    ///
+    /// The first call walks forward from the front position in `O(remaining length)`,
+    /// later calls move from the cached back position.
+    pub fn back_position(&self) -> Position {
+        let offset = self.offset_back();
+        let pos = match self.back.get() {
+            Some(pos) => pos,
+            None => self.position(),
+        }.move_to(self.origin, offset);
+        self.back.set(Some(pos));
+        pos
+    }
+
+    /// Returns the [`Position`] of a substring of the origin.
+    ///
+    /// Returns `None` if `s` is not a substring of the origin.
+    ///
+    /// The result is not cached, each call walks from the front position
+    /// in `O(distance)`.
+    pub fn position_of(&self, s: &str) -> Option<Position> {
+        let offset = substr_range(self.origin, s)?.start;
+        Some(self.front.get().move_to(self.origin, offset))
+    }
+
+    /// Returns the [`Span`] of a substring of the origin.
+    ///
+    /// Returns `None` if `s` is not a substring of the origin.
+    pub fn span_of(&self, s: &str) -> Option<Span> {
+        Span::of(self.origin, s)
+    }
+
+    fn offset_of(&self, ptr: *const u8) -> usize {
+        match (ptr as usize).checked_sub(self.origin.as_ptr() as usize) {
+            Some(offset) if offset <= self.origin.len() => offset,
+            _ => panic!("TrackedIter is no longer a substring of its origin"),
+        }
+    }
+}
+
+impl<'t> Deref for TrackedIter<'t> {
+    type Target = StringIter<'t>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.iter
+    }
+}
+
+impl<'t> DerefMut for TrackedIter<'t> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.iter
+    }
+}
+
+impl AsRef<str> for TrackedIter<'_> {
+    fn as_ref(&self) -> &str {
+        self.iter.as_str()
+    }
+}
+
+impl core::borrow::Borrow<str> for TrackedIter<'_> {
+    fn borrow(&self) -> &str {
+        self.iter.as_str()
+    }
+}
+
+impl<'t> From<&'t str> for TrackedIter<'t> {
+    fn from(value: &'t str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'t> Iterator for TrackedIter<'t> {
+    type Item = (char, &'t str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<'t> DoubleEndedIterator for TrackedIter<'t> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'t> FusedIterator for TrackedIter<'t> {}
@@ -0,0 +1,291 @@
+use core::iter::FusedIterator;
+
+use crate::{StringIter, ParseError, Expected};
+use crate::search::find_bytes;
+
+/// Configuration of whitespace and comments skipped between tokens,
+/// see [`skip_trivia()`](StringIter::skip_trivia).
+///
+/// ```
+/// # use string_iter::{prelude::*, Trivia};
+/// let trivia = Trivia::new()
+///     .line_comments(&["--"])
+///     .block_comments(&[("{-", "-}")])
+///     .nested(true);
+/// let mut iter = "  {- {- nested -} -} -- comment\n  main".str_iter();
+/// let comments: Vec<_> = iter.skip_trivia(&trivia).unwrap().collect();
+/// assert_eq!(comments, ["{- {- nested -} -}", "-- comment"]);
+/// assert_eq!(iter.as_str(), "main");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Trivia<'a> {
+    line_comments: &'a [&'a str],
+    block_comments: &'a [(&'a str, &'a str)],
+    nested: bool,
+    whitespace: fn(char) -> bool,
+    newlines: bool,
+}
+
+impl<'a> Trivia<'a> {
+    /// Whitespace only.
+    pub const WHITESPACE: Trivia<'static> = Trivia {
+        line_comments: &[],
+        block_comments: &[],
+        nested: false,
+        whitespace: char::is_whitespace,
+        newlines: false,
+    };
+
+    /// Whitespace, `//` line comments and `/* */` block comments.
+    pub const C: Trivia<'static> = Trivia {
+        line_comments: &["//"],
+        block_comments: &[("/*", "*/")],
+        ..Trivia::WHITESPACE
+    };
+
+    /// Whitespace, `//` line comments and nested `/* */` block comments.
+    pub const RUST: Trivia<'static> = Trivia {
+        nested: true,
+        ..Trivia::C
+    };
+
+    /// Whitespace and `#` line comments.
+    pub const HASH: Trivia<'static> = Trivia {
+        line_comments: &["#"],
+        ..Trivia::WHITESPACE
+    };
+
+    /// Construct a [`Trivia`] of whitespace only, same as [`WHITESPACE`](Trivia::WHITESPACE).
+    pub const fn new() -> Self {
+        Trivia::WHITESPACE
+    }
+
+    /// Set the markers of line comments,
+    /// which run until the next line break, exclusive.
+    ///
+    /// # Panics
+    ///
+    /// If a marker is `""`.
+    pub fn line_comments(self, line_comments: &'a [&'a str]) -> Self {
+        assert!(line_comments.iter().all(|marker| !marker.is_empty()),
+            "\"\" is not a valid comment delimiter");
+        Trivia { line_comments, ..self }
+    }
+
+    /// Set the pairs of start and end delimiters of block comments.
+    ///
+    /// Block comments are checked before line comments.
+    ///
+    /// # Panics
+    ///
+    /// If a delimiter is `""`.
+    pub fn block_comments(self, block_comments: &'a [(&'a str, &'a str)]) -> Self {
+        assert!(block_comments.iter().all(|(start, end)| !start.is_empty() && !end.is_empty()),
+            "\"\" is not a valid comment delimiter");
+        Trivia { block_comments, ..self }
+    }
+
+    /// If `true`, block comments nest like `/* /* */ */`, default `false`.
+    pub const fn nested(self, nested: bool) -> Self {
+        Trivia { nested, ..self }
+    }
+
+    /// Set the class of whitespace [`char`]s, default [`char::is_whitespace`].
+    pub const fn whitespace(self, whitespace: fn(char) -> bool) -> Self {
+        Trivia { whitespace, ..self }
+    }
+
+    /// If `true`, line breaks are not trivia and are returned as tokens
+    /// by [`next_token_after_trivia()`](StringIter::next_token_after_trivia), default `false`.
+    ///
+    /// Line breaks are `"\n"`, `"\r\n"` and a lone `"\r"`, like [`lines()`](StringIter::lines).
+    pub const fn significant_newlines(self, newlines: bool) -> Self {
+        Trivia { newlines, ..self }
+    }
+
+    fn is_whitespace(&self, c: char) -> bool {
+        (self.whitespace)(c) && !(self.newlines && matches!(c, '\n' | '\r'))
+    }
+
+    /// Returns the length of a line break at the front of `s`, if significant.
+    fn newline_len(&self, s: &str) -> Option<usize> {
+        match s.as_bytes() {
+            _ if !self.newlines => None,
+            [b'\r', b'\n', ..] => Some(2),
+            [b'\n' | b'\r', ..] => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of a comment at the front of `s`,
+    /// or the end delimiter of an unterminated block comment.
+    fn comment_len(&self, s: &str) -> Option<Result<usize, &'a str>> {
+        for (start, end) in self.block_comments {
+            if let Some(rest) = s.strip_prefix(start) {
+                return Some(self.block_len(rest, start, end).map(|len| start.len() + len).ok_or(*end));
+            }
+        }
+        self.line_comments.iter().any(|marker| s.starts_with(marker))
+            .then(|| Ok(find_bytes(b"\r\n", s.as_bytes()).unwrap_or(s.len())))
+    }
+
+    /// Returns the length of the rest of a block comment, including `end`.
+    fn block_len(&self, s: &str, start: &str, end: &str) -> Option<usize> {
+        if !self.nested {
+            return s.find(end).map(|index| index + end.len());
+        }
+        let mut depth = 1usize;
+        let mut iter = StringIter::new(s);
+        loop {
+            let rest = iter.as_str();
+            if rest.starts_with(end) {
+                iter.skip_front(end.chars().count());
+                depth -= 1;
+                if depth == 0 {
+                    return Some(s.len() - iter.len());
+                }
+            } else if rest.starts_with(start) {
+                iter.skip_front(start.chars().count());
+                depth += 1;
+            } else {
+                iter.next()?;
+            }
+        }
+    }
+
+    /// Returns `true` if `s` starts with whitespace or a comment.
+    pub(crate) fn starts_trivia(&self, s: &str) -> bool {
+        s.chars().next().is_some_and(|c| self.is_whitespace(c)) || self.comment_len(s).is_some()
+    }
+
+    /// Returns the length of leading whitespace and the length of the comment after it.
+    fn scan<'s>(&self, s: &'s str) -> Result<(usize, Option<usize>), ParseError<'s>> where 'a: 's {
+        let whitespace = s.find(|c| !self.is_whitespace(c)).unwrap_or(s.len());
+        match self.comment_len(&s[whitespace..]) {
+            None => Ok((whitespace, None)),
+            Some(Ok(len)) => Ok((whitespace, Some(len))),
+            Some(Err(end)) => Err(ParseError::new(Expected::Str(end), &s[whitespace..])),
+        }
+    }
+}
+
+impl Default for Trivia<'_> {
+    fn default() -> Self {
+        Trivia::WHITESPACE
+    }
+}
+
+/// An iterator of the comments skipped by [`skip_trivia()`](StringIter::skip_trivia),
+/// including their delimiters.
+#[derive(Debug, Clone)]
+pub struct Comments<'t, 'a> {
+    rest: &'t str,
+    trivia: Trivia<'a>,
+}
+
+impl<'t> Comments<'t, '_> {
+    /// Returns the remaining skipped trivia.
+    pub const fn as_str(&self) -> &'t str {
+        self.rest
+    }
+}
+
+impl<'t> Iterator for Comments<'t, '_> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (whitespace, len) = match self.trivia.scan(self.rest) {
+            Ok((whitespace, Some(len))) => (whitespace, len),
+            _ => {
+                self.rest = &self.rest[self.rest.len()..];
+                return None;
+            }
+        };
+        let (comment, rest) = self.rest[whitespace..].split_at(len);
+        self.rest = rest;
+        Some(comment)
+    }
+}
+
+impl FusedIterator for Comments<'_, '_> {}
+
+impl<'t> StringIter<'t> {
+
+    /// Skip leading whitespace and comments, returns an iterator of the skipped comments,
+    /// so doc comments can be kept.
+    ///
+    /// If line breaks are [significant](Trivia::significant_newlines),
+    /// this stops before the next line break.
+    ///
+    /// Fails if a block comment is not terminated,
+    /// the iterator will not be changed if this fails.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, Trivia};
+    /// let mut iter = "/// doc\n// comment\n/* block */ fn".str_iter();
+    /// let docs: Vec<_> = iter.skip_trivia(&Trivia::C).unwrap()
+    ///     .filter(|comment| comment.starts_with("///"))
+    ///     .collect();
+    /// assert_eq!(docs, ["/// doc"]);
+    /// assert_eq!(iter.as_str(), "fn");
+    ///
+    /// let mut iter = " /* unterminated".str_iter();
+    /// let err = iter.skip_trivia(&Trivia::C).unwrap_err();
+    /// assert_eq!(err.to_string(), "expected \"*/\", found '/'");
+    /// assert_eq!(err.as_str(), "/* unterminated");
+    /// assert_eq!(iter.as_str(), " /* unterminated");
+    /// ```
+    pub fn skip_trivia<'a>(&mut self, trivia: &Trivia<'a>) -> Result<Comments<'t, 'a>, ParseError<'a>> where 't: 'a {
+        let mut rest = self.str;
+        loop {
+            match trivia.scan(rest)? {
+                (whitespace, None) => {
+                    rest = &rest[whitespace..];
+                    break;
+                }
+                (whitespace, Some(len)) => rest = &rest[whitespace + len..],
+            }
+        }
+        let skipped = &self.str[..self.str.len() - rest.len()];
+        self.str = rest;
+        Ok(Comments { rest: skipped, trivia: *trivia })
+    }
+
+    /// Skip trivia with [`skip_trivia()`](StringIter::skip_trivia),
+    /// then returns the next token, which runs until the next whitespace or comment.
+    ///
+    /// If line breaks are [significant](Trivia::significant_newlines),
+    /// each line break is a token of its own.
+    /// Returns `Ok(None)` at the end of the input.
+    ///
+    /// ```
+    /// # use string_iter::{prelude::*, Trivia};
+    /// let trivia = Trivia::HASH.significant_newlines(true);
+    /// let mut iter = "mov r1, r2 # copy\r\n\tret".str_iter();
+    /// let mut tokens = Vec::new();
+    /// while let Some(token) = iter.next_token_after_trivia(&trivia).unwrap() {
+    ///     tokens.push(token);
+    /// }
+    /// assert_eq!(tokens, ["mov", "r1,", "r2", "\r\n", "ret"]);
+    /// ```
+    pub fn next_token_after_trivia<'a>(&mut self, trivia: &Trivia<'a>) -> Result<Option<&'t str>, ParseError<'a>> where 't: 'a {
+        self.skip_trivia(trivia)?;
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let len = match trivia.newline_len(self.str) {
+            Some(len) => len,
+            None => {
+                let mut iter = self.clone();
+                while !iter.is_empty() && !trivia.starts_trivia(iter.as_str())
+                        && trivia.newline_len(iter.as_str()).is_none() {
+                    iter.next();
+                }
+                self.len() - iter.len()
+            }
+        };
+        let (token, rest) = self.str.split_at(len);
+        self.str = rest;
+        Ok(Some(token))
+    }
+}
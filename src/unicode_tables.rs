@@ -0,0 +1,5443 @@
+// Generated by scripts/unicode.py from the Unicode Character Database 17.0.0.
+// Do not edit directly.
+
+/// The version of Unicode used by segmentation and display widths in this crate.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Grapheme_Cluster_Break property, with Extended_Pictographic
+/// and Indic_Conjunct_Break folded in.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum GraphemeCat {
+    Other,
+    CR,
+    LF,
+    Control,
+    Prepend,
+    /// Extend with Indic_Conjunct_Break=None
+    Extend,
+    /// Extend with Indic_Conjunct_Break=Extend
+    InCBExtend,
+    /// Extend with Indic_Conjunct_Break=Linker
+    InCBLinker,
+    /// Other with Indic_Conjunct_Break=Consonant
+    InCBConsonant,
+    /// Also Indic_Conjunct_Break=Extend
+    ZWJ,
+    RegionalIndicator,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+}
+
+pub(crate) const GRAPHEME_TABLE: &[(u32, u32, GraphemeCat)] = &[
+    (0x0000, 0x0009, GraphemeCat::Control),
+    (0x000a, 0x000a, GraphemeCat::LF),
+    (0x000b, 0x000c, GraphemeCat::Control),
+    (0x000d, 0x000d, GraphemeCat::CR),
+    (0x000e, 0x001f, GraphemeCat::Control),
+    (0x007f, 0x009f, GraphemeCat::Control),
+    (0x00a9, 0x00a9, GraphemeCat::ExtendedPictographic),
+    (0x00ad, 0x00ad, GraphemeCat::Control),
+    (0x00ae, 0x00ae, GraphemeCat::ExtendedPictographic),
+    (0x0300, 0x036f, GraphemeCat::InCBExtend),
+    (0x0483, 0x0489, GraphemeCat::InCBExtend),
+    (0x0591, 0x05bd, GraphemeCat::InCBExtend),
+    (0x05bf, 0x05bf, GraphemeCat::InCBExtend),
+    (0x05c1, 0x05c2, GraphemeCat::InCBExtend),
+    (0x05c4, 0x05c5, GraphemeCat::InCBExtend),
+    (0x05c7, 0x05c7, GraphemeCat::InCBExtend),
+    (0x0600, 0x0605, GraphemeCat::Prepend),
+    (0x0610, 0x061a, GraphemeCat::InCBExtend),
+    (0x061c, 0x061c, GraphemeCat::Control),
+    (0x064b, 0x065f, GraphemeCat::InCBExtend),
+    (0x0670, 0x0670, GraphemeCat::InCBExtend),
+    (0x06d6, 0x06dc, GraphemeCat::InCBExtend),
+    (0x06dd, 0x06dd, GraphemeCat::Prepend),
+    (0x06df, 0x06e4, GraphemeCat::InCBExtend),
+    (0x06e7, 0x06e8, GraphemeCat::InCBExtend),
+    (0x06ea, 0x06ed, GraphemeCat::InCBExtend),
+    (0x070f, 0x070f, GraphemeCat::Prepend),
+    (0x0711, 0x0711, GraphemeCat::InCBExtend),
+    (0x0730, 0x074a, GraphemeCat::InCBExtend),
+    (0x07a6, 0x07b0, GraphemeCat::InCBExtend),
+    (0x07eb, 0x07f3, GraphemeCat::InCBExtend),
+    (0x07fd, 0x07fd, GraphemeCat::InCBExtend),
+    (0x0816, 0x0819, GraphemeCat::InCBExtend),
+    (0x081b, 0x0823, GraphemeCat::InCBExtend),
+    (0x0825, 0x0827, GraphemeCat::InCBExtend),
+    (0x0829, 0x082d, GraphemeCat::InCBExtend),
+    (0x0859, 0x085b, GraphemeCat::InCBExtend),
+    (0x0890, 0x0891, GraphemeCat::Prepend),
+    (0x0897, 0x089f, GraphemeCat::InCBExtend),
+    (0x08ca, 0x08e1, GraphemeCat::InCBExtend),
+    (0x08e2, 0x08e2, GraphemeCat::Prepend),
+    (0x08e3, 0x0902, GraphemeCat::InCBExtend),
+    (0x0903, 0x0903, GraphemeCat::SpacingMark),
+    (0x0915, 0x0939, GraphemeCat::InCBConsonant),
+    (0x093a, 0x093a, GraphemeCat::InCBExtend),
+    (0x093b, 0x093b, GraphemeCat::SpacingMark),
+    (0x093c, 0x093c, GraphemeCat::InCBExtend),
+    (0x093e, 0x0940, GraphemeCat::SpacingMark),
+    (0x0941, 0x0948, GraphemeCat::InCBExtend),
+    (0x0949, 0x094c, GraphemeCat::SpacingMark),
+    (0x094d, 0x094d, GraphemeCat::InCBLinker),
+    (0x094e, 0x094f, GraphemeCat::SpacingMark),
+    (0x0951, 0x0957, GraphemeCat::InCBExtend),
+    (0x0958, 0x095f, GraphemeCat::InCBConsonant),
+    (0x0962, 0x0963, GraphemeCat::InCBExtend),
+    (0x0978, 0x097f, GraphemeCat::InCBConsonant),
+    (0x0981, 0x0981, GraphemeCat::InCBExtend),
+    (0x0982, 0x0983, GraphemeCat::SpacingMark),
+    (0x0995, 0x09a8, GraphemeCat::InCBConsonant),
+    (0x09aa, 0x09b0, GraphemeCat::InCBConsonant),
+    (0x09b2, 0x09b2, GraphemeCat::InCBConsonant),
+    (0x09b6, 0x09b9, GraphemeCat::InCBConsonant),
+    (0x09bc, 0x09bc, GraphemeCat::InCBExtend),
+    (0x09be, 0x09be, GraphemeCat::InCBExtend),
+    (0x09bf, 0x09c0, GraphemeCat::SpacingMark),
+    (0x09c1, 0x09c4, GraphemeCat::InCBExtend),
+    (0x09c7, 0x09c8, GraphemeCat::SpacingMark),
+    (0x09cb, 0x09cc, GraphemeCat::SpacingMark),
+    (0x09cd, 0x09cd, GraphemeCat::InCBLinker),
+    (0x09d7, 0x09d7, GraphemeCat::InCBExtend),
+    (0x09dc, 0x09dd, GraphemeCat::InCBConsonant),
+    (0x09df, 0x09df, GraphemeCat::InCBConsonant),
+    (0x09e2, 0x09e3, GraphemeCat::InCBExtend),
+    (0x09f0, 0x09f1, GraphemeCat::InCBConsonant),
+    (0x09fe, 0x09fe, GraphemeCat::InCBExtend),
+    (0x0a01, 0x0a02, GraphemeCat::InCBExtend),
+    (0x0a03, 0x0a03, GraphemeCat::SpacingMark),
+    (0x0a3c, 0x0a3c, GraphemeCat::InCBExtend),
+    (0x0a3e, 0x0a40, GraphemeCat::SpacingMark),
+    (0x0a41, 0x0a42, GraphemeCat::InCBExtend),
+    (0x0a47, 0x0a48, GraphemeCat::InCBExtend),
+    (0x0a4b, 0x0a4d, GraphemeCat::InCBExtend),
+    (0x0a51, 0x0a51, GraphemeCat::InCBExtend),
+    (0x0a70, 0x0a71, GraphemeCat::InCBExtend),
+    (0x0a75, 0x0a75, GraphemeCat::InCBExtend),
+    (0x0a81, 0x0a82, GraphemeCat::InCBExtend),
+    (0x0a83, 0x0a83, GraphemeCat::SpacingMark),
+    (0x0a95, 0x0aa8, GraphemeCat::InCBConsonant),
+    (0x0aaa, 0x0ab0, GraphemeCat::InCBConsonant),
+    (0x0ab2, 0x0ab3, GraphemeCat::InCBConsonant),
+    (0x0ab5, 0x0ab9, GraphemeCat::InCBConsonant),
+    (0x0abc, 0x0abc, GraphemeCat::InCBExtend),
+    (0x0abe, 0x0ac0, GraphemeCat::SpacingMark),
+    (0x0ac1, 0x0ac5, GraphemeCat::InCBExtend),
+    (0x0ac7, 0x0ac8, GraphemeCat::InCBExtend),
+    (0x0ac9, 0x0ac9, GraphemeCat::SpacingMark),
+    (0x0acb, 0x0acc, GraphemeCat::SpacingMark),
+    (0x0acd, 0x0acd, GraphemeCat::InCBLinker),
+    (0x0ae2, 0x0ae3, GraphemeCat::InCBExtend),
+    (0x0af9, 0x0af9, GraphemeCat::InCBConsonant),
+    (0x0afa, 0x0aff, GraphemeCat::InCBExtend),
+    (0x0b01, 0x0b01, GraphemeCat::InCBExtend),
+    (0x0b02, 0x0b03, GraphemeCat::SpacingMark),
+    (0x0b15, 0x0b28, GraphemeCat::InCBConsonant),
+    (0x0b2a, 0x0b30, GraphemeCat::InCBConsonant),
+    (0x0b32, 0x0b33, GraphemeCat::InCBConsonant),
+    (0x0b35, 0x0b39, GraphemeCat::InCBConsonant),
+    (0x0b3c, 0x0b3c, GraphemeCat::InCBExtend),
+    (0x0b3e, 0x0b3f, GraphemeCat::InCBExtend),
+    (0x0b40, 0x0b40, GraphemeCat::SpacingMark),
+    (0x0b41, 0x0b44, GraphemeCat::InCBExtend),
+    (0x0b47, 0x0b48, GraphemeCat::SpacingMark),
+    (0x0b4b, 0x0b4c, GraphemeCat::SpacingMark),
+    (0x0b4d, 0x0b4d, GraphemeCat::InCBLinker),
+    (0x0b55, 0x0b57, GraphemeCat::InCBExtend),
+    (0x0b5c, 0x0b5d, GraphemeCat::InCBConsonant),
+    (0x0b5f, 0x0b5f, GraphemeCat::InCBConsonant),
+    (0x0b62, 0x0b63, GraphemeCat::InCBExtend),
+    (0x0b71, 0x0b71, GraphemeCat::InCBConsonant),
+    (0x0b82, 0x0b82, GraphemeCat::InCBExtend),
+    (0x0bbe, 0x0bbe, GraphemeCat::InCBExtend),
+    (0x0bbf, 0x0bbf, GraphemeCat::SpacingMark),
+    (0x0bc0, 0x0bc0, GraphemeCat::InCBExtend),
+    (0x0bc1, 0x0bc2, GraphemeCat::SpacingMark),
+    (0x0bc6, 0x0bc8, GraphemeCat::SpacingMark),
+    (0x0bca, 0x0bcc, GraphemeCat::SpacingMark),
+    (0x0bcd, 0x0bcd, GraphemeCat::InCBExtend),
+    (0x0bd7, 0x0bd7, GraphemeCat::InCBExtend),
+    (0x0c00, 0x0c00, GraphemeCat::InCBExtend),
+    (0x0c01, 0x0c03, GraphemeCat::SpacingMark),
+    (0x0c04, 0x0c04, GraphemeCat::InCBExtend),
+    (0x0c15, 0x0c28, GraphemeCat::InCBConsonant),
+    (0x0c2a, 0x0c39, GraphemeCat::InCBConsonant),
+    (0x0c3c, 0x0c3c, GraphemeCat::InCBExtend),
+    (0x0c3e, 0x0c40, GraphemeCat::InCBExtend),
+    (0x0c41, 0x0c44, GraphemeCat::SpacingMark),
+    (0x0c46, 0x0c48, GraphemeCat::InCBExtend),
+    (0x0c4a, 0x0c4c, GraphemeCat::InCBExtend),
+    (0x0c4d, 0x0c4d, GraphemeCat::InCBLinker),
+    (0x0c55, 0x0c56, GraphemeCat::InCBExtend),
+    (0x0c58, 0x0c5a, GraphemeCat::InCBConsonant),
+    (0x0c62, 0x0c63, GraphemeCat::InCBExtend),
+    (0x0c81, 0x0c81, GraphemeCat::InCBExtend),
+    (0x0c82, 0x0c83, GraphemeCat::SpacingMark),
+    (0x0cbc, 0x0cbc, GraphemeCat::InCBExtend),
+    (0x0cbe, 0x0cbe, GraphemeCat::SpacingMark),
+    (0x0cbf, 0x0cc0, GraphemeCat::InCBExtend),
+    (0x0cc1, 0x0cc1, GraphemeCat::SpacingMark),
+    (0x0cc2, 0x0cc2, GraphemeCat::InCBExtend),
+    (0x0cc3, 0x0cc4, GraphemeCat::SpacingMark),
+    (0x0cc6, 0x0cc8, GraphemeCat::InCBExtend),
+    (0x0cca, 0x0ccd, GraphemeCat::InCBExtend),
+    (0x0cd5, 0x0cd6, GraphemeCat::InCBExtend),
+    (0x0ce2, 0x0ce3, GraphemeCat::InCBExtend),
+    (0x0cf3, 0x0cf3, GraphemeCat::SpacingMark),
+    (0x0d00, 0x0d01, GraphemeCat::InCBExtend),
+    (0x0d02, 0x0d03, GraphemeCat::SpacingMark),
+    (0x0d15, 0x0d3a, GraphemeCat::InCBConsonant),
+    (0x0d3b, 0x0d3c, GraphemeCat::InCBExtend),
+    (0x0d3e, 0x0d3e, GraphemeCat::InCBExtend),
+    (0x0d3f, 0x0d40, GraphemeCat::SpacingMark),
+    (0x0d41, 0x0d44, GraphemeCat::InCBExtend),
+    (0x0d46, 0x0d48, GraphemeCat::SpacingMark),
+    (0x0d4a, 0x0d4c, GraphemeCat::SpacingMark),
+    (0x0d4d, 0x0d4d, GraphemeCat::InCBLinker),
+    (0x0d4e, 0x0d4e, GraphemeCat::Prepend),
+    (0x0d57, 0x0d57, GraphemeCat::InCBExtend),
+    (0x0d62, 0x0d63, GraphemeCat::InCBExtend),
+    (0x0d81, 0x0d81, GraphemeCat::InCBExtend),
+    (0x0d82, 0x0d83, GraphemeCat::SpacingMark),
+    (0x0dca, 0x0dca, GraphemeCat::InCBExtend),
+    (0x0dcf, 0x0dcf, GraphemeCat::InCBExtend),
+    (0x0dd0, 0x0dd1, GraphemeCat::SpacingMark),
+    (0x0dd2, 0x0dd4, GraphemeCat::InCBExtend),
+    (0x0dd6, 0x0dd6, GraphemeCat::InCBExtend),
+    (0x0dd8, 0x0dde, GraphemeCat::SpacingMark),
+    (0x0ddf, 0x0ddf, GraphemeCat::InCBExtend),
+    (0x0df2, 0x0df3, GraphemeCat::SpacingMark),
+    (0x0e31, 0x0e31, GraphemeCat::InCBExtend),
+    (0x0e33, 0x0e33, GraphemeCat::SpacingMark),
+    (0x0e34, 0x0e3a, GraphemeCat::InCBExtend),
+    (0x0e47, 0x0e4e, GraphemeCat::InCBExtend),
+    (0x0eb1, 0x0eb1, GraphemeCat::InCBExtend),
+    (0x0eb3, 0x0eb3, GraphemeCat::SpacingMark),
+    (0x0eb4, 0x0ebc, GraphemeCat::InCBExtend),
+    (0x0ec8, 0x0ece, GraphemeCat::InCBExtend),
+    (0x0f18, 0x0f19, GraphemeCat::InCBExtend),
+    (0x0f35, 0x0f35, GraphemeCat::InCBExtend),
+    (0x0f37, 0x0f37, GraphemeCat::InCBExtend),
+    (0x0f39, 0x0f39, GraphemeCat::InCBExtend),
+    (0x0f3e, 0x0f3f, GraphemeCat::SpacingMark),
+    (0x0f71, 0x0f7e, GraphemeCat::InCBExtend),
+    (0x0f7f, 0x0f7f, GraphemeCat::SpacingMark),
+    (0x0f80, 0x0f84, GraphemeCat::InCBExtend),
+    (0x0f86, 0x0f87, GraphemeCat::InCBExtend),
+    (0x0f8d, 0x0f97, GraphemeCat::InCBExtend),
+    (0x0f99, 0x0fbc, GraphemeCat::InCBExtend),
+    (0x0fc6, 0x0fc6, GraphemeCat::InCBExtend),
+    (0x1000, 0x102a, GraphemeCat::InCBConsonant),
+    (0x102d, 0x1030, GraphemeCat::InCBExtend),
+    (0x1031, 0x1031, GraphemeCat::SpacingMark),
+    (0x1032, 0x1037, GraphemeCat::InCBExtend),
+    (0x1039, 0x1039, GraphemeCat::InCBLinker),
+    (0x103a, 0x103a, GraphemeCat::InCBExtend),
+    (0x103b, 0x103c, GraphemeCat::SpacingMark),
+    (0x103d, 0x103e, GraphemeCat::InCBExtend),
+    (0x103f, 0x103f, GraphemeCat::InCBConsonant),
+    (0x1050, 0x1055, GraphemeCat::InCBConsonant),
+    (0x1056, 0x1057, GraphemeCat::SpacingMark),
+    (0x1058, 0x1059, GraphemeCat::InCBExtend),
+    (0x105a, 0x105d, GraphemeCat::InCBConsonant),
+    (0x105e, 0x1060, GraphemeCat::InCBExtend),
+    (0x1061, 0x1061, GraphemeCat::InCBConsonant),
+    (0x1065, 0x1066, GraphemeCat::InCBConsonant),
+    (0x106e, 0x1070, GraphemeCat::InCBConsonant),
+    (0x1071, 0x1074, GraphemeCat::InCBExtend),
+    (0x1075, 0x1081, GraphemeCat::InCBConsonant),
+    (0x1082, 0x1082, GraphemeCat::InCBExtend),
+    (0x1084, 0x1084, GraphemeCat::SpacingMark),
+    (0x1085, 0x1086, GraphemeCat::InCBExtend),
+    (0x108d, 0x108d, GraphemeCat::InCBExtend),
+    (0x108e, 0x108e, GraphemeCat::InCBConsonant),
+    (0x109d, 0x109d, GraphemeCat::InCBExtend),
+    (0x1100, 0x115f, GraphemeCat::L),
+    (0x1160, 0x11a7, GraphemeCat::V),
+    (0x11a8, 0x11ff, GraphemeCat::T),
+    (0x135d, 0x135f, GraphemeCat::InCBExtend),
+    (0x1712, 0x1715, GraphemeCat::InCBExtend),
+    (0x1732, 0x1734, GraphemeCat::InCBExtend),
+    (0x1752, 0x1753, GraphemeCat::InCBExtend),
+    (0x1772, 0x1773, GraphemeCat::InCBExtend),
+    (0x1780, 0x17b3, GraphemeCat::InCBConsonant),
+    (0x17b4, 0x17b5, GraphemeCat::InCBExtend),
+    (0x17b6, 0x17b6, GraphemeCat::SpacingMark),
+    (0x17b7, 0x17bd, GraphemeCat::InCBExtend),
+    (0x17be, 0x17c5, GraphemeCat::SpacingMark),
+    (0x17c6, 0x17c6, GraphemeCat::InCBExtend),
+    (0x17c7, 0x17c8, GraphemeCat::SpacingMark),
+    (0x17c9, 0x17d1, GraphemeCat::InCBExtend),
+    (0x17d2, 0x17d2, GraphemeCat::InCBLinker),
+    (0x17d3, 0x17d3, GraphemeCat::InCBExtend),
+    (0x17dd, 0x17dd, GraphemeCat::InCBExtend),
+    (0x180b, 0x180d, GraphemeCat::InCBExtend),
+    (0x180e, 0x180e, GraphemeCat::Control),
+    (0x180f, 0x180f, GraphemeCat::InCBExtend),
+    (0x1885, 0x1886, GraphemeCat::InCBExtend),
+    (0x18a9, 0x18a9, GraphemeCat::InCBExtend),
+    (0x1920, 0x1922, GraphemeCat::InCBExtend),
+    (0x1923, 0x1926, GraphemeCat::SpacingMark),
+    (0x1927, 0x1928, GraphemeCat::InCBExtend),
+    (0x1929, 0x192b, GraphemeCat::SpacingMark),
+    (0x1930, 0x1931, GraphemeCat::SpacingMark),
+    (0x1932, 0x1932, GraphemeCat::InCBExtend),
+    (0x1933, 0x1938, GraphemeCat::SpacingMark),
+    (0x1939, 0x193b, GraphemeCat::InCBExtend),
+    (0x1a17, 0x1a18, GraphemeCat::InCBExtend),
+    (0x1a19, 0x1a1a, GraphemeCat::SpacingMark),
+    (0x1a1b, 0x1a1b, GraphemeCat::InCBExtend),
+    (0x1a20, 0x1a54, GraphemeCat::InCBConsonant),
+    (0x1a55, 0x1a55, GraphemeCat::SpacingMark),
+    (0x1a56, 0x1a56, GraphemeCat::InCBExtend),
+    (0x1a57, 0x1a57, GraphemeCat::SpacingMark),
+    (0x1a58, 0x1a5e, GraphemeCat::InCBExtend),
+    (0x1a60, 0x1a60, GraphemeCat::InCBLinker),
+    (0x1a62, 0x1a62, GraphemeCat::InCBExtend),
+    (0x1a65, 0x1a6c, GraphemeCat::InCBExtend),
+    (0x1a6d, 0x1a72, GraphemeCat::SpacingMark),
+    (0x1a73, 0x1a7c, GraphemeCat::InCBExtend),
+    (0x1a7f, 0x1a7f, GraphemeCat::InCBExtend),
+    (0x1ab0, 0x1add, GraphemeCat::InCBExtend),
+    (0x1ae0, 0x1aeb, GraphemeCat::InCBExtend),
+    (0x1b00, 0x1b03, GraphemeCat::InCBExtend),
+    (0x1b04, 0x1b04, GraphemeCat::SpacingMark),
+    (0x1b0b, 0x1b0c, GraphemeCat::InCBConsonant),
+    (0x1b13, 0x1b33, GraphemeCat::InCBConsonant),
+    (0x1b34, 0x1b3d, GraphemeCat::InCBExtend),
+    (0x1b3e, 0x1b41, GraphemeCat::SpacingMark),
+    (0x1b42, 0x1b43, GraphemeCat::InCBExtend),
+    (0x1b44, 0x1b44, GraphemeCat::InCBLinker),
+    (0x1b45, 0x1b4c, GraphemeCat::InCBConsonant),
+    (0x1b6b, 0x1b73, GraphemeCat::InCBExtend),
+    (0x1b80, 0x1b81, GraphemeCat::InCBExtend),
+    (0x1b82, 0x1b82, GraphemeCat::SpacingMark),
+    (0x1b83, 0x1ba0, GraphemeCat::InCBConsonant),
+    (0x1ba1, 0x1ba1, GraphemeCat::SpacingMark),
+    (0x1ba2, 0x1ba5, GraphemeCat::InCBExtend),
+    (0x1ba6, 0x1ba7, GraphemeCat::SpacingMark),
+    (0x1ba8, 0x1baa, GraphemeCat::InCBExtend),
+    (0x1bab, 0x1bab, GraphemeCat::InCBLinker),
+    (0x1bac, 0x1bad, GraphemeCat::InCBExtend),
+    (0x1bae, 0x1baf, GraphemeCat::InCBConsonant),
+    (0x1bbb, 0x1bbd, GraphemeCat::InCBConsonant),
+    (0x1be6, 0x1be6, GraphemeCat::InCBExtend),
+    (0x1be7, 0x1be7, GraphemeCat::SpacingMark),
+    (0x1be8, 0x1be9, GraphemeCat::InCBExtend),
+    (0x1bea, 0x1bec, GraphemeCat::SpacingMark),
+    (0x1bed, 0x1bed, GraphemeCat::InCBExtend),
+    (0x1bee, 0x1bee, GraphemeCat::SpacingMark),
+    (0x1bef, 0x1bf3, GraphemeCat::InCBExtend),
+    (0x1c24, 0x1c2b, GraphemeCat::SpacingMark),
+    (0x1c2c, 0x1c33, GraphemeCat::InCBExtend),
+    (0x1c34, 0x1c35, GraphemeCat::SpacingMark),
+    (0x1c36, 0x1c37, GraphemeCat::InCBExtend),
+    (0x1cd0, 0x1cd2, GraphemeCat::InCBExtend),
+    (0x1cd4, 0x1ce0, GraphemeCat::InCBExtend),
+    (0x1ce1, 0x1ce1, GraphemeCat::SpacingMark),
+    (0x1ce2, 0x1ce8, GraphemeCat::InCBExtend),
+    (0x1ced, 0x1ced, GraphemeCat::InCBExtend),
+    (0x1cf4, 0x1cf4, GraphemeCat::InCBExtend),
+    (0x1cf7, 0x1cf7, GraphemeCat::SpacingMark),
+    (0x1cf8, 0x1cf9, GraphemeCat::InCBExtend),
+    (0x1dc0, 0x1dff, GraphemeCat::InCBExtend),
+    (0x200b, 0x200b, GraphemeCat::Control),
+    (0x200c, 0x200c, GraphemeCat::Extend),
+    (0x200d, 0x200d, GraphemeCat::ZWJ),
+    (0x200e, 0x200f, GraphemeCat::Control),
+    (0x2028, 0x202e, GraphemeCat::Control),
+    (0x203c, 0x203c, GraphemeCat::ExtendedPictographic),
+    (0x2049, 0x2049, GraphemeCat::ExtendedPictographic),
+    (0x2060, 0x206f, GraphemeCat::Control),
+    (0x20d0, 0x20f0, GraphemeCat::InCBExtend),
+    (0x2122, 0x2122, GraphemeCat::ExtendedPictographic),
+    (0x2139, 0x2139, GraphemeCat::ExtendedPictographic),
+    (0x2194, 0x2199, GraphemeCat::ExtendedPictographic),
+    (0x21a9, 0x21aa, GraphemeCat::ExtendedPictographic),
+    (0x231a, 0x231b, GraphemeCat::ExtendedPictographic),
+    (0x2328, 0x2328, GraphemeCat::ExtendedPictographic),
+    (0x23cf, 0x23cf, GraphemeCat::ExtendedPictographic),
+    (0x23e9, 0x23f3, GraphemeCat::ExtendedPictographic),
+    (0x23f8, 0x23fa, GraphemeCat::ExtendedPictographic),
+    (0x24c2, 0x24c2, GraphemeCat::ExtendedPictographic),
+    (0x25aa, 0x25ab, GraphemeCat::ExtendedPictographic),
+    (0x25b6, 0x25b6, GraphemeCat::ExtendedPictographic),
+    (0x25c0, 0x25c0, GraphemeCat::ExtendedPictographic),
+    (0x25fb, 0x25fe, GraphemeCat::ExtendedPictographic),
+    (0x2600, 0x2604, GraphemeCat::ExtendedPictographic),
+    (0x260e, 0x260e, GraphemeCat::ExtendedPictographic),
+    (0x2611, 0x2611, GraphemeCat::ExtendedPictographic),
+    (0x2614, 0x2615, GraphemeCat::ExtendedPictographic),
+    (0x2618, 0x2618, GraphemeCat::ExtendedPictographic),
+    (0x261d, 0x261d, GraphemeCat::ExtendedPictographic),
+    (0x2620, 0x2620, GraphemeCat::ExtendedPictographic),
+    (0x2622, 0x2623, GraphemeCat::ExtendedPictographic),
+    (0x2626, 0x2626, GraphemeCat::ExtendedPictographic),
+    (0x262a, 0x262a, GraphemeCat::ExtendedPictographic),
+    (0x262e, 0x262f, GraphemeCat::ExtendedPictographic),
+    (0x2638, 0x263a, GraphemeCat::ExtendedPictographic),
+    (0x2640, 0x2640, GraphemeCat::ExtendedPictographic),
+    (0x2642, 0x2642, GraphemeCat::ExtendedPictographic),
+    (0x2648, 0x2653, GraphemeCat::ExtendedPictographic),
+    (0x265f, 0x2660, GraphemeCat::ExtendedPictographic),
+    (0x2663, 0x2663, GraphemeCat::ExtendedPictographic),
+    (0x2665, 0x2666, GraphemeCat::ExtendedPictographic),
+    (0x2668, 0x2668, GraphemeCat::ExtendedPictographic),
+    (0x267b, 0x267b, GraphemeCat::ExtendedPictographic),
+    (0x267e, 0x267f, GraphemeCat::ExtendedPictographic),
+    (0x2692, 0x2697, GraphemeCat::ExtendedPictographic),
+    (0x2699, 0x2699, GraphemeCat::ExtendedPictographic),
+    (0x269b, 0x269c, GraphemeCat::ExtendedPictographic),
+    (0x26a0, 0x26a1, GraphemeCat::ExtendedPictographic),
+    (0x26a7, 0x26a7, GraphemeCat::ExtendedPictographic),
+    (0x26aa, 0x26ab, GraphemeCat::ExtendedPictographic),
+    (0x26b0, 0x26b1, GraphemeCat::ExtendedPictographic),
+    (0x26bd, 0x26be, GraphemeCat::ExtendedPictographic),
+    (0x26c4, 0x26c5, GraphemeCat::ExtendedPictographic),
+    (0x26c8, 0x26c8, GraphemeCat::ExtendedPictographic),
+    (0x26ce, 0x26cf, GraphemeCat::ExtendedPictographic),
+    (0x26d1, 0x26d1, GraphemeCat::ExtendedPictographic),
+    (0x26d3, 0x26d4, GraphemeCat::ExtendedPictographic),
+    (0x26e9, 0x26ea, GraphemeCat::ExtendedPictographic),
+    (0x26f0, 0x26f5, GraphemeCat::ExtendedPictographic),
+    (0x26f7, 0x26fa, GraphemeCat::ExtendedPictographic),
+    (0x26fd, 0x26fd, GraphemeCat::ExtendedPictographic),
+    (0x2702, 0x2702, GraphemeCat::ExtendedPictographic),
+    (0x2705, 0x2705, GraphemeCat::ExtendedPictographic),
+    (0x2708, 0x270d, GraphemeCat::ExtendedPictographic),
+    (0x270f, 0x270f, GraphemeCat::ExtendedPictographic),
+    (0x2712, 0x2712, GraphemeCat::ExtendedPictographic),
+    (0x2714, 0x2714, GraphemeCat::ExtendedPictographic),
+    (0x2716, 0x2716, GraphemeCat::ExtendedPictographic),
+    (0x271d, 0x271d, GraphemeCat::ExtendedPictographic),
+    (0x2721, 0x2721, GraphemeCat::ExtendedPictographic),
+    (0x2728, 0x2728, GraphemeCat::ExtendedPictographic),
+    (0x2733, 0x2734, GraphemeCat::ExtendedPictographic),
+    (0x2744, 0x2744, GraphemeCat::ExtendedPictographic),
+    (0x2747, 0x2747, GraphemeCat::ExtendedPictographic),
+    (0x274c, 0x274c, GraphemeCat::ExtendedPictographic),
+    (0x274e, 0x274e, GraphemeCat::ExtendedPictographic),
+    (0x2753, 0x2755, GraphemeCat::ExtendedPictographic),
+    (0x2757, 0x2757, GraphemeCat::ExtendedPictographic),
+    (0x2763, 0x2764, GraphemeCat::ExtendedPictographic),
+    (0x2795, 0x2797, GraphemeCat::ExtendedPictographic),
+    (0x27a1, 0x27a1, GraphemeCat::ExtendedPictographic),
+    (0x27b0, 0x27b0, GraphemeCat::ExtendedPictographic),
+    (0x27bf, 0x27bf, GraphemeCat::ExtendedPictographic),
+    (0x2934, 0x2935, GraphemeCat::ExtendedPictographic),
+    (0x2b05, 0x2b07, GraphemeCat::ExtendedPictographic),
+    (0x2b1b, 0x2b1c, GraphemeCat::ExtendedPictographic),
+    (0x2b50, 0x2b50, GraphemeCat::ExtendedPictographic),
+    (0x2b55, 0x2b55, GraphemeCat::ExtendedPictographic),
+    (0x2cef, 0x2cf1, GraphemeCat::InCBExtend),
+    (0x2d7f, 0x2d7f, GraphemeCat::InCBExtend),
+    (0x2de0, 0x2dff, GraphemeCat::InCBExtend),
+    (0x302a, 0x302f, GraphemeCat::InCBExtend),
+    (0x3030, 0x3030, GraphemeCat::ExtendedPictographic),
+    (0x303d, 0x303d, GraphemeCat::ExtendedPictographic),
+    (0x3099, 0x309a, GraphemeCat::InCBExtend),
+    (0x3297, 0x3297, GraphemeCat::ExtendedPictographic),
+    (0x3299, 0x3299, GraphemeCat::ExtendedPictographic),
+    (0xa66f, 0xa672, GraphemeCat::InCBExtend),
+    (0xa674, 0xa67d, GraphemeCat::InCBExtend),
+    (0xa69e, 0xa69f, GraphemeCat::InCBExtend),
+    (0xa6f0, 0xa6f1, GraphemeCat::InCBExtend),
+    (0xa802, 0xa802, GraphemeCat::InCBExtend),
+    (0xa806, 0xa806, GraphemeCat::InCBExtend),
+    (0xa80b, 0xa80b, GraphemeCat::InCBExtend),
+    (0xa823, 0xa824, GraphemeCat::SpacingMark),
+    (0xa825, 0xa826, GraphemeCat::InCBExtend),
+    (0xa827, 0xa827, GraphemeCat::SpacingMark),
+    (0xa82c, 0xa82c, GraphemeCat::InCBExtend),
+    (0xa880, 0xa881, GraphemeCat::SpacingMark),
+    (0xa8b4, 0xa8c3, GraphemeCat::SpacingMark),
+    (0xa8c4, 0xa8c5, GraphemeCat::InCBExtend),
+    (0xa8e0, 0xa8f1, GraphemeCat::InCBExtend),
+    (0xa8ff, 0xa8ff, GraphemeCat::InCBExtend),
+    (0xa926, 0xa92d, GraphemeCat::InCBExtend),
+    (0xa947, 0xa951, GraphemeCat::InCBExtend),
+    (0xa952, 0xa952, GraphemeCat::SpacingMark),
+    (0xa953, 0xa953, GraphemeCat::InCBExtend),
+    (0xa960, 0xa97c, GraphemeCat::L),
+    (0xa980, 0xa982, GraphemeCat::InCBExtend),
+    (0xa983, 0xa983, GraphemeCat::SpacingMark),
+    (0xa989, 0xa98b, GraphemeCat::InCBConsonant),
+    (0xa98f, 0xa9b2, GraphemeCat::InCBConsonant),
+    (0xa9b3, 0xa9b3, GraphemeCat::InCBExtend),
+    (0xa9b4, 0xa9b5, GraphemeCat::SpacingMark),
+    (0xa9b6, 0xa9b9, GraphemeCat::InCBExtend),
+    (0xa9ba, 0xa9bb, GraphemeCat::SpacingMark),
+    (0xa9bc, 0xa9bd, GraphemeCat::InCBExtend),
+    (0xa9be, 0xa9bf, GraphemeCat::SpacingMark),
+    (0xa9c0, 0xa9c0, GraphemeCat::InCBLinker),
+    (0xa9e0, 0xa9e4, GraphemeCat::InCBConsonant),
+    (0xa9e5, 0xa9e5, GraphemeCat::InCBExtend),
+    (0xa9e7, 0xa9ef, GraphemeCat::InCBConsonant),
+    (0xa9fa, 0xa9fe, GraphemeCat::InCBConsonant),
+    (0xaa29, 0xaa2e, GraphemeCat::InCBExtend),
+    (0xaa2f, 0xaa30, GraphemeCat::SpacingMark),
+    (0xaa31, 0xaa32, GraphemeCat::InCBExtend),
+    (0xaa33, 0xaa34, GraphemeCat::SpacingMark),
+    (0xaa35, 0xaa36, GraphemeCat::InCBExtend),
+    (0xaa43, 0xaa43, GraphemeCat::InCBExtend),
+    (0xaa4c, 0xaa4c, GraphemeCat::InCBExtend),
+    (0xaa4d, 0xaa4d, GraphemeCat::SpacingMark),
+    (0xaa60, 0xaa6f, GraphemeCat::InCBConsonant),
+    (0xaa71, 0xaa73, GraphemeCat::InCBConsonant),
+    (0xaa7a, 0xaa7a, GraphemeCat::InCBConsonant),
+    (0xaa7c, 0xaa7c, GraphemeCat::InCBExtend),
+    (0xaa7e, 0xaa7f, GraphemeCat::InCBConsonant),
+    (0xaab0, 0xaab0, GraphemeCat::InCBExtend),
+    (0xaab2, 0xaab4, GraphemeCat::InCBExtend),
+    (0xaab7, 0xaab8, GraphemeCat::InCBExtend),
+    (0xaabe, 0xaabf, GraphemeCat::InCBExtend),
+    (0xaac1, 0xaac1, GraphemeCat::InCBExtend),
+    (0xaae0, 0xaaea, GraphemeCat::InCBConsonant),
+    (0xaaeb, 0xaaeb, GraphemeCat::SpacingMark),
+    (0xaaec, 0xaaed, GraphemeCat::InCBExtend),
+    (0xaaee, 0xaaef, GraphemeCat::SpacingMark),
+    (0xaaf5, 0xaaf5, GraphemeCat::SpacingMark),
+    (0xaaf6, 0xaaf6, GraphemeCat::InCBLinker),
+    (0xabc0, 0xabda, GraphemeCat::InCBConsonant),
+    (0xabe3, 0xabe4, GraphemeCat::SpacingMark),
+    (0xabe5, 0xabe5, GraphemeCat::InCBExtend),
+    (0xabe6, 0xabe7, GraphemeCat::SpacingMark),
+    (0xabe8, 0xabe8, GraphemeCat::InCBExtend),
+    (0xabe9, 0xabea, GraphemeCat::SpacingMark),
+    (0xabec, 0xabec, GraphemeCat::SpacingMark),
+    (0xabed, 0xabed, GraphemeCat::InCBExtend),
+    (0xac00, 0xac00, GraphemeCat::LV),
+    (0xac01, 0xac1b, GraphemeCat::LVT),
+    (0xac1c, 0xac1c, GraphemeCat::LV),
+    (0xac1d, 0xac37, GraphemeCat::LVT),
+    (0xac38, 0xac38, GraphemeCat::LV),
+    (0xac39, 0xac53, GraphemeCat::LVT),
+    (0xac54, 0xac54, GraphemeCat::LV),
+    (0xac55, 0xac6f, GraphemeCat::LVT),
+    (0xac70, 0xac70, GraphemeCat::LV),
+    (0xac71, 0xac8b, GraphemeCat::LVT),
+    (0xac8c, 0xac8c, GraphemeCat::LV),
+    (0xac8d, 0xaca7, GraphemeCat::LVT),
+    (0xaca8, 0xaca8, GraphemeCat::LV),
+    (0xaca9, 0xacc3, GraphemeCat::LVT),
+    (0xacc4, 0xacc4, GraphemeCat::LV),
+    (0xacc5, 0xacdf, GraphemeCat::LVT),
+    (0xace0, 0xace0, GraphemeCat::LV),
+    (0xace1, 0xacfb, GraphemeCat::LVT),
+    (0xacfc, 0xacfc, GraphemeCat::LV),
+    (0xacfd, 0xad17, GraphemeCat::LVT),
+    (0xad18, 0xad18, GraphemeCat::LV),
+    (0xad19, 0xad33, GraphemeCat::LVT),
+    (0xad34, 0xad34, GraphemeCat::LV),
+    (0xad35, 0xad4f, GraphemeCat::LVT),
+    (0xad50, 0xad50, GraphemeCat::LV),
+    (0xad51, 0xad6b, GraphemeCat::LVT),
+    (0xad6c, 0xad6c, GraphemeCat::LV),
+    (0xad6d, 0xad87, GraphemeCat::LVT),
+    (0xad88, 0xad88, GraphemeCat::LV),
+    (0xad89, 0xada3, GraphemeCat::LVT),
+    (0xada4, 0xada4, GraphemeCat::LV),
+    (0xada5, 0xadbf, GraphemeCat::LVT),
+    (0xadc0, 0xadc0, GraphemeCat::LV),
+    (0xadc1, 0xaddb, GraphemeCat::LVT),
+    (0xaddc, 0xaddc, GraphemeCat::LV),
+    (0xaddd, 0xadf7, GraphemeCat::LVT),
+    (0xadf8, 0xadf8, GraphemeCat::LV),
+    (0xadf9, 0xae13, GraphemeCat::LVT),
+    (0xae14, 0xae14, GraphemeCat::LV),
+    (0xae15, 0xae2f, GraphemeCat::LVT),
+    (0xae30, 0xae30, GraphemeCat::LV),
+    (0xae31, 0xae4b, GraphemeCat::LVT),
+    (0xae4c, 0xae4c, GraphemeCat::LV),
+    (0xae4d, 0xae67, GraphemeCat::LVT),
+    (0xae68, 0xae68, GraphemeCat::LV),
+    (0xae69, 0xae83, GraphemeCat::LVT),
+    (0xae84, 0xae84, GraphemeCat::LV),
+    (0xae85, 0xae9f, GraphemeCat::LVT),
+    (0xaea0, 0xaea0, GraphemeCat::LV),
+    (0xaea1, 0xaebb, GraphemeCat::LVT),
+    (0xaebc, 0xaebc, GraphemeCat::LV),
+    (0xaebd, 0xaed7, GraphemeCat::LVT),
+    (0xaed8, 0xaed8, GraphemeCat::LV),
+    (0xaed9, 0xaef3, GraphemeCat::LVT),
+    (0xaef4, 0xaef4, GraphemeCat::LV),
+    (0xaef5, 0xaf0f, GraphemeCat::LVT),
+    (0xaf10, 0xaf10, GraphemeCat::LV),
+    (0xaf11, 0xaf2b, GraphemeCat::LVT),
+    (0xaf2c, 0xaf2c, GraphemeCat::LV),
+    (0xaf2d, 0xaf47, GraphemeCat::LVT),
+    (0xaf48, 0xaf48, GraphemeCat::LV),
+    (0xaf49, 0xaf63, GraphemeCat::LVT),
+    (0xaf64, 0xaf64, GraphemeCat::LV),
+    (0xaf65, 0xaf7f, GraphemeCat::LVT),
+    (0xaf80, 0xaf80, GraphemeCat::LV),
+    (0xaf81, 0xaf9b, GraphemeCat::LVT),
+    (0xaf9c, 0xaf9c, GraphemeCat::LV),
+    (0xaf9d, 0xafb7, GraphemeCat::LVT),
+    (0xafb8, 0xafb8, GraphemeCat::LV),
+    (0xafb9, 0xafd3, GraphemeCat::LVT),
+    (0xafd4, 0xafd4, GraphemeCat::LV),
+    (0xafd5, 0xafef, GraphemeCat::LVT),
+    (0xaff0, 0xaff0, GraphemeCat::LV),
+    (0xaff1, 0xb00b, GraphemeCat::LVT),
+    (0xb00c, 0xb00c, GraphemeCat::LV),
+    (0xb00d, 0xb027, GraphemeCat::LVT),
+    (0xb028, 0xb028, GraphemeCat::LV),
+    (0xb029, 0xb043, GraphemeCat::LVT),
+    (0xb044, 0xb044, GraphemeCat::LV),
+    (0xb045, 0xb05f, GraphemeCat::LVT),
+    (0xb060, 0xb060, GraphemeCat::LV),
+    (0xb061, 0xb07b, GraphemeCat::LVT),
+    (0xb07c, 0xb07c, GraphemeCat::LV),
+    (0xb07d, 0xb097, GraphemeCat::LVT),
+    (0xb098, 0xb098, GraphemeCat::LV),
+    (0xb099, 0xb0b3, GraphemeCat::LVT),
+    (0xb0b4, 0xb0b4, GraphemeCat::LV),
+    (0xb0b5, 0xb0cf, GraphemeCat::LVT),
+    (0xb0d0, 0xb0d0, GraphemeCat::LV),
+    (0xb0d1, 0xb0eb, GraphemeCat::LVT),
+    (0xb0ec, 0xb0ec, GraphemeCat::LV),
+    (0xb0ed, 0xb107, GraphemeCat::LVT),
+    (0xb108, 0xb108, GraphemeCat::LV),
+    (0xb109, 0xb123, GraphemeCat::LVT),
+    (0xb124, 0xb124, GraphemeCat::LV),
+    (0xb125, 0xb13f, GraphemeCat::LVT),
+    (0xb140, 0xb140, GraphemeCat::LV),
+    (0xb141, 0xb15b, GraphemeCat::LVT),
+    (0xb15c, 0xb15c, GraphemeCat::LV),
+    (0xb15d, 0xb177, GraphemeCat::LVT),
+    (0xb178, 0xb178, GraphemeCat::LV),
+    (0xb179, 0xb193, GraphemeCat::LVT),
+    (0xb194, 0xb194, GraphemeCat::LV),
+    (0xb195, 0xb1af, GraphemeCat::LVT),
+    (0xb1b0, 0xb1b0, GraphemeCat::LV),
+    (0xb1b1, 0xb1cb, GraphemeCat::LVT),
+    (0xb1cc, 0xb1cc, GraphemeCat::LV),
+    (0xb1cd, 0xb1e7, GraphemeCat::LVT),
+    (0xb1e8, 0xb1e8, GraphemeCat::LV),
+    (0xb1e9, 0xb203, GraphemeCat::LVT),
+    (0xb204, 0xb204, GraphemeCat::LV),
+    (0xb205, 0xb21f, GraphemeCat::LVT),
+    (0xb220, 0xb220, GraphemeCat::LV),
+    (0xb221, 0xb23b, GraphemeCat::LVT),
+    (0xb23c, 0xb23c, GraphemeCat::LV),
+    (0xb23d, 0xb257, GraphemeCat::LVT),
+    (0xb258, 0xb258, GraphemeCat::LV),
+    (0xb259, 0xb273, GraphemeCat::LVT),
+    (0xb274, 0xb274, GraphemeCat::LV),
+    (0xb275, 0xb28f, GraphemeCat::LVT),
+    (0xb290, 0xb290, GraphemeCat::LV),
+    (0xb291, 0xb2ab, GraphemeCat::LVT),
+    (0xb2ac, 0xb2ac, GraphemeCat::LV),
+    (0xb2ad, 0xb2c7, GraphemeCat::LVT),
+    (0xb2c8, 0xb2c8, GraphemeCat::LV),
+    (0xb2c9, 0xb2e3, GraphemeCat::LVT),
+    (0xb2e4, 0xb2e4, GraphemeCat::LV),
+    (0xb2e5, 0xb2ff, GraphemeCat::LVT),
+    (0xb300, 0xb300, GraphemeCat::LV),
+    (0xb301, 0xb31b, GraphemeCat::LVT),
+    (0xb31c, 0xb31c, GraphemeCat::LV),
+    (0xb31d, 0xb337, GraphemeCat::LVT),
+    (0xb338, 0xb338, GraphemeCat::LV),
+    (0xb339, 0xb353, GraphemeCat::LVT),
+    (0xb354, 0xb354, GraphemeCat::LV),
+    (0xb355, 0xb36f, GraphemeCat::LVT),
+    (0xb370, 0xb370, GraphemeCat::LV),
+    (0xb371, 0xb38b, GraphemeCat::LVT),
+    (0xb38c, 0xb38c, GraphemeCat::LV),
+    (0xb38d, 0xb3a7, GraphemeCat::LVT),
+    (0xb3a8, 0xb3a8, GraphemeCat::LV),
+    (0xb3a9, 0xb3c3, GraphemeCat::LVT),
+    (0xb3c4, 0xb3c4, GraphemeCat::LV),
+    (0xb3c5, 0xb3df, GraphemeCat::LVT),
+    (0xb3e0, 0xb3e0, GraphemeCat::LV),
+    (0xb3e1, 0xb3fb, GraphemeCat::LVT),
+    (0xb3fc, 0xb3fc, GraphemeCat::LV),
+    (0xb3fd, 0xb417, GraphemeCat::LVT),
+    (0xb418, 0xb418, GraphemeCat::LV),
+    (0xb419, 0xb433, GraphemeCat::LVT),
+    (0xb434, 0xb434, GraphemeCat::LV),
+    (0xb435, 0xb44f, GraphemeCat::LVT),
+    (0xb450, 0xb450, GraphemeCat::LV),
+    (0xb451, 0xb46b, GraphemeCat::LVT),
+    (0xb46c, 0xb46c, GraphemeCat::LV),
+    (0xb46d, 0xb487, GraphemeCat::LVT),
+    (0xb488, 0xb488, GraphemeCat::LV),
+    (0xb489, 0xb4a3, GraphemeCat::LVT),
+    (0xb4a4, 0xb4a4, GraphemeCat::LV),
+    (0xb4a5, 0xb4bf, GraphemeCat::LVT),
+    (0xb4c0, 0xb4c0, GraphemeCat::LV),
+    (0xb4c1, 0xb4db, GraphemeCat::LVT),
+    (0xb4dc, 0xb4dc, GraphemeCat::LV),
+    (0xb4dd, 0xb4f7, GraphemeCat::LVT),
+    (0xb4f8, 0xb4f8, GraphemeCat::LV),
+    (0xb4f9, 0xb513, GraphemeCat::LVT),
+    (0xb514, 0xb514, GraphemeCat::LV),
+    (0xb515, 0xb52f, GraphemeCat::LVT),
+    (0xb530, 0xb530, GraphemeCat::LV),
+    (0xb531, 0xb54b, GraphemeCat::LVT),
+    (0xb54c, 0xb54c, GraphemeCat::LV),
+    (0xb54d, 0xb567, GraphemeCat::LVT),
+    (0xb568, 0xb568, GraphemeCat::LV),
+    (0xb569, 0xb583, GraphemeCat::LVT),
+    (0xb584, 0xb584, GraphemeCat::LV),
+    (0xb585, 0xb59f, GraphemeCat::LVT),
+    (0xb5a0, 0xb5a0, GraphemeCat::LV),
+    (0xb5a1, 0xb5bb, GraphemeCat::LVT),
+    (0xb5bc, 0xb5bc, GraphemeCat::LV),
+    (0xb5bd, 0xb5d7, GraphemeCat::LVT),
+    (0xb5d8, 0xb5d8, GraphemeCat::LV),
+    (0xb5d9, 0xb5f3, GraphemeCat::LVT),
+    (0xb5f4, 0xb5f4, GraphemeCat::LV),
+    (0xb5f5, 0xb60f, GraphemeCat::LVT),
+    (0xb610, 0xb610, GraphemeCat::LV),
+    (0xb611, 0xb62b, GraphemeCat::LVT),
+    (0xb62c, 0xb62c, GraphemeCat::LV),
+    (0xb62d, 0xb647, GraphemeCat::LVT),
+    (0xb648, 0xb648, GraphemeCat::LV),
+    (0xb649, 0xb663, GraphemeCat::LVT),
+    (0xb664, 0xb664, GraphemeCat::LV),
+    (0xb665, 0xb67f, GraphemeCat::LVT),
+    (0xb680, 0xb680, GraphemeCat::LV),
+    (0xb681, 0xb69b, GraphemeCat::LVT),
+    (0xb69c, 0xb69c, GraphemeCat::LV),
+    (0xb69d, 0xb6b7, GraphemeCat::LVT),
+    (0xb6b8, 0xb6b8, GraphemeCat::LV),
+    (0xb6b9, 0xb6d3, GraphemeCat::LVT),
+    (0xb6d4, 0xb6d4, GraphemeCat::LV),
+    (0xb6d5, 0xb6ef, GraphemeCat::LVT),
+    (0xb6f0, 0xb6f0, GraphemeCat::LV),
+    (0xb6f1, 0xb70b, GraphemeCat::LVT),
+    (0xb70c, 0xb70c, GraphemeCat::LV),
+    (0xb70d, 0xb727, GraphemeCat::LVT),
+    (0xb728, 0xb728, GraphemeCat::LV),
+    (0xb729, 0xb743, GraphemeCat::LVT),
+    (0xb744, 0xb744, GraphemeCat::LV),
+    (0xb745, 0xb75f, GraphemeCat::LVT),
+    (0xb760, 0xb760, GraphemeCat::LV),
+    (0xb761, 0xb77b, GraphemeCat::LVT),
+    (0xb77c, 0xb77c, GraphemeCat::LV),
+    (0xb77d, 0xb797, GraphemeCat::LVT),
+    (0xb798, 0xb798, GraphemeCat::LV),
+    (0xb799, 0xb7b3, GraphemeCat::LVT),
+    (0xb7b4, 0xb7b4, GraphemeCat::LV),
+    (0xb7b5, 0xb7cf, GraphemeCat::LVT),
+    (0xb7d0, 0xb7d0, GraphemeCat::LV),
+    (0xb7d1, 0xb7eb, GraphemeCat::LVT),
+    (0xb7ec, 0xb7ec, GraphemeCat::LV),
+    (0xb7ed, 0xb807, GraphemeCat::LVT),
+    (0xb808, 0xb808, GraphemeCat::LV),
+    (0xb809, 0xb823, GraphemeCat::LVT),
+    (0xb824, 0xb824, GraphemeCat::LV),
+    (0xb825, 0xb83f, GraphemeCat::LVT),
+    (0xb840, 0xb840, GraphemeCat::LV),
+    (0xb841, 0xb85b, GraphemeCat::LVT),
+    (0xb85c, 0xb85c, GraphemeCat::LV),
+    (0xb85d, 0xb877, GraphemeCat::LVT),
+    (0xb878, 0xb878, GraphemeCat::LV),
+    (0xb879, 0xb893, GraphemeCat::LVT),
+    (0xb894, 0xb894, GraphemeCat::LV),
+    (0xb895, 0xb8af, GraphemeCat::LVT),
+    (0xb8b0, 0xb8b0, GraphemeCat::LV),
+    (0xb8b1, 0xb8cb, GraphemeCat::LVT),
+    (0xb8cc, 0xb8cc, GraphemeCat::LV),
+    (0xb8cd, 0xb8e7, GraphemeCat::LVT),
+    (0xb8e8, 0xb8e8, GraphemeCat::LV),
+    (0xb8e9, 0xb903, GraphemeCat::LVT),
+    (0xb904, 0xb904, GraphemeCat::LV),
+    (0xb905, 0xb91f, GraphemeCat::LVT),
+    (0xb920, 0xb920, GraphemeCat::LV),
+    (0xb921, 0xb93b, GraphemeCat::LVT),
+    (0xb93c, 0xb93c, GraphemeCat::LV),
+    (0xb93d, 0xb957, GraphemeCat::LVT),
+    (0xb958, 0xb958, GraphemeCat::LV),
+    (0xb959, 0xb973, GraphemeCat::LVT),
+    (0xb974, 0xb974, GraphemeCat::LV),
+    (0xb975, 0xb98f, GraphemeCat::LVT),
+    (0xb990, 0xb990, GraphemeCat::LV),
+    (0xb991, 0xb9ab, GraphemeCat::LVT),
+    (0xb9ac, 0xb9ac, GraphemeCat::LV),
+    (0xb9ad, 0xb9c7, GraphemeCat::LVT),
+    (0xb9c8, 0xb9c8, GraphemeCat::LV),
+    (0xb9c9, 0xb9e3, GraphemeCat::LVT),
+    (0xb9e4, 0xb9e4, GraphemeCat::LV),
+    (0xb9e5, 0xb9ff, GraphemeCat::LVT),
+    (0xba00, 0xba00, GraphemeCat::LV),
+    (0xba01, 0xba1b, GraphemeCat::LVT),
+    (0xba1c, 0xba1c, GraphemeCat::LV),
+    (0xba1d, 0xba37, GraphemeCat::LVT),
+    (0xba38, 0xba38, GraphemeCat::LV),
+    (0xba39, 0xba53, GraphemeCat::LVT),
+    (0xba54, 0xba54, GraphemeCat::LV),
+    (0xba55, 0xba6f, GraphemeCat::LVT),
+    (0xba70, 0xba70, GraphemeCat::LV),
+    (0xba71, 0xba8b, GraphemeCat::LVT),
+    (0xba8c, 0xba8c, GraphemeCat::LV),
+    (0xba8d, 0xbaa7, GraphemeCat::LVT),
+    (0xbaa8, 0xbaa8, GraphemeCat::LV),
+    (0xbaa9, 0xbac3, GraphemeCat::LVT),
+    (0xbac4, 0xbac4, GraphemeCat::LV),
+    (0xbac5, 0xbadf, GraphemeCat::LVT),
+    (0xbae0, 0xbae0, GraphemeCat::LV),
+    (0xbae1, 0xbafb, GraphemeCat::LVT),
+    (0xbafc, 0xbafc, GraphemeCat::LV),
+    (0xbafd, 0xbb17, GraphemeCat::LVT),
+    (0xbb18, 0xbb18, GraphemeCat::LV),
+    (0xbb19, 0xbb33, GraphemeCat::LVT),
+    (0xbb34, 0xbb34, GraphemeCat::LV),
+    (0xbb35, 0xbb4f, GraphemeCat::LVT),
+    (0xbb50, 0xbb50, GraphemeCat::LV),
+    (0xbb51, 0xbb6b, GraphemeCat::LVT),
+    (0xbb6c, 0xbb6c, GraphemeCat::LV),
+    (0xbb6d, 0xbb87, GraphemeCat::LVT),
+    (0xbb88, 0xbb88, GraphemeCat::LV),
+    (0xbb89, 0xbba3, GraphemeCat::LVT),
+    (0xbba4, 0xbba4, GraphemeCat::LV),
+    (0xbba5, 0xbbbf, GraphemeCat::LVT),
+    (0xbbc0, 0xbbc0, GraphemeCat::LV),
+    (0xbbc1, 0xbbdb, GraphemeCat::LVT),
+    (0xbbdc, 0xbbdc, GraphemeCat::LV),
+    (0xbbdd, 0xbbf7, GraphemeCat::LVT),
+    (0xbbf8, 0xbbf8, GraphemeCat::LV),
+    (0xbbf9, 0xbc13, GraphemeCat::LVT),
+    (0xbc14, 0xbc14, GraphemeCat::LV),
+    (0xbc15, 0xbc2f, GraphemeCat::LVT),
+    (0xbc30, 0xbc30, GraphemeCat::LV),
+    (0xbc31, 0xbc4b, GraphemeCat::LVT),
+    (0xbc4c, 0xbc4c, GraphemeCat::LV),
+    (0xbc4d, 0xbc67, GraphemeCat::LVT),
+    (0xbc68, 0xbc68, GraphemeCat::LV),
+    (0xbc69, 0xbc83, GraphemeCat::LVT),
+    (0xbc84, 0xbc84, GraphemeCat::LV),
+    (0xbc85, 0xbc9f, GraphemeCat::LVT),
+    (0xbca0, 0xbca0, GraphemeCat::LV),
+    (0xbca1, 0xbcbb, GraphemeCat::LVT),
+    (0xbcbc, 0xbcbc, GraphemeCat::LV),
+    (0xbcbd, 0xbcd7, GraphemeCat::LVT),
+    (0xbcd8, 0xbcd8, GraphemeCat::LV),
+    (0xbcd9, 0xbcf3, GraphemeCat::LVT),
+    (0xbcf4, 0xbcf4, GraphemeCat::LV),
+    (0xbcf5, 0xbd0f, GraphemeCat::LVT),
+    (0xbd10, 0xbd10, GraphemeCat::LV),
+    (0xbd11, 0xbd2b, GraphemeCat::LVT),
+    (0xbd2c, 0xbd2c, GraphemeCat::LV),
+    (0xbd2d, 0xbd47, GraphemeCat::LVT),
+    (0xbd48, 0xbd48, GraphemeCat::LV),
+    (0xbd49, 0xbd63, GraphemeCat::LVT),
+    (0xbd64, 0xbd64, GraphemeCat::LV),
+    (0xbd65, 0xbd7f, GraphemeCat::LVT),
+    (0xbd80, 0xbd80, GraphemeCat::LV),
+    (0xbd81, 0xbd9b, GraphemeCat::LVT),
+    (0xbd9c, 0xbd9c, GraphemeCat::LV),
+    (0xbd9d, 0xbdb7, GraphemeCat::LVT),
+    (0xbdb8, 0xbdb8, GraphemeCat::LV),
+    (0xbdb9, 0xbdd3, GraphemeCat::LVT),
+    (0xbdd4, 0xbdd4, GraphemeCat::LV),
+    (0xbdd5, 0xbdef, GraphemeCat::LVT),
+    (0xbdf0, 0xbdf0, GraphemeCat::LV),
+    (0xbdf1, 0xbe0b, GraphemeCat::LVT),
+    (0xbe0c, 0xbe0c, GraphemeCat::LV),
+    (0xbe0d, 0xbe27, GraphemeCat::LVT),
+    (0xbe28, 0xbe28, GraphemeCat::LV),
+    (0xbe29, 0xbe43, GraphemeCat::LVT),
+    (0xbe44, 0xbe44, GraphemeCat::LV),
+    (0xbe45, 0xbe5f, GraphemeCat::LVT),
+    (0xbe60, 0xbe60, GraphemeCat::LV),
+    (0xbe61, 0xbe7b, GraphemeCat::LVT),
+    (0xbe7c, 0xbe7c, GraphemeCat::LV),
+    (0xbe7d, 0xbe97, GraphemeCat::LVT),
+    (0xbe98, 0xbe98, GraphemeCat::LV),
+    (0xbe99, 0xbeb3, GraphemeCat::LVT),
+    (0xbeb4, 0xbeb4, GraphemeCat::LV),
+    (0xbeb5, 0xbecf, GraphemeCat::LVT),
+    (0xbed0, 0xbed0, GraphemeCat::LV),
+    (0xbed1, 0xbeeb, GraphemeCat::LVT),
+    (0xbeec, 0xbeec, GraphemeCat::LV),
+    (0xbeed, 0xbf07, GraphemeCat::LVT),
+    (0xbf08, 0xbf08, GraphemeCat::LV),
+    (0xbf09, 0xbf23, GraphemeCat::LVT),
+    (0xbf24, 0xbf24, GraphemeCat::LV),
+    (0xbf25, 0xbf3f, GraphemeCat::LVT),
+    (0xbf40, 0xbf40, GraphemeCat::LV),
+    (0xbf41, 0xbf5b, GraphemeCat::LVT),
+    (0xbf5c, 0xbf5c, GraphemeCat::LV),
+    (0xbf5d, 0xbf77, GraphemeCat::LVT),
+    (0xbf78, 0xbf78, GraphemeCat::LV),
+    (0xbf79, 0xbf93, GraphemeCat::LVT),
+    (0xbf94, 0xbf94, GraphemeCat::LV),
+    (0xbf95, 0xbfaf, GraphemeCat::LVT),
+    (0xbfb0, 0xbfb0, GraphemeCat::LV),
+    (0xbfb1, 0xbfcb, GraphemeCat::LVT),
+    (0xbfcc, 0xbfcc, GraphemeCat::LV),
+    (0xbfcd, 0xbfe7, GraphemeCat::LVT),
+    (0xbfe8, 0xbfe8, GraphemeCat::LV),
+    (0xbfe9, 0xc003, GraphemeCat::LVT),
+    (0xc004, 0xc004, GraphemeCat::LV),
+    (0xc005, 0xc01f, GraphemeCat::LVT),
+    (0xc020, 0xc020, GraphemeCat::LV),
+    (0xc021, 0xc03b, GraphemeCat::LVT),
+    (0xc03c, 0xc03c, GraphemeCat::LV),
+    (0xc03d, 0xc057, GraphemeCat::LVT),
+    (0xc058, 0xc058, GraphemeCat::LV),
+    (0xc059, 0xc073, GraphemeCat::LVT),
+    (0xc074, 0xc074, GraphemeCat::LV),
+    (0xc075, 0xc08f, GraphemeCat::LVT),
+    (0xc090, 0xc090, GraphemeCat::LV),
+    (0xc091, 0xc0ab, GraphemeCat::LVT),
+    (0xc0ac, 0xc0ac, GraphemeCat::LV),
+    (0xc0ad, 0xc0c7, GraphemeCat::LVT),
+    (0xc0c8, 0xc0c8, GraphemeCat::LV),
+    (0xc0c9, 0xc0e3, GraphemeCat::LVT),
+    (0xc0e4, 0xc0e4, GraphemeCat::LV),
+    (0xc0e5, 0xc0ff, GraphemeCat::LVT),
+    (0xc100, 0xc100, GraphemeCat::LV),
+    (0xc101, 0xc11b, GraphemeCat::LVT),
+    (0xc11c, 0xc11c, GraphemeCat::LV),
+    (0xc11d, 0xc137, GraphemeCat::LVT),
+    (0xc138, 0xc138, GraphemeCat::LV),
+    (0xc139, 0xc153, GraphemeCat::LVT),
+    (0xc154, 0xc154, GraphemeCat::LV),
+    (0xc155, 0xc16f, GraphemeCat::LVT),
+    (0xc170, 0xc170, GraphemeCat::LV),
+    (0xc171, 0xc18b, GraphemeCat::LVT),
+    (0xc18c, 0xc18c, GraphemeCat::LV),
+    (0xc18d, 0xc1a7, GraphemeCat::LVT),
+    (0xc1a8, 0xc1a8, GraphemeCat::LV),
+    (0xc1a9, 0xc1c3, GraphemeCat::LVT),
+    (0xc1c4, 0xc1c4, GraphemeCat::LV),
+    (0xc1c5, 0xc1df, GraphemeCat::LVT),
+    (0xc1e0, 0xc1e0, GraphemeCat::LV),
+    (0xc1e1, 0xc1fb, GraphemeCat::LVT),
+    (0xc1fc, 0xc1fc, GraphemeCat::LV),
+    (0xc1fd, 0xc217, GraphemeCat::LVT),
+    (0xc218, 0xc218, GraphemeCat::LV),
+    (0xc219, 0xc233, GraphemeCat::LVT),
+    (0xc234, 0xc234, GraphemeCat::LV),
+    (0xc235, 0xc24f, GraphemeCat::LVT),
+    (0xc250, 0xc250, GraphemeCat::LV),
+    (0xc251, 0xc26b, GraphemeCat::LVT),
+    (0xc26c, 0xc26c, GraphemeCat::LV),
+    (0xc26d, 0xc287, GraphemeCat::LVT),
+    (0xc288, 0xc288, GraphemeCat::LV),
+    (0xc289, 0xc2a3, GraphemeCat::LVT),
+    (0xc2a4, 0xc2a4, GraphemeCat::LV),
+    (0xc2a5, 0xc2bf, GraphemeCat::LVT),
+    (0xc2c0, 0xc2c0, GraphemeCat::LV),
+    (0xc2c1, 0xc2db, GraphemeCat::LVT),
+    (0xc2dc, 0xc2dc, GraphemeCat::LV),
+    (0xc2dd, 0xc2f7, GraphemeCat::LVT),
+    (0xc2f8, 0xc2f8, GraphemeCat::LV),
+    (0xc2f9, 0xc313, GraphemeCat::LVT),
+    (0xc314, 0xc314, GraphemeCat::LV),
+    (0xc315, 0xc32f, GraphemeCat::LVT),
+    (0xc330, 0xc330, GraphemeCat::LV),
+    (0xc331, 0xc34b, GraphemeCat::LVT),
+    (0xc34c, 0xc34c, GraphemeCat::LV),
+    (0xc34d, 0xc367, GraphemeCat::LVT),
+    (0xc368, 0xc368, GraphemeCat::LV),
+    (0xc369, 0xc383, GraphemeCat::LVT),
+    (0xc384, 0xc384, GraphemeCat::LV),
+    (0xc385, 0xc39f, GraphemeCat::LVT),
+    (0xc3a0, 0xc3a0, GraphemeCat::LV),
+    (0xc3a1, 0xc3bb, GraphemeCat::LVT),
+    (0xc3bc, 0xc3bc, GraphemeCat::LV),
+    (0xc3bd, 0xc3d7, GraphemeCat::LVT),
+    (0xc3d8, 0xc3d8, GraphemeCat::LV),
+    (0xc3d9, 0xc3f3, GraphemeCat::LVT),
+    (0xc3f4, 0xc3f4, GraphemeCat::LV),
+    (0xc3f5, 0xc40f, GraphemeCat::LVT),
+    (0xc410, 0xc410, GraphemeCat::LV),
+    (0xc411, 0xc42b, GraphemeCat::LVT),
+    (0xc42c, 0xc42c, GraphemeCat::LV),
+    (0xc42d, 0xc447, GraphemeCat::LVT),
+    (0xc448, 0xc448, GraphemeCat::LV),
+    (0xc449, 0xc463, GraphemeCat::LVT),
+    (0xc464, 0xc464, GraphemeCat::LV),
+    (0xc465, 0xc47f, GraphemeCat::LVT),
+    (0xc480, 0xc480, GraphemeCat::LV),
+    (0xc481, 0xc49b, GraphemeCat::LVT),
+    (0xc49c, 0xc49c, GraphemeCat::LV),
+    (0xc49d, 0xc4b7, GraphemeCat::LVT),
+    (0xc4b8, 0xc4b8, GraphemeCat::LV),
+    (0xc4b9, 0xc4d3, GraphemeCat::LVT),
+    (0xc4d4, 0xc4d4, GraphemeCat::LV),
+    (0xc4d5, 0xc4ef, GraphemeCat::LVT),
+    (0xc4f0, 0xc4f0, GraphemeCat::LV),
+    (0xc4f1, 0xc50b, GraphemeCat::LVT),
+    (0xc50c, 0xc50c, GraphemeCat::LV),
+    (0xc50d, 0xc527, GraphemeCat::LVT),
+    (0xc528, 0xc528, GraphemeCat::LV),
+    (0xc529, 0xc543, GraphemeCat::LVT),
+    (0xc544, 0xc544, GraphemeCat::LV),
+    (0xc545, 0xc55f, GraphemeCat::LVT),
+    (0xc560, 0xc560, GraphemeCat::LV),
+    (0xc561, 0xc57b, GraphemeCat::LVT),
+    (0xc57c, 0xc57c, GraphemeCat::LV),
+    (0xc57d, 0xc597, GraphemeCat::LVT),
+    (0xc598, 0xc598, GraphemeCat::LV),
+    (0xc599, 0xc5b3, GraphemeCat::LVT),
+    (0xc5b4, 0xc5b4, GraphemeCat::LV),
+    (0xc5b5, 0xc5cf, GraphemeCat::LVT),
+    (0xc5d0, 0xc5d0, GraphemeCat::LV),
+    (0xc5d1, 0xc5eb, GraphemeCat::LVT),
+    (0xc5ec, 0xc5ec, GraphemeCat::LV),
+    (0xc5ed, 0xc607, GraphemeCat::LVT),
+    (0xc608, 0xc608, GraphemeCat::LV),
+    (0xc609, 0xc623, GraphemeCat::LVT),
+    (0xc624, 0xc624, GraphemeCat::LV),
+    (0xc625, 0xc63f, GraphemeCat::LVT),
+    (0xc640, 0xc640, GraphemeCat::LV),
+    (0xc641, 0xc65b, GraphemeCat::LVT),
+    (0xc65c, 0xc65c, GraphemeCat::LV),
+    (0xc65d, 0xc677, GraphemeCat::LVT),
+    (0xc678, 0xc678, GraphemeCat::LV),
+    (0xc679, 0xc693, GraphemeCat::LVT),
+    (0xc694, 0xc694, GraphemeCat::LV),
+    (0xc695, 0xc6af, GraphemeCat::LVT),
+    (0xc6b0, 0xc6b0, GraphemeCat::LV),
+    (0xc6b1, 0xc6cb, GraphemeCat::LVT),
+    (0xc6cc, 0xc6cc, GraphemeCat::LV),
+    (0xc6cd, 0xc6e7, GraphemeCat::LVT),
+    (0xc6e8, 0xc6e8, GraphemeCat::LV),
+    (0xc6e9, 0xc703, GraphemeCat::LVT),
+    (0xc704, 0xc704, GraphemeCat::LV),
+    (0xc705, 0xc71f, GraphemeCat::LVT),
+    (0xc720, 0xc720, GraphemeCat::LV),
+    (0xc721, 0xc73b, GraphemeCat::LVT),
+    (0xc73c, 0xc73c, GraphemeCat::LV),
+    (0xc73d, 0xc757, GraphemeCat::LVT),
+    (0xc758, 0xc758, GraphemeCat::LV),
+    (0xc759, 0xc773, GraphemeCat::LVT),
+    (0xc774, 0xc774, GraphemeCat::LV),
+    (0xc775, 0xc78f, GraphemeCat::LVT),
+    (0xc790, 0xc790, GraphemeCat::LV),
+    (0xc791, 0xc7ab, GraphemeCat::LVT),
+    (0xc7ac, 0xc7ac, GraphemeCat::LV),
+    (0xc7ad, 0xc7c7, GraphemeCat::LVT),
+    (0xc7c8, 0xc7c8, GraphemeCat::LV),
+    (0xc7c9, 0xc7e3, GraphemeCat::LVT),
+    (0xc7e4, 0xc7e4, GraphemeCat::LV),
+    (0xc7e5, 0xc7ff, GraphemeCat::LVT),
+    (0xc800, 0xc800, GraphemeCat::LV),
+    (0xc801, 0xc81b, GraphemeCat::LVT),
+    (0xc81c, 0xc81c, GraphemeCat::LV),
+    (0xc81d, 0xc837, GraphemeCat::LVT),
+    (0xc838, 0xc838, GraphemeCat::LV),
+    (0xc839, 0xc853, GraphemeCat::LVT),
+    (0xc854, 0xc854, GraphemeCat::LV),
+    (0xc855, 0xc86f, GraphemeCat::LVT),
+    (0xc870, 0xc870, GraphemeCat::LV),
+    (0xc871, 0xc88b, GraphemeCat::LVT),
+    (0xc88c, 0xc88c, GraphemeCat::LV),
+    (0xc88d, 0xc8a7, GraphemeCat::LVT),
+    (0xc8a8, 0xc8a8, GraphemeCat::LV),
+    (0xc8a9, 0xc8c3, GraphemeCat::LVT),
+    (0xc8c4, 0xc8c4, GraphemeCat::LV),
+    (0xc8c5, 0xc8df, GraphemeCat::LVT),
+    (0xc8e0, 0xc8e0, GraphemeCat::LV),
+    (0xc8e1, 0xc8fb, GraphemeCat::LVT),
+    (0xc8fc, 0xc8fc, GraphemeCat::LV),
+    (0xc8fd, 0xc917, GraphemeCat::LVT),
+    (0xc918, 0xc918, GraphemeCat::LV),
+    (0xc919, 0xc933, GraphemeCat::LVT),
+    (0xc934, 0xc934, GraphemeCat::LV),
+    (0xc935, 0xc94f, GraphemeCat::LVT),
+    (0xc950, 0xc950, GraphemeCat::LV),
+    (0xc951, 0xc96b, GraphemeCat::LVT),
+    (0xc96c, 0xc96c, GraphemeCat::LV),
+    (0xc96d, 0xc987, GraphemeCat::LVT),
+    (0xc988, 0xc988, GraphemeCat::LV),
+    (0xc989, 0xc9a3, GraphemeCat::LVT),
+    (0xc9a4, 0xc9a4, GraphemeCat::LV),
+    (0xc9a5, 0xc9bf, GraphemeCat::LVT),
+    (0xc9c0, 0xc9c0, GraphemeCat::LV),
+    (0xc9c1, 0xc9db, GraphemeCat::LVT),
+    (0xc9dc, 0xc9dc, GraphemeCat::LV),
+    (0xc9dd, 0xc9f7, GraphemeCat::LVT),
+    (0xc9f8, 0xc9f8, GraphemeCat::LV),
+    (0xc9f9, 0xca13, GraphemeCat::LVT),
+    (0xca14, 0xca14, GraphemeCat::LV),
+    (0xca15, 0xca2f, GraphemeCat::LVT),
+    (0xca30, 0xca30, GraphemeCat::LV),
+    (0xca31, 0xca4b, GraphemeCat::LVT),
+    (0xca4c, 0xca4c, GraphemeCat::LV),
+    (0xca4d, 0xca67, GraphemeCat::LVT),
+    (0xca68, 0xca68, GraphemeCat::LV),
+    (0xca69, 0xca83, GraphemeCat::LVT),
+    (0xca84, 0xca84, GraphemeCat::LV),
+    (0xca85, 0xca9f, GraphemeCat::LVT),
+    (0xcaa0, 0xcaa0, GraphemeCat::LV),
+    (0xcaa1, 0xcabb, GraphemeCat::LVT),
+    (0xcabc, 0xcabc, GraphemeCat::LV),
+    (0xcabd, 0xcad7, GraphemeCat::LVT),
+    (0xcad8, 0xcad8, GraphemeCat::LV),
+    (0xcad9, 0xcaf3, GraphemeCat::LVT),
+    (0xcaf4, 0xcaf4, GraphemeCat::LV),
+    (0xcaf5, 0xcb0f, GraphemeCat::LVT),
+    (0xcb10, 0xcb10, GraphemeCat::LV),
+    (0xcb11, 0xcb2b, GraphemeCat::LVT),
+    (0xcb2c, 0xcb2c, GraphemeCat::LV),
+    (0xcb2d, 0xcb47, GraphemeCat::LVT),
+    (0xcb48, 0xcb48, GraphemeCat::LV),
+    (0xcb49, 0xcb63, GraphemeCat::LVT),
+    (0xcb64, 0xcb64, GraphemeCat::LV),
+    (0xcb65, 0xcb7f, GraphemeCat::LVT),
+    (0xcb80, 0xcb80, GraphemeCat::LV),
+    (0xcb81, 0xcb9b, GraphemeCat::LVT),
+    (0xcb9c, 0xcb9c, GraphemeCat::LV),
+    (0xcb9d, 0xcbb7, GraphemeCat::LVT),
+    (0xcbb8, 0xcbb8, GraphemeCat::LV),
+    (0xcbb9, 0xcbd3, GraphemeCat::LVT),
+    (0xcbd4, 0xcbd4, GraphemeCat::LV),
+    (0xcbd5, 0xcbef, GraphemeCat::LVT),
+    (0xcbf0, 0xcbf0, GraphemeCat::LV),
+    (0xcbf1, 0xcc0b, GraphemeCat::LVT),
+    (0xcc0c, 0xcc0c, GraphemeCat::LV),
+    (0xcc0d, 0xcc27, GraphemeCat::LVT),
+    (0xcc28, 0xcc28, GraphemeCat::LV),
+    (0xcc29, 0xcc43, GraphemeCat::LVT),
+    (0xcc44, 0xcc44, GraphemeCat::LV),
+    (0xcc45, 0xcc5f, GraphemeCat::LVT),
+    (0xcc60, 0xcc60, GraphemeCat::LV),
+    (0xcc61, 0xcc7b, GraphemeCat::LVT),
+    (0xcc7c, 0xcc7c, GraphemeCat::LV),
+    (0xcc7d, 0xcc97, GraphemeCat::LVT),
+    (0xcc98, 0xcc98, GraphemeCat::LV),
+    (0xcc99, 0xccb3, GraphemeCat::LVT),
+    (0xccb4, 0xccb4, GraphemeCat::LV),
+    (0xccb5, 0xcccf, GraphemeCat::LVT),
+    (0xccd0, 0xccd0, GraphemeCat::LV),
+    (0xccd1, 0xcceb, GraphemeCat::LVT),
+    (0xccec, 0xccec, GraphemeCat::LV),
+    (0xcced, 0xcd07, GraphemeCat::LVT),
+    (0xcd08, 0xcd08, GraphemeCat::LV),
+    (0xcd09, 0xcd23, GraphemeCat::LVT),
+    (0xcd24, 0xcd24, GraphemeCat::LV),
+    (0xcd25, 0xcd3f, GraphemeCat::LVT),
+    (0xcd40, 0xcd40, GraphemeCat::LV),
+    (0xcd41, 0xcd5b, GraphemeCat::LVT),
+    (0xcd5c, 0xcd5c, GraphemeCat::LV),
+    (0xcd5d, 0xcd77, GraphemeCat::LVT),
+    (0xcd78, 0xcd78, GraphemeCat::LV),
+    (0xcd79, 0xcd93, GraphemeCat::LVT),
+    (0xcd94, 0xcd94, GraphemeCat::LV),
+    (0xcd95, 0xcdaf, GraphemeCat::LVT),
+    (0xcdb0, 0xcdb0, GraphemeCat::LV),
+    (0xcdb1, 0xcdcb, GraphemeCat::LVT),
+    (0xcdcc, 0xcdcc, GraphemeCat::LV),
+    (0xcdcd, 0xcde7, GraphemeCat::LVT),
+    (0xcde8, 0xcde8, GraphemeCat::LV),
+    (0xcde9, 0xce03, GraphemeCat::LVT),
+    (0xce04, 0xce04, GraphemeCat::LV),
+    (0xce05, 0xce1f, GraphemeCat::LVT),
+    (0xce20, 0xce20, GraphemeCat::LV),
+    (0xce21, 0xce3b, GraphemeCat::LVT),
+    (0xce3c, 0xce3c, GraphemeCat::LV),
+    (0xce3d, 0xce57, GraphemeCat::LVT),
+    (0xce58, 0xce58, GraphemeCat::LV),
+    (0xce59, 0xce73, GraphemeCat::LVT),
+    (0xce74, 0xce74, GraphemeCat::LV),
+    (0xce75, 0xce8f, GraphemeCat::LVT),
+    (0xce90, 0xce90, GraphemeCat::LV),
+    (0xce91, 0xceab, GraphemeCat::LVT),
+    (0xceac, 0xceac, GraphemeCat::LV),
+    (0xcead, 0xcec7, GraphemeCat::LVT),
+    (0xcec8, 0xcec8, GraphemeCat::LV),
+    (0xcec9, 0xcee3, GraphemeCat::LVT),
+    (0xcee4, 0xcee4, GraphemeCat::LV),
+    (0xcee5, 0xceff, GraphemeCat::LVT),
+    (0xcf00, 0xcf00, GraphemeCat::LV),
+    (0xcf01, 0xcf1b, GraphemeCat::LVT),
+    (0xcf1c, 0xcf1c, GraphemeCat::LV),
+    (0xcf1d, 0xcf37, GraphemeCat::LVT),
+    (0xcf38, 0xcf38, GraphemeCat::LV),
+    (0xcf39, 0xcf53, GraphemeCat::LVT),
+    (0xcf54, 0xcf54, GraphemeCat::LV),
+    (0xcf55, 0xcf6f, GraphemeCat::LVT),
+    (0xcf70, 0xcf70, GraphemeCat::LV),
+    (0xcf71, 0xcf8b, GraphemeCat::LVT),
+    (0xcf8c, 0xcf8c, GraphemeCat::LV),
+    (0xcf8d, 0xcfa7, GraphemeCat::LVT),
+    (0xcfa8, 0xcfa8, GraphemeCat::LV),
+    (0xcfa9, 0xcfc3, GraphemeCat::LVT),
+    (0xcfc4, 0xcfc4, GraphemeCat::LV),
+    (0xcfc5, 0xcfdf, GraphemeCat::LVT),
+    (0xcfe0, 0xcfe0, GraphemeCat::LV),
+    (0xcfe1, 0xcffb, GraphemeCat::LVT),
+    (0xcffc, 0xcffc, GraphemeCat::LV),
+    (0xcffd, 0xd017, GraphemeCat::LVT),
+    (0xd018, 0xd018, GraphemeCat::LV),
+    (0xd019, 0xd033, GraphemeCat::LVT),
+    (0xd034, 0xd034, GraphemeCat::LV),
+    (0xd035, 0xd04f, GraphemeCat::LVT),
+    (0xd050, 0xd050, GraphemeCat::LV),
+    (0xd051, 0xd06b, GraphemeCat::LVT),
+    (0xd06c, 0xd06c, GraphemeCat::LV),
+    (0xd06d, 0xd087, GraphemeCat::LVT),
+    (0xd088, 0xd088, GraphemeCat::LV),
+    (0xd089, 0xd0a3, GraphemeCat::LVT),
+    (0xd0a4, 0xd0a4, GraphemeCat::LV),
+    (0xd0a5, 0xd0bf, GraphemeCat::LVT),
+    (0xd0c0, 0xd0c0, GraphemeCat::LV),
+    (0xd0c1, 0xd0db, GraphemeCat::LVT),
+    (0xd0dc, 0xd0dc, GraphemeCat::LV),
+    (0xd0dd, 0xd0f7, GraphemeCat::LVT),
+    (0xd0f8, 0xd0f8, GraphemeCat::LV),
+    (0xd0f9, 0xd113, GraphemeCat::LVT),
+    (0xd114, 0xd114, GraphemeCat::LV),
+    (0xd115, 0xd12f, GraphemeCat::LVT),
+    (0xd130, 0xd130, GraphemeCat::LV),
+    (0xd131, 0xd14b, GraphemeCat::LVT),
+    (0xd14c, 0xd14c, GraphemeCat::LV),
+    (0xd14d, 0xd167, GraphemeCat::LVT),
+    (0xd168, 0xd168, GraphemeCat::LV),
+    (0xd169, 0xd183, GraphemeCat::LVT),
+    (0xd184, 0xd184, GraphemeCat::LV),
+    (0xd185, 0xd19f, GraphemeCat::LVT),
+    (0xd1a0, 0xd1a0, GraphemeCat::LV),
+    (0xd1a1, 0xd1bb, GraphemeCat::LVT),
+    (0xd1bc, 0xd1bc, GraphemeCat::LV),
+    (0xd1bd, 0xd1d7, GraphemeCat::LVT),
+    (0xd1d8, 0xd1d8, GraphemeCat::LV),
+    (0xd1d9, 0xd1f3, GraphemeCat::LVT),
+    (0xd1f4, 0xd1f4, GraphemeCat::LV),
+    (0xd1f5, 0xd20f, GraphemeCat::LVT),
+    (0xd210, 0xd210, GraphemeCat::LV),
+    (0xd211, 0xd22b, GraphemeCat::LVT),
+    (0xd22c, 0xd22c, GraphemeCat::LV),
+    (0xd22d, 0xd247, GraphemeCat::LVT),
+    (0xd248, 0xd248, GraphemeCat::LV),
+    (0xd249, 0xd263, GraphemeCat::LVT),
+    (0xd264, 0xd264, GraphemeCat::LV),
+    (0xd265, 0xd27f, GraphemeCat::LVT),
+    (0xd280, 0xd280, GraphemeCat::LV),
+    (0xd281, 0xd29b, GraphemeCat::LVT),
+    (0xd29c, 0xd29c, GraphemeCat::LV),
+    (0xd29d, 0xd2b7, GraphemeCat::LVT),
+    (0xd2b8, 0xd2b8, GraphemeCat::LV),
+    (0xd2b9, 0xd2d3, GraphemeCat::LVT),
+    (0xd2d4, 0xd2d4, GraphemeCat::LV),
+    (0xd2d5, 0xd2ef, GraphemeCat::LVT),
+    (0xd2f0, 0xd2f0, GraphemeCat::LV),
+    (0xd2f1, 0xd30b, GraphemeCat::LVT),
+    (0xd30c, 0xd30c, GraphemeCat::LV),
+    (0xd30d, 0xd327, GraphemeCat::LVT),
+    (0xd328, 0xd328, GraphemeCat::LV),
+    (0xd329, 0xd343, GraphemeCat::LVT),
+    (0xd344, 0xd344, GraphemeCat::LV),
+    (0xd345, 0xd35f, GraphemeCat::LVT),
+    (0xd360, 0xd360, GraphemeCat::LV),
+    (0xd361, 0xd37b, GraphemeCat::LVT),
+    (0xd37c, 0xd37c, GraphemeCat::LV),
+    (0xd37d, 0xd397, GraphemeCat::LVT),
+    (0xd398, 0xd398, GraphemeCat::LV),
+    (0xd399, 0xd3b3, GraphemeCat::LVT),
+    (0xd3b4, 0xd3b4, GraphemeCat::LV),
+    (0xd3b5, 0xd3cf, GraphemeCat::LVT),
+    (0xd3d0, 0xd3d0, GraphemeCat::LV),
+    (0xd3d1, 0xd3eb, GraphemeCat::LVT),
+    (0xd3ec, 0xd3ec, GraphemeCat::LV),
+    (0xd3ed, 0xd407, GraphemeCat::LVT),
+    (0xd408, 0xd408, GraphemeCat::LV),
+    (0xd409, 0xd423, GraphemeCat::LVT),
+    (0xd424, 0xd424, GraphemeCat::LV),
+    (0xd425, 0xd43f, GraphemeCat::LVT),
+    (0xd440, 0xd440, GraphemeCat::LV),
+    (0xd441, 0xd45b, GraphemeCat::LVT),
+    (0xd45c, 0xd45c, GraphemeCat::LV),
+    (0xd45d, 0xd477, GraphemeCat::LVT),
+    (0xd478, 0xd478, GraphemeCat::LV),
+    (0xd479, 0xd493, GraphemeCat::LVT),
+    (0xd494, 0xd494, GraphemeCat::LV),
+    (0xd495, 0xd4af, GraphemeCat::LVT),
+    (0xd4b0, 0xd4b0, GraphemeCat::LV),
+    (0xd4b1, 0xd4cb, GraphemeCat::LVT),
+    (0xd4cc, 0xd4cc, GraphemeCat::LV),
+    (0xd4cd, 0xd4e7, GraphemeCat::LVT),
+    (0xd4e8, 0xd4e8, GraphemeCat::LV),
+    (0xd4e9, 0xd503, GraphemeCat::LVT),
+    (0xd504, 0xd504, GraphemeCat::LV),
+    (0xd505, 0xd51f, GraphemeCat::LVT),
+    (0xd520, 0xd520, GraphemeCat::LV),
+    (0xd521, 0xd53b, GraphemeCat::LVT),
+    (0xd53c, 0xd53c, GraphemeCat::LV),
+    (0xd53d, 0xd557, GraphemeCat::LVT),
+    (0xd558, 0xd558, GraphemeCat::LV),
+    (0xd559, 0xd573, GraphemeCat::LVT),
+    (0xd574, 0xd574, GraphemeCat::LV),
+    (0xd575, 0xd58f, GraphemeCat::LVT),
+    (0xd590, 0xd590, GraphemeCat::LV),
+    (0xd591, 0xd5ab, GraphemeCat::LVT),
+    (0xd5ac, 0xd5ac, GraphemeCat::LV),
+    (0xd5ad, 0xd5c7, GraphemeCat::LVT),
+    (0xd5c8, 0xd5c8, GraphemeCat::LV),
+    (0xd5c9, 0xd5e3, GraphemeCat::LVT),
+    (0xd5e4, 0xd5e4, GraphemeCat::LV),
+    (0xd5e5, 0xd5ff, GraphemeCat::LVT),
+    (0xd600, 0xd600, GraphemeCat::LV),
+    (0xd601, 0xd61b, GraphemeCat::LVT),
+    (0xd61c, 0xd61c, GraphemeCat::LV),
+    (0xd61d, 0xd637, GraphemeCat::LVT),
+    (0xd638, 0xd638, GraphemeCat::LV),
+    (0xd639, 0xd653, GraphemeCat::LVT),
+    (0xd654, 0xd654, GraphemeCat::LV),
+    (0xd655, 0xd66f, GraphemeCat::LVT),
+    (0xd670, 0xd670, GraphemeCat::LV),
+    (0xd671, 0xd68b, GraphemeCat::LVT),
+    (0xd68c, 0xd68c, GraphemeCat::LV),
+    (0xd68d, 0xd6a7, GraphemeCat::LVT),
+    (0xd6a8, 0xd6a8, GraphemeCat::LV),
+    (0xd6a9, 0xd6c3, GraphemeCat::LVT),
+    (0xd6c4, 0xd6c4, GraphemeCat::LV),
+    (0xd6c5, 0xd6df, GraphemeCat::LVT),
+    (0xd6e0, 0xd6e0, GraphemeCat::LV),
+    (0xd6e1, 0xd6fb, GraphemeCat::LVT),
+    (0xd6fc, 0xd6fc, GraphemeCat::LV),
+    (0xd6fd, 0xd717, GraphemeCat::LVT),
+    (0xd718, 0xd718, GraphemeCat::LV),
+    (0xd719, 0xd733, GraphemeCat::LVT),
+    (0xd734, 0xd734, GraphemeCat::LV),
+    (0xd735, 0xd74f, GraphemeCat::LVT),
+    (0xd750, 0xd750, GraphemeCat::LV),
+    (0xd751, 0xd76b, GraphemeCat::LVT),
+    (0xd76c, 0xd76c, GraphemeCat::LV),
+    (0xd76d, 0xd787, GraphemeCat::LVT),
+    (0xd788, 0xd788, GraphemeCat::LV),
+    (0xd789, 0xd7a3, GraphemeCat::LVT),
+    (0xd7b0, 0xd7c6, GraphemeCat::V),
+    (0xd7cb, 0xd7fb, GraphemeCat::T),
+    (0xfb1e, 0xfb1e, GraphemeCat::InCBExtend),
+    (0xfe00, 0xfe0f, GraphemeCat::InCBExtend),
+    (0xfe20, 0xfe2f, GraphemeCat::InCBExtend),
+    (0xfeff, 0xfeff, GraphemeCat::Control),
+    (0xff9e, 0xff9f, GraphemeCat::InCBExtend),
+    (0xfff0, 0xfffb, GraphemeCat::Control),
+    (0x101fd, 0x101fd, GraphemeCat::InCBExtend),
+    (0x102e0, 0x102e0, GraphemeCat::InCBExtend),
+    (0x10376, 0x1037a, GraphemeCat::InCBExtend),
+    (0x10a00, 0x10a00, GraphemeCat::InCBConsonant),
+    (0x10a01, 0x10a03, GraphemeCat::InCBExtend),
+    (0x10a05, 0x10a06, GraphemeCat::InCBExtend),
+    (0x10a0c, 0x10a0f, GraphemeCat::InCBExtend),
+    (0x10a10, 0x10a13, GraphemeCat::InCBConsonant),
+    (0x10a15, 0x10a17, GraphemeCat::InCBConsonant),
+    (0x10a19, 0x10a35, GraphemeCat::InCBConsonant),
+    (0x10a38, 0x10a3a, GraphemeCat::InCBExtend),
+    (0x10a3f, 0x10a3f, GraphemeCat::InCBLinker),
+    (0x10ae5, 0x10ae6, GraphemeCat::InCBExtend),
+    (0x10d24, 0x10d27, GraphemeCat::InCBExtend),
+    (0x10d69, 0x10d6d, GraphemeCat::InCBExtend),
+    (0x10eab, 0x10eac, GraphemeCat::InCBExtend),
+    (0x10efa, 0x10eff, GraphemeCat::InCBExtend),
+    (0x10f46, 0x10f50, GraphemeCat::InCBExtend),
+    (0x10f82, 0x10f85, GraphemeCat::InCBExtend),
+    (0x11000, 0x11000, GraphemeCat::SpacingMark),
+    (0x11001, 0x11001, GraphemeCat::InCBExtend),
+    (0x11002, 0x11002, GraphemeCat::SpacingMark),
+    (0x11038, 0x11046, GraphemeCat::InCBExtend),
+    (0x11070, 0x11070, GraphemeCat::InCBExtend),
+    (0x11073, 0x11074, GraphemeCat::InCBExtend),
+    (0x1107f, 0x11081, GraphemeCat::InCBExtend),
+    (0x11082, 0x11082, GraphemeCat::SpacingMark),
+    (0x110b0, 0x110b2, GraphemeCat::SpacingMark),
+    (0x110b3, 0x110b6, GraphemeCat::InCBExtend),
+    (0x110b7, 0x110b8, GraphemeCat::SpacingMark),
+    (0x110b9, 0x110ba, GraphemeCat::InCBExtend),
+    (0x110bd, 0x110bd, GraphemeCat::Prepend),
+    (0x110c2, 0x110c2, GraphemeCat::InCBExtend),
+    (0x110cd, 0x110cd, GraphemeCat::Prepend),
+    (0x11100, 0x11102, GraphemeCat::InCBExtend),
+    (0x11103, 0x11126, GraphemeCat::InCBConsonant),
+    (0x11127, 0x1112b, GraphemeCat::InCBExtend),
+    (0x1112c, 0x1112c, GraphemeCat::SpacingMark),
+    (0x1112d, 0x11132, GraphemeCat::InCBExtend),
+    (0x11133, 0x11133, GraphemeCat::InCBLinker),
+    (0x11134, 0x11134, GraphemeCat::InCBExtend),
+    (0x11144, 0x11144, GraphemeCat::InCBConsonant),
+    (0x11145, 0x11146, GraphemeCat::SpacingMark),
+    (0x11147, 0x11147, GraphemeCat::InCBConsonant),
+    (0x11173, 0x11173, GraphemeCat::InCBExtend),
+    (0x11180, 0x11181, GraphemeCat::InCBExtend),
+    (0x11182, 0x11182, GraphemeCat::SpacingMark),
+    (0x111b3, 0x111b5, GraphemeCat::SpacingMark),
+    (0x111b6, 0x111be, GraphemeCat::InCBExtend),
+    (0x111bf, 0x111bf, GraphemeCat::SpacingMark),
+    (0x111c0, 0x111c0, GraphemeCat::InCBExtend),
+    (0x111c2, 0x111c3, GraphemeCat::Prepend),
+    (0x111c9, 0x111cc, GraphemeCat::InCBExtend),
+    (0x111ce, 0x111ce, GraphemeCat::SpacingMark),
+    (0x111cf, 0x111cf, GraphemeCat::InCBExtend),
+    (0x1122c, 0x1122e, GraphemeCat::SpacingMark),
+    (0x1122f, 0x11231, GraphemeCat::InCBExtend),
+    (0x11232, 0x11233, GraphemeCat::SpacingMark),
+    (0x11234, 0x11237, GraphemeCat::InCBExtend),
+    (0x1123e, 0x1123e, GraphemeCat::InCBExtend),
+    (0x11241, 0x11241, GraphemeCat::InCBExtend),
+    (0x112df, 0x112df, GraphemeCat::InCBExtend),
+    (0x112e0, 0x112e2, GraphemeCat::SpacingMark),
+    (0x112e3, 0x112ea, GraphemeCat::InCBExtend),
+    (0x11300, 0x11301, GraphemeCat::InCBExtend),
+    (0x11302, 0x11303, GraphemeCat::SpacingMark),
+    (0x1133b, 0x1133c, GraphemeCat::InCBExtend),
+    (0x1133e, 0x1133e, GraphemeCat::InCBExtend),
+    (0x1133f, 0x1133f, GraphemeCat::SpacingMark),
+    (0x11340, 0x11340, GraphemeCat::InCBExtend),
+    (0x11341, 0x11344, GraphemeCat::SpacingMark),
+    (0x11347, 0x11348, GraphemeCat::SpacingMark),
+    (0x1134b, 0x1134c, GraphemeCat::SpacingMark),
+    (0x1134d, 0x1134d, GraphemeCat::InCBExtend),
+    (0x11357, 0x11357, GraphemeCat::InCBExtend),
+    (0x11362, 0x11363, GraphemeCat::SpacingMark),
+    (0x11366, 0x1136c, GraphemeCat::InCBExtend),
+    (0x11370, 0x11374, GraphemeCat::InCBExtend),
+    (0x11380, 0x11389, GraphemeCat::InCBConsonant),
+    (0x1138b, 0x1138b, GraphemeCat::InCBConsonant),
+    (0x1138e, 0x1138e, GraphemeCat::InCBConsonant),
+    (0x11390, 0x113b5, GraphemeCat::InCBConsonant),
+    (0x113b8, 0x113b8, GraphemeCat::InCBExtend),
+    (0x113b9, 0x113ba, GraphemeCat::SpacingMark),
+    (0x113bb, 0x113c0, GraphemeCat::InCBExtend),
+    (0x113c2, 0x113c2, GraphemeCat::InCBExtend),
+    (0x113c5, 0x113c5, GraphemeCat::InCBExtend),
+    (0x113c7, 0x113c9, GraphemeCat::InCBExtend),
+    (0x113ca, 0x113ca, GraphemeCat::SpacingMark),
+    (0x113cc, 0x113cd, GraphemeCat::SpacingMark),
+    (0x113ce, 0x113cf, GraphemeCat::InCBExtend),
+    (0x113d0, 0x113d0, GraphemeCat::InCBLinker),
+    (0x113d1, 0x113d1, GraphemeCat::Prepend),
+    (0x113d2, 0x113d2, GraphemeCat::InCBExtend),
+    (0x113e1, 0x113e2, GraphemeCat::InCBExtend),
+    (0x11435, 0x11437, GraphemeCat::SpacingMark),
+    (0x11438, 0x1143f, GraphemeCat::InCBExtend),
+    (0x11440, 0x11441, GraphemeCat::SpacingMark),
+    (0x11442, 0x11444, GraphemeCat::InCBExtend),
+    (0x11445, 0x11445, GraphemeCat::SpacingMark),
+    (0x11446, 0x11446, GraphemeCat::InCBExtend),
+    (0x1145e, 0x1145e, GraphemeCat::InCBExtend),
+    (0x114b0, 0x114b0, GraphemeCat::InCBExtend),
+    (0x114b1, 0x114b2, GraphemeCat::SpacingMark),
+    (0x114b3, 0x114b8, GraphemeCat::InCBExtend),
+    (0x114b9, 0x114b9, GraphemeCat::SpacingMark),
+    (0x114ba, 0x114ba, GraphemeCat::InCBExtend),
+    (0x114bb, 0x114bc, GraphemeCat::SpacingMark),
+    (0x114bd, 0x114bd, GraphemeCat::InCBExtend),
+    (0x114be, 0x114be, GraphemeCat::SpacingMark),
+    (0x114bf, 0x114c0, GraphemeCat::InCBExtend),
+    (0x114c1, 0x114c1, GraphemeCat::SpacingMark),
+    (0x114c2, 0x114c3, GraphemeCat::InCBExtend),
+    (0x115af, 0x115af, GraphemeCat::InCBExtend),
+    (0x115b0, 0x115b1, GraphemeCat::SpacingMark),
+    (0x115b2, 0x115b5, GraphemeCat::InCBExtend),
+    (0x115b8, 0x115bb, GraphemeCat::SpacingMark),
+    (0x115bc, 0x115bd, GraphemeCat::InCBExtend),
+    (0x115be, 0x115be, GraphemeCat::SpacingMark),
+    (0x115bf, 0x115c0, GraphemeCat::InCBExtend),
+    (0x115dc, 0x115dd, GraphemeCat::InCBExtend),
+    (0x11630, 0x11632, GraphemeCat::SpacingMark),
+    (0x11633, 0x1163a, GraphemeCat::InCBExtend),
+    (0x1163b, 0x1163c, GraphemeCat::SpacingMark),
+    (0x1163d, 0x1163d, GraphemeCat::InCBExtend),
+    (0x1163e, 0x1163e, GraphemeCat::SpacingMark),
+    (0x1163f, 0x11640, GraphemeCat::InCBExtend),
+    (0x116ab, 0x116ab, GraphemeCat::InCBExtend),
+    (0x116ac, 0x116ac, GraphemeCat::SpacingMark),
+    (0x116ad, 0x116ad, GraphemeCat::InCBExtend),
+    (0x116ae, 0x116af, GraphemeCat::SpacingMark),
+    (0x116b0, 0x116b7, GraphemeCat::InCBExtend),
+    (0x1171d, 0x1171d, GraphemeCat::InCBExtend),
+    (0x1171e, 0x1171e, GraphemeCat::SpacingMark),
+    (0x1171f, 0x1171f, GraphemeCat::InCBExtend),
+    (0x11722, 0x11725, GraphemeCat::InCBExtend),
+    (0x11726, 0x11726, GraphemeCat::SpacingMark),
+    (0x11727, 0x1172b, GraphemeCat::InCBExtend),
+    (0x1182c, 0x1182e, GraphemeCat::SpacingMark),
+    (0x1182f, 0x11837, GraphemeCat::InCBExtend),
+    (0x11838, 0x11838, GraphemeCat::SpacingMark),
+    (0x11839, 0x1183a, GraphemeCat::InCBExtend),
+    (0x11900, 0x11906, GraphemeCat::InCBConsonant),
+    (0x11909, 0x11909, GraphemeCat::InCBConsonant),
+    (0x1190c, 0x11913, GraphemeCat::InCBConsonant),
+    (0x11915, 0x11916, GraphemeCat::InCBConsonant),
+    (0x11918, 0x1192f, GraphemeCat::InCBConsonant),
+    (0x11930, 0x11930, GraphemeCat::InCBExtend),
+    (0x11931, 0x11935, GraphemeCat::SpacingMark),
+    (0x11937, 0x11938, GraphemeCat::SpacingMark),
+    (0x1193b, 0x1193d, GraphemeCat::InCBExtend),
+    (0x1193e, 0x1193e, GraphemeCat::InCBLinker),
+    (0x1193f, 0x1193f, GraphemeCat::Prepend),
+    (0x11940, 0x11940, GraphemeCat::SpacingMark),
+    (0x11941, 0x11941, GraphemeCat::Prepend),
+    (0x11942, 0x11942, GraphemeCat::SpacingMark),
+    (0x11943, 0x11943, GraphemeCat::InCBExtend),
+    (0x119d1, 0x119d3, GraphemeCat::SpacingMark),
+    (0x119d4, 0x119d7, GraphemeCat::InCBExtend),
+    (0x119da, 0x119db, GraphemeCat::InCBExtend),
+    (0x119dc, 0x119df, GraphemeCat::SpacingMark),
+    (0x119e0, 0x119e0, GraphemeCat::InCBExtend),
+    (0x119e4, 0x119e4, GraphemeCat::SpacingMark),
+    (0x11a00, 0x11a00, GraphemeCat::InCBConsonant),
+    (0x11a01, 0x11a0a, GraphemeCat::InCBExtend),
+    (0x11a0b, 0x11a32, GraphemeCat::InCBConsonant),
+    (0x11a33, 0x11a38, GraphemeCat::InCBExtend),
+    (0x11a39, 0x11a39, GraphemeCat::SpacingMark),
+    (0x11a3b, 0x11a3e, GraphemeCat::InCBExtend),
+    (0x11a47, 0x11a47, GraphemeCat::InCBLinker),
+    (0x11a50, 0x11a50, GraphemeCat::InCBConsonant),
+    (0x11a51, 0x11a56, GraphemeCat::InCBExtend),
+    (0x11a57, 0x11a58, GraphemeCat::SpacingMark),
+    (0x11a59, 0x11a5b, GraphemeCat::InCBExtend),
+    (0x11a5c, 0x11a83, GraphemeCat::InCBConsonant),
+    (0x11a84, 0x11a89, GraphemeCat::Prepend),
+    (0x11a8a, 0x11a96, GraphemeCat::InCBExtend),
+    (0x11a97, 0x11a97, GraphemeCat::SpacingMark),
+    (0x11a98, 0x11a98, GraphemeCat::InCBExtend),
+    (0x11a99, 0x11a99, GraphemeCat::InCBLinker),
+    (0x11b60, 0x11b60, GraphemeCat::InCBExtend),
+    (0x11b61, 0x11b61, GraphemeCat::SpacingMark),
+    (0x11b62, 0x11b64, GraphemeCat::InCBExtend),
+    (0x11b65, 0x11b65, GraphemeCat::SpacingMark),
+    (0x11b66, 0x11b66, GraphemeCat::InCBExtend),
+    (0x11b67, 0x11b67, GraphemeCat::SpacingMark),
+    (0x11c2f, 0x11c2f, GraphemeCat::SpacingMark),
+    (0x11c30, 0x11c36, GraphemeCat::InCBExtend),
+    (0x11c38, 0x11c3d, GraphemeCat::InCBExtend),
+    (0x11c3e, 0x11c3e, GraphemeCat::SpacingMark),
+    (0x11c3f, 0x11c3f, GraphemeCat::InCBExtend),
+    (0x11c92, 0x11ca7, GraphemeCat::InCBExtend),
+    (0x11ca9, 0x11ca9, GraphemeCat::SpacingMark),
+    (0x11caa, 0x11cb0, GraphemeCat::InCBExtend),
+    (0x11cb1, 0x11cb1, GraphemeCat::SpacingMark),
+    (0x11cb2, 0x11cb3, GraphemeCat::InCBExtend),
+    (0x11cb4, 0x11cb4, GraphemeCat::SpacingMark),
+    (0x11cb5, 0x11cb6, GraphemeCat::InCBExtend),
+    (0x11d31, 0x11d36, GraphemeCat::InCBExtend),
+    (0x11d3a, 0x11d3a, GraphemeCat::InCBExtend),
+    (0x11d3c, 0x11d3d, GraphemeCat::InCBExtend),
+    (0x11d3f, 0x11d45, GraphemeCat::InCBExtend),
+    (0x11d46, 0x11d46, GraphemeCat::Prepend),
+    (0x11d47, 0x11d47, GraphemeCat::InCBExtend),
+    (0x11d8a, 0x11d8e, GraphemeCat::SpacingMark),
+    (0x11d90, 0x11d91, GraphemeCat::InCBExtend),
+    (0x11d93, 0x11d94, GraphemeCat::SpacingMark),
+    (0x11d95, 0x11d95, GraphemeCat::InCBExtend),
+    (0x11d96, 0x11d96, GraphemeCat::SpacingMark),
+    (0x11d97, 0x11d97, GraphemeCat::InCBExtend),
+    (0x11ef3, 0x11ef4, GraphemeCat::InCBExtend),
+    (0x11ef5, 0x11ef6, GraphemeCat::SpacingMark),
+    (0x11f00, 0x11f01, GraphemeCat::InCBExtend),
+    (0x11f02, 0x11f02, GraphemeCat::Prepend),
+    (0x11f03, 0x11f03, GraphemeCat::SpacingMark),
+    (0x11f04, 0x11f10, GraphemeCat::InCBConsonant),
+    (0x11f12, 0x11f33, GraphemeCat::InCBConsonant),
+    (0x11f34, 0x11f35, GraphemeCat::SpacingMark),
+    (0x11f36, 0x11f3a, GraphemeCat::InCBExtend),
+    (0x11f3e, 0x11f3f, GraphemeCat::SpacingMark),
+    (0x11f40, 0x11f41, GraphemeCat::InCBExtend),
+    (0x11f42, 0x11f42, GraphemeCat::InCBLinker),
+    (0x11f5a, 0x11f5a, GraphemeCat::InCBExtend),
+    (0x13430, 0x1343f, GraphemeCat::Control),
+    (0x13440, 0x13440, GraphemeCat::InCBExtend),
+    (0x13447, 0x13455, GraphemeCat::InCBExtend),
+    (0x1611e, 0x16129, GraphemeCat::InCBExtend),
+    (0x1612a, 0x1612c, GraphemeCat::SpacingMark),
+    (0x1612d, 0x1612f, GraphemeCat::InCBExtend),
+    (0x16af0, 0x16af4, GraphemeCat::InCBExtend),
+    (0x16b30, 0x16b36, GraphemeCat::InCBExtend),
+    (0x16d63, 0x16d63, GraphemeCat::V),
+    (0x16d67, 0x16d6a, GraphemeCat::V),
+    (0x16f4f, 0x16f4f, GraphemeCat::InCBExtend),
+    (0x16f51, 0x16f87, GraphemeCat::SpacingMark),
+    (0x16f8f, 0x16f92, GraphemeCat::InCBExtend),
+    (0x16fe4, 0x16fe4, GraphemeCat::InCBExtend),
+    (0x16ff0, 0x16ff1, GraphemeCat::InCBExtend),
+    (0x1bc9d, 0x1bc9e, GraphemeCat::InCBExtend),
+    (0x1bca0, 0x1bca3, GraphemeCat::Control),
+    (0x1cf00, 0x1cf2d, GraphemeCat::InCBExtend),
+    (0x1cf30, 0x1cf46, GraphemeCat::InCBExtend),
+    (0x1d165, 0x1d169, GraphemeCat::InCBExtend),
+    (0x1d16d, 0x1d172, GraphemeCat::InCBExtend),
+    (0x1d173, 0x1d17a, GraphemeCat::Control),
+    (0x1d17b, 0x1d182, GraphemeCat::InCBExtend),
+    (0x1d185, 0x1d18b, GraphemeCat::InCBExtend),
+    (0x1d1aa, 0x1d1ad, GraphemeCat::InCBExtend),
+    (0x1d242, 0x1d244, GraphemeCat::InCBExtend),
+    (0x1da00, 0x1da36, GraphemeCat::InCBExtend),
+    (0x1da3b, 0x1da6c, GraphemeCat::InCBExtend),
+    (0x1da75, 0x1da75, GraphemeCat::InCBExtend),
+    (0x1da84, 0x1da84, GraphemeCat::InCBExtend),
+    (0x1da9b, 0x1da9f, GraphemeCat::InCBExtend),
+    (0x1daa1, 0x1daaf, GraphemeCat::InCBExtend),
+    (0x1e000, 0x1e006, GraphemeCat::InCBExtend),
+    (0x1e008, 0x1e018, GraphemeCat::InCBExtend),
+    (0x1e01b, 0x1e021, GraphemeCat::InCBExtend),
+    (0x1e023, 0x1e024, GraphemeCat::InCBExtend),
+    (0x1e026, 0x1e02a, GraphemeCat::InCBExtend),
+    (0x1e08f, 0x1e08f, GraphemeCat::InCBExtend),
+    (0x1e130, 0x1e136, GraphemeCat::InCBExtend),
+    (0x1e2ae, 0x1e2ae, GraphemeCat::InCBExtend),
+    (0x1e2ec, 0x1e2ef, GraphemeCat::InCBExtend),
+    (0x1e4ec, 0x1e4ef, GraphemeCat::InCBExtend),
+    (0x1e5ee, 0x1e5ef, GraphemeCat::InCBExtend),
+    (0x1e6e3, 0x1e6e3, GraphemeCat::InCBExtend),
+    (0x1e6e6, 0x1e6e6, GraphemeCat::InCBExtend),
+    (0x1e6ee, 0x1e6ef, GraphemeCat::InCBExtend),
+    (0x1e6f5, 0x1e6f5, GraphemeCat::InCBExtend),
+    (0x1e8d0, 0x1e8d6, GraphemeCat::InCBExtend),
+    (0x1e944, 0x1e94a, GraphemeCat::InCBExtend),
+    (0x1f004, 0x1f004, GraphemeCat::ExtendedPictographic),
+    (0x1f02c, 0x1f02f, GraphemeCat::ExtendedPictographic),
+    (0x1f094, 0x1f09f, GraphemeCat::ExtendedPictographic),
+    (0x1f0af, 0x1f0b0, GraphemeCat::ExtendedPictographic),
+    (0x1f0c0, 0x1f0c0, GraphemeCat::ExtendedPictographic),
+    (0x1f0cf, 0x1f0d0, GraphemeCat::ExtendedPictographic),
+    (0x1f0f6, 0x1f0ff, GraphemeCat::ExtendedPictographic),
+    (0x1f170, 0x1f171, GraphemeCat::ExtendedPictographic),
+    (0x1f17e, 0x1f17f, GraphemeCat::ExtendedPictographic),
+    (0x1f18e, 0x1f18e, GraphemeCat::ExtendedPictographic),
+    (0x1f191, 0x1f19a, GraphemeCat::ExtendedPictographic),
+    (0x1f1ae, 0x1f1e5, GraphemeCat::ExtendedPictographic),
+    (0x1f1e6, 0x1f1ff, GraphemeCat::RegionalIndicator),
+    (0x1f201, 0x1f20f, GraphemeCat::ExtendedPictographic),
+    (0x1f21a, 0x1f21a, GraphemeCat::ExtendedPictographic),
+    (0x1f22f, 0x1f22f, GraphemeCat::ExtendedPictographic),
+    (0x1f232, 0x1f23a, GraphemeCat::ExtendedPictographic),
+    (0x1f23c, 0x1f23f, GraphemeCat::ExtendedPictographic),
+    (0x1f249, 0x1f25f, GraphemeCat::ExtendedPictographic),
+    (0x1f266, 0x1f321, GraphemeCat::ExtendedPictographic),
+    (0x1f324, 0x1f393, GraphemeCat::ExtendedPictographic),
+    (0x1f396, 0x1f397, GraphemeCat::ExtendedPictographic),
+    (0x1f399, 0x1f39b, GraphemeCat::ExtendedPictographic),
+    (0x1f39e, 0x1f3f0, GraphemeCat::ExtendedPictographic),
+    (0x1f3f3, 0x1f3f5, GraphemeCat::ExtendedPictographic),
+    (0x1f3f7, 0x1f3fa, GraphemeCat::ExtendedPictographic),
+    (0x1f3fb, 0x1f3ff, GraphemeCat::InCBExtend),
+    (0x1f400, 0x1f4fd, GraphemeCat::ExtendedPictographic),
+    (0x1f4ff, 0x1f53d, GraphemeCat::ExtendedPictographic),
+    (0x1f549, 0x1f54e, GraphemeCat::ExtendedPictographic),
+    (0x1f550, 0x1f567, GraphemeCat::ExtendedPictographic),
+    (0x1f56f, 0x1f570, GraphemeCat::ExtendedPictographic),
+    (0x1f573, 0x1f57a, GraphemeCat::ExtendedPictographic),
+    (0x1f587, 0x1f587, GraphemeCat::ExtendedPictographic),
+    (0x1f58a, 0x1f58d, GraphemeCat::ExtendedPictographic),
+    (0x1f590, 0x1f590, GraphemeCat::ExtendedPictographic),
+    (0x1f595, 0x1f596, GraphemeCat::ExtendedPictographic),
+    (0x1f5a4, 0x1f5a5, GraphemeCat::ExtendedPictographic),
+    (0x1f5a8, 0x1f5a8, GraphemeCat::ExtendedPictographic),
+    (0x1f5b1, 0x1f5b2, GraphemeCat::ExtendedPictographic),
+    (0x1f5bc, 0x1f5bc, GraphemeCat::ExtendedPictographic),
+    (0x1f5c2, 0x1f5c4, GraphemeCat::ExtendedPictographic),
+    (0x1f5d1, 0x1f5d3, GraphemeCat::ExtendedPictographic),
+    (0x1f5dc, 0x1f5de, GraphemeCat::ExtendedPictographic),
+    (0x1f5e1, 0x1f5e1, GraphemeCat::ExtendedPictographic),
+    (0x1f5e3, 0x1f5e3, GraphemeCat::ExtendedPictographic),
+    (0x1f5e8, 0x1f5e8, GraphemeCat::ExtendedPictographic),
+    (0x1f5ef, 0x1f5ef, GraphemeCat::ExtendedPictographic),
+    (0x1f5f3, 0x1f5f3, GraphemeCat::ExtendedPictographic),
+    (0x1f5fa, 0x1f64f, GraphemeCat::ExtendedPictographic),
+    (0x1f680, 0x1f6c5, GraphemeCat::ExtendedPictographic),
+    (0x1f6cb, 0x1f6d2, GraphemeCat::ExtendedPictographic),
+    (0x1f6d5, 0x1f6e5, GraphemeCat::ExtendedPictographic),
+    (0x1f6e9, 0x1f6e9, GraphemeCat::ExtendedPictographic),
+    (0x1f6eb, 0x1f6f0, GraphemeCat::ExtendedPictographic),
+    (0x1f6f3, 0x1f6ff, GraphemeCat::ExtendedPictographic),
+    (0x1f7da, 0x1f7ff, GraphemeCat::ExtendedPictographic),
+    (0x1f80c, 0x1f80f, GraphemeCat::ExtendedPictographic),
+    (0x1f848, 0x1f84f, GraphemeCat::ExtendedPictographic),
+    (0x1f85a, 0x1f85f, GraphemeCat::ExtendedPictographic),
+    (0x1f888, 0x1f88f, GraphemeCat::ExtendedPictographic),
+    (0x1f8ae, 0x1f8af, GraphemeCat::ExtendedPictographic),
+    (0x1f8bc, 0x1f8bf, GraphemeCat::ExtendedPictographic),
+    (0x1f8c2, 0x1f8cf, GraphemeCat::ExtendedPictographic),
+    (0x1f8d9, 0x1f8ff, GraphemeCat::ExtendedPictographic),
+    (0x1f90c, 0x1f93a, GraphemeCat::ExtendedPictographic),
+    (0x1f93c, 0x1f945, GraphemeCat::ExtendedPictographic),
+    (0x1f947, 0x1f9ff, GraphemeCat::ExtendedPictographic),
+    (0x1fa58, 0x1fa5f, GraphemeCat::ExtendedPictographic),
+    (0x1fa6e, 0x1faff, GraphemeCat::ExtendedPictographic),
+    (0x1fc00, 0x1fffd, GraphemeCat::ExtendedPictographic),
+    (0xe0000, 0xe001f, GraphemeCat::Control),
+    (0xe0020, 0xe007f, GraphemeCat::InCBExtend),
+    (0xe0080, 0xe00ff, GraphemeCat::Control),
+    (0xe0100, 0xe01ef, GraphemeCat::InCBExtend),
+    (0xe01f0, 0xe0fff, GraphemeCat::Control),
+];
+
+/// Word_Break property.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum WordCat {
+    Other,
+    ALetter,
+    CR,
+    DoubleQuote,
+    Extend,
+    ExtendNumLet,
+    Format,
+    HebrewLetter,
+    Katakana,
+    LF,
+    MidLetter,
+    MidNum,
+    MidNumLet,
+    Newline,
+    Numeric,
+    RegionalIndicator,
+    SingleQuote,
+    WSegSpace,
+    ZWJ,
+}
+
+pub(crate) const WORD_TABLE: &[(u32, u32, WordCat)] = &[
+    (0x000a, 0x000a, WordCat::LF),
+    (0x000b, 0x000c, WordCat::Newline),
+    (0x000d, 0x000d, WordCat::CR),
+    (0x0020, 0x0020, WordCat::WSegSpace),
+    (0x0022, 0x0022, WordCat::DoubleQuote),
+    (0x0027, 0x0027, WordCat::SingleQuote),
+    (0x002c, 0x002c, WordCat::MidNum),
+    (0x002e, 0x002e, WordCat::MidNumLet),
+    (0x0030, 0x0039, WordCat::Numeric),
+    (0x003a, 0x003a, WordCat::MidLetter),
+    (0x003b, 0x003b, WordCat::MidNum),
+    (0x0041, 0x005a, WordCat::ALetter),
+    (0x005f, 0x005f, WordCat::ExtendNumLet),
+    (0x0061, 0x007a, WordCat::ALetter),
+    (0x0085, 0x0085, WordCat::Newline),
+    (0x00aa, 0x00aa, WordCat::ALetter),
+    (0x00ad, 0x00ad, WordCat::Format),
+    (0x00b5, 0x00b5, WordCat::ALetter),
+    (0x00b7, 0x00b7, WordCat::MidLetter),
+    (0x00b8, 0x00b8, WordCat::ALetter),
+    (0x00ba, 0x00ba, WordCat::ALetter),
+    (0x00c0, 0x00d6, WordCat::ALetter),
+    (0x00d8, 0x00f6, WordCat::ALetter),
+    (0x00f8, 0x02d7, WordCat::ALetter),
+    (0x02de, 0x02ff, WordCat::ALetter),
+    (0x0300, 0x036f, WordCat::Extend),
+    (0x0370, 0x0374, WordCat::ALetter),
+    (0x0376, 0x0377, WordCat::ALetter),
+    (0x037a, 0x037d, WordCat::ALetter),
+    (0x037e, 0x037e, WordCat::MidNum),
+    (0x037f, 0x037f, WordCat::ALetter),
+    (0x0386, 0x0386, WordCat::ALetter),
+    (0x0387, 0x0387, WordCat::MidLetter),
+    (0x0388, 0x038a, WordCat::ALetter),
+    (0x038c, 0x038c, WordCat::ALetter),
+    (0x038e, 0x03a1, WordCat::ALetter),
+    (0x03a3, 0x03f5, WordCat::ALetter),
+    (0x03f7, 0x0481, WordCat::ALetter),
+    (0x0483, 0x0489, WordCat::Extend),
+    (0x048a, 0x052f, WordCat::ALetter),
+    (0x0531, 0x0556, WordCat::ALetter),
+    (0x0559, 0x055c, WordCat::ALetter),
+    (0x055e, 0x055e, WordCat::ALetter),
+    (0x055f, 0x055f, WordCat::MidLetter),
+    (0x0560, 0x0588, WordCat::ALetter),
+    (0x0589, 0x0589, WordCat::MidNum),
+    (0x058a, 0x058a, WordCat::ALetter),
+    (0x0591, 0x05bd, WordCat::Extend),
+    (0x05bf, 0x05bf, WordCat::Extend),
+    (0x05c1, 0x05c2, WordCat::Extend),
+    (0x05c4, 0x05c5, WordCat::Extend),
+    (0x05c7, 0x05c7, WordCat::Extend),
+    (0x05d0, 0x05ea, WordCat::HebrewLetter),
+    (0x05ef, 0x05f2, WordCat::HebrewLetter),
+    (0x05f3, 0x05f3, WordCat::ALetter),
+    (0x05f4, 0x05f4, WordCat::MidLetter),
+    (0x0600, 0x0605, WordCat::Numeric),
+    (0x060c, 0x060d, WordCat::MidNum),
+    (0x0610, 0x061a, WordCat::Extend),
+    (0x061c, 0x061c, WordCat::Format),
+    (0x0620, 0x064a, WordCat::ALetter),
+    (0x064b, 0x065f, WordCat::Extend),
+    (0x0660, 0x0669, WordCat::Numeric),
+    (0x066b, 0x066b, WordCat::Numeric),
+    (0x066c, 0x066c, WordCat::MidNum),
+    (0x066e, 0x066f, WordCat::ALetter),
+    (0x0670, 0x0670, WordCat::Extend),
+    (0x0671, 0x06d3, WordCat::ALetter),
+    (0x06d5, 0x06d5, WordCat::ALetter),
+    (0x06d6, 0x06dc, WordCat::Extend),
+    (0x06dd, 0x06dd, WordCat::Numeric),
+    (0x06df, 0x06e4, WordCat::Extend),
+    (0x06e5, 0x06e6, WordCat::ALetter),
+    (0x06e7, 0x06e8, WordCat::Extend),
+    (0x06ea, 0x06ed, WordCat::Extend),
+    (0x06ee, 0x06ef, WordCat::ALetter),
+    (0x06f0, 0x06f9, WordCat::Numeric),
+    (0x06fa, 0x06fc, WordCat::ALetter),
+    (0x06ff, 0x06ff, WordCat::ALetter),
+    (0x070f, 0x0710, WordCat::ALetter),
+    (0x0711, 0x0711, WordCat::Extend),
+    (0x0712, 0x072f, WordCat::ALetter),
+    (0x0730, 0x074a, WordCat::Extend),
+    (0x074d, 0x07a5, WordCat::ALetter),
+    (0x07a6, 0x07b0, WordCat::Extend),
+    (0x07b1, 0x07b1, WordCat::ALetter),
+    (0x07c0, 0x07c9, WordCat::Numeric),
+    (0x07ca, 0x07ea, WordCat::ALetter),
+    (0x07eb, 0x07f3, WordCat::Extend),
+    (0x07f4, 0x07f5, WordCat::ALetter),
+    (0x07f8, 0x07f8, WordCat::MidNum),
+    (0x07fa, 0x07fa, WordCat::ALetter),
+    (0x07fd, 0x07fd, WordCat::Extend),
+    (0x0800, 0x0815, WordCat::ALetter),
+    (0x0816, 0x0819, WordCat::Extend),
+    (0x081a, 0x081a, WordCat::ALetter),
+    (0x081b, 0x0823, WordCat::Extend),
+    (0x0824, 0x0824, WordCat::ALetter),
+    (0x0825, 0x0827, WordCat::Extend),
+    (0x0828, 0x0828, WordCat::ALetter),
+    (0x0829, 0x082d, WordCat::Extend),
+    (0x0840, 0x0858, WordCat::ALetter),
+    (0x0859, 0x085b, WordCat::Extend),
+    (0x0860, 0x086a, WordCat::ALetter),
+    (0x0870, 0x0887, WordCat::ALetter),
+    (0x0889, 0x088f, WordCat::ALetter),
+    (0x0890, 0x0891, WordCat::Numeric),
+    (0x0897, 0x089f, WordCat::Extend),
+    (0x08a0, 0x08c9, WordCat::ALetter),
+    (0x08ca, 0x08e1, WordCat::Extend),
+    (0x08e2, 0x08e2, WordCat::Numeric),
+    (0x08e3, 0x0903, WordCat::Extend),
+    (0x0904, 0x0939, WordCat::ALetter),
+    (0x093a, 0x093c, WordCat::Extend),
+    (0x093d, 0x093d, WordCat::ALetter),
+    (0x093e, 0x094f, WordCat::Extend),
+    (0x0950, 0x0950, WordCat::ALetter),
+    (0x0951, 0x0957, WordCat::Extend),
+    (0x0958, 0x0961, WordCat::ALetter),
+    (0x0962, 0x0963, WordCat::Extend),
+    (0x0966, 0x096f, WordCat::Numeric),
+    (0x0971, 0x0980, WordCat::ALetter),
+    (0x0981, 0x0983, WordCat::Extend),
+    (0x0985, 0x098c, WordCat::ALetter),
+    (0x098f, 0x0990, WordCat::ALetter),
+    (0x0993, 0x09a8, WordCat::ALetter),
+    (0x09aa, 0x09b0, WordCat::ALetter),
+    (0x09b2, 0x09b2, WordCat::ALetter),
+    (0x09b6, 0x09b9, WordCat::ALetter),
+    (0x09bc, 0x09bc, WordCat::Extend),
+    (0x09bd, 0x09bd, WordCat::ALetter),
+    (0x09be, 0x09c4, WordCat::Extend),
+    (0x09c7, 0x09c8, WordCat::Extend),
+    (0x09cb, 0x09cd, WordCat::Extend),
+    (0x09ce, 0x09ce, WordCat::ALetter),
+    (0x09d7, 0x09d7, WordCat::Extend),
+    (0x09dc, 0x09dd, WordCat::ALetter),
+    (0x09df, 0x09e1, WordCat::ALetter),
+    (0x09e2, 0x09e3, WordCat::Extend),
+    (0x09e6, 0x09ef, WordCat::Numeric),
+    (0x09f0, 0x09f1, WordCat::ALetter),
+    (0x09fc, 0x09fc, WordCat::ALetter),
+    (0x09fe, 0x09fe, WordCat::Extend),
+    (0x0a01, 0x0a03, WordCat::Extend),
+    (0x0a05, 0x0a0a, WordCat::ALetter),
+    (0x0a0f, 0x0a10, WordCat::ALetter),
+    (0x0a13, 0x0a28, WordCat::ALetter),
+    (0x0a2a, 0x0a30, WordCat::ALetter),
+    (0x0a32, 0x0a33, WordCat::ALetter),
+    (0x0a35, 0x0a36, WordCat::ALetter),
+    (0x0a38, 0x0a39, WordCat::ALetter),
+    (0x0a3c, 0x0a3c, WordCat::Extend),
+    (0x0a3e, 0x0a42, WordCat::Extend),
+    (0x0a47, 0x0a48, WordCat::Extend),
+    (0x0a4b, 0x0a4d, WordCat::Extend),
+    (0x0a51, 0x0a51, WordCat::Extend),
+    (0x0a59, 0x0a5c, WordCat::ALetter),
+    (0x0a5e, 0x0a5e, WordCat::ALetter),
+    (0x0a66, 0x0a6f, WordCat::Numeric),
+    (0x0a70, 0x0a71, WordCat::Extend),
+    (0x0a72, 0x0a74, WordCat::ALetter),
+    (0x0a75, 0x0a75, WordCat::Extend),
+    (0x0a81, 0x0a83, WordCat::Extend),
+    (0x0a85, 0x0a8d, WordCat::ALetter),
+    (0x0a8f, 0x0a91, WordCat::ALetter),
+    (0x0a93, 0x0aa8, WordCat::ALetter),
+    (0x0aaa, 0x0ab0, WordCat::ALetter),
+    (0x0ab2, 0x0ab3, WordCat::ALetter),
+    (0x0ab5, 0x0ab9, WordCat::ALetter),
+    (0x0abc, 0x0abc, WordCat::Extend),
+    (0x0abd, 0x0abd, WordCat::ALetter),
+    (0x0abe, 0x0ac5, WordCat::Extend),
+    (0x0ac7, 0x0ac9, WordCat::Extend),
+    (0x0acb, 0x0acd, WordCat::Extend),
+    (0x0ad0, 0x0ad0, WordCat::ALetter),
+    (0x0ae0, 0x0ae1, WordCat::ALetter),
+    (0x0ae2, 0x0ae3, WordCat::Extend),
+    (0x0ae6, 0x0aef, WordCat::Numeric),
+    (0x0af9, 0x0af9, WordCat::ALetter),
+    (0x0afa, 0x0aff, WordCat::Extend),
+    (0x0b01, 0x0b03, WordCat::Extend),
+    (0x0b05, 0x0b0c, WordCat::ALetter),
+    (0x0b0f, 0x0b10, WordCat::ALetter),
+    (0x0b13, 0x0b28, WordCat::ALetter),
+    (0x0b2a, 0x0b30, WordCat::ALetter),
+    (0x0b32, 0x0b33, WordCat::ALetter),
+    (0x0b35, 0x0b39, WordCat::ALetter),
+    (0x0b3c, 0x0b3c, WordCat::Extend),
+    (0x0b3d, 0x0b3d, WordCat::ALetter),
+    (0x0b3e, 0x0b44, WordCat::Extend),
+    (0x0b47, 0x0b48, WordCat::Extend),
+    (0x0b4b, 0x0b4d, WordCat::Extend),
+    (0x0b55, 0x0b57, WordCat::Extend),
+    (0x0b5c, 0x0b5d, WordCat::ALetter),
+    (0x0b5f, 0x0b61, WordCat::ALetter),
+    (0x0b62, 0x0b63, WordCat::Extend),
+    (0x0b66, 0x0b6f, WordCat::Numeric),
+    (0x0b71, 0x0b71, WordCat::ALetter),
+    (0x0b82, 0x0b82, WordCat::Extend),
+    (0x0b83, 0x0b83, WordCat::ALetter),
+    (0x0b85, 0x0b8a, WordCat::ALetter),
+    (0x0b8e, 0x0b90, WordCat::ALetter),
+    (0x0b92, 0x0b95, WordCat::ALetter),
+    (0x0b99, 0x0b9a, WordCat::ALetter),
+    (0x0b9c, 0x0b9c, WordCat::ALetter),
+    (0x0b9e, 0x0b9f, WordCat::ALetter),
+    (0x0ba3, 0x0ba4, WordCat::ALetter),
+    (0x0ba8, 0x0baa, WordCat::ALetter),
+    (0x0bae, 0x0bb9, WordCat::ALetter),
+    (0x0bbe, 0x0bc2, WordCat::Extend),
+    (0x0bc6, 0x0bc8, WordCat::Extend),
+    (0x0bca, 0x0bcd, WordCat::Extend),
+    (0x0bd0, 0x0bd0, WordCat::ALetter),
+    (0x0bd7, 0x0bd7, WordCat::Extend),
+    (0x0be6, 0x0bef, WordCat::Numeric),
+    (0x0c00, 0x0c04, WordCat::Extend),
+    (0x0c05, 0x0c0c, WordCat::ALetter),
+    (0x0c0e, 0x0c10, WordCat::ALetter),
+    (0x0c12, 0x0c28, WordCat::ALetter),
+    (0x0c2a, 0x0c39, WordCat::ALetter),
+    (0x0c3c, 0x0c3c, WordCat::Extend),
+    (0x0c3d, 0x0c3d, WordCat::ALetter),
+    (0x0c3e, 0x0c44, WordCat::Extend),
+    (0x0c46, 0x0c48, WordCat::Extend),
+    (0x0c4a, 0x0c4d, WordCat::Extend),
+    (0x0c55, 0x0c56, WordCat::Extend),
+    (0x0c58, 0x0c5a, WordCat::ALetter),
+    (0x0c5c, 0x0c5d, WordCat::ALetter),
+    (0x0c60, 0x0c61, WordCat::ALetter),
+    (0x0c62, 0x0c63, WordCat::Extend),
+    (0x0c66, 0x0c6f, WordCat::Numeric),
+    (0x0c80, 0x0c80, WordCat::ALetter),
+    (0x0c81, 0x0c83, WordCat::Extend),
+    (0x0c85, 0x0c8c, WordCat::ALetter),
+    (0x0c8e, 0x0c90, WordCat::ALetter),
+    (0x0c92, 0x0ca8, WordCat::ALetter),
+    (0x0caa, 0x0cb3, WordCat::ALetter),
+    (0x0cb5, 0x0cb9, WordCat::ALetter),
+    (0x0cbc, 0x0cbc, WordCat::Extend),
+    (0x0cbd, 0x0cbd, WordCat::ALetter),
+    (0x0cbe, 0x0cc4, WordCat::Extend),
+    (0x0cc6, 0x0cc8, WordCat::Extend),
+    (0x0cca, 0x0ccd, WordCat::Extend),
+    (0x0cd5, 0x0cd6, WordCat::Extend),
+    (0x0cdc, 0x0cde, WordCat::ALetter),
+    (0x0ce0, 0x0ce1, WordCat::ALetter),
+    (0x0ce2, 0x0ce3, WordCat::Extend),
+    (0x0ce6, 0x0cef, WordCat::Numeric),
+    (0x0cf1, 0x0cf2, WordCat::ALetter),
+    (0x0cf3, 0x0cf3, WordCat::Extend),
+    (0x0d00, 0x0d03, WordCat::Extend),
+    (0x0d04, 0x0d0c, WordCat::ALetter),
+    (0x0d0e, 0x0d10, WordCat::ALetter),
+    (0x0d12, 0x0d3a, WordCat::ALetter),
+    (0x0d3b, 0x0d3c, WordCat::Extend),
+    (0x0d3d, 0x0d3d, WordCat::ALetter),
+    (0x0d3e, 0x0d44, WordCat::Extend),
+    (0x0d46, 0x0d48, WordCat::Extend),
+    (0x0d4a, 0x0d4d, WordCat::Extend),
+    (0x0d4e, 0x0d4e, WordCat::ALetter),
+    (0x0d54, 0x0d56, WordCat::ALetter),
+    (0x0d57, 0x0d57, WordCat::Extend),
+    (0x0d5f, 0x0d61, WordCat::ALetter),
+    (0x0d62, 0x0d63, WordCat::Extend),
+    (0x0d66, 0x0d6f, WordCat::Numeric),
+    (0x0d7a, 0x0d7f, WordCat::ALetter),
+    (0x0d81, 0x0d83, WordCat::Extend),
+    (0x0d85, 0x0d96, WordCat::ALetter),
+    (0x0d9a, 0x0db1, WordCat::ALetter),
+    (0x0db3, 0x0dbb, WordCat::ALetter),
+    (0x0dbd, 0x0dbd, WordCat::ALetter),
+    (0x0dc0, 0x0dc6, WordCat::ALetter),
+    (0x0dca, 0x0dca, WordCat::Extend),
+    (0x0dcf, 0x0dd4, WordCat::Extend),
+    (0x0dd6, 0x0dd6, WordCat::Extend),
+    (0x0dd8, 0x0ddf, WordCat::Extend),
+    (0x0de6, 0x0def, WordCat::Numeric),
+    (0x0df2, 0x0df3, WordCat::Extend),
+    (0x0e31, 0x0e31, WordCat::Extend),
+    (0x0e34, 0x0e3a, WordCat::Extend),
+    (0x0e47, 0x0e4e, WordCat::Extend),
+    (0x0e50, 0x0e59, WordCat::Numeric),
+    (0x0eb1, 0x0eb1, WordCat::Extend),
+    (0x0eb4, 0x0ebc, WordCat::Extend),
+    (0x0ec8, 0x0ece, WordCat::Extend),
+    (0x0ed0, 0x0ed9, WordCat::Numeric),
+    (0x0f00, 0x0f00, WordCat::ALetter),
+    (0x0f18, 0x0f19, WordCat::Extend),
+    (0x0f20, 0x0f29, WordCat::Numeric),
+    (0x0f35, 0x0f35, WordCat::Extend),
+    (0x0f37, 0x0f37, WordCat::Extend),
+    (0x0f39, 0x0f39, WordCat::Extend),
+    (0x0f3e, 0x0f3f, WordCat::Extend),
+    (0x0f40, 0x0f47, WordCat::ALetter),
+    (0x0f49, 0x0f6c, WordCat::ALetter),
+    (0x0f71, 0x0f84, WordCat::Extend),
+    (0x0f86, 0x0f87, WordCat::Extend),
+    (0x0f88, 0x0f8c, WordCat::ALetter),
+    (0x0f8d, 0x0f97, WordCat::Extend),
+    (0x0f99, 0x0fbc, WordCat::Extend),
+    (0x0fc6, 0x0fc6, WordCat::Extend),
+    (0x102b, 0x103e, WordCat::Extend),
+    (0x1040, 0x1049, WordCat::Numeric),
+    (0x1056, 0x1059, WordCat::Extend),
+    (0x105e, 0x1060, WordCat::Extend),
+    (0x1062, 0x1064, WordCat::Extend),
+    (0x1067, 0x106d, WordCat::Extend),
+    (0x1071, 0x1074, WordCat::Extend),
+    (0x1082, 0x108d, WordCat::Extend),
+    (0x108f, 0x108f, WordCat::Extend),
+    (0x1090, 0x1099, WordCat::Numeric),
+    (0x109a, 0x109d, WordCat::Extend),
+    (0x10a0, 0x10c5, WordCat::ALetter),
+    (0x10c7, 0x10c7, WordCat::ALetter),
+    (0x10cd, 0x10cd, WordCat::ALetter),
+    (0x10d0, 0x10fa, WordCat::ALetter),
+    (0x10fc, 0x1248, WordCat::ALetter),
+    (0x124a, 0x124d, WordCat::ALetter),
+    (0x1250, 0x1256, WordCat::ALetter),
+    (0x1258, 0x1258, WordCat::ALetter),
+    (0x125a, 0x125d, WordCat::ALetter),
+    (0x1260, 0x1288, WordCat::ALetter),
+    (0x128a, 0x128d, WordCat::ALetter),
+    (0x1290, 0x12b0, WordCat::ALetter),
+    (0x12b2, 0x12b5, WordCat::ALetter),
+    (0x12b8, 0x12be, WordCat::ALetter),
+    (0x12c0, 0x12c0, WordCat::ALetter),
+    (0x12c2, 0x12c5, WordCat::ALetter),
+    (0x12c8, 0x12d6, WordCat::ALetter),
+    (0x12d8, 0x1310, WordCat::ALetter),
+    (0x1312, 0x1315, WordCat::ALetter),
+    (0x1318, 0x135a, WordCat::ALetter),
+    (0x135d, 0x135f, WordCat::Extend),
+    (0x1380, 0x138f, WordCat::ALetter),
+    (0x13a0, 0x13f5, WordCat::ALetter),
+    (0x13f8, 0x13fd, WordCat::ALetter),
+    (0x1401, 0x166c, WordCat::ALetter),
+    (0x166f, 0x167f, WordCat::ALetter),
+    (0x1680, 0x1680, WordCat::WSegSpace),
+    (0x1681, 0x169a, WordCat::ALetter),
+    (0x16a0, 0x16ea, WordCat::ALetter),
+    (0x16ee, 0x16f8, WordCat::ALetter),
+    (0x1700, 0x1711, WordCat::ALetter),
+    (0x1712, 0x1715, WordCat::Extend),
+    (0x171f, 0x1731, WordCat::ALetter),
+    (0x1732, 0x1734, WordCat::Extend),
+    (0x1740, 0x1751, WordCat::ALetter),
+    (0x1752, 0x1753, WordCat::Extend),
+    (0x1760, 0x176c, WordCat::ALetter),
+    (0x176e, 0x1770, WordCat::ALetter),
+    (0x1772, 0x1773, WordCat::Extend),
+    (0x17b4, 0x17d3, WordCat::Extend),
+    (0x17dd, 0x17dd, WordCat::Extend),
+    (0x17e0, 0x17e9, WordCat::Numeric),
+    (0x180b, 0x180d, WordCat::Extend),
+    (0x180e, 0x180e, WordCat::Format),
+    (0x180f, 0x180f, WordCat::Extend),
+    (0x1810, 0x1819, WordCat::Numeric),
+    (0x1820, 0x1878, WordCat::ALetter),
+    (0x1880, 0x1884, WordCat::ALetter),
+    (0x1885, 0x1886, WordCat::Extend),
+    (0x1887, 0x18a8, WordCat::ALetter),
+    (0x18a9, 0x18a9, WordCat::Extend),
+    (0x18aa, 0x18aa, WordCat::ALetter),
+    (0x18b0, 0x18f5, WordCat::ALetter),
+    (0x1900, 0x191e, WordCat::ALetter),
+    (0x1920, 0x192b, WordCat::Extend),
+    (0x1930, 0x193b, WordCat::Extend),
+    (0x1946, 0x194f, WordCat::Numeric),
+    (0x19d0, 0x19da, WordCat::Numeric),
+    (0x1a00, 0x1a16, WordCat::ALetter),
+    (0x1a17, 0x1a1b, WordCat::Extend),
+    (0x1a55, 0x1a5e, WordCat::Extend),
+    (0x1a60, 0x1a7c, WordCat::Extend),
+    (0x1a7f, 0x1a7f, WordCat::Extend),
+    (0x1a80, 0x1a89, WordCat::Numeric),
+    (0x1a90, 0x1a99, WordCat::Numeric),
+    (0x1ab0, 0x1add, WordCat::Extend),
+    (0x1ae0, 0x1aeb, WordCat::Extend),
+    (0x1b00, 0x1b04, WordCat::Extend),
+    (0x1b05, 0x1b33, WordCat::ALetter),
+    (0x1b34, 0x1b44, WordCat::Extend),
+    (0x1b45, 0x1b4c, WordCat::ALetter),
+    (0x1b50, 0x1b59, WordCat::Numeric),
+    (0x1b6b, 0x1b73, WordCat::Extend),
+    (0x1b80, 0x1b82, WordCat::Extend),
+    (0x1b83, 0x1ba0, WordCat::ALetter),
+    (0x1ba1, 0x1bad, WordCat::Extend),
+    (0x1bae, 0x1baf, WordCat::ALetter),
+    (0x1bb0, 0x1bb9, WordCat::Numeric),
+    (0x1bba, 0x1be5, WordCat::ALetter),
+    (0x1be6, 0x1bf3, WordCat::Extend),
+    (0x1c00, 0x1c23, WordCat::ALetter),
+    (0x1c24, 0x1c37, WordCat::Extend),
+    (0x1c40, 0x1c49, WordCat::Numeric),
+    (0x1c4d, 0x1c4f, WordCat::ALetter),
+    (0x1c50, 0x1c59, WordCat::Numeric),
+    (0x1c5a, 0x1c7d, WordCat::ALetter),
+    (0x1c80, 0x1c8a, WordCat::ALetter),
+    (0x1c90, 0x1cba, WordCat::ALetter),
+    (0x1cbd, 0x1cbf, WordCat::ALetter),
+    (0x1cd0, 0x1cd2, WordCat::Extend),
+    (0x1cd4, 0x1ce8, WordCat::Extend),
+    (0x1ce9, 0x1cec, WordCat::ALetter),
+    (0x1ced, 0x1ced, WordCat::Extend),
+    (0x1cee, 0x1cf3, WordCat::ALetter),
+    (0x1cf4, 0x1cf4, WordCat::Extend),
+    (0x1cf5, 0x1cf6, WordCat::ALetter),
+    (0x1cf7, 0x1cf9, WordCat::Extend),
+    (0x1cfa, 0x1cfa, WordCat::ALetter),
+    (0x1d00, 0x1dbf, WordCat::ALetter),
+    (0x1dc0, 0x1dff, WordCat::Extend),
+    (0x1e00, 0x1f15, WordCat::ALetter),
+    (0x1f18, 0x1f1d, WordCat::ALetter),
+    (0x1f20, 0x1f45, WordCat::ALetter),
+    (0x1f48, 0x1f4d, WordCat::ALetter),
+    (0x1f50, 0x1f57, WordCat::ALetter),
+    (0x1f59, 0x1f59, WordCat::ALetter),
+    (0x1f5b, 0x1f5b, WordCat::ALetter),
+    (0x1f5d, 0x1f5d, WordCat::ALetter),
+    (0x1f5f, 0x1f7d, WordCat::ALetter),
+    (0x1f80, 0x1fb4, WordCat::ALetter),
+    (0x1fb6, 0x1fbc, WordCat::ALetter),
+    (0x1fbe, 0x1fbe, WordCat::ALetter),
+    (0x1fc2, 0x1fc4, WordCat::ALetter),
+    (0x1fc6, 0x1fcc, WordCat::ALetter),
+    (0x1fd0, 0x1fd3, WordCat::ALetter),
+    (0x1fd6, 0x1fdb, WordCat::ALetter),
+    (0x1fe0, 0x1fec, WordCat::ALetter),
+    (0x1ff2, 0x1ff4, WordCat::ALetter),
+    (0x1ff6, 0x1ffc, WordCat::ALetter),
+    (0x2000, 0x2006, WordCat::WSegSpace),
+    (0x2008, 0x200a, WordCat::WSegSpace),
+    (0x200c, 0x200c, WordCat::Extend),
+    (0x200d, 0x200d, WordCat::ZWJ),
+    (0x200e, 0x200f, WordCat::Format),
+    (0x2018, 0x2019, WordCat::MidNumLet),
+    (0x2024, 0x2024, WordCat::MidNumLet),
+    (0x2027, 0x2027, WordCat::MidLetter),
+    (0x2028, 0x2029, WordCat::Newline),
+    (0x202a, 0x202e, WordCat::Format),
+    (0x202f, 0x202f, WordCat::ExtendNumLet),
+    (0x203f, 0x2040, WordCat::ExtendNumLet),
+    (0x2044, 0x2044, WordCat::MidNum),
+    (0x2054, 0x2054, WordCat::ExtendNumLet),
+    (0x205f, 0x205f, WordCat::WSegSpace),
+    (0x2060, 0x2064, WordCat::Format),
+    (0x2066, 0x206f, WordCat::Format),
+    (0x2071, 0x2071, WordCat::ALetter),
+    (0x207f, 0x207f, WordCat::ALetter),
+    (0x2090, 0x209c, WordCat::ALetter),
+    (0x20d0, 0x20f0, WordCat::Extend),
+    (0x2102, 0x2102, WordCat::ALetter),
+    (0x2107, 0x2107, WordCat::ALetter),
+    (0x210a, 0x2113, WordCat::ALetter),
+    (0x2115, 0x2115, WordCat::ALetter),
+    (0x2119, 0x211d, WordCat::ALetter),
+    (0x2124, 0x2124, WordCat::ALetter),
+    (0x2126, 0x2126, WordCat::ALetter),
+    (0x2128, 0x2128, WordCat::ALetter),
+    (0x212a, 0x212d, WordCat::ALetter),
+    (0x212f, 0x2139, WordCat::ALetter),
+    (0x213c, 0x213f, WordCat::ALetter),
+    (0x2145, 0x2149, WordCat::ALetter),
+    (0x214e, 0x214e, WordCat::ALetter),
+    (0x2160, 0x2188, WordCat::ALetter),
+    (0x24b6, 0x24e9, WordCat::ALetter),
+    (0x2c00, 0x2ce4, WordCat::ALetter),
+    (0x2ceb, 0x2cee, WordCat::ALetter),
+    (0x2cef, 0x2cf1, WordCat::Extend),
+    (0x2cf2, 0x2cf3, WordCat::ALetter),
+    (0x2d00, 0x2d25, WordCat::ALetter),
+    (0x2d27, 0x2d27, WordCat::ALetter),
+    (0x2d2d, 0x2d2d, WordCat::ALetter),
+    (0x2d30, 0x2d67, WordCat::ALetter),
+    (0x2d6f, 0x2d6f, WordCat::ALetter),
+    (0x2d7f, 0x2d7f, WordCat::Extend),
+    (0x2d80, 0x2d96, WordCat::ALetter),
+    (0x2da0, 0x2da6, WordCat::ALetter),
+    (0x2da8, 0x2dae, WordCat::ALetter),
+    (0x2db0, 0x2db6, WordCat::ALetter),
+    (0x2db8, 0x2dbe, WordCat::ALetter),
+    (0x2dc0, 0x2dc6, WordCat::ALetter),
+    (0x2dc8, 0x2dce, WordCat::ALetter),
+    (0x2dd0, 0x2dd6, WordCat::ALetter),
+    (0x2dd8, 0x2dde, WordCat::ALetter),
+    (0x2de0, 0x2dff, WordCat::Extend),
+    (0x2e2f, 0x2e2f, WordCat::ALetter),
+    (0x3000, 0x3000, WordCat::WSegSpace),
+    (0x3005, 0x3005, WordCat::ALetter),
+    (0x302a, 0x302f, WordCat::Extend),
+    (0x3031, 0x3035, WordCat::Katakana),
+    (0x303b, 0x303c, WordCat::ALetter),
+    (0x3099, 0x309a, WordCat::Extend),
+    (0x309b, 0x309c, WordCat::Katakana),
+    (0x30a0, 0x30fa, WordCat::Katakana),
+    (0x30fc, 0x30ff, WordCat::Katakana),
+    (0x3105, 0x312f, WordCat::ALetter),
+    (0x3131, 0x318e, WordCat::ALetter),
+    (0x31a0, 0x31bf, WordCat::ALetter),
+    (0x31f0, 0x31ff, WordCat::Katakana),
+    (0x32d0, 0x32fe, WordCat::Katakana),
+    (0x3300, 0x3357, WordCat::Katakana),
+    (0xa000, 0xa48c, WordCat::ALetter),
+    (0xa4d0, 0xa4fd, WordCat::ALetter),
+    (0xa500, 0xa60c, WordCat::ALetter),
+    (0xa610, 0xa61f, WordCat::ALetter),
+    (0xa620, 0xa629, WordCat::Numeric),
+    (0xa62a, 0xa62b, WordCat::ALetter),
+    (0xa640, 0xa66e, WordCat::ALetter),
+    (0xa66f, 0xa672, WordCat::Extend),
+    (0xa674, 0xa67d, WordCat::Extend),
+    (0xa67f, 0xa69d, WordCat::ALetter),
+    (0xa69e, 0xa69f, WordCat::Extend),
+    (0xa6a0, 0xa6ef, WordCat::ALetter),
+    (0xa6f0, 0xa6f1, WordCat::Extend),
+    (0xa708, 0xa7dc, WordCat::ALetter),
+    (0xa7f1, 0xa801, WordCat::ALetter),
+    (0xa802, 0xa802, WordCat::Extend),
+    (0xa803, 0xa805, WordCat::ALetter),
+    (0xa806, 0xa806, WordCat::Extend),
+    (0xa807, 0xa80a, WordCat::ALetter),
+    (0xa80b, 0xa80b, WordCat::Extend),
+    (0xa80c, 0xa822, WordCat::ALetter),
+    (0xa823, 0xa827, WordCat::Extend),
+    (0xa82c, 0xa82c, WordCat::Extend),
+    (0xa840, 0xa873, WordCat::ALetter),
+    (0xa880, 0xa881, WordCat::Extend),
+    (0xa882, 0xa8b3, WordCat::ALetter),
+    (0xa8b4, 0xa8c5, WordCat::Extend),
+    (0xa8d0, 0xa8d9, WordCat::Numeric),
+    (0xa8e0, 0xa8f1, WordCat::Extend),
+    (0xa8f2, 0xa8f7, WordCat::ALetter),
+    (0xa8fb, 0xa8fb, WordCat::ALetter),
+    (0xa8fd, 0xa8fe, WordCat::ALetter),
+    (0xa8ff, 0xa8ff, WordCat::Extend),
+    (0xa900, 0xa909, WordCat::Numeric),
+    (0xa90a, 0xa925, WordCat::ALetter),
+    (0xa926, 0xa92d, WordCat::Extend),
+    (0xa930, 0xa946, WordCat::ALetter),
+    (0xa947, 0xa953, WordCat::Extend),
+    (0xa960, 0xa97c, WordCat::ALetter),
+    (0xa980, 0xa983, WordCat::Extend),
+    (0xa984, 0xa9b2, WordCat::ALetter),
+    (0xa9b3, 0xa9c0, WordCat::Extend),
+    (0xa9cf, 0xa9cf, WordCat::ALetter),
+    (0xa9d0, 0xa9d9, WordCat::Numeric),
+    (0xa9e5, 0xa9e5, WordCat::Extend),
+    (0xa9f0, 0xa9f9, WordCat::Numeric),
+    (0xaa00, 0xaa28, WordCat::ALetter),
+    (0xaa29, 0xaa36, WordCat::Extend),
+    (0xaa40, 0xaa42, WordCat::ALetter),
+    (0xaa43, 0xaa43, WordCat::Extend),
+    (0xaa44, 0xaa4b, WordCat::ALetter),
+    (0xaa4c, 0xaa4d, WordCat::Extend),
+    (0xaa50, 0xaa59, WordCat::Numeric),
+    (0xaa7b, 0xaa7d, WordCat::Extend),
+    (0xaab0, 0xaab0, WordCat::Extend),
+    (0xaab2, 0xaab4, WordCat::Extend),
+    (0xaab7, 0xaab8, WordCat::Extend),
+    (0xaabe, 0xaabf, WordCat::Extend),
+    (0xaac1, 0xaac1, WordCat::Extend),
+    (0xaae0, 0xaaea, WordCat::ALetter),
+    (0xaaeb, 0xaaef, WordCat::Extend),
+    (0xaaf2, 0xaaf4, WordCat::ALetter),
+    (0xaaf5, 0xaaf6, WordCat::Extend),
+    (0xab01, 0xab06, WordCat::ALetter),
+    (0xab09, 0xab0e, WordCat::ALetter),
+    (0xab11, 0xab16, WordCat::ALetter),
+    (0xab20, 0xab26, WordCat::ALetter),
+    (0xab28, 0xab2e, WordCat::ALetter),
+    (0xab30, 0xab69, WordCat::ALetter),
+    (0xab70, 0xabe2, WordCat::ALetter),
+    (0xabe3, 0xabea, WordCat::Extend),
+    (0xabec, 0xabed, WordCat::Extend),
+    (0xabf0, 0xabf9, WordCat::Numeric),
+    (0xac00, 0xd7a3, WordCat::ALetter),
+    (0xd7b0, 0xd7c6, WordCat::ALetter),
+    (0xd7cb, 0xd7fb, WordCat::ALetter),
+    (0xfb00, 0xfb06, WordCat::ALetter),
+    (0xfb13, 0xfb17, WordCat::ALetter),
+    (0xfb1d, 0xfb1d, WordCat::HebrewLetter),
+    (0xfb1e, 0xfb1e, WordCat::Extend),
+    (0xfb1f, 0xfb28, WordCat::HebrewLetter),
+    (0xfb2a, 0xfb36, WordCat::HebrewLetter),
+    (0xfb38, 0xfb3c, WordCat::HebrewLetter),
+    (0xfb3e, 0xfb3e, WordCat::HebrewLetter),
+    (0xfb40, 0xfb41, WordCat::HebrewLetter),
+    (0xfb43, 0xfb44, WordCat::HebrewLetter),
+    (0xfb46, 0xfb4f, WordCat::HebrewLetter),
+    (0xfb50, 0xfbb1, WordCat::ALetter),
+    (0xfbd3, 0xfd3d, WordCat::ALetter),
+    (0xfd50, 0xfd8f, WordCat::ALetter),
+    (0xfd92, 0xfdc7, WordCat::ALetter),
+    (0xfdf0, 0xfdfb, WordCat::ALetter),
+    (0xfe00, 0xfe0f, WordCat::Extend),
+    (0xfe13, 0xfe13, WordCat::MidLetter),
+    (0xfe20, 0xfe2f, WordCat::Extend),
+    (0xfe33, 0xfe34, WordCat::ExtendNumLet),
+    (0xfe4d, 0xfe4f, WordCat::ExtendNumLet),
+    (0xfe50, 0xfe50, WordCat::MidNum),
+    (0xfe52, 0xfe52, WordCat::MidNumLet),
+    (0xfe54, 0xfe54, WordCat::MidNum),
+    (0xfe55, 0xfe55, WordCat::MidLetter),
+    (0xfe70, 0xfe74, WordCat::ALetter),
+    (0xfe76, 0xfefc, WordCat::ALetter),
+    (0xfeff, 0xfeff, WordCat::Format),
+    (0xff07, 0xff07, WordCat::MidNumLet),
+    (0xff0c, 0xff0c, WordCat::MidNum),
+    (0xff0e, 0xff0e, WordCat::MidNumLet),
+    (0xff10, 0xff19, WordCat::Numeric),
+    (0xff1a, 0xff1a, WordCat::MidLetter),
+    (0xff1b, 0xff1b, WordCat::MidNum),
+    (0xff21, 0xff3a, WordCat::ALetter),
+    (0xff3f, 0xff3f, WordCat::ExtendNumLet),
+    (0xff41, 0xff5a, WordCat::ALetter),
+    (0xff66, 0xff9d, WordCat::Katakana),
+    (0xff9e, 0xff9f, WordCat::Extend),
+    (0xffa0, 0xffbe, WordCat::ALetter),
+    (0xffc2, 0xffc7, WordCat::ALetter),
+    (0xffca, 0xffcf, WordCat::ALetter),
+    (0xffd2, 0xffd7, WordCat::ALetter),
+    (0xffda, 0xffdc, WordCat::ALetter),
+    (0xfff9, 0xfffb, WordCat::Format),
+    (0x10000, 0x1000b, WordCat::ALetter),
+    (0x1000d, 0x10026, WordCat::ALetter),
+    (0x10028, 0x1003a, WordCat::ALetter),
+    (0x1003c, 0x1003d, WordCat::ALetter),
+    (0x1003f, 0x1004d, WordCat::ALetter),
+    (0x10050, 0x1005d, WordCat::ALetter),
+    (0x10080, 0x100fa, WordCat::ALetter),
+    (0x10140, 0x10174, WordCat::ALetter),
+    (0x101fd, 0x101fd, WordCat::Extend),
+    (0x10280, 0x1029c, WordCat::ALetter),
+    (0x102a0, 0x102d0, WordCat::ALetter),
+    (0x102e0, 0x102e0, WordCat::Extend),
+    (0x10300, 0x1031f, WordCat::ALetter),
+    (0x1032d, 0x1034a, WordCat::ALetter),
+    (0x10350, 0x10375, WordCat::ALetter),
+    (0x10376, 0x1037a, WordCat::Extend),
+    (0x10380, 0x1039d, WordCat::ALetter),
+    (0x103a0, 0x103c3, WordCat::ALetter),
+    (0x103c8, 0x103cf, WordCat::ALetter),
+    (0x103d1, 0x103d5, WordCat::ALetter),
+    (0x10400, 0x1049d, WordCat::ALetter),
+    (0x104a0, 0x104a9, WordCat::Numeric),
+    (0x104b0, 0x104d3, WordCat::ALetter),
+    (0x104d8, 0x104fb, WordCat::ALetter),
+    (0x10500, 0x10527, WordCat::ALetter),
+    (0x10530, 0x10563, WordCat::ALetter),
+    (0x10570, 0x1057a, WordCat::ALetter),
+    (0x1057c, 0x1058a, WordCat::ALetter),
+    (0x1058c, 0x10592, WordCat::ALetter),
+    (0x10594, 0x10595, WordCat::ALetter),
+    (0x10597, 0x105a1, WordCat::ALetter),
+    (0x105a3, 0x105b1, WordCat::ALetter),
+    (0x105b3, 0x105b9, WordCat::ALetter),
+    (0x105bb, 0x105bc, WordCat::ALetter),
+    (0x105c0, 0x105f3, WordCat::ALetter),
+    (0x10600, 0x10736, WordCat::ALetter),
+    (0x10740, 0x10755, WordCat::ALetter),
+    (0x10760, 0x10767, WordCat::ALetter),
+    (0x10780, 0x10785, WordCat::ALetter),
+    (0x10787, 0x107b0, WordCat::ALetter),
+    (0x107b2, 0x107ba, WordCat::ALetter),
+    (0x10800, 0x10805, WordCat::ALetter),
+    (0x10808, 0x10808, WordCat::ALetter),
+    (0x1080a, 0x10835, WordCat::ALetter),
+    (0x10837, 0x10838, WordCat::ALetter),
+    (0x1083c, 0x1083c, WordCat::ALetter),
+    (0x1083f, 0x10855, WordCat::ALetter),
+    (0x10860, 0x10876, WordCat::ALetter),
+    (0x10880, 0x1089e, WordCat::ALetter),
+    (0x108e0, 0x108f2, WordCat::ALetter),
+    (0x108f4, 0x108f5, WordCat::ALetter),
+    (0x10900, 0x10915, WordCat::ALetter),
+    (0x10920, 0x10939, WordCat::ALetter),
+    (0x10940, 0x10959, WordCat::ALetter),
+    (0x10980, 0x109b7, WordCat::ALetter),
+    (0x109be, 0x109bf, WordCat::ALetter),
+    (0x10a00, 0x10a00, WordCat::ALetter),
+    (0x10a01, 0x10a03, WordCat::Extend),
+    (0x10a05, 0x10a06, WordCat::Extend),
+    (0x10a0c, 0x10a0f, WordCat::Extend),
+    (0x10a10, 0x10a13, WordCat::ALetter),
+    (0x10a15, 0x10a17, WordCat::ALetter),
+    (0x10a19, 0x10a35, WordCat::ALetter),
+    (0x10a38, 0x10a3a, WordCat::Extend),
+    (0x10a3f, 0x10a3f, WordCat::Extend),
+    (0x10a60, 0x10a7c, WordCat::ALetter),
+    (0x10a80, 0x10a9c, WordCat::ALetter),
+    (0x10ac0, 0x10ac7, WordCat::ALetter),
+    (0x10ac9, 0x10ae4, WordCat::ALetter),
+    (0x10ae5, 0x10ae6, WordCat::Extend),
+    (0x10b00, 0x10b35, WordCat::ALetter),
+    (0x10b40, 0x10b55, WordCat::ALetter),
+    (0x10b60, 0x10b72, WordCat::ALetter),
+    (0x10b80, 0x10b91, WordCat::ALetter),
+    (0x10c00, 0x10c48, WordCat::ALetter),
+    (0x10c80, 0x10cb2, WordCat::ALetter),
+    (0x10cc0, 0x10cf2, WordCat::ALetter),
+    (0x10d00, 0x10d23, WordCat::ALetter),
+    (0x10d24, 0x10d27, WordCat::Extend),
+    (0x10d30, 0x10d39, WordCat::Numeric),
+    (0x10d40, 0x10d49, WordCat::Numeric),
+    (0x10d4a, 0x10d65, WordCat::ALetter),
+    (0x10d69, 0x10d6d, WordCat::Extend),
+    (0x10d6f, 0x10d85, WordCat::ALetter),
+    (0x10e80, 0x10ea9, WordCat::ALetter),
+    (0x10eab, 0x10eac, WordCat::Extend),
+    (0x10eb0, 0x10eb1, WordCat::ALetter),
+    (0x10ec2, 0x10ec7, WordCat::ALetter),
+    (0x10efa, 0x10eff, WordCat::Extend),
+    (0x10f00, 0x10f1c, WordCat::ALetter),
+    (0x10f27, 0x10f27, WordCat::ALetter),
+    (0x10f30, 0x10f45, WordCat::ALetter),
+    (0x10f46, 0x10f50, WordCat::Extend),
+    (0x10f70, 0x10f81, WordCat::ALetter),
+    (0x10f82, 0x10f85, WordCat::Extend),
+    (0x10fb0, 0x10fc4, WordCat::ALetter),
+    (0x10fe0, 0x10ff6, WordCat::ALetter),
+    (0x11000, 0x11002, WordCat::Extend),
+    (0x11003, 0x11037, WordCat::ALetter),
+    (0x11038, 0x11046, WordCat::Extend),
+    (0x11066, 0x1106f, WordCat::Numeric),
+    (0x11070, 0x11070, WordCat::Extend),
+    (0x11071, 0x11072, WordCat::ALetter),
+    (0x11073, 0x11074, WordCat::Extend),
+    (0x11075, 0x11075, WordCat::ALetter),
+    (0x1107f, 0x11082, WordCat::Extend),
+    (0x11083, 0x110af, WordCat::ALetter),
+    (0x110b0, 0x110ba, WordCat::Extend),
+    (0x110bd, 0x110bd, WordCat::Numeric),
+    (0x110c2, 0x110c2, WordCat::Extend),
+    (0x110cd, 0x110cd, WordCat::Numeric),
+    (0x110d0, 0x110e8, WordCat::ALetter),
+    (0x110f0, 0x110f9, WordCat::Numeric),
+    (0x11100, 0x11102, WordCat::Extend),
+    (0x11103, 0x11126, WordCat::ALetter),
+    (0x11127, 0x11134, WordCat::Extend),
+    (0x11136, 0x1113f, WordCat::Numeric),
+    (0x11144, 0x11144, WordCat::ALetter),
+    (0x11145, 0x11146, WordCat::Extend),
+    (0x11147, 0x11147, WordCat::ALetter),
+    (0x11150, 0x11172, WordCat::ALetter),
+    (0x11173, 0x11173, WordCat::Extend),
+    (0x11176, 0x11176, WordCat::ALetter),
+    (0x11180, 0x11182, WordCat::Extend),
+    (0x11183, 0x111b2, WordCat::ALetter),
+    (0x111b3, 0x111c0, WordCat::Extend),
+    (0x111c1, 0x111c4, WordCat::ALetter),
+    (0x111c9, 0x111cc, WordCat::Extend),
+    (0x111ce, 0x111cf, WordCat::Extend),
+    (0x111d0, 0x111d9, WordCat::Numeric),
+    (0x111da, 0x111da, WordCat::ALetter),
+    (0x111dc, 0x111dc, WordCat::ALetter),
+    (0x11200, 0x11211, WordCat::ALetter),
+    (0x11213, 0x1122b, WordCat::ALetter),
+    (0x1122c, 0x11237, WordCat::Extend),
+    (0x1123e, 0x1123e, WordCat::Extend),
+    (0x1123f, 0x11240, WordCat::ALetter),
+    (0x11241, 0x11241, WordCat::Extend),
+    (0x11280, 0x11286, WordCat::ALetter),
+    (0x11288, 0x11288, WordCat::ALetter),
+    (0x1128a, 0x1128d, WordCat::ALetter),
+    (0x1128f, 0x1129d, WordCat::ALetter),
+    (0x1129f, 0x112a8, WordCat::ALetter),
+    (0x112b0, 0x112de, WordCat::ALetter),
+    (0x112df, 0x112ea, WordCat::Extend),
+    (0x112f0, 0x112f9, WordCat::Numeric),
+    (0x11300, 0x11303, WordCat::Extend),
+    (0x11305, 0x1130c, WordCat::ALetter),
+    (0x1130f, 0x11310, WordCat::ALetter),
+    (0x11313, 0x11328, WordCat::ALetter),
+    (0x1132a, 0x11330, WordCat::ALetter),
+    (0x11332, 0x11333, WordCat::ALetter),
+    (0x11335, 0x11339, WordCat::ALetter),
+    (0x1133b, 0x1133c, WordCat::Extend),
+    (0x1133d, 0x1133d, WordCat::ALetter),
+    (0x1133e, 0x11344, WordCat::Extend),
+    (0x11347, 0x11348, WordCat::Extend),
+    (0x1134b, 0x1134d, WordCat::Extend),
+    (0x11350, 0x11350, WordCat::ALetter),
+    (0x11357, 0x11357, WordCat::Extend),
+    (0x1135d, 0x11361, WordCat::ALetter),
+    (0x11362, 0x11363, WordCat::Extend),
+    (0x11366, 0x1136c, WordCat::Extend),
+    (0x11370, 0x11374, WordCat::Extend),
+    (0x11380, 0x11389, WordCat::ALetter),
+    (0x1138b, 0x1138b, WordCat::ALetter),
+    (0x1138e, 0x1138e, WordCat::ALetter),
+    (0x11390, 0x113b5, WordCat::ALetter),
+    (0x113b7, 0x113b7, WordCat::ALetter),
+    (0x113b8, 0x113c0, WordCat::Extend),
+    (0x113c2, 0x113c2, WordCat::Extend),
+    (0x113c5, 0x113c5, WordCat::Extend),
+    (0x113c7, 0x113ca, WordCat::Extend),
+    (0x113cc, 0x113d0, WordCat::Extend),
+    (0x113d1, 0x113d1, WordCat::ALetter),
+    (0x113d2, 0x113d2, WordCat::Extend),
+    (0x113d3, 0x113d3, WordCat::ALetter),
+    (0x113e1, 0x113e2, WordCat::Extend),
+    (0x11400, 0x11434, WordCat::ALetter),
+    (0x11435, 0x11446, WordCat::Extend),
+    (0x11447, 0x1144a, WordCat::ALetter),
+    (0x11450, 0x11459, WordCat::Numeric),
+    (0x1145e, 0x1145e, WordCat::Extend),
+    (0x1145f, 0x11461, WordCat::ALetter),
+    (0x11480, 0x114af, WordCat::ALetter),
+    (0x114b0, 0x114c3, WordCat::Extend),
+    (0x114c4, 0x114c5, WordCat::ALetter),
+    (0x114c7, 0x114c7, WordCat::ALetter),
+    (0x114d0, 0x114d9, WordCat::Numeric),
+    (0x11580, 0x115ae, WordCat::ALetter),
+    (0x115af, 0x115b5, WordCat::Extend),
+    (0x115b8, 0x115c0, WordCat::Extend),
+    (0x115d8, 0x115db, WordCat::ALetter),
+    (0x115dc, 0x115dd, WordCat::Extend),
+    (0x11600, 0x1162f, WordCat::ALetter),
+    (0x11630, 0x11640, WordCat::Extend),
+    (0x11644, 0x11644, WordCat::ALetter),
+    (0x11650, 0x11659, WordCat::Numeric),
+    (0x11680, 0x116aa, WordCat::ALetter),
+    (0x116ab, 0x116b7, WordCat::Extend),
+    (0x116b8, 0x116b8, WordCat::ALetter),
+    (0x116c0, 0x116c9, WordCat::Numeric),
+    (0x116d0, 0x116e3, WordCat::Numeric),
+    (0x1171d, 0x1172b, WordCat::Extend),
+    (0x11730, 0x11739, WordCat::Numeric),
+    (0x11800, 0x1182b, WordCat::ALetter),
+    (0x1182c, 0x1183a, WordCat::Extend),
+    (0x118a0, 0x118df, WordCat::ALetter),
+    (0x118e0, 0x118e9, WordCat::Numeric),
+    (0x118ff, 0x11906, WordCat::ALetter),
+    (0x11909, 0x11909, WordCat::ALetter),
+    (0x1190c, 0x11913, WordCat::ALetter),
+    (0x11915, 0x11916, WordCat::ALetter),
+    (0x11918, 0x1192f, WordCat::ALetter),
+    (0x11930, 0x11935, WordCat::Extend),
+    (0x11937, 0x11938, WordCat::Extend),
+    (0x1193b, 0x1193e, WordCat::Extend),
+    (0x1193f, 0x1193f, WordCat::ALetter),
+    (0x11940, 0x11940, WordCat::Extend),
+    (0x11941, 0x11941, WordCat::ALetter),
+    (0x11942, 0x11943, WordCat::Extend),
+    (0x11950, 0x11959, WordCat::Numeric),
+    (0x119a0, 0x119a7, WordCat::ALetter),
+    (0x119aa, 0x119d0, WordCat::ALetter),
+    (0x119d1, 0x119d7, WordCat::Extend),
+    (0x119da, 0x119e0, WordCat::Extend),
+    (0x119e1, 0x119e1, WordCat::ALetter),
+    (0x119e3, 0x119e3, WordCat::ALetter),
+    (0x119e4, 0x119e4, WordCat::Extend),
+    (0x11a00, 0x11a00, WordCat::ALetter),
+    (0x11a01, 0x11a0a, WordCat::Extend),
+    (0x11a0b, 0x11a32, WordCat::ALetter),
+    (0x11a33, 0x11a39, WordCat::Extend),
+    (0x11a3a, 0x11a3a, WordCat::ALetter),
+    (0x11a3b, 0x11a3e, WordCat::Extend),
+    (0x11a47, 0x11a47, WordCat::Extend),
+    (0x11a50, 0x11a50, WordCat::ALetter),
+    (0x11a51, 0x11a5b, WordCat::Extend),
+    (0x11a5c, 0x11a89, WordCat::ALetter),
+    (0x11a8a, 0x11a99, WordCat::Extend),
+    (0x11a9d, 0x11a9d, WordCat::ALetter),
+    (0x11ab0, 0x11af8, WordCat::ALetter),
+    (0x11b60, 0x11b67, WordCat::Extend),
+    (0x11bc0, 0x11be0, WordCat::ALetter),
+    (0x11bf0, 0x11bf9, WordCat::Numeric),
+    (0x11c00, 0x11c08, WordCat::ALetter),
+    (0x11c0a, 0x11c2e, WordCat::ALetter),
+    (0x11c2f, 0x11c36, WordCat::Extend),
+    (0x11c38, 0x11c3f, WordCat::Extend),
+    (0x11c40, 0x11c40, WordCat::ALetter),
+    (0x11c50, 0x11c59, WordCat::Numeric),
+    (0x11c72, 0x11c8f, WordCat::ALetter),
+    (0x11c92, 0x11ca7, WordCat::Extend),
+    (0x11ca9, 0x11cb6, WordCat::Extend),
+    (0x11d00, 0x11d06, WordCat::ALetter),
+    (0x11d08, 0x11d09, WordCat::ALetter),
+    (0x11d0b, 0x11d30, WordCat::ALetter),
+    (0x11d31, 0x11d36, WordCat::Extend),
+    (0x11d3a, 0x11d3a, WordCat::Extend),
+    (0x11d3c, 0x11d3d, WordCat::Extend),
+    (0x11d3f, 0x11d45, WordCat::Extend),
+    (0x11d46, 0x11d46, WordCat::ALetter),
+    (0x11d47, 0x11d47, WordCat::Extend),
+    (0x11d50, 0x11d59, WordCat::Numeric),
+    (0x11d60, 0x11d65, WordCat::ALetter),
+    (0x11d67, 0x11d68, WordCat::ALetter),
+    (0x11d6a, 0x11d89, WordCat::ALetter),
+    (0x11d8a, 0x11d8e, WordCat::Extend),
+    (0x11d90, 0x11d91, WordCat::Extend),
+    (0x11d93, 0x11d97, WordCat::Extend),
+    (0x11d98, 0x11d98, WordCat::ALetter),
+    (0x11da0, 0x11da9, WordCat::Numeric),
+    (0x11db0, 0x11ddb, WordCat::ALetter),
+    (0x11de0, 0x11de9, WordCat::Numeric),
+    (0x11ee0, 0x11ef2, WordCat::ALetter),
+    (0x11ef3, 0x11ef6, WordCat::Extend),
+    (0x11f00, 0x11f01, WordCat::Extend),
+    (0x11f02, 0x11f02, WordCat::ALetter),
+    (0x11f03, 0x11f03, WordCat::Extend),
+    (0x11f04, 0x11f10, WordCat::ALetter),
+    (0x11f12, 0x11f33, WordCat::ALetter),
+    (0x11f34, 0x11f3a, WordCat::Extend),
+    (0x11f3e, 0x11f42, WordCat::Extend),
+    (0x11f50, 0x11f59, WordCat::Numeric),
+    (0x11f5a, 0x11f5a, WordCat::Extend),
+    (0x11fb0, 0x11fb0, WordCat::ALetter),
+    (0x12000, 0x12399, WordCat::ALetter),
+    (0x12400, 0x1246e, WordCat::ALetter),
+    (0x12480, 0x12543, WordCat::ALetter),
+    (0x12f90, 0x12ff0, WordCat::ALetter),
+    (0x13000, 0x1342f, WordCat::ALetter),
+    (0x13430, 0x1343f, WordCat::Format),
+    (0x13440, 0x13440, WordCat::Extend),
+    (0x13441, 0x13446, WordCat::ALetter),
+    (0x13447, 0x13455, WordCat::Extend),
+    (0x13460, 0x143fa, WordCat::ALetter),
+    (0x14400, 0x14646, WordCat::ALetter),
+    (0x16100, 0x1611d, WordCat::ALetter),
+    (0x1611e, 0x1612f, WordCat::Extend),
+    (0x16130, 0x16139, WordCat::Numeric),
+    (0x16800, 0x16a38, WordCat::ALetter),
+    (0x16a40, 0x16a5e, WordCat::ALetter),
+    (0x16a60, 0x16a69, WordCat::Numeric),
+    (0x16a70, 0x16abe, WordCat::ALetter),
+    (0x16ac0, 0x16ac9, WordCat::Numeric),
+    (0x16ad0, 0x16aed, WordCat::ALetter),
+    (0x16af0, 0x16af4, WordCat::Extend),
+    (0x16b00, 0x16b2f, WordCat::ALetter),
+    (0x16b30, 0x16b36, WordCat::Extend),
+    (0x16b40, 0x16b43, WordCat::ALetter),
+    (0x16b50, 0x16b59, WordCat::Numeric),
+    (0x16b63, 0x16b77, WordCat::ALetter),
+    (0x16b7d, 0x16b8f, WordCat::ALetter),
+    (0x16d40, 0x16d6c, WordCat::ALetter),
+    (0x16d70, 0x16d79, WordCat::Numeric),
+    (0x16e40, 0x16e7f, WordCat::ALetter),
+    (0x16ea0, 0x16eb8, WordCat::ALetter),
+    (0x16ebb, 0x16ed3, WordCat::ALetter),
+    (0x16f00, 0x16f4a, WordCat::ALetter),
+    (0x16f4f, 0x16f4f, WordCat::Extend),
+    (0x16f50, 0x16f50, WordCat::ALetter),
+    (0x16f51, 0x16f87, WordCat::Extend),
+    (0x16f8f, 0x16f92, WordCat::Extend),
+    (0x16f93, 0x16f9f, WordCat::ALetter),
+    (0x16fe0, 0x16fe1, WordCat::ALetter),
+    (0x16fe3, 0x16fe3, WordCat::ALetter),
+    (0x16fe4, 0x16fe4, WordCat::Extend),
+    (0x16ff0, 0x16ff1, WordCat::Extend),
+    (0x1aff0, 0x1aff3, WordCat::Katakana),
+    (0x1aff5, 0x1affb, WordCat::Katakana),
+    (0x1affd, 0x1affe, WordCat::Katakana),
+    (0x1b000, 0x1b000, WordCat::Katakana),
+    (0x1b120, 0x1b122, WordCat::Katakana),
+    (0x1b155, 0x1b155, WordCat::Katakana),
+    (0x1b164, 0x1b167, WordCat::Katakana),
+    (0x1bc00, 0x1bc6a, WordCat::ALetter),
+    (0x1bc70, 0x1bc7c, WordCat::ALetter),
+    (0x1bc80, 0x1bc88, WordCat::ALetter),
+    (0x1bc90, 0x1bc99, WordCat::ALetter),
+    (0x1bc9d, 0x1bc9e, WordCat::Extend),
+    (0x1bca0, 0x1bca3, WordCat::Format),
+    (0x1ccf0, 0x1ccf9, WordCat::Numeric),
+    (0x1cf00, 0x1cf2d, WordCat::Extend),
+    (0x1cf30, 0x1cf46, WordCat::Extend),
+    (0x1d165, 0x1d169, WordCat::Extend),
+    (0x1d16d, 0x1d172, WordCat::Extend),
+    (0x1d173, 0x1d17a, WordCat::Format),
+    (0x1d17b, 0x1d182, WordCat::Extend),
+    (0x1d185, 0x1d18b, WordCat::Extend),
+    (0x1d1aa, 0x1d1ad, WordCat::Extend),
+    (0x1d242, 0x1d244, WordCat::Extend),
+    (0x1d400, 0x1d454, WordCat::ALetter),
+    (0x1d456, 0x1d49c, WordCat::ALetter),
+    (0x1d49e, 0x1d49f, WordCat::ALetter),
+    (0x1d4a2, 0x1d4a2, WordCat::ALetter),
+    (0x1d4a5, 0x1d4a6, WordCat::ALetter),
+    (0x1d4a9, 0x1d4ac, WordCat::ALetter),
+    (0x1d4ae, 0x1d4b9, WordCat::ALetter),
+    (0x1d4bb, 0x1d4bb, WordCat::ALetter),
+    (0x1d4bd, 0x1d4c3, WordCat::ALetter),
+    (0x1d4c5, 0x1d505, WordCat::ALetter),
+    (0x1d507, 0x1d50a, WordCat::ALetter),
+    (0x1d50d, 0x1d514, WordCat::ALetter),
+    (0x1d516, 0x1d51c, WordCat::ALetter),
+    (0x1d51e, 0x1d539, WordCat::ALetter),
+    (0x1d53b, 0x1d53e, WordCat::ALetter),
+    (0x1d540, 0x1d544, WordCat::ALetter),
+    (0x1d546, 0x1d546, WordCat::ALetter),
+    (0x1d54a, 0x1d550, WordCat::ALetter),
+    (0x1d552, 0x1d6a5, WordCat::ALetter),
+    (0x1d6a8, 0x1d6c0, WordCat::ALetter),
+    (0x1d6c2, 0x1d6da, WordCat::ALetter),
+    (0x1d6dc, 0x1d6fa, WordCat::ALetter),
+    (0x1d6fc, 0x1d714, WordCat::ALetter),
+    (0x1d716, 0x1d734, WordCat::ALetter),
+    (0x1d736, 0x1d74e, WordCat::ALetter),
+    (0x1d750, 0x1d76e, WordCat::ALetter),
+    (0x1d770, 0x1d788, WordCat::ALetter),
+    (0x1d78a, 0x1d7a8, WordCat::ALetter),
+    (0x1d7aa, 0x1d7c2, WordCat::ALetter),
+    (0x1d7c4, 0x1d7cb, WordCat::ALetter),
+    (0x1d7ce, 0x1d7ff, WordCat::Numeric),
+    (0x1da00, 0x1da36, WordCat::Extend),
+    (0x1da3b, 0x1da6c, WordCat::Extend),
+    (0x1da75, 0x1da75, WordCat::Extend),
+    (0x1da84, 0x1da84, WordCat::Extend),
+    (0x1da9b, 0x1da9f, WordCat::Extend),
+    (0x1daa1, 0x1daaf, WordCat::Extend),
+    (0x1df00, 0x1df1e, WordCat::ALetter),
+    (0x1df25, 0x1df2a, WordCat::ALetter),
+    (0x1e000, 0x1e006, WordCat::Extend),
+    (0x1e008, 0x1e018, WordCat::Extend),
+    (0x1e01b, 0x1e021, WordCat::Extend),
+    (0x1e023, 0x1e024, WordCat::Extend),
+    (0x1e026, 0x1e02a, WordCat::Extend),
+    (0x1e030, 0x1e06d, WordCat::ALetter),
+    (0x1e08f, 0x1e08f, WordCat::Extend),
+    (0x1e100, 0x1e12c, WordCat::ALetter),
+    (0x1e130, 0x1e136, WordCat::Extend),
+    (0x1e137, 0x1e13d, WordCat::ALetter),
+    (0x1e140, 0x1e149, WordCat::Numeric),
+    (0x1e14e, 0x1e14e, WordCat::ALetter),
+    (0x1e290, 0x1e2ad, WordCat::ALetter),
+    (0x1e2ae, 0x1e2ae, WordCat::Extend),
+    (0x1e2c0, 0x1e2eb, WordCat::ALetter),
+    (0x1e2ec, 0x1e2ef, WordCat::Extend),
+    (0x1e2f0, 0x1e2f9, WordCat::Numeric),
+    (0x1e4d0, 0x1e4eb, WordCat::ALetter),
+    (0x1e4ec, 0x1e4ef, WordCat::Extend),
+    (0x1e4f0, 0x1e4f9, WordCat::Numeric),
+    (0x1e5d0, 0x1e5ed, WordCat::ALetter),
+    (0x1e5ee, 0x1e5ef, WordCat::Extend),
+    (0x1e5f0, 0x1e5f0, WordCat::ALetter),
+    (0x1e5f1, 0x1e5fa, WordCat::Numeric),
+    (0x1e6c0, 0x1e6de, WordCat::ALetter),
+    (0x1e6e0, 0x1e6e2, WordCat::ALetter),
+    (0x1e6e3, 0x1e6e3, WordCat::Extend),
+    (0x1e6e4, 0x1e6e5, WordCat::ALetter),
+    (0x1e6e6, 0x1e6e6, WordCat::Extend),
+    (0x1e6e7, 0x1e6ed, WordCat::ALetter),
+    (0x1e6ee, 0x1e6ef, WordCat::Extend),
+    (0x1e6f0, 0x1e6f4, WordCat::ALetter),
+    (0x1e6f5, 0x1e6f5, WordCat::Extend),
+    (0x1e6fe, 0x1e6ff, WordCat::ALetter),
+    (0x1e7e0, 0x1e7e6, WordCat::ALetter),
+    (0x1e7e8, 0x1e7eb, WordCat::ALetter),
+    (0x1e7ed, 0x1e7ee, WordCat::ALetter),
+    (0x1e7f0, 0x1e7fe, WordCat::ALetter),
+    (0x1e800, 0x1e8c4, WordCat::ALetter),
+    (0x1e8d0, 0x1e8d6, WordCat::Extend),
+    (0x1e900, 0x1e943, WordCat::ALetter),
+    (0x1e944, 0x1e94a, WordCat::Extend),
+    (0x1e94b, 0x1e94b, WordCat::ALetter),
+    (0x1e950, 0x1e959, WordCat::Numeric),
+    (0x1ee00, 0x1ee03, WordCat::ALetter),
+    (0x1ee05, 0x1ee1f, WordCat::ALetter),
+    (0x1ee21, 0x1ee22, WordCat::ALetter),
+    (0x1ee24, 0x1ee24, WordCat::ALetter),
+    (0x1ee27, 0x1ee27, WordCat::ALetter),
+    (0x1ee29, 0x1ee32, WordCat::ALetter),
+    (0x1ee34, 0x1ee37, WordCat::ALetter),
+    (0x1ee39, 0x1ee39, WordCat::ALetter),
+    (0x1ee3b, 0x1ee3b, WordCat::ALetter),
+    (0x1ee42, 0x1ee42, WordCat::ALetter),
+    (0x1ee47, 0x1ee47, WordCat::ALetter),
+    (0x1ee49, 0x1ee49, WordCat::ALetter),
+    (0x1ee4b, 0x1ee4b, WordCat::ALetter),
+    (0x1ee4d, 0x1ee4f, WordCat::ALetter),
+    (0x1ee51, 0x1ee52, WordCat::ALetter),
+    (0x1ee54, 0x1ee54, WordCat::ALetter),
+    (0x1ee57, 0x1ee57, WordCat::ALetter),
+    (0x1ee59, 0x1ee59, WordCat::ALetter),
+    (0x1ee5b, 0x1ee5b, WordCat::ALetter),
+    (0x1ee5d, 0x1ee5d, WordCat::ALetter),
+    (0x1ee5f, 0x1ee5f, WordCat::ALetter),
+    (0x1ee61, 0x1ee62, WordCat::ALetter),
+    (0x1ee64, 0x1ee64, WordCat::ALetter),
+    (0x1ee67, 0x1ee6a, WordCat::ALetter),
+    (0x1ee6c, 0x1ee72, WordCat::ALetter),
+    (0x1ee74, 0x1ee77, WordCat::ALetter),
+    (0x1ee79, 0x1ee7c, WordCat::ALetter),
+    (0x1ee7e, 0x1ee7e, WordCat::ALetter),
+    (0x1ee80, 0x1ee89, WordCat::ALetter),
+    (0x1ee8b, 0x1ee9b, WordCat::ALetter),
+    (0x1eea1, 0x1eea3, WordCat::ALetter),
+    (0x1eea5, 0x1eea9, WordCat::ALetter),
+    (0x1eeab, 0x1eebb, WordCat::ALetter),
+    (0x1f130, 0x1f149, WordCat::ALetter),
+    (0x1f150, 0x1f169, WordCat::ALetter),
+    (0x1f170, 0x1f189, WordCat::ALetter),
+    (0x1f1e6, 0x1f1ff, WordCat::RegionalIndicator),
+    (0x1f3fb, 0x1f3ff, WordCat::Extend),
+    (0x1fbf0, 0x1fbf9, WordCat::Numeric),
+    (0xe0001, 0xe0001, WordCat::Format),
+    (0xe0020, 0xe007f, WordCat::Extend),
+    (0xe0100, 0xe01ef, WordCat::Extend),
+];
+
+/// Sentence_Break property.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum SentenceCat {
+    Other,
+    ATerm,
+    CR,
+    Close,
+    Extend,
+    Format,
+    LF,
+    Lower,
+    Numeric,
+    OLetter,
+    SContinue,
+    STerm,
+    Sep,
+    Sp,
+    Upper,
+}
+
+pub(crate) const SENTENCE_TABLE: &[(u32, u32, SentenceCat)] = &[
+    (0x0009, 0x0009, SentenceCat::Sp),
+    (0x000a, 0x000a, SentenceCat::LF),
+    (0x000b, 0x000c, SentenceCat::Sp),
+    (0x000d, 0x000d, SentenceCat::CR),
+    (0x0020, 0x0020, SentenceCat::Sp),
+    (0x0021, 0x0021, SentenceCat::STerm),
+    (0x0022, 0x0022, SentenceCat::Close),
+    (0x0027, 0x0029, SentenceCat::Close),
+    (0x002c, 0x002d, SentenceCat::SContinue),
+    (0x002e, 0x002e, SentenceCat::ATerm),
+    (0x0030, 0x0039, SentenceCat::Numeric),
+    (0x003a, 0x003b, SentenceCat::SContinue),
+    (0x003f, 0x003f, SentenceCat::STerm),
+    (0x0041, 0x005a, SentenceCat::Upper),
+    (0x005b, 0x005b, SentenceCat::Close),
+    (0x005d, 0x005d, SentenceCat::Close),
+    (0x0061, 0x007a, SentenceCat::Lower),
+    (0x007b, 0x007b, SentenceCat::Close),
+    (0x007d, 0x007d, SentenceCat::Close),
+    (0x0085, 0x0085, SentenceCat::Sep),
+    (0x00a0, 0x00a0, SentenceCat::Sp),
+    (0x00aa, 0x00aa, SentenceCat::Lower),
+    (0x00ab, 0x00ab, SentenceCat::Close),
+    (0x00ad, 0x00ad, SentenceCat::Format),
+    (0x00b5, 0x00b5, SentenceCat::Lower),
+    (0x00ba, 0x00ba, SentenceCat::Lower),
+    (0x00bb, 0x00bb, SentenceCat::Close),
+    (0x00c0, 0x00d6, SentenceCat::Upper),
+    (0x00d8, 0x00de, SentenceCat::Upper),
+    (0x00df, 0x00f6, SentenceCat::Lower),
+    (0x00f8, 0x00ff, SentenceCat::Lower),
+    (0x0100, 0x0100, SentenceCat::Upper),
+    (0x0101, 0x0101, SentenceCat::Lower),
+    (0x0102, 0x0102, SentenceCat::Upper),
+    (0x0103, 0x0103, SentenceCat::Lower),
+    (0x0104, 0x0104, SentenceCat::Upper),
+    (0x0105, 0x0105, SentenceCat::Lower),
+    (0x0106, 0x0106, SentenceCat::Upper),
+    (0x0107, 0x0107, SentenceCat::Lower),
+    (0x0108, 0x0108, SentenceCat::Upper),
+    (0x0109, 0x0109, SentenceCat::Lower),
+    (0x010a, 0x010a, SentenceCat::Upper),
+    (0x010b, 0x010b, SentenceCat::Lower),
+    (0x010c, 0x010c, SentenceCat::Upper),
+    (0x010d, 0x010d, SentenceCat::Lower),
+    (0x010e, 0x010e, SentenceCat::Upper),
+    (0x010f, 0x010f, SentenceCat::Lower),
+    (0x0110, 0x0110, SentenceCat::Upper),
+    (0x0111, 0x0111, SentenceCat::Lower),
+    (0x0112, 0x0112, SentenceCat::Upper),
+    (0x0113, 0x0113, SentenceCat::Lower),
+    (0x0114, 0x0114, SentenceCat::Upper),
+    (0x0115, 0x0115, SentenceCat::Lower),
+    (0x0116, 0x0116, SentenceCat::Upper),
+    (0x0117, 0x0117, SentenceCat::Lower),
+    (0x0118, 0x0118, SentenceCat::Upper),
+    (0x0119, 0x0119, SentenceCat::Lower),
+    (0x011a, 0x011a, SentenceCat::Upper),
+    (0x011b, 0x011b, SentenceCat::Lower),
+    (0x011c, 0x011c, SentenceCat::Upper),
+    (0x011d, 0x011d, SentenceCat::Lower),
+    (0x011e, 0x011e, SentenceCat::Upper),
+    (0x011f, 0x011f, SentenceCat::Lower),
+    (0x0120, 0x0120, SentenceCat::Upper),
+    (0x0121, 0x0121, SentenceCat::Lower),
+    (0x0122, 0x0122, SentenceCat::Upper),
+    (0x0123, 0x0123, SentenceCat::Lower),
+    (0x0124, 0x0124, SentenceCat::Upper),
+    (0x0125, 0x0125, SentenceCat::Lower),
+    (0x0126, 0x0126, SentenceCat::Upper),
+    (0x0127, 0x0127, SentenceCat::Lower),
+    (0x0128, 0x0128, SentenceCat::Upper),
+    (0x0129, 0x0129, SentenceCat::Lower),
+    (0x012a, 0x012a, SentenceCat::Upper),
+    (0x012b, 0x012b, SentenceCat::Lower),
+    (0x012c, 0x012c, SentenceCat::Upper),
+    (0x012d, 0x012d, SentenceCat::Lower),
+    (0x012e, 0x012e, SentenceCat::Upper),
+    (0x012f, 0x012f, SentenceCat::Lower),
+    (0x0130, 0x0130, SentenceCat::Upper),
+    (0x0131, 0x0131, SentenceCat::Lower),
+    (0x0132, 0x0132, SentenceCat::Upper),
+    (0x0133, 0x0133, SentenceCat::Lower),
+    (0x0134, 0x0134, SentenceCat::Upper),
+    (0x0135, 0x0135, SentenceCat::Lower),
+    (0x0136, 0x0136, SentenceCat::Upper),
+    (0x0137, 0x0138, SentenceCat::Lower),
+    (0x0139, 0x0139, SentenceCat::Upper),
+    (0x013a, 0x013a, SentenceCat::Lower),
+    (0x013b, 0x013b, SentenceCat::Upper),
+    (0x013c, 0x013c, SentenceCat::Lower),
+    (0x013d, 0x013d, SentenceCat::Upper),
+    (0x013e, 0x013e, SentenceCat::Lower),
+    (0x013f, 0x013f, SentenceCat::Upper),
+    (0x0140, 0x0140, SentenceCat::Lower),
+    (0x0141, 0x0141, SentenceCat::Upper),
+    (0x0142, 0x0142, SentenceCat::Lower),
+    (0x0143, 0x0143, SentenceCat::Upper),
+    (0x0144, 0x0144, SentenceCat::Lower),
+    (0x0145, 0x0145, SentenceCat::Upper),
+    (0x0146, 0x0146, SentenceCat::Lower),
+    (0x0147, 0x0147, SentenceCat::Upper),
+    (0x0148, 0x0149, SentenceCat::Lower),
+    (0x014a, 0x014a, SentenceCat::Upper),
+    (0x014b, 0x014b, SentenceCat::Lower),
+    (0x014c, 0x014c, SentenceCat::Upper),
+    (0x014d, 0x014d, SentenceCat::Lower),
+    (0x014e, 0x014e, SentenceCat::Upper),
+    (0x014f, 0x014f, SentenceCat::Lower),
+    (0x0150, 0x0150, SentenceCat::Upper),
+    (0x0151, 0x0151, SentenceCat::Lower),
+    (0x0152, 0x0152, SentenceCat::Upper),
+    (0x0153, 0x0153, SentenceCat::Lower),
+    (0x0154, 0x0154, SentenceCat::Upper),
+    (0x0155, 0x0155, SentenceCat::Lower),
+    (0x0156, 0x0156, SentenceCat::Upper),
+    (0x0157, 0x0157, SentenceCat::Lower),
+    (0x0158, 0x0158, SentenceCat::Upper),
+    (0x0159, 0x0159, SentenceCat::Lower),
+    (0x015a, 0x015a, SentenceCat::Upper),
+    (0x015b, 0x015b, SentenceCat::Lower),
+    (0x015c, 0x015c, SentenceCat::Upper),
+    (0x015d, 0x015d, SentenceCat::Lower),
+    (0x015e, 0x015e, SentenceCat::Upper),
+    (0x015f, 0x015f, SentenceCat::Lower),
+    (0x0160, 0x0160, SentenceCat::Upper),
+    (0x0161, 0x0161, SentenceCat::Lower),
+    (0x0162, 0x0162, SentenceCat::Upper),
+    (0x0163, 0x0163, SentenceCat::Lower),
+    (0x0164, 0x0164, SentenceCat::Upper),
+    (0x0165, 0x0165, SentenceCat::Lower),
+    (0x0166, 0x0166, SentenceCat::Upper),
+    (0x0167, 0x0167, SentenceCat::Lower),
+    (0x0168, 0x0168, SentenceCat::Upper),
+    (0x0169, 0x0169, SentenceCat::Lower),
+    (0x016a, 0x016a, SentenceCat::Upper),
+    (0x016b, 0x016b, SentenceCat::Lower),
+    (0x016c, 0x016c, SentenceCat::Upper),
+    (0x016d, 0x016d, SentenceCat::Lower),
+    (0x016e, 0x016e, SentenceCat::Upper),
+    (0x016f, 0x016f, SentenceCat::Lower),
+    (0x0170, 0x0170, SentenceCat::Upper),
+    (0x0171, 0x0171, SentenceCat::Lower),
+    (0x0172, 0x0172, SentenceCat::Upper),
+    (0x0173, 0x0173, SentenceCat::Lower),
+    (0x0174, 0x0174, SentenceCat::Upper),
+    (0x0175, 0x0175, SentenceCat::Lower),
+    (0x0176, 0x0176, SentenceCat::Upper),
+    (0x0177, 0x0177, SentenceCat::Lower),
+    (0x0178, 0x0179, SentenceCat::Upper),
+    (0x017a, 0x017a, SentenceCat::Lower),
+    (0x017b, 0x017b, SentenceCat::Upper),
+    (0x017c, 0x017c, SentenceCat::Lower),
+    (0x017d, 0x017d, SentenceCat::Upper),
+    (0x017e, 0x0180, SentenceCat::Lower),
+    (0x0181, 0x0182, SentenceCat::Upper),
+    (0x0183, 0x0183, SentenceCat::Lower),
+    (0x0184, 0x0184, SentenceCat::Upper),
+    (0x0185, 0x0185, SentenceCat::Lower),
+    (0x0186, 0x0187, SentenceCat::Upper),
+    (0x0188, 0x0188, SentenceCat::Lower),
+    (0x0189, 0x018b, SentenceCat::Upper),
+    (0x018c, 0x018d, SentenceCat::Lower),
+    (0x018e, 0x0191, SentenceCat::Upper),
+    (0x0192, 0x0192, SentenceCat::Lower),
+    (0x0193, 0x0194, SentenceCat::Upper),
+    (0x0195, 0x0195, SentenceCat::Lower),
+    (0x0196, 0x0198, SentenceCat::Upper),
+    (0x0199, 0x019b, SentenceCat::Lower),
+    (0x019c, 0x019d, SentenceCat::Upper),
+    (0x019e, 0x019e, SentenceCat::Lower),
+    (0x019f, 0x01a0, SentenceCat::Upper),
+    (0x01a1, 0x01a1, SentenceCat::Lower),
+    (0x01a2, 0x01a2, SentenceCat::Upper),
+    (0x01a3, 0x01a3, SentenceCat::Lower),
+    (0x01a4, 0x01a4, SentenceCat::Upper),
+    (0x01a5, 0x01a5, SentenceCat::Lower),
+    (0x01a6, 0x01a7, SentenceCat::Upper),
+    (0x01a8, 0x01a8, SentenceCat::Lower),
+    (0x01a9, 0x01a9, SentenceCat::Upper),
+    (0x01aa, 0x01ab, SentenceCat::Lower),
+    (0x01ac, 0x01ac, SentenceCat::Upper),
+    (0x01ad, 0x01ad, SentenceCat::Lower),
+    (0x01ae, 0x01af, SentenceCat::Upper),
+    (0x01b0, 0x01b0, SentenceCat::Lower),
+    (0x01b1, 0x01b3, SentenceCat::Upper),
+    (0x01b4, 0x01b4, SentenceCat::Lower),
+    (0x01b5, 0x01b5, SentenceCat::Upper),
+    (0x01b6, 0x01b6, SentenceCat::Lower),
+    (0x01b7, 0x01b8, SentenceCat::Upper),
+    (0x01b9, 0x01ba, SentenceCat::Lower),
+    (0x01bb, 0x01bb, SentenceCat::OLetter),
+    (0x01bc, 0x01bc, SentenceCat::Upper),
+    (0x01bd, 0x01bf, SentenceCat::Lower),
+    (0x01c0, 0x01c3, SentenceCat::OLetter),
+    (0x01c4, 0x01c5, SentenceCat::Upper),
+    (0x01c6, 0x01c6, SentenceCat::Lower),
+    (0x01c7, 0x01c8, SentenceCat::Upper),
+    (0x01c9, 0x01c9, SentenceCat::Lower),
+    (0x01ca, 0x01cb, SentenceCat::Upper),
+    (0x01cc, 0x01cc, SentenceCat::Lower),
+    (0x01cd, 0x01cd, SentenceCat::Upper),
+    (0x01ce, 0x01ce, SentenceCat::Lower),
+    (0x01cf, 0x01cf, SentenceCat::Upper),
+    (0x01d0, 0x01d0, SentenceCat::Lower),
+    (0x01d1, 0x01d1, SentenceCat::Upper),
+    (0x01d2, 0x01d2, SentenceCat::Lower),
+    (0x01d3, 0x01d3, SentenceCat::Upper),
+    (0x01d4, 0x01d4, SentenceCat::Lower),
+    (0x01d5, 0x01d5, SentenceCat::Upper),
+    (0x01d6, 0x01d6, SentenceCat::Lower),
+    (0x01d7, 0x01d7, SentenceCat::Upper),
+    (0x01d8, 0x01d8, SentenceCat::Lower),
+    (0x01d9, 0x01d9, SentenceCat::Upper),
+    (0x01da, 0x01da, SentenceCat::Lower),
+    (0x01db, 0x01db, SentenceCat::Upper),
+    (0x01dc, 0x01dd, SentenceCat::Lower),
+    (0x01de, 0x01de, SentenceCat::Upper),
+    (0x01df, 0x01df, SentenceCat::Lower),
+    (0x01e0, 0x01e0, SentenceCat::Upper),
+    (0x01e1, 0x01e1, SentenceCat::Lower),
+    (0x01e2, 0x01e2, SentenceCat::Upper),
+    (0x01e3, 0x01e3, SentenceCat::Lower),
+    (0x01e4, 0x01e4, SentenceCat::Upper),
+    (0x01e5, 0x01e5, SentenceCat::Lower),
+    (0x01e6, 0x01e6, SentenceCat::Upper),
+    (0x01e7, 0x01e7, SentenceCat::Lower),
+    (0x01e8, 0x01e8, SentenceCat::Upper),
+    (0x01e9, 0x01e9, SentenceCat::Lower),
+    (0x01ea, 0x01ea, SentenceCat::Upper),
+    (0x01eb, 0x01eb, SentenceCat::Lower),
+    (0x01ec, 0x01ec, SentenceCat::Upper),
+    (0x01ed, 0x01ed, SentenceCat::Lower),
+    (0x01ee, 0x01ee, SentenceCat::Upper),
+    (0x01ef, 0x01f0, SentenceCat::Lower),
+    (0x01f1, 0x01f2, SentenceCat::Upper),
+    (0x01f3, 0x01f3, SentenceCat::Lower),
+    (0x01f4, 0x01f4, SentenceCat::Upper),
+    (0x01f5, 0x01f5, SentenceCat::Lower),
+    (0x01f6, 0x01f8, SentenceCat::Upper),
+    (0x01f9, 0x01f9, SentenceCat::Lower),
+    (0x01fa, 0x01fa, SentenceCat::Upper),
+    (0x01fb, 0x01fb, SentenceCat::Lower),
+    (0x01fc, 0x01fc, SentenceCat::Upper),
+    (0x01fd, 0x01fd, SentenceCat::Lower),
+    (0x01fe, 0x01fe, SentenceCat::Upper),
+    (0x01ff, 0x01ff, SentenceCat::Lower),
+    (0x0200, 0x0200, SentenceCat::Upper),
+    (0x0201, 0x0201, SentenceCat::Lower),
+    (0x0202, 0x0202, SentenceCat::Upper),
+    (0x0203, 0x0203, SentenceCat::Lower),
+    (0x0204, 0x0204, SentenceCat::Upper),
+    (0x0205, 0x0205, SentenceCat::Lower),
+    (0x0206, 0x0206, SentenceCat::Upper),
+    (0x0207, 0x0207, SentenceCat::Lower),
+    (0x0208, 0x0208, SentenceCat::Upper),
+    (0x0209, 0x0209, SentenceCat::Lower),
+    (0x020a, 0x020a, SentenceCat::Upper),
+    (0x020b, 0x020b, SentenceCat::Lower),
+    (0x020c, 0x020c, SentenceCat::Upper),
+    (0x020d, 0x020d, SentenceCat::Lower),
+    (0x020e, 0x020e, SentenceCat::Upper),
+    (0x020f, 0x020f, SentenceCat::Lower),
+    (0x0210, 0x0210, SentenceCat::Upper),
+    (0x0211, 0x0211, SentenceCat::Lower),
+    (0x0212, 0x0212, SentenceCat::Upper),
+    (0x0213, 0x0213, SentenceCat::Lower),
+    (0x0214, 0x0214, SentenceCat::Upper),
+    (0x0215, 0x0215, SentenceCat::Lower),
+    (0x0216, 0x0216, SentenceCat::Upper),
+    (0x0217, 0x0217, SentenceCat::Lower),
+    (0x0218, 0x0218, SentenceCat::Upper),
+    (0x0219, 0x0219, SentenceCat::Lower),
+    (0x021a, 0x021a, SentenceCat::Upper),
+    (0x021b, 0x021b, SentenceCat::Lower),
+    (0x021c, 0x021c, SentenceCat::Upper),
+    (0x021d, 0x021d, SentenceCat::Lower),
+    (0x021e, 0x021e, SentenceCat::Upper),
+    (0x021f, 0x021f, SentenceCat::Lower),
+    (0x0220, 0x0220, SentenceCat::Upper),
+    (0x0221, 0x0221, SentenceCat::Lower),
+    (0x0222, 0x0222, SentenceCat::Upper),
+    (0x0223, 0x0223, SentenceCat::Lower),
+    (0x0224, 0x0224, SentenceCat::Upper),
+    (0x0225, 0x0225, SentenceCat::Lower),
+    (0x0226, 0x0226, SentenceCat::Upper),
+    (0x0227, 0x0227, SentenceCat::Lower),
+    (0x0228, 0x0228, SentenceCat::Upper),
+    (0x0229, 0x0229, SentenceCat::Lower),
+    (0x022a, 0x022a, SentenceCat::Upper),
+    (0x022b, 0x022b, SentenceCat::Lower),
+    (0x022c, 0x022c, SentenceCat::Upper),
+    (0x022d, 0x022d, SentenceCat::Lower),
+    (0x022e, 0x022e, SentenceCat::Upper),
+    (0x022f, 0x022f, SentenceCat::Lower),
+    (0x0230, 0x0230, SentenceCat::Upper),
+    (0x0231, 0x0231, SentenceCat::Lower),
+    (0x0232, 0x0232, SentenceCat::Upper),
+    (0x0233, 0x0239, SentenceCat::Lower),
+    (0x023a, 0x023b, SentenceCat::Upper),
+    (0x023c, 0x023c, SentenceCat::Lower),
+    (0x023d, 0x023e, SentenceCat::Upper),
+    (0x023f, 0x0240, SentenceCat::Lower),
+    (0x0241, 0x0241, SentenceCat::Upper),
+    (0x0242, 0x0242, SentenceCat::Lower),
+    (0x0243, 0x0246, SentenceCat::Upper),
+    (0x0247, 0x0247, SentenceCat::Lower),
+    (0x0248, 0x0248, SentenceCat::Upper),
+    (0x0249, 0x0249, SentenceCat::Lower),
+    (0x024a, 0x024a, SentenceCat::Upper),
+    (0x024b, 0x024b, SentenceCat::Lower),
+    (0x024c, 0x024c, SentenceCat::Upper),
+    (0x024d, 0x024d, SentenceCat::Lower),
+    (0x024e, 0x024e, SentenceCat::Upper),
+    (0x024f, 0x0293, SentenceCat::Lower),
+    (0x0294, 0x0295, SentenceCat::OLetter),
+    (0x0296, 0x02b8, SentenceCat::Lower),
+    (0x02b9, 0x02bf, SentenceCat::OLetter),
+    (0x02c0, 0x02c1, SentenceCat::Lower),
+    (0x02c6, 0x02d1, SentenceCat::OLetter),
+    (0x02e0, 0x02e4, SentenceCat::Lower),
+    (0x02ec, 0x02ec, SentenceCat::OLetter),
+    (0x02ee, 0x02ee, SentenceCat::OLetter),
+    (0x0300, 0x036f, SentenceCat::Extend),
+    (0x0370, 0x0370, SentenceCat::Upper),
+    (0x0371, 0x0371, SentenceCat::Lower),
+    (0x0372, 0x0372, SentenceCat::Upper),
+    (0x0373, 0x0373, SentenceCat::Lower),
+    (0x0374, 0x0374, SentenceCat::OLetter),
+    (0x0376, 0x0376, SentenceCat::Upper),
+    (0x0377, 0x0377, SentenceCat::Lower),
+    (0x037a, 0x037d, SentenceCat::Lower),
+    (0x037e, 0x037e, SentenceCat::SContinue),
+    (0x037f, 0x037f, SentenceCat::Upper),
+    (0x0386, 0x0386, SentenceCat::Upper),
+    (0x0388, 0x038a, SentenceCat::Upper),
+    (0x038c, 0x038c, SentenceCat::Upper),
+    (0x038e, 0x038f, SentenceCat::Upper),
+    (0x0390, 0x0390, SentenceCat::Lower),
+    (0x0391, 0x03a1, SentenceCat::Upper),
+    (0x03a3, 0x03ab, SentenceCat::Upper),
+    (0x03ac, 0x03ce, SentenceCat::Lower),
+    (0x03cf, 0x03cf, SentenceCat::Upper),
+    (0x03d0, 0x03d1, SentenceCat::Lower),
+    (0x03d2, 0x03d4, SentenceCat::Upper),
+    (0x03d5, 0x03d7, SentenceCat::Lower),
+    (0x03d8, 0x03d8, SentenceCat::Upper),
+    (0x03d9, 0x03d9, SentenceCat::Lower),
+    (0x03da, 0x03da, SentenceCat::Upper),
+    (0x03db, 0x03db, SentenceCat::Lower),
+    (0x03dc, 0x03dc, SentenceCat::Upper),
+    (0x03dd, 0x03dd, SentenceCat::Lower),
+    (0x03de, 0x03de, SentenceCat::Upper),
+    (0x03df, 0x03df, SentenceCat::Lower),
+    (0x03e0, 0x03e0, SentenceCat::Upper),
+    (0x03e1, 0x03e1, SentenceCat::Lower),
+    (0x03e2, 0x03e2, SentenceCat::Upper),
+    (0x03e3, 0x03e3, SentenceCat::Lower),
+    (0x03e4, 0x03e4, SentenceCat::Upper),
+    (0x03e5, 0x03e5, SentenceCat::Lower),
+    (0x03e6, 0x03e6, SentenceCat::Upper),
+    (0x03e7, 0x03e7, SentenceCat::Lower),
+    (0x03e8, 0x03e8, SentenceCat::Upper),
+    (0x03e9, 0x03e9, SentenceCat::Lower),
+    (0x03ea, 0x03ea, SentenceCat::Upper),
+    (0x03eb, 0x03eb, SentenceCat::Lower),
+    (0x03ec, 0x03ec, SentenceCat::Upper),
+    (0x03ed, 0x03ed, SentenceCat::Lower),
+    (0x03ee, 0x03ee, SentenceCat::Upper),
+    (0x03ef, 0x03f3, SentenceCat::Lower),
+    (0x03f4, 0x03f4, SentenceCat::Upper),
+    (0x03f5, 0x03f5, SentenceCat::Lower),
+    (0x03f7, 0x03f7, SentenceCat::Upper),
+    (0x03f8, 0x03f8, SentenceCat::Lower),
+    (0x03f9, 0x03fa, SentenceCat::Upper),
+    (0x03fb, 0x03fc, SentenceCat::Lower),
+    (0x03fd, 0x042f, SentenceCat::Upper),
+    (0x0430, 0x045f, SentenceCat::Lower),
+    (0x0460, 0x0460, SentenceCat::Upper),
+    (0x0461, 0x0461, SentenceCat::Lower),
+    (0x0462, 0x0462, SentenceCat::Upper),
+    (0x0463, 0x0463, SentenceCat::Lower),
+    (0x0464, 0x0464, SentenceCat::Upper),
+    (0x0465, 0x0465, SentenceCat::Lower),
+    (0x0466, 0x0466, SentenceCat::Upper),
+    (0x0467, 0x0467, SentenceCat::Lower),
+    (0x0468, 0x0468, SentenceCat::Upper),
+    (0x0469, 0x0469, SentenceCat::Lower),
+    (0x046a, 0x046a, SentenceCat::Upper),
+    (0x046b, 0x046b, SentenceCat::Lower),
+    (0x046c, 0x046c, SentenceCat::Upper),
+    (0x046d, 0x046d, SentenceCat::Lower),
+    (0x046e, 0x046e, SentenceCat::Upper),
+    (0x046f, 0x046f, SentenceCat::Lower),
+    (0x0470, 0x0470, SentenceCat::Upper),
+    (0x0471, 0x0471, SentenceCat::Lower),
+    (0x0472, 0x0472, SentenceCat::Upper),
+    (0x0473, 0x0473, SentenceCat::Lower),
+    (0x0474, 0x0474, SentenceCat::Upper),
+    (0x0475, 0x0475, SentenceCat::Lower),
+    (0x0476, 0x0476, SentenceCat::Upper),
+    (0x0477, 0x0477, SentenceCat::Lower),
+    (0x0478, 0x0478, SentenceCat::Upper),
+    (0x0479, 0x0479, SentenceCat::Lower),
+    (0x047a, 0x047a, SentenceCat::Upper),
+    (0x047b, 0x047b, SentenceCat::Lower),
+    (0x047c, 0x047c, SentenceCat::Upper),
+    (0x047d, 0x047d, SentenceCat::Lower),
+    (0x047e, 0x047e, SentenceCat::Upper),
+    (0x047f, 0x047f, SentenceCat::Lower),
+    (0x0480, 0x0480, SentenceCat::Upper),
+    (0x0481, 0x0481, SentenceCat::Lower),
+    (0x0483, 0x0489, SentenceCat::Extend),
+    (0x048a, 0x048a, SentenceCat::Upper),
+    (0x048b, 0x048b, SentenceCat::Lower),
+    (0x048c, 0x048c, SentenceCat::Upper),
+    (0x048d, 0x048d, SentenceCat::Lower),
+    (0x048e, 0x048e, SentenceCat::Upper),
+    (0x048f, 0x048f, SentenceCat::Lower),
+    (0x0490, 0x0490, SentenceCat::Upper),
+    (0x0491, 0x0491, SentenceCat::Lower),
+    (0x0492, 0x0492, SentenceCat::Upper),
+    (0x0493, 0x0493, SentenceCat::Lower),
+    (0x0494, 0x0494, SentenceCat::Upper),
+    (0x0495, 0x0495, SentenceCat::Lower),
+    (0x0496, 0x0496, SentenceCat::Upper),
+    (0x0497, 0x0497, SentenceCat::Lower),
+    (0x0498, 0x0498, SentenceCat::Upper),
+    (0x0499, 0x0499, SentenceCat::Lower),
+    (0x049a, 0x049a, SentenceCat::Upper),
+    (0x049b, 0x049b, SentenceCat::Lower),
+    (0x049c, 0x049c, SentenceCat::Upper),
+    (0x049d, 0x049d, SentenceCat::Lower),
+    (0x049e, 0x049e, SentenceCat::Upper),
+    (0x049f, 0x049f, SentenceCat::Lower),
+    (0x04a0, 0x04a0, SentenceCat::Upper),
+    (0x04a1, 0x04a1, SentenceCat::Lower),
+    (0x04a2, 0x04a2, SentenceCat::Upper),
+    (0x04a3, 0x04a3, SentenceCat::Lower),
+    (0x04a4, 0x04a4, SentenceCat::Upper),
+    (0x04a5, 0x04a5, SentenceCat::Lower),
+    (0x04a6, 0x04a6, SentenceCat::Upper),
+    (0x04a7, 0x04a7, SentenceCat::Lower),
+    (0x04a8, 0x04a8, SentenceCat::Upper),
+    (0x04a9, 0x04a9, SentenceCat::Lower),
+    (0x04aa, 0x04aa, SentenceCat::Upper),
+    (0x04ab, 0x04ab, SentenceCat::Lower),
+    (0x04ac, 0x04ac, SentenceCat::Upper),
+    (0x04ad, 0x04ad, SentenceCat::Lower),
+    (0x04ae, 0x04ae, SentenceCat::Upper),
+    (0x04af, 0x04af, SentenceCat::Lower),
+    (0x04b0, 0x04b0, SentenceCat::Upper),
+    (0x04b1, 0x04b1, SentenceCat::Lower),
+    (0x04b2, 0x04b2, SentenceCat::Upper),
+    (0x04b3, 0x04b3, SentenceCat::Lower),
+    (0x04b4, 0x04b4, SentenceCat::Upper),
+    (0x04b5, 0x04b5, SentenceCat::Lower),
+    (0x04b6, 0x04b6, SentenceCat::Upper),
+    (0x04b7, 0x04b7, SentenceCat::Lower),
+    (0x04b8, 0x04b8, SentenceCat::Upper),
+    (0x04b9, 0x04b9, SentenceCat::Lower),
+    (0x04ba, 0x04ba, SentenceCat::Upper),
+    (0x04bb, 0x04bb, SentenceCat::Lower),
+    (0x04bc, 0x04bc, SentenceCat::Upper),
+    (0x04bd, 0x04bd, SentenceCat::Lower),
+    (0x04be, 0x04be, SentenceCat::Upper),
+    (0x04bf, 0x04bf, SentenceCat::Lower),
+    (0x04c0, 0x04c1, SentenceCat::Upper),
+    (0x04c2, 0x04c2, SentenceCat::Lower),
+    (0x04c3, 0x04c3, SentenceCat::Upper),
+    (0x04c4, 0x04c4, SentenceCat::Lower),
+    (0x04c5, 0x04c5, SentenceCat::Upper),
+    (0x04c6, 0x04c6, SentenceCat::Lower),
+    (0x04c7, 0x04c7, SentenceCat::Upper),
+    (0x04c8, 0x04c8, SentenceCat::Lower),
+    (0x04c9, 0x04c9, SentenceCat::Upper),
+    (0x04ca, 0x04ca, SentenceCat::Lower),
+    (0x04cb, 0x04cb, SentenceCat::Upper),
+    (0x04cc, 0x04cc, SentenceCat::Lower),
+    (0x04cd, 0x04cd, SentenceCat::Upper),
+    (0x04ce, 0x04cf, SentenceCat::Lower),
+    (0x04d0, 0x04d0, SentenceCat::Upper),
+    (0x04d1, 0x04d1, SentenceCat::Lower),
+    (0x04d2, 0x04d2, SentenceCat::Upper),
+    (0x04d3, 0x04d3, SentenceCat::Lower),
+    (0x04d4, 0x04d4, SentenceCat::Upper),
+    (0x04d5, 0x04d5, SentenceCat::Lower),
+    (0x04d6, 0x04d6, SentenceCat::Upper),
+    (0x04d7, 0x04d7, SentenceCat::Lower),
+    (0x04d8, 0x04d8, SentenceCat::Upper),
+    (0x04d9, 0x04d9, SentenceCat::Lower),
+    (0x04da, 0x04da, SentenceCat::Upper),
+    (0x04db, 0x04db, SentenceCat::Lower),
+    (0x04dc, 0x04dc, SentenceCat::Upper),
+    (0x04dd, 0x04dd, SentenceCat::Lower),
+    (0x04de, 0x04de, SentenceCat::Upper),
+    (0x04df, 0x04df, SentenceCat::Lower),
+    (0x04e0, 0x04e0, SentenceCat::Upper),
+    (0x04e1, 0x04e1, SentenceCat::Lower),
+    (0x04e2, 0x04e2, SentenceCat::Upper),
+    (0x04e3, 0x04e3, SentenceCat::Lower),
+    (0x04e4, 0x04e4, SentenceCat::Upper),
+    (0x04e5, 0x04e5, SentenceCat::Lower),
+    (0x04e6, 0x04e6, SentenceCat::Upper),
+    (0x04e7, 0x04e7, SentenceCat::Lower),
+    (0x04e8, 0x04e8, SentenceCat::Upper),
+    (0x04e9, 0x04e9, SentenceCat::Lower),
+    (0x04ea, 0x04ea, SentenceCat::Upper),
+    (0x04eb, 0x04eb, SentenceCat::Lower),
+    (0x04ec, 0x04ec, SentenceCat::Upper),
+    (0x04ed, 0x04ed, SentenceCat::Lower),
+    (0x04ee, 0x04ee, SentenceCat::Upper),
+    (0x04ef, 0x04ef, SentenceCat::Lower),
+    (0x04f0, 0x04f0, SentenceCat::Upper),
+    (0x04f1, 0x04f1, SentenceCat::Lower),
+    (0x04f2, 0x04f2, SentenceCat::Upper),
+    (0x04f3, 0x04f3, SentenceCat::Lower),
+    (0x04f4, 0x04f4, SentenceCat::Upper),
+    (0x04f5, 0x04f5, SentenceCat::Lower),
+    (0x04f6, 0x04f6, SentenceCat::Upper),
+    (0x04f7, 0x04f7, SentenceCat::Lower),
+    (0x04f8, 0x04f8, SentenceCat::Upper),
+    (0x04f9, 0x04f9, SentenceCat::Lower),
+    (0x04fa, 0x04fa, SentenceCat::Upper),
+    (0x04fb, 0x04fb, SentenceCat::Lower),
+    (0x04fc, 0x04fc, SentenceCat::Upper),
+    (0x04fd, 0x04fd, SentenceCat::Lower),
+    (0x04fe, 0x04fe, SentenceCat::Upper),
+    (0x04ff, 0x04ff, SentenceCat::Lower),
+    (0x0500, 0x0500, SentenceCat::Upper),
+    (0x0501, 0x0501, SentenceCat::Lower),
+    (0x0502, 0x0502, SentenceCat::Upper),
+    (0x0503, 0x0503, SentenceCat::Lower),
+    (0x0504, 0x0504, SentenceCat::Upper),
+    (0x0505, 0x0505, SentenceCat::Lower),
+    (0x0506, 0x0506, SentenceCat::Upper),
+    (0x0507, 0x0507, SentenceCat::Lower),
+    (0x0508, 0x0508, SentenceCat::Upper),
+    (0x0509, 0x0509, SentenceCat::Lower),
+    (0x050a, 0x050a, SentenceCat::Upper),
+    (0x050b, 0x050b, SentenceCat::Lower),
+    (0x050c, 0x050c, SentenceCat::Upper),
+    (0x050d, 0x050d, SentenceCat::Lower),
+    (0x050e, 0x050e, SentenceCat::Upper),
+    (0x050f, 0x050f, SentenceCat::Lower),
+    (0x0510, 0x0510, SentenceCat::Upper),
+    (0x0511, 0x0511, SentenceCat::Lower),
+    (0x0512, 0x0512, SentenceCat::Upper),
+    (0x0513, 0x0513, SentenceCat::Lower),
+    (0x0514, 0x0514, SentenceCat::Upper),
+    (0x0515, 0x0515, SentenceCat::Lower),
+    (0x0516, 0x0516, SentenceCat::Upper),
+    (0x0517, 0x0517, SentenceCat::Lower),
+    (0x0518, 0x0518, SentenceCat::Upper),
+    (0x0519, 0x0519, SentenceCat::Lower),
+    (0x051a, 0x051a, SentenceCat::Upper),
+    (0x051b, 0x051b, SentenceCat::Lower),
+    (0x051c, 0x051c, SentenceCat::Upper),
+    (0x051d, 0x051d, SentenceCat::Lower),
+    (0x051e, 0x051e, SentenceCat::Upper),
+    (0x051f, 0x051f, SentenceCat::Lower),
+    (0x0520, 0x0520, SentenceCat::Upper),
+    (0x0521, 0x0521, SentenceCat::Lower),
+    (0x0522, 0x0522, SentenceCat::Upper),
+    (0x0523, 0x0523, SentenceCat::Lower),
+    (0x0524, 0x0524, SentenceCat::Upper),
+    (0x0525, 0x0525, SentenceCat::Lower),
+    (0x0526, 0x0526, SentenceCat::Upper),
+    (0x0527, 0x0527, SentenceCat::Lower),
+    (0x0528, 0x0528, SentenceCat::Upper),
+    (0x0529, 0x0529, SentenceCat::Lower),
+    (0x052a, 0x052a, SentenceCat::Upper),
+    (0x052b, 0x052b, SentenceCat::Lower),
+    (0x052c, 0x052c, SentenceCat::Upper),
+    (0x052d, 0x052d, SentenceCat::Lower),
+    (0x052e, 0x052e, SentenceCat::Upper),
+    (0x052f, 0x052f, SentenceCat::Lower),
+    (0x0531, 0x0556, SentenceCat::Upper),
+    (0x0559, 0x0559, SentenceCat::OLetter),
+    (0x055d, 0x055d, SentenceCat::SContinue),
+    (0x0560, 0x0588, SentenceCat::Lower),
+    (0x0589, 0x0589, SentenceCat::STerm),
+    (0x0591, 0x05bd, SentenceCat::Extend),
+    (0x05bf, 0x05bf, SentenceCat::Extend),
+    (0x05c1, 0x05c2, SentenceCat::Extend),
+    (0x05c4, 0x05c5, SentenceCat::Extend),
+    (0x05c7, 0x05c7, SentenceCat::Extend),
+    (0x05d0, 0x05ea, SentenceCat::OLetter),
+    (0x05ef, 0x05f3, SentenceCat::OLetter),
+    (0x0600, 0x0605, SentenceCat::Numeric),
+    (0x060c, 0x060d, SentenceCat::SContinue),
+    (0x0610, 0x061a, SentenceCat::Extend),
+    (0x061c, 0x061c, SentenceCat::Format),
+    (0x061d, 0x061f, SentenceCat::STerm),
+    (0x0620, 0x064a, SentenceCat::OLetter),
+    (0x064b, 0x065f, SentenceCat::Extend),
+    (0x0660, 0x0669, SentenceCat::Numeric),
+    (0x066b, 0x066c, SentenceCat::Numeric),
+    (0x066e, 0x066f, SentenceCat::OLetter),
+    (0x0670, 0x0670, SentenceCat::Extend),
+    (0x0671, 0x06d3, SentenceCat::OLetter),
+    (0x06d4, 0x06d4, SentenceCat::STerm),
+    (0x06d5, 0x06d5, SentenceCat::OLetter),
+    (0x06d6, 0x06dc, SentenceCat::Extend),
+    (0x06dd, 0x06dd, SentenceCat::Numeric),
+    (0x06df, 0x06e4, SentenceCat::Extend),
+    (0x06e5, 0x06e6, SentenceCat::OLetter),
+    (0x06e7, 0x06e8, SentenceCat::Extend),
+    (0x06ea, 0x06ed, SentenceCat::Extend),
+    (0x06ee, 0x06ef, SentenceCat::OLetter),
+    (0x06f0, 0x06f9, SentenceCat::Numeric),
+    (0x06fa, 0x06fc, SentenceCat::OLetter),
+    (0x06ff, 0x06ff, SentenceCat::OLetter),
+    (0x0700, 0x0702, SentenceCat::STerm),
+    (0x070f, 0x070f, SentenceCat::Format),
+    (0x0710, 0x0710, SentenceCat::OLetter),
+    (0x0711, 0x0711, SentenceCat::Extend),
+    (0x0712, 0x072f, SentenceCat::OLetter),
+    (0x0730, 0x074a, SentenceCat::Extend),
+    (0x074d, 0x07a5, SentenceCat::OLetter),
+    (0x07a6, 0x07b0, SentenceCat::Extend),
+    (0x07b1, 0x07b1, SentenceCat::OLetter),
+    (0x07c0, 0x07c9, SentenceCat::Numeric),
+    (0x07ca, 0x07ea, SentenceCat::OLetter),
+    (0x07eb, 0x07f3, SentenceCat::Extend),
+    (0x07f4, 0x07f5, SentenceCat::OLetter),
+    (0x07f8, 0x07f8, SentenceCat::SContinue),
+    (0x07f9, 0x07f9, SentenceCat::STerm),
+    (0x07fa, 0x07fa, SentenceCat::OLetter),
+    (0x07fd, 0x07fd, SentenceCat::Extend),
+    (0x0800, 0x0815, SentenceCat::OLetter),
+    (0x0816, 0x0819, SentenceCat::Extend),
+    (0x081a, 0x081a, SentenceCat::OLetter),
+    (0x081b, 0x0823, SentenceCat::Extend),
+    (0x0824, 0x0824, SentenceCat::OLetter),
+    (0x0825, 0x0827, SentenceCat::Extend),
+    (0x0828, 0x0828, SentenceCat::OLetter),
+    (0x0829, 0x082d, SentenceCat::Extend),
+    (0x0837, 0x0837, SentenceCat::STerm),
+    (0x0839, 0x0839, SentenceCat::STerm),
+    (0x083d, 0x083e, SentenceCat::STerm),
+    (0x0840, 0x0858, SentenceCat::OLetter),
+    (0x0859, 0x085b, SentenceCat::Extend),
+    (0x0860, 0x086a, SentenceCat::OLetter),
+    (0x0870, 0x0887, SentenceCat::OLetter),
+    (0x0889, 0x088f, SentenceCat::OLetter),
+    (0x0890, 0x0891, SentenceCat::Numeric),
+    (0x0897, 0x089f, SentenceCat::Extend),
+    (0x08a0, 0x08c9, SentenceCat::OLetter),
+    (0x08ca, 0x08e1, SentenceCat::Extend),
+    (0x08e2, 0x08e2, SentenceCat::Numeric),
+    (0x08e3, 0x0903, SentenceCat::Extend),
+    (0x0904, 0x0939, SentenceCat::OLetter),
+    (0x093a, 0x093c, SentenceCat::Extend),
+    (0x093d, 0x093d, SentenceCat::OLetter),
+    (0x093e, 0x094f, SentenceCat::Extend),
+    (0x0950, 0x0950, SentenceCat::OLetter),
+    (0x0951, 0x0957, SentenceCat::Extend),
+    (0x0958, 0x0961, SentenceCat::OLetter),
+    (0x0962, 0x0963, SentenceCat::Extend),
+    (0x0964, 0x0965, SentenceCat::STerm),
+    (0x0966, 0x096f, SentenceCat::Numeric),
+    (0x0971, 0x0980, SentenceCat::OLetter),
+    (0x0981, 0x0983, SentenceCat::Extend),
+    (0x0985, 0x098c, SentenceCat::OLetter),
+    (0x098f, 0x0990, SentenceCat::OLetter),
+    (0x0993, 0x09a8, SentenceCat::OLetter),
+    (0x09aa, 0x09b0, SentenceCat::OLetter),
+    (0x09b2, 0x09b2, SentenceCat::OLetter),
+    (0x09b6, 0x09b9, SentenceCat::OLetter),
+    (0x09bc, 0x09bc, SentenceCat::Extend),
+    (0x09bd, 0x09bd, SentenceCat::OLetter),
+    (0x09be, 0x09c4, SentenceCat::Extend),
+    (0x09c7, 0x09c8, SentenceCat::Extend),
+    (0x09cb, 0x09cd, SentenceCat::Extend),
+    (0x09ce, 0x09ce, SentenceCat::OLetter),
+    (0x09d7, 0x09d7, SentenceCat::Extend),
+    (0x09dc, 0x09dd, SentenceCat::OLetter),
+    (0x09df, 0x09e1, SentenceCat::OLetter),
+    (0x09e2, 0x09e3, SentenceCat::Extend),
+    (0x09e6, 0x09ef, SentenceCat::Numeric),
+    (0x09f0, 0x09f1, SentenceCat::OLetter),
+    (0x09fc, 0x09fc, SentenceCat::OLetter),
+    (0x09fe, 0x09fe, SentenceCat::Extend),
+    (0x0a01, 0x0a03, SentenceCat::Extend),
+    (0x0a05, 0x0a0a, SentenceCat::OLetter),
+    (0x0a0f, 0x0a10, SentenceCat::OLetter),
+    (0x0a13, 0x0a28, SentenceCat::OLetter),
+    (0x0a2a, 0x0a30, SentenceCat::OLetter),
+    (0x0a32, 0x0a33, SentenceCat::OLetter),
+    (0x0a35, 0x0a36, SentenceCat::OLetter),
+    (0x0a38, 0x0a39, SentenceCat::OLetter),
+    (0x0a3c, 0x0a3c, SentenceCat::Extend),
+    (0x0a3e, 0x0a42, SentenceCat::Extend),
+    (0x0a47, 0x0a48, SentenceCat::Extend),
+    (0x0a4b, 0x0a4d, SentenceCat::Extend),
+    (0x0a51, 0x0a51, SentenceCat::Extend),
+    (0x0a59, 0x0a5c, SentenceCat::OLetter),
+    (0x0a5e, 0x0a5e, SentenceCat::OLetter),
+    (0x0a66, 0x0a6f, SentenceCat::Numeric),
+    (0x0a70, 0x0a71, SentenceCat::Extend),
+    (0x0a72, 0x0a74, SentenceCat::OLetter),
+    (0x0a75, 0x0a75, SentenceCat::Extend),
+    (0x0a81, 0x0a83, SentenceCat::Extend),
+    (0x0a85, 0x0a8d, SentenceCat::OLetter),
+    (0x0a8f, 0x0a91, SentenceCat::OLetter),
+    (0x0a93, 0x0aa8, SentenceCat::OLetter),
+    (0x0aaa, 0x0ab0, SentenceCat::OLetter),
+    (0x0ab2, 0x0ab3, SentenceCat::OLetter),
+    (0x0ab5, 0x0ab9, SentenceCat::OLetter),
+    (0x0abc, 0x0abc, SentenceCat::Extend),
+    (0x0abd, 0x0abd, SentenceCat::OLetter),
+    (0x0abe, 0x0ac5, SentenceCat::Extend),
+    (0x0ac7, 0x0ac9, SentenceCat::Extend),
+    (0x0acb, 0x0acd, SentenceCat::Extend),
+    (0x0ad0, 0x0ad0, SentenceCat::OLetter),
+    (0x0ae0, 0x0ae1, SentenceCat::OLetter),
+    (0x0ae2, 0x0ae3, SentenceCat::Extend),
+    (0x0ae6, 0x0aef, SentenceCat::Numeric),
+    (0x0af9, 0x0af9, SentenceCat::OLetter),
+    (0x0afa, 0x0aff, SentenceCat::Extend),
+    (0x0b01, 0x0b03, SentenceCat::Extend),
+    (0x0b05, 0x0b0c, SentenceCat::OLetter),
+    (0x0b0f, 0x0b10, SentenceCat::OLetter),
+    (0x0b13, 0x0b28, SentenceCat::OLetter),
+    (0x0b2a, 0x0b30, SentenceCat::OLetter),
+    (0x0b32, 0x0b33, SentenceCat::OLetter),
+    (0x0b35, 0x0b39, SentenceCat::OLetter),
+    (0x0b3c, 0x0b3c, SentenceCat::Extend),
+    (0x0b3d, 0x0b3d, SentenceCat::OLetter),
+    (0x0b3e, 0x0b44, SentenceCat::Extend),
+    (0x0b47, 0x0b48, SentenceCat::Extend),
+    (0x0b4b, 0x0b4d, SentenceCat::Extend),
+    (0x0b55, 0x0b57, SentenceCat::Extend),
+    (0x0b5c, 0x0b5d, SentenceCat::OLetter),
+    (0x0b5f, 0x0b61, SentenceCat::OLetter),
+    (0x0b62, 0x0b63, SentenceCat::Extend),
+    (0x0b66, 0x0b6f, SentenceCat::Numeric),
+    (0x0b71, 0x0b71, SentenceCat::OLetter),
+    (0x0b82, 0x0b82, SentenceCat::Extend),
+    (0x0b83, 0x0b83, SentenceCat::OLetter),
+    (0x0b85, 0x0b8a, SentenceCat::OLetter),
+    (0x0b8e, 0x0b90, SentenceCat::OLetter),
+    (0x0b92, 0x0b95, SentenceCat::OLetter),
+    (0x0b99, 0x0b9a, SentenceCat::OLetter),
+    (0x0b9c, 0x0b9c, SentenceCat::OLetter),
+    (0x0b9e, 0x0b9f, SentenceCat::OLetter),
+    (0x0ba3, 0x0ba4, SentenceCat::OLetter),
+    (0x0ba8, 0x0baa, SentenceCat::OLetter),
+    (0x0bae, 0x0bb9, SentenceCat::OLetter),
+    (0x0bbe, 0x0bc2, SentenceCat::Extend),
+    (0x0bc6, 0x0bc8, SentenceCat::Extend),
+    (0x0bca, 0x0bcd, SentenceCat::Extend),
+    (0x0bd0, 0x0bd0, SentenceCat::OLetter),
+    (0x0bd7, 0x0bd7, SentenceCat::Extend),
+    (0x0be6, 0x0bef, SentenceCat::Numeric),
+    (0x0c00, 0x0c04, SentenceCat::Extend),
+    (0x0c05, 0x0c0c, SentenceCat::OLetter),
+    (0x0c0e, 0x0c10, SentenceCat::OLetter),
+    (0x0c12, 0x0c28, SentenceCat::OLetter),
+    (0x0c2a, 0x0c39, SentenceCat::OLetter),
+    (0x0c3c, 0x0c3c, SentenceCat::Extend),
+    (0x0c3d, 0x0c3d, SentenceCat::OLetter),
+    (0x0c3e, 0x0c44, SentenceCat::Extend),
+    (0x0c46, 0x0c48, SentenceCat::Extend),
+    (0x0c4a, 0x0c4d, SentenceCat::Extend),
+    (0x0c55, 0x0c56, SentenceCat::Extend),
+    (0x0c58, 0x0c5a, SentenceCat::OLetter),
+    (0x0c5c, 0x0c5d, SentenceCat::OLetter),
+    (0x0c60, 0x0c61, SentenceCat::OLetter),
+    (0x0c62, 0x0c63, SentenceCat::Extend),
+    (0x0c66, 0x0c6f, SentenceCat::Numeric),
+    (0x0c80, 0x0c80, SentenceCat::OLetter),
+    (0x0c81, 0x0c83, SentenceCat::Extend),
+    (0x0c85, 0x0c8c, SentenceCat::OLetter),
+    (0x0c8e, 0x0c90, SentenceCat::OLetter),
+    (0x0c92, 0x0ca8, SentenceCat::OLetter),
+    (0x0caa, 0x0cb3, SentenceCat::OLetter),
+    (0x0cb5, 0x0cb9, SentenceCat::OLetter),
+    (0x0cbc, 0x0cbc, SentenceCat::Extend),
+    (0x0cbd, 0x0cbd, SentenceCat::OLetter),
+    (0x0cbe, 0x0cc4, SentenceCat::Extend),
+    (0x0cc6, 0x0cc8, SentenceCat::Extend),
+    (0x0cca, 0x0ccd, SentenceCat::Extend),
+    (0x0cd5, 0x0cd6, SentenceCat::Extend),
+    (0x0cdc, 0x0cde, SentenceCat::OLetter),
+    (0x0ce0, 0x0ce1, SentenceCat::OLetter),
+    (0x0ce2, 0x0ce3, SentenceCat::Extend),
+    (0x0ce6, 0x0cef, SentenceCat::Numeric),
+    (0x0cf1, 0x0cf2, SentenceCat::OLetter),
+    (0x0cf3, 0x0cf3, SentenceCat::Extend),
+    (0x0d00, 0x0d03, SentenceCat::Extend),
+    (0x0d04, 0x0d0c, SentenceCat::OLetter),
+    (0x0d0e, 0x0d10, SentenceCat::OLetter),
+    (0x0d12, 0x0d3a, SentenceCat::OLetter),
+    (0x0d3b, 0x0d3c, SentenceCat::Extend),
+    (0x0d3d, 0x0d3d, SentenceCat::OLetter),
+    (0x0d3e, 0x0d44, SentenceCat::Extend),
+    (0x0d46, 0x0d48, SentenceCat::Extend),
+    (0x0d4a, 0x0d4d, SentenceCat::Extend),
+    (0x0d4e, 0x0d4e, SentenceCat::OLetter),
+    (0x0d54, 0x0d56, SentenceCat::OLetter),
+    (0x0d57, 0x0d57, SentenceCat::Extend),
+    (0x0d5f, 0x0d61, SentenceCat::OLetter),
+    (0x0d62, 0x0d63, SentenceCat::Extend),
+    (0x0d66, 0x0d6f, SentenceCat::Numeric),
+    (0x0d7a, 0x0d7f, SentenceCat::OLetter),
+    (0x0d81, 0x0d83, SentenceCat::Extend),
+    (0x0d85, 0x0d96, SentenceCat::OLetter),
+    (0x0d9a, 0x0db1, SentenceCat::OLetter),
+    (0x0db3, 0x0dbb, SentenceCat::OLetter),
+    (0x0dbd, 0x0dbd, SentenceCat::OLetter),
+    (0x0dc0, 0x0dc6, SentenceCat::OLetter),
+    (0x0dca, 0x0dca, SentenceCat::Extend),
+    (0x0dcf, 0x0dd4, SentenceCat::Extend),
+    (0x0dd6, 0x0dd6, SentenceCat::Extend),
+    (0x0dd8, 0x0ddf, SentenceCat::Extend),
+    (0x0de6, 0x0def, SentenceCat::Numeric),
+    (0x0df2, 0x0df3, SentenceCat::Extend),
+    (0x0e01, 0x0e30, SentenceCat::OLetter),
+    (0x0e31, 0x0e31, SentenceCat::Extend),
+    (0x0e32, 0x0e33, SentenceCat::OLetter),
+    (0x0e34, 0x0e3a, SentenceCat::Extend),
+    (0x0e40, 0x0e46, SentenceCat::OLetter),
+    (0x0e47, 0x0e4e, SentenceCat::Extend),
+    (0x0e50, 0x0e59, SentenceCat::Numeric),
+    (0x0e81, 0x0e82, SentenceCat::OLetter),
+    (0x0e84, 0x0e84, SentenceCat::OLetter),
+    (0x0e86, 0x0e8a, SentenceCat::OLetter),
+    (0x0e8c, 0x0ea3, SentenceCat::OLetter),
+    (0x0ea5, 0x0ea5, SentenceCat::OLetter),
+    (0x0ea7, 0x0eb0, SentenceCat::OLetter),
+    (0x0eb1, 0x0eb1, SentenceCat::Extend),
+    (0x0eb2, 0x0eb3, SentenceCat::OLetter),
+    (0x0eb4, 0x0ebc, SentenceCat::Extend),
+    (0x0ebd, 0x0ebd, SentenceCat::OLetter),
+    (0x0ec0, 0x0ec4, SentenceCat::OLetter),
+    (0x0ec6, 0x0ec6, SentenceCat::OLetter),
+    (0x0ec8, 0x0ece, SentenceCat::Extend),
+    (0x0ed0, 0x0ed9, SentenceCat::Numeric),
+    (0x0edc, 0x0edf, SentenceCat::OLetter),
+    (0x0f00, 0x0f00, SentenceCat::OLetter),
+    (0x0f18, 0x0f19, SentenceCat::Extend),
+    (0x0f20, 0x0f29, SentenceCat::Numeric),
+    (0x0f35, 0x0f35, SentenceCat::Extend),
+    (0x0f37, 0x0f37, SentenceCat::Extend),
+    (0x0f39, 0x0f39, SentenceCat::Extend),
+    (0x0f3a, 0x0f3d, SentenceCat::Close),
+    (0x0f3e, 0x0f3f, SentenceCat::Extend),
+    (0x0f40, 0x0f47, SentenceCat::OLetter),
+    (0x0f49, 0x0f6c, SentenceCat::OLetter),
+    (0x0f71, 0x0f84, SentenceCat::Extend),
+    (0x0f86, 0x0f87, SentenceCat::Extend),
+    (0x0f88, 0x0f8c, SentenceCat::OLetter),
+    (0x0f8d, 0x0f97, SentenceCat::Extend),
+    (0x0f99, 0x0fbc, SentenceCat::Extend),
+    (0x0fc6, 0x0fc6, SentenceCat::Extend),
+    (0x1000, 0x102a, SentenceCat::OLetter),
+    (0x102b, 0x103e, SentenceCat::Extend),
+    (0x103f, 0x103f, SentenceCat::OLetter),
+    (0x1040, 0x1049, SentenceCat::Numeric),
+    (0x104a, 0x104b, SentenceCat::STerm),
+    (0x1050, 0x1055, SentenceCat::OLetter),
+    (0x1056, 0x1059, SentenceCat::Extend),
+    (0x105a, 0x105d, SentenceCat::OLetter),
+    (0x105e, 0x1060, SentenceCat::Extend),
+    (0x1061, 0x1061, SentenceCat::OLetter),
+    (0x1062, 0x1064, SentenceCat::Extend),
+    (0x1065, 0x1066, SentenceCat::OLetter),
+    (0x1067, 0x106d, SentenceCat::Extend),
+    (0x106e, 0x1070, SentenceCat::OLetter),
+    (0x1071, 0x1074, SentenceCat::Extend),
+    (0x1075, 0x1081, SentenceCat::OLetter),
+    (0x1082, 0x108d, SentenceCat::Extend),
+    (0x108e, 0x108e, SentenceCat::OLetter),
+    (0x108f, 0x108f, SentenceCat::Extend),
+    (0x1090, 0x1099, SentenceCat::Numeric),
+    (0x109a, 0x109d, SentenceCat::Extend),
+    (0x10a0, 0x10c5, SentenceCat::Upper),
+    (0x10c7, 0x10c7, SentenceCat::Upper),
+    (0x10cd, 0x10cd, SentenceCat::Upper),
+    (0x10d0, 0x10fa, SentenceCat::OLetter),
+    (0x10fc, 0x10fc, SentenceCat::Lower),
+    (0x10fd, 0x1248, SentenceCat::OLetter),
+    (0x124a, 0x124d, SentenceCat::OLetter),
+    (0x1250, 0x1256, SentenceCat::OLetter),
+    (0x1258, 0x1258, SentenceCat::OLetter),
+    (0x125a, 0x125d, SentenceCat::OLetter),
+    (0x1260, 0x1288, SentenceCat::OLetter),
+    (0x128a, 0x128d, SentenceCat::OLetter),
+    (0x1290, 0x12b0, SentenceCat::OLetter),
+    (0x12b2, 0x12b5, SentenceCat::OLetter),
+    (0x12b8, 0x12be, SentenceCat::OLetter),
+    (0x12c0, 0x12c0, SentenceCat::OLetter),
+    (0x12c2, 0x12c5, SentenceCat::OLetter),
+    (0x12c8, 0x12d6, SentenceCat::OLetter),
+    (0x12d8, 0x1310, SentenceCat::OLetter),
+    (0x1312, 0x1315, SentenceCat::OLetter),
+    (0x1318, 0x135a, SentenceCat::OLetter),
+    (0x135d, 0x135f, SentenceCat::Extend),
+    (0x1362, 0x1362, SentenceCat::STerm),
+    (0x1367, 0x1368, SentenceCat::STerm),
+    (0x1380, 0x138f, SentenceCat::OLetter),
+    (0x13a0, 0x13f5, SentenceCat::Upper),
+    (0x13f8, 0x13fd, SentenceCat::Lower),
+    (0x1401, 0x166c, SentenceCat::OLetter),
+    (0x166e, 0x166e, SentenceCat::STerm),
+    (0x166f, 0x167f, SentenceCat::OLetter),
+    (0x1680, 0x1680, SentenceCat::Sp),
+    (0x1681, 0x169a, SentenceCat::OLetter),
+    (0x169b, 0x169c, SentenceCat::Close),
+    (0x16a0, 0x16ea, SentenceCat::OLetter),
+    (0x16ee, 0x16f8, SentenceCat::OLetter),
+    (0x1700, 0x1711, SentenceCat::OLetter),
+    (0x1712, 0x1715, SentenceCat::Extend),
+    (0x171f, 0x1731, SentenceCat::OLetter),
+    (0x1732, 0x1734, SentenceCat::Extend),
+    (0x1735, 0x1736, SentenceCat::STerm),
+    (0x1740, 0x1751, SentenceCat::OLetter),
+    (0x1752, 0x1753, SentenceCat::Extend),
+    (0x1760, 0x176c, SentenceCat::OLetter),
+    (0x176e, 0x1770, SentenceCat::OLetter),
+    (0x1772, 0x1773, SentenceCat::Extend),
+    (0x1780, 0x17b3, SentenceCat::OLetter),
+    (0x17b4, 0x17d3, SentenceCat::Extend),
+    (0x17d4, 0x17d5, SentenceCat::STerm),
+    (0x17d7, 0x17d7, SentenceCat::OLetter),
+    (0x17dc, 0x17dc, SentenceCat::OLetter),
+    (0x17dd, 0x17dd, SentenceCat::Extend),
+    (0x17e0, 0x17e9, SentenceCat::Numeric),
+    (0x1802, 0x1802, SentenceCat::SContinue),
+    (0x1803, 0x1803, SentenceCat::STerm),
+    (0x1808, 0x1808, SentenceCat::SContinue),
+    (0x1809, 0x1809, SentenceCat::STerm),
+    (0x180b, 0x180d, SentenceCat::Extend),
+    (0x180e, 0x180e, SentenceCat::Format),
+    (0x180f, 0x180f, SentenceCat::Extend),
+    (0x1810, 0x1819, SentenceCat::Numeric),
+    (0x1820, 0x1878, SentenceCat::OLetter),
+    (0x1880, 0x1884, SentenceCat::OLetter),
+    (0x1885, 0x1886, SentenceCat::Extend),
+    (0x1887, 0x18a8, SentenceCat::OLetter),
+    (0x18a9, 0x18a9, SentenceCat::Extend),
+    (0x18aa, 0x18aa, SentenceCat::OLetter),
+    (0x18b0, 0x18f5, SentenceCat::OLetter),
+    (0x1900, 0x191e, SentenceCat::OLetter),
+    (0x1920, 0x192b, SentenceCat::Extend),
+    (0x1930, 0x193b, SentenceCat::Extend),
+    (0x1944, 0x1945, SentenceCat::STerm),
+    (0x1946, 0x194f, SentenceCat::Numeric),
+    (0x1950, 0x196d, SentenceCat::OLetter),
+    (0x1970, 0x1974, SentenceCat::OLetter),
+    (0x1980, 0x19ab, SentenceCat::OLetter),
+    (0x19b0, 0x19c9, SentenceCat::OLetter),
+    (0x19d0, 0x19da, SentenceCat::Numeric),
+    (0x1a00, 0x1a16, SentenceCat::OLetter),
+    (0x1a17, 0x1a1b, SentenceCat::Extend),
+    (0x1a20, 0x1a54, SentenceCat::OLetter),
+    (0x1a55, 0x1a5e, SentenceCat::Extend),
+    (0x1a60, 0x1a7c, SentenceCat::Extend),
+    (0x1a7f, 0x1a7f, SentenceCat::Extend),
+    (0x1a80, 0x1a89, SentenceCat::Numeric),
+    (0x1a90, 0x1a99, SentenceCat::Numeric),
+    (0x1aa7, 0x1aa7, SentenceCat::OLetter),
+    (0x1aa8, 0x1aab, SentenceCat::STerm),
+    (0x1ab0, 0x1add, SentenceCat::Extend),
+    (0x1ae0, 0x1aeb, SentenceCat::Extend),
+    (0x1b00, 0x1b04, SentenceCat::Extend),
+    (0x1b05, 0x1b33, SentenceCat::OLetter),
+    (0x1b34, 0x1b44, SentenceCat::Extend),
+    (0x1b45, 0x1b4c, SentenceCat::OLetter),
+    (0x1b4e, 0x1b4f, SentenceCat::STerm),
+    (0x1b50, 0x1b59, SentenceCat::Numeric),
+    (0x1b5a, 0x1b5b, SentenceCat::STerm),
+    (0x1b5e, 0x1b5f, SentenceCat::STerm),
+    (0x1b6b, 0x1b73, SentenceCat::Extend),
+    (0x1b7d, 0x1b7f, SentenceCat::STerm),
+    (0x1b80, 0x1b82, SentenceCat::Extend),
+    (0x1b83, 0x1ba0, SentenceCat::OLetter),
+    (0x1ba1, 0x1bad, SentenceCat::Extend),
+    (0x1bae, 0x1baf, SentenceCat::OLetter),
+    (0x1bb0, 0x1bb9, SentenceCat::Numeric),
+    (0x1bba, 0x1be5, SentenceCat::OLetter),
+    (0x1be6, 0x1bf3, SentenceCat::Extend),
+    (0x1c00, 0x1c23, SentenceCat::OLetter),
+    (0x1c24, 0x1c37, SentenceCat::Extend),
+    (0x1c3b, 0x1c3c, SentenceCat::STerm),
+    (0x1c40, 0x1c49, SentenceCat::Numeric),
+    (0x1c4d, 0x1c4f, SentenceCat::OLetter),
+    (0x1c50, 0x1c59, SentenceCat::Numeric),
+    (0x1c5a, 0x1c7d, SentenceCat::OLetter),
+    (0x1c7e, 0x1c7f, SentenceCat::STerm),
+    (0x1c80, 0x1c88, SentenceCat::Lower),
+    (0x1c89, 0x1c89, SentenceCat::Upper),
+    (0x1c8a, 0x1c8a, SentenceCat::Lower),
+    (0x1c90, 0x1cba, SentenceCat::OLetter),
+    (0x1cbd, 0x1cbf, SentenceCat::OLetter),
+    (0x1cd0, 0x1cd2, SentenceCat::Extend),
+    (0x1cd4, 0x1ce8, SentenceCat::Extend),
+    (0x1ce9, 0x1cec, SentenceCat::OLetter),
+    (0x1ced, 0x1ced, SentenceCat::Extend),
+    (0x1cee, 0x1cf3, SentenceCat::OLetter),
+    (0x1cf4, 0x1cf4, SentenceCat::Extend),
+    (0x1cf5, 0x1cf6, SentenceCat::OLetter),
+    (0x1cf7, 0x1cf9, SentenceCat::Extend),
+    (0x1cfa, 0x1cfa, SentenceCat::OLetter),
+    (0x1d00, 0x1dbf, SentenceCat::Lower),
+    (0x1dc0, 0x1dff, SentenceCat::Extend),
+    (0x1e00, 0x1e00, SentenceCat::Upper),
+    (0x1e01, 0x1e01, SentenceCat::Lower),
+    (0x1e02, 0x1e02, SentenceCat::Upper),
+    (0x1e03, 0x1e03, SentenceCat::Lower),
+    (0x1e04, 0x1e04, SentenceCat::Upper),
+    (0x1e05, 0x1e05, SentenceCat::Lower),
+    (0x1e06, 0x1e06, SentenceCat::Upper),
+    (0x1e07, 0x1e07, SentenceCat::Lower),
+    (0x1e08, 0x1e08, SentenceCat::Upper),
+    (0x1e09, 0x1e09, SentenceCat::Lower),
+    (0x1e0a, 0x1e0a, SentenceCat::Upper),
+    (0x1e0b, 0x1e0b, SentenceCat::Lower),
+    (0x1e0c, 0x1e0c, SentenceCat::Upper),
+    (0x1e0d, 0x1e0d, SentenceCat::Lower),
+    (0x1e0e, 0x1e0e, SentenceCat::Upper),
+    (0x1e0f, 0x1e0f, SentenceCat::Lower),
+    (0x1e10, 0x1e10, SentenceCat::Upper),
+    (0x1e11, 0x1e11, SentenceCat::Lower),
+    (0x1e12, 0x1e12, SentenceCat::Upper),
+    (0x1e13, 0x1e13, SentenceCat::Lower),
+    (0x1e14, 0x1e14, SentenceCat::Upper),
+    (0x1e15, 0x1e15, SentenceCat::Lower),
+    (0x1e16, 0x1e16, SentenceCat::Upper),
+    (0x1e17, 0x1e17, SentenceCat::Lower),
+    (0x1e18, 0x1e18, SentenceCat::Upper),
+    (0x1e19, 0x1e19, SentenceCat::Lower),
+    (0x1e1a, 0x1e1a, SentenceCat::Upper),
+    (0x1e1b, 0x1e1b, SentenceCat::Lower),
+    (0x1e1c, 0x1e1c, SentenceCat::Upper),
+    (0x1e1d, 0x1e1d, SentenceCat::Lower),
+    (0x1e1e, 0x1e1e, SentenceCat::Upper),
+    (0x1e1f, 0x1e1f, SentenceCat::Lower),
+    (0x1e20, 0x1e20, SentenceCat::Upper),
+    (0x1e21, 0x1e21, SentenceCat::Lower),
+    (0x1e22, 0x1e22, SentenceCat::Upper),
+    (0x1e23, 0x1e23, SentenceCat::Lower),
+    (0x1e24, 0x1e24, SentenceCat::Upper),
+    (0x1e25, 0x1e25, SentenceCat::Lower),
+    (0x1e26, 0x1e26, SentenceCat::Upper),
+    (0x1e27, 0x1e27, SentenceCat::Lower),
+    (0x1e28, 0x1e28, SentenceCat::Upper),
+    (0x1e29, 0x1e29, SentenceCat::Lower),
+    (0x1e2a, 0x1e2a, SentenceCat::Upper),
+    (0x1e2b, 0x1e2b, SentenceCat::Lower),
+    (0x1e2c, 0x1e2c, SentenceCat::Upper),
+    (0x1e2d, 0x1e2d, SentenceCat::Lower),
+    (0x1e2e, 0x1e2e, SentenceCat::Upper),
+    (0x1e2f, 0x1e2f, SentenceCat::Lower),
+    (0x1e30, 0x1e30, SentenceCat::Upper),
+    (0x1e31, 0x1e31, SentenceCat::Lower),
+    (0x1e32, 0x1e32, SentenceCat::Upper),
+    (0x1e33, 0x1e33, SentenceCat::Lower),
+    (0x1e34, 0x1e34, SentenceCat::Upper),
+    (0x1e35, 0x1e35, SentenceCat::Lower),
+    (0x1e36, 0x1e36, SentenceCat::Upper),
+    (0x1e37, 0x1e37, SentenceCat::Lower),
+    (0x1e38, 0x1e38, SentenceCat::Upper),
+    (0x1e39, 0x1e39, SentenceCat::Lower),
+    (0x1e3a, 0x1e3a, SentenceCat::Upper),
+    (0x1e3b, 0x1e3b, SentenceCat::Lower),
+    (0x1e3c, 0x1e3c, SentenceCat::Upper),
+    (0x1e3d, 0x1e3d, SentenceCat::Lower),
+    (0x1e3e, 0x1e3e, SentenceCat::Upper),
+    (0x1e3f, 0x1e3f, SentenceCat::Lower),
+    (0x1e40, 0x1e40, SentenceCat::Upper),
+    (0x1e41, 0x1e41, SentenceCat::Lower),
+    (0x1e42, 0x1e42, SentenceCat::Upper),
+    (0x1e43, 0x1e43, SentenceCat::Lower),
+    (0x1e44, 0x1e44, SentenceCat::Upper),
+    (0x1e45, 0x1e45, SentenceCat::Lower),
+    (0x1e46, 0x1e46, SentenceCat::Upper),
+    (0x1e47, 0x1e47, SentenceCat::Lower),
+    (0x1e48, 0x1e48, SentenceCat::Upper),
+    (0x1e49, 0x1e49, SentenceCat::Lower),
+    (0x1e4a, 0x1e4a, SentenceCat::Upper),
+    (0x1e4b, 0x1e4b, SentenceCat::Lower),
+    (0x1e4c, 0x1e4c, SentenceCat::Upper),
+    (0x1e4d, 0x1e4d, SentenceCat::Lower),
+    (0x1e4e, 0x1e4e, SentenceCat::Upper),
+    (0x1e4f, 0x1e4f, SentenceCat::Lower),
+    (0x1e50, 0x1e50, SentenceCat::Upper),
+    (0x1e51, 0x1e51, SentenceCat::Lower),
+    (0x1e52, 0x1e52, SentenceCat::Upper),
+    (0x1e53, 0x1e53, SentenceCat::Lower),
+    (0x1e54, 0x1e54, SentenceCat::Upper),
+    (0x1e55, 0x1e55, SentenceCat::Lower),
+    (0x1e56, 0x1e56, SentenceCat::Upper),
+    (0x1e57, 0x1e57, SentenceCat::Lower),
+    (0x1e58, 0x1e58, SentenceCat::Upper),
+    (0x1e59, 0x1e59, SentenceCat::Lower),
+    (0x1e5a, 0x1e5a, SentenceCat::Upper),
+    (0x1e5b, 0x1e5b, SentenceCat::Lower),
+    (0x1e5c, 0x1e5c, SentenceCat::Upper),
+    (0x1e5d, 0x1e5d, SentenceCat::Lower),
+    (0x1e5e, 0x1e5e, SentenceCat::Upper),
+    (0x1e5f, 0x1e5f, SentenceCat::Lower),
+    (0x1e60, 0x1e60, SentenceCat::Upper),
+    (0x1e61, 0x1e61, SentenceCat::Lower),
+    (0x1e62, 0x1e62, SentenceCat::Upper),
+    (0x1e63, 0x1e63, SentenceCat::Lower),
+    (0x1e64, 0x1e64, SentenceCat::Upper),
+    (0x1e65, 0x1e65, SentenceCat::Lower),
+    (0x1e66, 0x1e66, SentenceCat::Upper),
+    (0x1e67, 0x1e67, SentenceCat::Lower),
+    (0x1e68, 0x1e68, SentenceCat::Upper),
+    (0x1e69, 0x1e69, SentenceCat::Lower),
+    (0x1e6a, 0x1e6a, SentenceCat::Upper),
+    (0x1e6b, 0x1e6b, SentenceCat::Lower),
+    (0x1e6c, 0x1e6c, SentenceCat::Upper),
+    (0x1e6d, 0x1e6d, SentenceCat::Lower),
+    (0x1e6e, 0x1e6e, SentenceCat::Upper),
+    (0x1e6f, 0x1e6f, SentenceCat::Lower),
+    (0x1e70, 0x1e70, SentenceCat::Upper),
+    (0x1e71, 0x1e71, SentenceCat::Lower),
+    (0x1e72, 0x1e72, SentenceCat::Upper),
+    (0x1e73, 0x1e73, SentenceCat::Lower),
+    (0x1e74, 0x1e74, SentenceCat::Upper),
+    (0x1e75, 0x1e75, SentenceCat::Lower),
+    (0x1e76, 0x1e76, SentenceCat::Upper),
+    (0x1e77, 0x1e77, SentenceCat::Lower),
+    (0x1e78, 0x1e78, SentenceCat::Upper),
+    (0x1e79, 0x1e79, SentenceCat::Lower),
+    (0x1e7a, 0x1e7a, SentenceCat::Upper),
+    (0x1e7b, 0x1e7b, SentenceCat::Lower),
+    (0x1e7c, 0x1e7c, SentenceCat::Upper),
+    (0x1e7d, 0x1e7d, SentenceCat::Lower),
+    (0x1e7e, 0x1e7e, SentenceCat::Upper),
+    (0x1e7f, 0x1e7f, SentenceCat::Lower),
+    (0x1e80, 0x1e80, SentenceCat::Upper),
+    (0x1e81, 0x1e81, SentenceCat::Lower),
+    (0x1e82, 0x1e82, SentenceCat::Upper),
+    (0x1e83, 0x1e83, SentenceCat::Lower),
+    (0x1e84, 0x1e84, SentenceCat::Upper),
+    (0x1e85, 0x1e85, SentenceCat::Lower),
+    (0x1e86, 0x1e86, SentenceCat::Upper),
+    (0x1e87, 0x1e87, SentenceCat::Lower),
+    (0x1e88, 0x1e88, SentenceCat::Upper),
+    (0x1e89, 0x1e89, SentenceCat::Lower),
+    (0x1e8a, 0x1e8a, SentenceCat::Upper),
+    (0x1e8b, 0x1e8b, SentenceCat::Lower),
+    (0x1e8c, 0x1e8c, SentenceCat::Upper),
+    (0x1e8d, 0x1e8d, SentenceCat::Lower),
+    (0x1e8e, 0x1e8e, SentenceCat::Upper),
+    (0x1e8f, 0x1e8f, SentenceCat::Lower),
+    (0x1e90, 0x1e90, SentenceCat::Upper),
+    (0x1e91, 0x1e91, SentenceCat::Lower),
+    (0x1e92, 0x1e92, SentenceCat::Upper),
+    (0x1e93, 0x1e93, SentenceCat::Lower),
+    (0x1e94, 0x1e94, SentenceCat::Upper),
+    (0x1e95, 0x1e9d, SentenceCat::Lower),
+    (0x1e9e, 0x1e9e, SentenceCat::Upper),
+    (0x1e9f, 0x1e9f, SentenceCat::Lower),
+    (0x1ea0, 0x1ea0, SentenceCat::Upper),
+    (0x1ea1, 0x1ea1, SentenceCat::Lower),
+    (0x1ea2, 0x1ea2, SentenceCat::Upper),
+    (0x1ea3, 0x1ea3, SentenceCat::Lower),
+    (0x1ea4, 0x1ea4, SentenceCat::Upper),
+    (0x1ea5, 0x1ea5, SentenceCat::Lower),
+    (0x1ea6, 0x1ea6, SentenceCat::Upper),
+    (0x1ea7, 0x1ea7, SentenceCat::Lower),
+    (0x1ea8, 0x1ea8, SentenceCat::Upper),
+    (0x1ea9, 0x1ea9, SentenceCat::Lower),
+    (0x1eaa, 0x1eaa, SentenceCat::Upper),
+    (0x1eab, 0x1eab, SentenceCat::Lower),
+    (0x1eac, 0x1eac, SentenceCat::Upper),
+    (0x1ead, 0x1ead, SentenceCat::Lower),
+    (0x1eae, 0x1eae, SentenceCat::Upper),
+    (0x1eaf, 0x1eaf, SentenceCat::Lower),
+    (0x1eb0, 0x1eb0, SentenceCat::Upper),
+    (0x1eb1, 0x1eb1, SentenceCat::Lower),
+    (0x1eb2, 0x1eb2, SentenceCat::Upper),
+    (0x1eb3, 0x1eb3, SentenceCat::Lower),
+    (0x1eb4, 0x1eb4, SentenceCat::Upper),
+    (0x1eb5, 0x1eb5, SentenceCat::Lower),
+    (0x1eb6, 0x1eb6, SentenceCat::Upper),
+    (0x1eb7, 0x1eb7, SentenceCat::Lower),
+    (0x1eb8, 0x1eb8, SentenceCat::Upper),
+    (0x1eb9, 0x1eb9, SentenceCat::Lower),
+    (0x1eba, 0x1eba, SentenceCat::Upper),
+    (0x1ebb, 0x1ebb, SentenceCat::Lower),
+    (0x1ebc, 0x1ebc, SentenceCat::Upper),
+    (0x1ebd, 0x1ebd, SentenceCat::Lower),
+    (0x1ebe, 0x1ebe, SentenceCat::Upper),
+    (0x1ebf, 0x1ebf, SentenceCat::Lower),
+    (0x1ec0, 0x1ec0, SentenceCat::Upper),
+    (0x1ec1, 0x1ec1, SentenceCat::Lower),
+    (0x1ec2, 0x1ec2, SentenceCat::Upper),
+    (0x1ec3, 0x1ec3, SentenceCat::Lower),
+    (0x1ec4, 0x1ec4, SentenceCat::Upper),
+    (0x1ec5, 0x1ec5, SentenceCat::Lower),
+    (0x1ec6, 0x1ec6, SentenceCat::Upper),
+    (0x1ec7, 0x1ec7, SentenceCat::Lower),
+    (0x1ec8, 0x1ec8, SentenceCat::Upper),
+    (0x1ec9, 0x1ec9, SentenceCat::Lower),
+    (0x1eca, 0x1eca, SentenceCat::Upper),
+    (0x1ecb, 0x1ecb, SentenceCat::Lower),
+    (0x1ecc, 0x1ecc, SentenceCat::Upper),
+    (0x1ecd, 0x1ecd, SentenceCat::Lower),
+    (0x1ece, 0x1ece, SentenceCat::Upper),
+    (0x1ecf, 0x1ecf, SentenceCat::Lower),
+    (0x1ed0, 0x1ed0, SentenceCat::Upper),
+    (0x1ed1, 0x1ed1, SentenceCat::Lower),
+    (0x1ed2, 0x1ed2, SentenceCat::Upper),
+    (0x1ed3, 0x1ed3, SentenceCat::Lower),
+    (0x1ed4, 0x1ed4, SentenceCat::Upper),
+    (0x1ed5, 0x1ed5, SentenceCat::Lower),
+    (0x1ed6, 0x1ed6, SentenceCat::Upper),
+    (0x1ed7, 0x1ed7, SentenceCat::Lower),
+    (0x1ed8, 0x1ed8, SentenceCat::Upper),
+    (0x1ed9, 0x1ed9, SentenceCat::Lower),
+    (0x1eda, 0x1eda, SentenceCat::Upper),
+    (0x1edb, 0x1edb, SentenceCat::Lower),
+    (0x1edc, 0x1edc, SentenceCat::Upper),
+    (0x1edd, 0x1edd, SentenceCat::Lower),
+    (0x1ede, 0x1ede, SentenceCat::Upper),
+    (0x1edf, 0x1edf, SentenceCat::Lower),
+    (0x1ee0, 0x1ee0, SentenceCat::Upper),
+    (0x1ee1, 0x1ee1, SentenceCat::Lower),
+    (0x1ee2, 0x1ee2, SentenceCat::Upper),
+    (0x1ee3, 0x1ee3, SentenceCat::Lower),
+    (0x1ee4, 0x1ee4, SentenceCat::Upper),
+    (0x1ee5, 0x1ee5, SentenceCat::Lower),
+    (0x1ee6, 0x1ee6, SentenceCat::Upper),
+    (0x1ee7, 0x1ee7, SentenceCat::Lower),
+    (0x1ee8, 0x1ee8, SentenceCat::Upper),
+    (0x1ee9, 0x1ee9, SentenceCat::Lower),
+    (0x1eea, 0x1eea, SentenceCat::Upper),
+    (0x1eeb, 0x1eeb, SentenceCat::Lower),
+    (0x1eec, 0x1eec, SentenceCat::Upper),
+    (0x1eed, 0x1eed, SentenceCat::Lower),
+    (0x1eee, 0x1eee, SentenceCat::Upper),
+    (0x1eef, 0x1eef, SentenceCat::Lower),
+    (0x1ef0, 0x1ef0, SentenceCat::Upper),
+    (0x1ef1, 0x1ef1, SentenceCat::Lower),
+    (0x1ef2, 0x1ef2, SentenceCat::Upper),
+    (0x1ef3, 0x1ef3, SentenceCat::Lower),
+    (0x1ef4, 0x1ef4, SentenceCat::Upper),
+    (0x1ef5, 0x1ef5, SentenceCat::Lower),
+    (0x1ef6, 0x1ef6, SentenceCat::Upper),
+    (0x1ef7, 0x1ef7, SentenceCat::Lower),
+    (0x1ef8, 0x1ef8, SentenceCat::Upper),
+    (0x1ef9, 0x1ef9, SentenceCat::Lower),
+    (0x1efa, 0x1efa, SentenceCat::Upper),
+    (0x1efb, 0x1efb, SentenceCat::Lower),
+    (0x1efc, 0x1efc, SentenceCat::Upper),
+    (0x1efd, 0x1efd, SentenceCat::Lower),
+    (0x1efe, 0x1efe, SentenceCat::Upper),
+    (0x1eff, 0x1f07, SentenceCat::Lower),
+    (0x1f08, 0x1f0f, SentenceCat::Upper),
+    (0x1f10, 0x1f15, SentenceCat::Lower),
+    (0x1f18, 0x1f1d, SentenceCat::Upper),
+    (0x1f20, 0x1f27, SentenceCat::Lower),
+    (0x1f28, 0x1f2f, SentenceCat::Upper),
+    (0x1f30, 0x1f37, SentenceCat::Lower),
+    (0x1f38, 0x1f3f, SentenceCat::Upper),
+    (0x1f40, 0x1f45, SentenceCat::Lower),
+    (0x1f48, 0x1f4d, SentenceCat::Upper),
+    (0x1f50, 0x1f57, SentenceCat::Lower),
+    (0x1f59, 0x1f59, SentenceCat::Upper),
+    (0x1f5b, 0x1f5b, SentenceCat::Upper),
+    (0x1f5d, 0x1f5d, SentenceCat::Upper),
+    (0x1f5f, 0x1f5f, SentenceCat::Upper),
+    (0x1f60, 0x1f67, SentenceCat::Lower),
+    (0x1f68, 0x1f6f, SentenceCat::Upper),
+    (0x1f70, 0x1f7d, SentenceCat::Lower),
+    (0x1f80, 0x1f87, SentenceCat::Lower),
+    (0x1f88, 0x1f8f, SentenceCat::Upper),
+    (0x1f90, 0x1f97, SentenceCat::Lower),
+    (0x1f98, 0x1f9f, SentenceCat::Upper),
+    (0x1fa0, 0x1fa7, SentenceCat::Lower),
+    (0x1fa8, 0x1faf, SentenceCat::Upper),
+    (0x1fb0, 0x1fb4, SentenceCat::Lower),
+    (0x1fb6, 0x1fb7, SentenceCat::Lower),
+    (0x1fb8, 0x1fbc, SentenceCat::Upper),
+    (0x1fbe, 0x1fbe, SentenceCat::Lower),
+    (0x1fc2, 0x1fc4, SentenceCat::Lower),
+    (0x1fc6, 0x1fc7, SentenceCat::Lower),
+    (0x1fc8, 0x1fcc, SentenceCat::Upper),
+    (0x1fd0, 0x1fd3, SentenceCat::Lower),
+    (0x1fd6, 0x1fd7, SentenceCat::Lower),
+    (0x1fd8, 0x1fdb, SentenceCat::Upper),
+    (0x1fe0, 0x1fe7, SentenceCat::Lower),
+    (0x1fe8, 0x1fec, SentenceCat::Upper),
+    (0x1ff2, 0x1ff4, SentenceCat::Lower),
+    (0x1ff6, 0x1ff7, SentenceCat::Lower),
+    (0x1ff8, 0x1ffc, SentenceCat::Upper),
+    (0x2000, 0x200a, SentenceCat::Sp),
+    (0x200b, 0x200b, SentenceCat::Format),
+    (0x200c, 0x200d, SentenceCat::Extend),
+    (0x200e, 0x200f, SentenceCat::Format),
+    (0x2013, 0x2014, SentenceCat::SContinue),
+    (0x2018, 0x201f, SentenceCat::Close),
+    (0x2024, 0x2024, SentenceCat::ATerm),
+    (0x2028, 0x2029, SentenceCat::Sep),
+    (0x202a, 0x202e, SentenceCat::Format),
+    (0x202f, 0x202f, SentenceCat::Sp),
+    (0x2039, 0x203a, SentenceCat::Close),
+    (0x203c, 0x203d, SentenceCat::STerm),
+    (0x2045, 0x2046, SentenceCat::Close),
+    (0x2047, 0x2049, SentenceCat::STerm),
+    (0x205f, 0x205f, SentenceCat::Sp),
+    (0x2060, 0x2064, SentenceCat::Format),
+    (0x2066, 0x206f, SentenceCat::Format),
+    (0x2071, 0x2071, SentenceCat::Lower),
+    (0x207d, 0x207e, SentenceCat::Close),
+    (0x207f, 0x207f, SentenceCat::Lower),
+    (0x208d, 0x208e, SentenceCat::Close),
+    (0x2090, 0x209c, SentenceCat::Lower),
+    (0x20d0, 0x20f0, SentenceCat::Extend),
+    (0x2102, 0x2102, SentenceCat::Upper),
+    (0x2107, 0x2107, SentenceCat::Upper),
+    (0x210a, 0x210a, SentenceCat::Lower),
+    (0x210b, 0x210d, SentenceCat::Upper),
+    (0x210e, 0x210f, SentenceCat::Lower),
+    (0x2110, 0x2112, SentenceCat::Upper),
+    (0x2113, 0x2113, SentenceCat::Lower),
+    (0x2115, 0x2115, SentenceCat::Upper),
+    (0x2119, 0x211d, SentenceCat::Upper),
+    (0x2124, 0x2124, SentenceCat::Upper),
+    (0x2126, 0x2126, SentenceCat::Upper),
+    (0x2128, 0x2128, SentenceCat::Upper),
+    (0x212a, 0x212d, SentenceCat::Upper),
+    (0x212f, 0x212f, SentenceCat::Lower),
+    (0x2130, 0x2133, SentenceCat::Upper),
+    (0x2134, 0x2134, SentenceCat::Lower),
+    (0x2135, 0x2138, SentenceCat::OLetter),
+    (0x2139, 0x2139, SentenceCat::Lower),
+    (0x213c, 0x213d, SentenceCat::Lower),
+    (0x213e, 0x213f, SentenceCat::Upper),
+    (0x2145, 0x2145, SentenceCat::Upper),
+    (0x2146, 0x2149, SentenceCat::Lower),
+    (0x214e, 0x214e, SentenceCat::Lower),
+    (0x2160, 0x216f, SentenceCat::Upper),
+    (0x2170, 0x217f, SentenceCat::Lower),
+    (0x2180, 0x2182, SentenceCat::OLetter),
+    (0x2183, 0x2183, SentenceCat::Upper),
+    (0x2184, 0x2184, SentenceCat::Lower),
+    (0x2185, 0x2188, SentenceCat::OLetter),
+    (0x2308, 0x230b, SentenceCat::Close),
+    (0x2329, 0x232a, SentenceCat::Close),
+    (0x24b6, 0x24cf, SentenceCat::Upper),
+    (0x24d0, 0x24e9, SentenceCat::Lower),
+    (0x275b, 0x2760, SentenceCat::Close),
+    (0x2768, 0x2775, SentenceCat::Close),
+    (0x27c5, 0x27c6, SentenceCat::Close),
+    (0x27e6, 0x27ef, SentenceCat::Close),
+    (0x2983, 0x2998, SentenceCat::Close),
+    (0x29d8, 0x29db, SentenceCat::Close),
+    (0x29fc, 0x29fd, SentenceCat::Close),
+    (0x2c00, 0x2c2f, SentenceCat::Upper),
+    (0x2c30, 0x2c5f, SentenceCat::Lower),
+    (0x2c60, 0x2c60, SentenceCat::Upper),
+    (0x2c61, 0x2c61, SentenceCat::Lower),
+    (0x2c62, 0x2c64, SentenceCat::Upper),
+    (0x2c65, 0x2c66, SentenceCat::Lower),
+    (0x2c67, 0x2c67, SentenceCat::Upper),
+    (0x2c68, 0x2c68, SentenceCat::Lower),
+    (0x2c69, 0x2c69, SentenceCat::Upper),
+    (0x2c6a, 0x2c6a, SentenceCat::Lower),
+    (0x2c6b, 0x2c6b, SentenceCat::Upper),
+    (0x2c6c, 0x2c6c, SentenceCat::Lower),
+    (0x2c6d, 0x2c70, SentenceCat::Upper),
+    (0x2c71, 0x2c71, SentenceCat::Lower),
+    (0x2c72, 0x2c72, SentenceCat::Upper),
+    (0x2c73, 0x2c74, SentenceCat::Lower),
+    (0x2c75, 0x2c75, SentenceCat::Upper),
+    (0x2c76, 0x2c7d, SentenceCat::Lower),
+    (0x2c7e, 0x2c80, SentenceCat::Upper),
+    (0x2c81, 0x2c81, SentenceCat::Lower),
+    (0x2c82, 0x2c82, SentenceCat::Upper),
+    (0x2c83, 0x2c83, SentenceCat::Lower),
+    (0x2c84, 0x2c84, SentenceCat::Upper),
+    (0x2c85, 0x2c85, SentenceCat::Lower),
+    (0x2c86, 0x2c86, SentenceCat::Upper),
+    (0x2c87, 0x2c87, SentenceCat::Lower),
+    (0x2c88, 0x2c88, SentenceCat::Upper),
+    (0x2c89, 0x2c89, SentenceCat::Lower),
+    (0x2c8a, 0x2c8a, SentenceCat::Upper),
+    (0x2c8b, 0x2c8b, SentenceCat::Lower),
+    (0x2c8c, 0x2c8c, SentenceCat::Upper),
+    (0x2c8d, 0x2c8d, SentenceCat::Lower),
+    (0x2c8e, 0x2c8e, SentenceCat::Upper),
+    (0x2c8f, 0x2c8f, SentenceCat::Lower),
+    (0x2c90, 0x2c90, SentenceCat::Upper),
+    (0x2c91, 0x2c91, SentenceCat::Lower),
+    (0x2c92, 0x2c92, SentenceCat::Upper),
+    (0x2c93, 0x2c93, SentenceCat::Lower),
+    (0x2c94, 0x2c94, SentenceCat::Upper),
+    (0x2c95, 0x2c95, SentenceCat::Lower),
+    (0x2c96, 0x2c96, SentenceCat::Upper),
+    (0x2c97, 0x2c97, SentenceCat::Lower),
+    (0x2c98, 0x2c98, SentenceCat::Upper),
+    (0x2c99, 0x2c99, SentenceCat::Lower),
+    (0x2c9a, 0x2c9a, SentenceCat::Upper),
+    (0x2c9b, 0x2c9b, SentenceCat::Lower),
+    (0x2c9c, 0x2c9c, SentenceCat::Upper),
+    (0x2c9d, 0x2c9d, SentenceCat::Lower),
+    (0x2c9e, 0x2c9e, SentenceCat::Upper),
+    (0x2c9f, 0x2c9f, SentenceCat::Lower),
+    (0x2ca0, 0x2ca0, SentenceCat::Upper),
+    (0x2ca1, 0x2ca1, SentenceCat::Lower),
+    (0x2ca2, 0x2ca2, SentenceCat::Upper),
+    (0x2ca3, 0x2ca3, SentenceCat::Lower),
+    (0x2ca4, 0x2ca4, SentenceCat::Upper),
+    (0x2ca5, 0x2ca5, SentenceCat::Lower),
+    (0x2ca6, 0x2ca6, SentenceCat::Upper),
+    (0x2ca7, 0x2ca7, SentenceCat::Lower),
+    (0x2ca8, 0x2ca8, SentenceCat::Upper),
+    (0x2ca9, 0x2ca9, SentenceCat::Lower),
+    (0x2caa, 0x2caa, SentenceCat::Upper),
+    (0x2cab, 0x2cab, SentenceCat::Lower),
+    (0x2cac, 0x2cac, SentenceCat::Upper),
+    (0x2cad, 0x2cad, SentenceCat::Lower),
+    (0x2cae, 0x2cae, SentenceCat::Upper),
+    (0x2caf, 0x2caf, SentenceCat::Lower),
+    (0x2cb0, 0x2cb0, SentenceCat::Upper),
+    (0x2cb1, 0x2cb1, SentenceCat::Lower),
+    (0x2cb2, 0x2cb2, SentenceCat::Upper),
+    (0x2cb3, 0x2cb3, SentenceCat::Lower),
+    (0x2cb4, 0x2cb4, SentenceCat::Upper),
+    (0x2cb5, 0x2cb5, SentenceCat::Lower),
+    (0x2cb6, 0x2cb6, SentenceCat::Upper),
+    (0x2cb7, 0x2cb7, SentenceCat::Lower),
+    (0x2cb8, 0x2cb8, SentenceCat::Upper),
+    (0x2cb9, 0x2cb9, SentenceCat::Lower),
+    (0x2cba, 0x2cba, SentenceCat::Upper),
+    (0x2cbb, 0x2cbb, SentenceCat::Lower),
+    (0x2cbc, 0x2cbc, SentenceCat::Upper),
+    (0x2cbd, 0x2cbd, SentenceCat::Lower),
+    (0x2cbe, 0x2cbe, SentenceCat::Upper),
+    (0x2cbf, 0x2cbf, SentenceCat::Lower),
+    (0x2cc0, 0x2cc0, SentenceCat::Upper),
+    (0x2cc1, 0x2cc1, SentenceCat::Lower),
+    (0x2cc2, 0x2cc2, SentenceCat::Upper),
+    (0x2cc3, 0x2cc3, SentenceCat::Lower),
+    (0x2cc4, 0x2cc4, SentenceCat::Upper),
+    (0x2cc5, 0x2cc5, SentenceCat::Lower),
+    (0x2cc6, 0x2cc6, SentenceCat::Upper),
+    (0x2cc7, 0x2cc7, SentenceCat::Lower),
+    (0x2cc8, 0x2cc8, SentenceCat::Upper),
+    (0x2cc9, 0x2cc9, SentenceCat::Lower),
+    (0x2cca, 0x2cca, SentenceCat::Upper),
+    (0x2ccb, 0x2ccb, SentenceCat::Lower),
+    (0x2ccc, 0x2ccc, SentenceCat::Upper),
+    (0x2ccd, 0x2ccd, SentenceCat::Lower),
+    (0x2cce, 0x2cce, SentenceCat::Upper),
+    (0x2ccf, 0x2ccf, SentenceCat::Lower),
+    (0x2cd0, 0x2cd0, SentenceCat::Upper),
+    (0x2cd1, 0x2cd1, SentenceCat::Lower),
+    (0x2cd2, 0x2cd2, SentenceCat::Upper),
+    (0x2cd3, 0x2cd3, SentenceCat::Lower),
+    (0x2cd4, 0x2cd4, SentenceCat::Upper),
+    (0x2cd5, 0x2cd5, SentenceCat::Lower),
+    (0x2cd6, 0x2cd6, SentenceCat::Upper),
+    (0x2cd7, 0x2cd7, SentenceCat::Lower),
+    (0x2cd8, 0x2cd8, SentenceCat::Upper),
+    (0x2cd9, 0x2cd9, SentenceCat::Lower),
+    (0x2cda, 0x2cda, SentenceCat::Upper),
+    (0x2cdb, 0x2cdb, SentenceCat::Lower),
+    (0x2cdc, 0x2cdc, SentenceCat::Upper),
+    (0x2cdd, 0x2cdd, SentenceCat::Lower),
+    (0x2cde, 0x2cde, SentenceCat::Upper),
+    (0x2cdf, 0x2cdf, SentenceCat::Lower),
+    (0x2ce0, 0x2ce0, SentenceCat::Upper),
+    (0x2ce1, 0x2ce1, SentenceCat::Lower),
+    (0x2ce2, 0x2ce2, SentenceCat::Upper),
+    (0x2ce3, 0x2ce4, SentenceCat::Lower),
+    (0x2ceb, 0x2ceb, SentenceCat::Upper),
+    (0x2cec, 0x2cec, SentenceCat::Lower),
+    (0x2ced, 0x2ced, SentenceCat::Upper),
+    (0x2cee, 0x2cee, SentenceCat::Lower),
+    (0x2cef, 0x2cf1, SentenceCat::Extend),
+    (0x2cf2, 0x2cf2, SentenceCat::Upper),
+    (0x2cf3, 0x2cf3, SentenceCat::Lower),
+    (0x2cf9, 0x2cfb, SentenceCat::STerm),
+    (0x2d00, 0x2d25, SentenceCat::Lower),
+    (0x2d27, 0x2d27, SentenceCat::Lower),
+    (0x2d2d, 0x2d2d, SentenceCat::Lower),
+    (0x2d30, 0x2d67, SentenceCat::OLetter),
+    (0x2d6f, 0x2d6f, SentenceCat::OLetter),
+    (0x2d7f, 0x2d7f, SentenceCat::Extend),
+    (0x2d80, 0x2d96, SentenceCat::OLetter),
+    (0x2da0, 0x2da6, SentenceCat::OLetter),
+    (0x2da8, 0x2dae, SentenceCat::OLetter),
+    (0x2db0, 0x2db6, SentenceCat::OLetter),
+    (0x2db8, 0x2dbe, SentenceCat::OLetter),
+    (0x2dc0, 0x2dc6, SentenceCat::OLetter),
+    (0x2dc8, 0x2dce, SentenceCat::OLetter),
+    (0x2dd0, 0x2dd6, SentenceCat::OLetter),
+    (0x2dd8, 0x2dde, SentenceCat::OLetter),
+    (0x2de0, 0x2dff, SentenceCat::Extend),
+    (0x2e00, 0x2e0d, SentenceCat::Close),
+    (0x2e1c, 0x2e1d, SentenceCat::Close),
+    (0x2e20, 0x2e29, SentenceCat::Close),
+    (0x2e2e, 0x2e2e, SentenceCat::STerm),
+    (0x2e2f, 0x2e2f, SentenceCat::OLetter),
+    (0x2e3c, 0x2e3c, SentenceCat::STerm),
+    (0x2e42, 0x2e42, SentenceCat::Close),
+    (0x2e53, 0x2e54, SentenceCat::STerm),
+    (0x2e55, 0x2e5c, SentenceCat::Close),
+    (0x3000, 0x3000, SentenceCat::Sp),
+    (0x3001, 0x3001, SentenceCat::SContinue),
+    (0x3002, 0x3002, SentenceCat::STerm),
+    (0x3005, 0x3007, SentenceCat::OLetter),
+    (0x3008, 0x3011, SentenceCat::Close),
+    (0x3014, 0x301b, SentenceCat::Close),
+    (0x301d, 0x301f, SentenceCat::Close),
+    (0x3021, 0x3029, SentenceCat::OLetter),
+    (0x302a, 0x302f, SentenceCat::Extend),
+    (0x3031, 0x3035, SentenceCat::OLetter),
+    (0x3038, 0x303c, SentenceCat::OLetter),
+    (0x3041, 0x3096, SentenceCat::OLetter),
+    (0x3099, 0x309a, SentenceCat::Extend),
+    (0x309d, 0x309f, SentenceCat::OLetter),
+    (0x30a1, 0x30fa, SentenceCat::OLetter),
+    (0x30fc, 0x30ff, SentenceCat::OLetter),
+    (0x3105, 0x312f, SentenceCat::OLetter),
+    (0x3131, 0x318e, SentenceCat::OLetter),
+    (0x31a0, 0x31bf, SentenceCat::OLetter),
+    (0x31f0, 0x31ff, SentenceCat::OLetter),
+    (0x3400, 0x4dbf, SentenceCat::OLetter),
+    (0x4e00, 0xa48c, SentenceCat::OLetter),
+    (0xa4d0, 0xa4fd, SentenceCat::OLetter),
+    (0xa4ff, 0xa4ff, SentenceCat::STerm),
+    (0xa500, 0xa60c, SentenceCat::OLetter),
+    (0xa60e, 0xa60f, SentenceCat::STerm),
+    (0xa610, 0xa61f, SentenceCat::OLetter),
+    (0xa620, 0xa629, SentenceCat::Numeric),
+    (0xa62a, 0xa62b, SentenceCat::OLetter),
+    (0xa640, 0xa640, SentenceCat::Upper),
+    (0xa641, 0xa641, SentenceCat::Lower),
+    (0xa642, 0xa642, SentenceCat::Upper),
+    (0xa643, 0xa643, SentenceCat::Lower),
+    (0xa644, 0xa644, SentenceCat::Upper),
+    (0xa645, 0xa645, SentenceCat::Lower),
+    (0xa646, 0xa646, SentenceCat::Upper),
+    (0xa647, 0xa647, SentenceCat::Lower),
+    (0xa648, 0xa648, SentenceCat::Upper),
+    (0xa649, 0xa649, SentenceCat::Lower),
+    (0xa64a, 0xa64a, SentenceCat::Upper),
+    (0xa64b, 0xa64b, SentenceCat::Lower),
+    (0xa64c, 0xa64c, SentenceCat::Upper),
+    (0xa64d, 0xa64d, SentenceCat::Lower),
+    (0xa64e, 0xa64e, SentenceCat::Upper),
+    (0xa64f, 0xa64f, SentenceCat::Lower),
+    (0xa650, 0xa650, SentenceCat::Upper),
+    (0xa651, 0xa651, SentenceCat::Lower),
+    (0xa652, 0xa652, SentenceCat::Upper),
+    (0xa653, 0xa653, SentenceCat::Lower),
+    (0xa654, 0xa654, SentenceCat::Upper),
+    (0xa655, 0xa655, SentenceCat::Lower),
+    (0xa656, 0xa656, SentenceCat::Upper),
+    (0xa657, 0xa657, SentenceCat::Lower),
+    (0xa658, 0xa658, SentenceCat::Upper),
+    (0xa659, 0xa659, SentenceCat::Lower),
+    (0xa65a, 0xa65a, SentenceCat::Upper),
+    (0xa65b, 0xa65b, SentenceCat::Lower),
+    (0xa65c, 0xa65c, SentenceCat::Upper),
+    (0xa65d, 0xa65d, SentenceCat::Lower),
+    (0xa65e, 0xa65e, SentenceCat::Upper),
+    (0xa65f, 0xa65f, SentenceCat::Lower),
+    (0xa660, 0xa660, SentenceCat::Upper),
+    (0xa661, 0xa661, SentenceCat::Lower),
+    (0xa662, 0xa662, SentenceCat::Upper),
+    (0xa663, 0xa663, SentenceCat::Lower),
+    (0xa664, 0xa664, SentenceCat::Upper),
+    (0xa665, 0xa665, SentenceCat::Lower),
+    (0xa666, 0xa666, SentenceCat::Upper),
+    (0xa667, 0xa667, SentenceCat::Lower),
+    (0xa668, 0xa668, SentenceCat::Upper),
+    (0xa669, 0xa669, SentenceCat::Lower),
+    (0xa66a, 0xa66a, SentenceCat::Upper),
+    (0xa66b, 0xa66b, SentenceCat::Lower),
+    (0xa66c, 0xa66c, SentenceCat::Upper),
+    (0xa66d, 0xa66d, SentenceCat::Lower),
+    (0xa66e, 0xa66e, SentenceCat::OLetter),
+    (0xa66f, 0xa672, SentenceCat::Extend),
+    (0xa674, 0xa67d, SentenceCat::Extend),
+    (0xa67f, 0xa67f, SentenceCat::OLetter),
+    (0xa680, 0xa680, SentenceCat::Upper),
+    (0xa681, 0xa681, SentenceCat::Lower),
+    (0xa682, 0xa682, SentenceCat::Upper),
+    (0xa683, 0xa683, SentenceCat::Lower),
+    (0xa684, 0xa684, SentenceCat::Upper),
+    (0xa685, 0xa685, SentenceCat::Lower),
+    (0xa686, 0xa686, SentenceCat::Upper),
+    (0xa687, 0xa687, SentenceCat::Lower),
+    (0xa688, 0xa688, SentenceCat::Upper),
+    (0xa689, 0xa689, SentenceCat::Lower),
+    (0xa68a, 0xa68a, SentenceCat::Upper),
+    (0xa68b, 0xa68b, SentenceCat::Lower),
+    (0xa68c, 0xa68c, SentenceCat::Upper),
+    (0xa68d, 0xa68d, SentenceCat::Lower),
+    (0xa68e, 0xa68e, SentenceCat::Upper),
+    (0xa68f, 0xa68f, SentenceCat::Lower),
+    (0xa690, 0xa690, SentenceCat::Upper),
+    (0xa691, 0xa691, SentenceCat::Lower),
+    (0xa692, 0xa692, SentenceCat::Upper),
+    (0xa693, 0xa693, SentenceCat::Lower),
+    (0xa694, 0xa694, SentenceCat::Upper),
+    (0xa695, 0xa695, SentenceCat::Lower),
+    (0xa696, 0xa696, SentenceCat::Upper),
+    (0xa697, 0xa697, SentenceCat::Lower),
+    (0xa698, 0xa698, SentenceCat::Upper),
+    (0xa699, 0xa699, SentenceCat::Lower),
+    (0xa69a, 0xa69a, SentenceCat::Upper),
+    (0xa69b, 0xa69d, SentenceCat::Lower),
+    (0xa69e, 0xa69f, SentenceCat::Extend),
+    (0xa6a0, 0xa6ef, SentenceCat::OLetter),
+    (0xa6f0, 0xa6f1, SentenceCat::Extend),
+    (0xa6f3, 0xa6f3, SentenceCat::STerm),
+    (0xa6f7, 0xa6f7, SentenceCat::STerm),
+    (0xa717, 0xa71f, SentenceCat::OLetter),
+    (0xa722, 0xa722, SentenceCat::Upper),
+    (0xa723, 0xa723, SentenceCat::Lower),
+    (0xa724, 0xa724, SentenceCat::Upper),
+    (0xa725, 0xa725, SentenceCat::Lower),
+    (0xa726, 0xa726, SentenceCat::Upper),
+    (0xa727, 0xa727, SentenceCat::Lower),
+    (0xa728, 0xa728, SentenceCat::Upper),
+    (0xa729, 0xa729, SentenceCat::Lower),
+    (0xa72a, 0xa72a, SentenceCat::Upper),
+    (0xa72b, 0xa72b, SentenceCat::Lower),
+    (0xa72c, 0xa72c, SentenceCat::Upper),
+    (0xa72d, 0xa72d, SentenceCat::Lower),
+    (0xa72e, 0xa72e, SentenceCat::Upper),
+    (0xa72f, 0xa731, SentenceCat::Lower),
+    (0xa732, 0xa732, SentenceCat::Upper),
+    (0xa733, 0xa733, SentenceCat::Lower),
+    (0xa734, 0xa734, SentenceCat::Upper),
+    (0xa735, 0xa735, SentenceCat::Lower),
+    (0xa736, 0xa736, SentenceCat::Upper),
+    (0xa737, 0xa737, SentenceCat::Lower),
+    (0xa738, 0xa738, SentenceCat::Upper),
+    (0xa739, 0xa739, SentenceCat::Lower),
+    (0xa73a, 0xa73a, SentenceCat::Upper),
+    (0xa73b, 0xa73b, SentenceCat::Lower),
+    (0xa73c, 0xa73c, SentenceCat::Upper),
+    (0xa73d, 0xa73d, SentenceCat::Lower),
+    (0xa73e, 0xa73e, SentenceCat::Upper),
+    (0xa73f, 0xa73f, SentenceCat::Lower),
+    (0xa740, 0xa740, SentenceCat::Upper),
+    (0xa741, 0xa741, SentenceCat::Lower),
+    (0xa742, 0xa742, SentenceCat::Upper),
+    (0xa743, 0xa743, SentenceCat::Lower),
+    (0xa744, 0xa744, SentenceCat::Upper),
+    (0xa745, 0xa745, SentenceCat::Lower),
+    (0xa746, 0xa746, SentenceCat::Upper),
+    (0xa747, 0xa747, SentenceCat::Lower),
+    (0xa748, 0xa748, SentenceCat::Upper),
+    (0xa749, 0xa749, SentenceCat::Lower),
+    (0xa74a, 0xa74a, SentenceCat::Upper),
+    (0xa74b, 0xa74b, SentenceCat::Lower),
+    (0xa74c, 0xa74c, SentenceCat::Upper),
+    (0xa74d, 0xa74d, SentenceCat::Lower),
+    (0xa74e, 0xa74e, SentenceCat::Upper),
+    (0xa74f, 0xa74f, SentenceCat::Lower),
+    (0xa750, 0xa750, SentenceCat::Upper),
+    (0xa751, 0xa751, SentenceCat::Lower),
+    (0xa752, 0xa752, SentenceCat::Upper),
+    (0xa753, 0xa753, SentenceCat::Lower),
+    (0xa754, 0xa754, SentenceCat::Upper),
+    (0xa755, 0xa755, SentenceCat::Lower),
+    (0xa756, 0xa756, SentenceCat::Upper),
+    (0xa757, 0xa757, SentenceCat::Lower),
+    (0xa758, 0xa758, SentenceCat::Upper),
+    (0xa759, 0xa759, SentenceCat::Lower),
+    (0xa75a, 0xa75a, SentenceCat::Upper),
+    (0xa75b, 0xa75b, SentenceCat::Lower),
+    (0xa75c, 0xa75c, SentenceCat::Upper),
+    (0xa75d, 0xa75d, SentenceCat::Lower),
+    (0xa75e, 0xa75e, SentenceCat::Upper),
+    (0xa75f, 0xa75f, SentenceCat::Lower),
+    (0xa760, 0xa760, SentenceCat::Upper),
+    (0xa761, 0xa761, SentenceCat::Lower),
+    (0xa762, 0xa762, SentenceCat::Upper),
+    (0xa763, 0xa763, SentenceCat::Lower),
+    (0xa764, 0xa764, SentenceCat::Upper),
+    (0xa765, 0xa765, SentenceCat::Lower),
+    (0xa766, 0xa766, SentenceCat::Upper),
+    (0xa767, 0xa767, SentenceCat::Lower),
+    (0xa768, 0xa768, SentenceCat::Upper),
+    (0xa769, 0xa769, SentenceCat::Lower),
+    (0xa76a, 0xa76a, SentenceCat::Upper),
+    (0xa76b, 0xa76b, SentenceCat::Lower),
+    (0xa76c, 0xa76c, SentenceCat::Upper),
+    (0xa76d, 0xa76d, SentenceCat::Lower),
+    (0xa76e, 0xa76e, SentenceCat::Upper),
+    (0xa76f, 0xa778, SentenceCat::Lower),
+    (0xa779, 0xa779, SentenceCat::Upper),
+    (0xa77a, 0xa77a, SentenceCat::Lower),
+    (0xa77b, 0xa77b, SentenceCat::Upper),
+    (0xa77c, 0xa77c, SentenceCat::Lower),
+    (0xa77d, 0xa77e, SentenceCat::Upper),
+    (0xa77f, 0xa77f, SentenceCat::Lower),
+    (0xa780, 0xa780, SentenceCat::Upper),
+    (0xa781, 0xa781, SentenceCat::Lower),
+    (0xa782, 0xa782, SentenceCat::Upper),
+    (0xa783, 0xa783, SentenceCat::Lower),
+    (0xa784, 0xa784, SentenceCat::Upper),
+    (0xa785, 0xa785, SentenceCat::Lower),
+    (0xa786, 0xa786, SentenceCat::Upper),
+    (0xa787, 0xa787, SentenceCat::Lower),
+    (0xa788, 0xa788, SentenceCat::OLetter),
+    (0xa78b, 0xa78b, SentenceCat::Upper),
+    (0xa78c, 0xa78c, SentenceCat::Lower),
+    (0xa78d, 0xa78d, SentenceCat::Upper),
+    (0xa78e, 0xa78e, SentenceCat::Lower),
+    (0xa78f, 0xa78f, SentenceCat::OLetter),
+    (0xa790, 0xa790, SentenceCat::Upper),
+    (0xa791, 0xa791, SentenceCat::Lower),
+    (0xa792, 0xa792, SentenceCat::Upper),
+    (0xa793, 0xa795, SentenceCat::Lower),
+    (0xa796, 0xa796, SentenceCat::Upper),
+    (0xa797, 0xa797, SentenceCat::Lower),
+    (0xa798, 0xa798, SentenceCat::Upper),
+    (0xa799, 0xa799, SentenceCat::Lower),
+    (0xa79a, 0xa79a, SentenceCat::Upper),
+    (0xa79b, 0xa79b, SentenceCat::Lower),
+    (0xa79c, 0xa79c, SentenceCat::Upper),
+    (0xa79d, 0xa79d, SentenceCat::Lower),
+    (0xa79e, 0xa79e, SentenceCat::Upper),
+    (0xa79f, 0xa79f, SentenceCat::Lower),
+    (0xa7a0, 0xa7a0, SentenceCat::Upper),
+    (0xa7a1, 0xa7a1, SentenceCat::Lower),
+    (0xa7a2, 0xa7a2, SentenceCat::Upper),
+    (0xa7a3, 0xa7a3, SentenceCat::Lower),
+    (0xa7a4, 0xa7a4, SentenceCat::Upper),
+    (0xa7a5, 0xa7a5, SentenceCat::Lower),
+    (0xa7a6, 0xa7a6, SentenceCat::Upper),
+    (0xa7a7, 0xa7a7, SentenceCat::Lower),
+    (0xa7a8, 0xa7a8, SentenceCat::Upper),
+    (0xa7a9, 0xa7a9, SentenceCat::Lower),
+    (0xa7aa, 0xa7ae, SentenceCat::Upper),
+    (0xa7af, 0xa7af, SentenceCat::Lower),
+    (0xa7b0, 0xa7b4, SentenceCat::Upper),
+    (0xa7b5, 0xa7b5, SentenceCat::Lower),
+    (0xa7b6, 0xa7b6, SentenceCat::Upper),
+    (0xa7b7, 0xa7b7, SentenceCat::Lower),
+    (0xa7b8, 0xa7b8, SentenceCat::Upper),
+    (0xa7b9, 0xa7b9, SentenceCat::Lower),
+    (0xa7ba, 0xa7ba, SentenceCat::Upper),
+    (0xa7bb, 0xa7bb, SentenceCat::Lower),
+    (0xa7bc, 0xa7bc, SentenceCat::Upper),
+    (0xa7bd, 0xa7bd, SentenceCat::Lower),
+    (0xa7be, 0xa7be, SentenceCat::Upper),
+    (0xa7bf, 0xa7bf, SentenceCat::Lower),
+    (0xa7c0, 0xa7c0, SentenceCat::Upper),
+    (0xa7c1, 0xa7c1, SentenceCat::Lower),
+    (0xa7c2, 0xa7c2, SentenceCat::Upper),
+    (0xa7c3, 0xa7c3, SentenceCat::Lower),
+    (0xa7c4, 0xa7c7, SentenceCat::Upper),
+    (0xa7c8, 0xa7c8, SentenceCat::Lower),
+    (0xa7c9, 0xa7c9, SentenceCat::Upper),
+    (0xa7ca, 0xa7ca, SentenceCat::Lower),
+    (0xa7cb, 0xa7cc, SentenceCat::Upper),
+    (0xa7cd, 0xa7cd, SentenceCat::Lower),
+    (0xa7ce, 0xa7ce, SentenceCat::Upper),
+    (0xa7cf, 0xa7cf, SentenceCat::Lower),
+    (0xa7d0, 0xa7d0, SentenceCat::Upper),
+    (0xa7d1, 0xa7d1, SentenceCat::Lower),
+    (0xa7d2, 0xa7d2, SentenceCat::Upper),
+    (0xa7d3, 0xa7d3, SentenceCat::Lower),
+    (0xa7d4, 0xa7d4, SentenceCat::Upper),
+    (0xa7d5, 0xa7d5, SentenceCat::Lower),
+    (0xa7d6, 0xa7d6, SentenceCat::Upper),
+    (0xa7d7, 0xa7d7, SentenceCat::Lower),
+    (0xa7d8, 0xa7d8, SentenceCat::Upper),
+    (0xa7d9, 0xa7d9, SentenceCat::Lower),
+    (0xa7da, 0xa7da, SentenceCat::Upper),
+    (0xa7db, 0xa7db, SentenceCat::Lower),
+    (0xa7dc, 0xa7dc, SentenceCat::Upper),
+    (0xa7f1, 0xa7f4, SentenceCat::Lower),
+    (0xa7f5, 0xa7f5, SentenceCat::Upper),
+    (0xa7f6, 0xa7f6, SentenceCat::Lower),
+    (0xa7f7, 0xa7f7, SentenceCat::OLetter),
+    (0xa7f8, 0xa7fa, SentenceCat::Lower),
+    (0xa7fb, 0xa801, SentenceCat::OLetter),
+    (0xa802, 0xa802, SentenceCat::Extend),
+    (0xa803, 0xa805, SentenceCat::OLetter),
+    (0xa806, 0xa806, SentenceCat::Extend),
+    (0xa807, 0xa80a, SentenceCat::OLetter),
+    (0xa80b, 0xa80b, SentenceCat::Extend),
+    (0xa80c, 0xa822, SentenceCat::OLetter),
+    (0xa823, 0xa827, SentenceCat::Extend),
+    (0xa82c, 0xa82c, SentenceCat::Extend),
+    (0xa840, 0xa873, SentenceCat::OLetter),
+    (0xa876, 0xa877, SentenceCat::STerm),
+    (0xa880, 0xa881, SentenceCat::Extend),
+    (0xa882, 0xa8b3, SentenceCat::OLetter),
+    (0xa8b4, 0xa8c5, SentenceCat::Extend),
+    (0xa8ce, 0xa8cf, SentenceCat::STerm),
+    (0xa8d0, 0xa8d9, SentenceCat::Numeric),
+    (0xa8e0, 0xa8f1, SentenceCat::Extend),
+    (0xa8f2, 0xa8f7, SentenceCat::OLetter),
+    (0xa8fb, 0xa8fb, SentenceCat::OLetter),
+    (0xa8fd, 0xa8fe, SentenceCat::OLetter),
+    (0xa8ff, 0xa8ff, SentenceCat::Extend),
+    (0xa900, 0xa909, SentenceCat::Numeric),
+    (0xa90a, 0xa925, SentenceCat::OLetter),
+    (0xa926, 0xa92d, SentenceCat::Extend),
+    (0xa92f, 0xa92f, SentenceCat::STerm),
+    (0xa930, 0xa946, SentenceCat::OLetter),
+    (0xa947, 0xa953, SentenceCat::Extend),
+    (0xa960, 0xa97c, SentenceCat::OLetter),
+    (0xa980, 0xa983, SentenceCat::Extend),
+    (0xa984, 0xa9b2, SentenceCat::OLetter),
+    (0xa9b3, 0xa9c0, SentenceCat::Extend),
+    (0xa9c8, 0xa9c9, SentenceCat::STerm),
+    (0xa9cf, 0xa9cf, SentenceCat::OLetter),
+    (0xa9d0, 0xa9d9, SentenceCat::Numeric),
+    (0xa9e0, 0xa9e4, SentenceCat::OLetter),
+    (0xa9e5, 0xa9e5, SentenceCat::Extend),
+    (0xa9e6, 0xa9ef, SentenceCat::OLetter),
+    (0xa9f0, 0xa9f9, SentenceCat::Numeric),
+    (0xa9fa, 0xa9fe, SentenceCat::OLetter),
+    (0xaa00, 0xaa28, SentenceCat::OLetter),
+    (0xaa29, 0xaa36, SentenceCat::Extend),
+    (0xaa40, 0xaa42, SentenceCat::OLetter),
+    (0xaa43, 0xaa43, SentenceCat::Extend),
+    (0xaa44, 0xaa4b, SentenceCat::OLetter),
+    (0xaa4c, 0xaa4d, SentenceCat::Extend),
+    (0xaa50, 0xaa59, SentenceCat::Numeric),
+    (0xaa5d, 0xaa5f, SentenceCat::STerm),
+    (0xaa60, 0xaa76, SentenceCat::OLetter),
+    (0xaa7a, 0xaa7a, SentenceCat::OLetter),
+    (0xaa7b, 0xaa7d, SentenceCat::Extend),
+    (0xaa7e, 0xaaaf, SentenceCat::OLetter),
+    (0xaab0, 0xaab0, SentenceCat::Extend),
+    (0xaab1, 0xaab1, SentenceCat::OLetter),
+    (0xaab2, 0xaab4, SentenceCat::Extend),
+    (0xaab5, 0xaab6, SentenceCat::OLetter),
+    (0xaab7, 0xaab8, SentenceCat::Extend),
+    (0xaab9, 0xaabd, SentenceCat::OLetter),
+    (0xaabe, 0xaabf, SentenceCat::Extend),
+    (0xaac0, 0xaac0, SentenceCat::OLetter),
+    (0xaac1, 0xaac1, SentenceCat::Extend),
+    (0xaac2, 0xaac2, SentenceCat::OLetter),
+    (0xaadb, 0xaadd, SentenceCat::OLetter),
+    (0xaae0, 0xaaea, SentenceCat::OLetter),
+    (0xaaeb, 0xaaef, SentenceCat::Extend),
+    (0xaaf0, 0xaaf1, SentenceCat::STerm),
+    (0xaaf2, 0xaaf4, SentenceCat::OLetter),
+    (0xaaf5, 0xaaf6, SentenceCat::Extend),
+    (0xab01, 0xab06, SentenceCat::OLetter),
+    (0xab09, 0xab0e, SentenceCat::OLetter),
+    (0xab11, 0xab16, SentenceCat::OLetter),
+    (0xab20, 0xab26, SentenceCat::OLetter),
+    (0xab28, 0xab2e, SentenceCat::OLetter),
+    (0xab30, 0xab5a, SentenceCat::Lower),
+    (0xab5c, 0xab69, SentenceCat::Lower),
+    (0xab70, 0xabbf, SentenceCat::Lower),
+    (0xabc0, 0xabe2, SentenceCat::OLetter),
+    (0xabe3, 0xabea, SentenceCat::Extend),
+    (0xabeb, 0xabeb, SentenceCat::STerm),
+    (0xabec, 0xabed, SentenceCat::Extend),
+    (0xabf0, 0xabf9, SentenceCat::Numeric),
+    (0xac00, 0xd7a3, SentenceCat::OLetter),
+    (0xd7b0, 0xd7c6, SentenceCat::OLetter),
+    (0xd7cb, 0xd7fb, SentenceCat::OLetter),
+    (0xf900, 0xfa6d, SentenceCat::OLetter),
+    (0xfa70, 0xfad9, SentenceCat::OLetter),
+    (0xfb00, 0xfb06, SentenceCat::Lower),
+    (0xfb13, 0xfb17, SentenceCat::Lower),
+    (0xfb1d, 0xfb1d, SentenceCat::OLetter),
+    (0xfb1e, 0xfb1e, SentenceCat::Extend),
+    (0xfb1f, 0xfb28, SentenceCat::OLetter),
+    (0xfb2a, 0xfb36, SentenceCat::OLetter),
+    (0xfb38, 0xfb3c, SentenceCat::OLetter),
+    (0xfb3e, 0xfb3e, SentenceCat::OLetter),
+    (0xfb40, 0xfb41, SentenceCat::OLetter),
+    (0xfb43, 0xfb44, SentenceCat::OLetter),
+    (0xfb46, 0xfbb1, SentenceCat::OLetter),
+    (0xfbd3, 0xfd3d, SentenceCat::OLetter),
+    (0xfd3e, 0xfd3f, SentenceCat::Close),
+    (0xfd50, 0xfd8f, SentenceCat::OLetter),
+    (0xfd92, 0xfdc7, SentenceCat::OLetter),
+    (0xfdf0, 0xfdfb, SentenceCat::OLetter),
+    (0xfe00, 0xfe0f, SentenceCat::Extend),
+    (0xfe10, 0xfe11, SentenceCat::SContinue),
+    (0xfe12, 0xfe12, SentenceCat::STerm),
+    (0xfe13, 0xfe14, SentenceCat::SContinue),
+    (0xfe15, 0xfe16, SentenceCat::STerm),
+    (0xfe17, 0xfe18, SentenceCat::Close),
+    (0xfe20, 0xfe2f, SentenceCat::Extend),
+    (0xfe31, 0xfe32, SentenceCat::SContinue),
+    (0xfe35, 0xfe44, SentenceCat::Close),
+    (0xfe47, 0xfe48, SentenceCat::Close),
+    (0xfe50, 0xfe51, SentenceCat::SContinue),
+    (0xfe52, 0xfe52, SentenceCat::ATerm),
+    (0xfe54, 0xfe55, SentenceCat::SContinue),
+    (0xfe56, 0xfe57, SentenceCat::STerm),
+    (0xfe58, 0xfe58, SentenceCat::SContinue),
+    (0xfe59, 0xfe5e, SentenceCat::Close),
+    (0xfe63, 0xfe63, SentenceCat::SContinue),
+    (0xfe70, 0xfe74, SentenceCat::OLetter),
+    (0xfe76, 0xfefc, SentenceCat::OLetter),
+    (0xfeff, 0xfeff, SentenceCat::Format),
+    (0xff01, 0xff01, SentenceCat::STerm),
+    (0xff08, 0xff09, SentenceCat::Close),
+    (0xff0c, 0xff0d, SentenceCat::SContinue),
+    (0xff0e, 0xff0e, SentenceCat::ATerm),
+    (0xff10, 0xff19, SentenceCat::Numeric),
+    (0xff1a, 0xff1b, SentenceCat::SContinue),
+    (0xff1f, 0xff1f, SentenceCat::STerm),
+    (0xff21, 0xff3a, SentenceCat::Upper),
+    (0xff3b, 0xff3b, SentenceCat::Close),
+    (0xff3d, 0xff3d, SentenceCat::Close),
+    (0xff41, 0xff5a, SentenceCat::Lower),
+    (0xff5b, 0xff5b, SentenceCat::Close),
+    (0xff5d, 0xff5d, SentenceCat::Close),
+    (0xff5f, 0xff60, SentenceCat::Close),
+    (0xff61, 0xff61, SentenceCat::STerm),
+    (0xff62, 0xff63, SentenceCat::Close),
+    (0xff64, 0xff64, SentenceCat::SContinue),
+    (0xff66, 0xff9d, SentenceCat::OLetter),
+    (0xff9e, 0xff9f, SentenceCat::Extend),
+    (0xffa0, 0xffbe, SentenceCat::OLetter),
+    (0xffc2, 0xffc7, SentenceCat::OLetter),
+    (0xffca, 0xffcf, SentenceCat::OLetter),
+    (0xffd2, 0xffd7, SentenceCat::OLetter),
+    (0xffda, 0xffdc, SentenceCat::OLetter),
+    (0xfff9, 0xfffb, SentenceCat::Format),
+    (0x10000, 0x1000b, SentenceCat::OLetter),
+    (0x1000d, 0x10026, SentenceCat::OLetter),
+    (0x10028, 0x1003a, SentenceCat::OLetter),
+    (0x1003c, 0x1003d, SentenceCat::OLetter),
+    (0x1003f, 0x1004d, SentenceCat::OLetter),
+    (0x10050, 0x1005d, SentenceCat::OLetter),
+    (0x10080, 0x100fa, SentenceCat::OLetter),
+    (0x10140, 0x10174, SentenceCat::OLetter),
+    (0x101fd, 0x101fd, SentenceCat::Extend),
+    (0x10280, 0x1029c, SentenceCat::OLetter),
+    (0x102a0, 0x102d0, SentenceCat::OLetter),
+    (0x102e0, 0x102e0, SentenceCat::Extend),
+    (0x10300, 0x1031f, SentenceCat::OLetter),
+    (0x1032d, 0x1034a, SentenceCat::OLetter),
+    (0x10350, 0x10375, SentenceCat::OLetter),
+    (0x10376, 0x1037a, SentenceCat::Extend),
+    (0x10380, 0x1039d, SentenceCat::OLetter),
+    (0x103a0, 0x103c3, SentenceCat::OLetter),
+    (0x103c8, 0x103cf, SentenceCat::OLetter),
+    (0x103d1, 0x103d5, SentenceCat::OLetter),
+    (0x10400, 0x10427, SentenceCat::Upper),
+    (0x10428, 0x1044f, SentenceCat::Lower),
+    (0x10450, 0x1049d, SentenceCat::OLetter),
+    (0x104a0, 0x104a9, SentenceCat::Numeric),
+    (0x104b0, 0x104d3, SentenceCat::Upper),
+    (0x104d8, 0x104fb, SentenceCat::Lower),
+    (0x10500, 0x10527, SentenceCat::OLetter),
+    (0x10530, 0x10563, SentenceCat::OLetter),
+    (0x10570, 0x1057a, SentenceCat::Upper),
+    (0x1057c, 0x1058a, SentenceCat::Upper),
+    (0x1058c, 0x10592, SentenceCat::Upper),
+    (0x10594, 0x10595, SentenceCat::Upper),
+    (0x10597, 0x105a1, SentenceCat::Lower),
+    (0x105a3, 0x105b1, SentenceCat::Lower),
+    (0x105b3, 0x105b9, SentenceCat::Lower),
+    (0x105bb, 0x105bc, SentenceCat::Lower),
+    (0x105c0, 0x105f3, SentenceCat::OLetter),
+    (0x10600, 0x10736, SentenceCat::OLetter),
+    (0x10740, 0x10755, SentenceCat::OLetter),
+    (0x10760, 0x10767, SentenceCat::OLetter),
+    (0x10780, 0x10780, SentenceCat::Lower),
+    (0x10781, 0x10782, SentenceCat::OLetter),
+    (0x10783, 0x10785, SentenceCat::Lower),
+    (0x10787, 0x107b0, SentenceCat::Lower),
+    (0x107b2, 0x107ba, SentenceCat::Lower),
+    (0x10800, 0x10805, SentenceCat::OLetter),
+    (0x10808, 0x10808, SentenceCat::OLetter),
+    (0x1080a, 0x10835, SentenceCat::OLetter),
+    (0x10837, 0x10838, SentenceCat::OLetter),
+    (0x1083c, 0x1083c, SentenceCat::OLetter),
+    (0x1083f, 0x10855, SentenceCat::OLetter),
+    (0x10860, 0x10876, SentenceCat::OLetter),
+    (0x10880, 0x1089e, SentenceCat::OLetter),
+    (0x108e0, 0x108f2, SentenceCat::OLetter),
+    (0x108f4, 0x108f5, SentenceCat::OLetter),
+    (0x10900, 0x10915, SentenceCat::OLetter),
+    (0x10920, 0x10939, SentenceCat::OLetter),
+    (0x10940, 0x10959, SentenceCat::OLetter),
+    (0x10980, 0x109b7, SentenceCat::OLetter),
+    (0x109be, 0x109bf, SentenceCat::OLetter),
+    (0x10a00, 0x10a00, SentenceCat::OLetter),
+    (0x10a01, 0x10a03, SentenceCat::Extend),
+    (0x10a05, 0x10a06, SentenceCat::Extend),
+    (0x10a0c, 0x10a0f, SentenceCat::Extend),
+    (0x10a10, 0x10a13, SentenceCat::OLetter),
+    (0x10a15, 0x10a17, SentenceCat::OLetter),
+    (0x10a19, 0x10a35, SentenceCat::OLetter),
+    (0x10a38, 0x10a3a, SentenceCat::Extend),
+    (0x10a3f, 0x10a3f, SentenceCat::Extend),
+    (0x10a56, 0x10a57, SentenceCat::STerm),
+    (0x10a60, 0x10a7c, SentenceCat::OLetter),
+    (0x10a80, 0x10a9c, SentenceCat::OLetter),
+    (0x10ac0, 0x10ac7, SentenceCat::OLetter),
+    (0x10ac9, 0x10ae4, SentenceCat::OLetter),
+    (0x10ae5, 0x10ae6, SentenceCat::Extend),
+    (0x10b00, 0x10b35, SentenceCat::OLetter),
+    (0x10b40, 0x10b55, SentenceCat::OLetter),
+    (0x10b60, 0x10b72, SentenceCat::OLetter),
+    (0x10b80, 0x10b91, SentenceCat::OLetter),
+    (0x10c00, 0x10c48, SentenceCat::OLetter),
+    (0x10c80, 0x10cb2, SentenceCat::Upper),
+    (0x10cc0, 0x10cf2, SentenceCat::Lower),
+    (0x10d00, 0x10d23, SentenceCat::OLetter),
+    (0x10d24, 0x10d27, SentenceCat::Extend),
+    (0x10d30, 0x10d39, SentenceCat::Numeric),
+    (0x10d40, 0x10d49, SentenceCat::Numeric),
+    (0x10d4a, 0x10d4f, SentenceCat::OLetter),
+    (0x10d50, 0x10d65, SentenceCat::Upper),
+    (0x10d69, 0x10d6d, SentenceCat::Extend),
+    (0x10d6f, 0x10d6f, SentenceCat::OLetter),
+    (0x10d70, 0x10d85, SentenceCat::Lower),
+    (0x10e80, 0x10ea9, SentenceCat::OLetter),
+    (0x10eab, 0x10eac, SentenceCat::Extend),
+    (0x10eb0, 0x10eb1, SentenceCat::OLetter),
+    (0x10ec2, 0x10ec7, SentenceCat::OLetter),
+    (0x10efa, 0x10eff, SentenceCat::Extend),
+    (0x10f00, 0x10f1c, SentenceCat::OLetter),
+    (0x10f27, 0x10f27, SentenceCat::OLetter),
+    (0x10f30, 0x10f45, SentenceCat::OLetter),
+    (0x10f46, 0x10f50, SentenceCat::Extend),
+    (0x10f55, 0x10f59, SentenceCat::STerm),
+    (0x10f70, 0x10f81, SentenceCat::OLetter),
+    (0x10f82, 0x10f85, SentenceCat::Extend),
+    (0x10f86, 0x10f89, SentenceCat::STerm),
+    (0x10fb0, 0x10fc4, SentenceCat::OLetter),
+    (0x10fe0, 0x10ff6, SentenceCat::OLetter),
+    (0x11000, 0x11002, SentenceCat::Extend),
+    (0x11003, 0x11037, SentenceCat::OLetter),
+    (0x11038, 0x11046, SentenceCat::Extend),
+    (0x11047, 0x11048, SentenceCat::STerm),
+    (0x11066, 0x1106f, SentenceCat::Numeric),
+    (0x11070, 0x11070, SentenceCat::Extend),
+    (0x11071, 0x11072, SentenceCat::OLetter),
+    (0x11073, 0x11074, SentenceCat::Extend),
+    (0x11075, 0x11075, SentenceCat::OLetter),
+    (0x1107f, 0x11082, SentenceCat::Extend),
+    (0x11083, 0x110af, SentenceCat::OLetter),
+    (0x110b0, 0x110ba, SentenceCat::Extend),
+    (0x110bd, 0x110bd, SentenceCat::Numeric),
+    (0x110be, 0x110c1, SentenceCat::STerm),
+    (0x110c2, 0x110c2, SentenceCat::Extend),
+    (0x110cd, 0x110cd, SentenceCat::Numeric),
+    (0x110d0, 0x110e8, SentenceCat::OLetter),
+    (0x110f0, 0x110f9, SentenceCat::Numeric),
+    (0x11100, 0x11102, SentenceCat::Extend),
+    (0x11103, 0x11126, SentenceCat::OLetter),
+    (0x11127, 0x11134, SentenceCat::Extend),
+    (0x11136, 0x1113f, SentenceCat::Numeric),
+    (0x11141, 0x11143, SentenceCat::STerm),
+    (0x11144, 0x11144, SentenceCat::OLetter),
+    (0x11145, 0x11146, SentenceCat::Extend),
+    (0x11147, 0x11147, SentenceCat::OLetter),
+    (0x11150, 0x11172, SentenceCat::OLetter),
+    (0x11173, 0x11173, SentenceCat::Extend),
+    (0x11176, 0x11176, SentenceCat::OLetter),
+    (0x11180, 0x11182, SentenceCat::Extend),
+    (0x11183, 0x111b2, SentenceCat::OLetter),
+    (0x111b3, 0x111c0, SentenceCat::Extend),
+    (0x111c1, 0x111c4, SentenceCat::OLetter),
+    (0x111c5, 0x111c6, SentenceCat::STerm),
+    (0x111c9, 0x111cc, SentenceCat::Extend),
+    (0x111cd, 0x111cd, SentenceCat::STerm),
+    (0x111ce, 0x111cf, SentenceCat::Extend),
+    (0x111d0, 0x111d9, SentenceCat::Numeric),
+    (0x111da, 0x111da, SentenceCat::OLetter),
+    (0x111dc, 0x111dc, SentenceCat::OLetter),
+    (0x111de, 0x111df, SentenceCat::STerm),
+    (0x11200, 0x11211, SentenceCat::OLetter),
+    (0x11213, 0x1122b, SentenceCat::OLetter),
+    (0x1122c, 0x11237, SentenceCat::Extend),
+    (0x11238, 0x11239, SentenceCat::STerm),
+    (0x1123b, 0x1123c, SentenceCat::STerm),
+    (0x1123e, 0x1123e, SentenceCat::Extend),
+    (0x1123f, 0x11240, SentenceCat::OLetter),
+    (0x11241, 0x11241, SentenceCat::Extend),
+    (0x11280, 0x11286, SentenceCat::OLetter),
+    (0x11288, 0x11288, SentenceCat::OLetter),
+    (0x1128a, 0x1128d, SentenceCat::OLetter),
+    (0x1128f, 0x1129d, SentenceCat::OLetter),
+    (0x1129f, 0x112a8, SentenceCat::OLetter),
+    (0x112a9, 0x112a9, SentenceCat::STerm),
+    (0x112b0, 0x112de, SentenceCat::OLetter),
+    (0x112df, 0x112ea, SentenceCat::Extend),
+    (0x112f0, 0x112f9, SentenceCat::Numeric),
+    (0x11300, 0x11303, SentenceCat::Extend),
+    (0x11305, 0x1130c, SentenceCat::OLetter),
+    (0x1130f, 0x11310, SentenceCat::OLetter),
+    (0x11313, 0x11328, SentenceCat::OLetter),
+    (0x1132a, 0x11330, SentenceCat::OLetter),
+    (0x11332, 0x11333, SentenceCat::OLetter),
+    (0x11335, 0x11339, SentenceCat::OLetter),
+    (0x1133b, 0x1133c, SentenceCat::Extend),
+    (0x1133d, 0x1133d, SentenceCat::OLetter),
+    (0x1133e, 0x11344, SentenceCat::Extend),
+    (0x11347, 0x11348, SentenceCat::Extend),
+    (0x1134b, 0x1134d, SentenceCat::Extend),
+    (0x11350, 0x11350, SentenceCat::OLetter),
+    (0x11357, 0x11357, SentenceCat::Extend),
+    (0x1135d, 0x11361, SentenceCat::OLetter),
+    (0x11362, 0x11363, SentenceCat::Extend),
+    (0x11366, 0x1136c, SentenceCat::Extend),
+    (0x11370, 0x11374, SentenceCat::Extend),
+    (0x11380, 0x11389, SentenceCat::OLetter),
+    (0x1138b, 0x1138b, SentenceCat::OLetter),
+    (0x1138e, 0x1138e, SentenceCat::OLetter),
+    (0x11390, 0x113b5, SentenceCat::OLetter),
+    (0x113b7, 0x113b7, SentenceCat::OLetter),
+    (0x113b8, 0x113c0, SentenceCat::Extend),
+    (0x113c2, 0x113c2, SentenceCat::Extend),
+    (0x113c5, 0x113c5, SentenceCat::Extend),
+    (0x113c7, 0x113ca, SentenceCat::Extend),
+    (0x113cc, 0x113d0, SentenceCat::Extend),
+    (0x113d1, 0x113d1, SentenceCat::OLetter),
+    (0x113d2, 0x113d2, SentenceCat::Extend),
+    (0x113d3, 0x113d3, SentenceCat::OLetter),
+    (0x113d4, 0x113d5, SentenceCat::STerm),
+    (0x113e1, 0x113e2, SentenceCat::Extend),
+    (0x11400, 0x11434, SentenceCat::OLetter),
+    (0x11435, 0x11446, SentenceCat::Extend),
+    (0x11447, 0x1144a, SentenceCat::OLetter),
+    (0x1144b, 0x1144c, SentenceCat::STerm),
+    (0x11450, 0x11459, SentenceCat::Numeric),
+    (0x1145e, 0x1145e, SentenceCat::Extend),
+    (0x1145f, 0x11461, SentenceCat::OLetter),
+    (0x11480, 0x114af, SentenceCat::OLetter),
+    (0x114b0, 0x114c3, SentenceCat::Extend),
+    (0x114c4, 0x114c5, SentenceCat::OLetter),
+    (0x114c7, 0x114c7, SentenceCat::OLetter),
+    (0x114d0, 0x114d9, SentenceCat::Numeric),
+    (0x11580, 0x115ae, SentenceCat::OLetter),
+    (0x115af, 0x115b5, SentenceCat::Extend),
+    (0x115b8, 0x115c0, SentenceCat::Extend),
+    (0x115c2, 0x115c3, SentenceCat::STerm),
+    (0x115c9, 0x115d7, SentenceCat::STerm),
+    (0x115d8, 0x115db, SentenceCat::OLetter),
+    (0x115dc, 0x115dd, SentenceCat::Extend),
+    (0x11600, 0x1162f, SentenceCat::OLetter),
+    (0x11630, 0x11640, SentenceCat::Extend),
+    (0x11641, 0x11642, SentenceCat::STerm),
+    (0x11644, 0x11644, SentenceCat::OLetter),
+    (0x11650, 0x11659, SentenceCat::Numeric),
+    (0x11680, 0x116aa, SentenceCat::OLetter),
+    (0x116ab, 0x116b7, SentenceCat::Extend),
+    (0x116b8, 0x116b8, SentenceCat::OLetter),
+    (0x116c0, 0x116c9, SentenceCat::Numeric),
+    (0x116d0, 0x116e3, SentenceCat::Numeric),
+    (0x11700, 0x1171a, SentenceCat::OLetter),
+    (0x1171d, 0x1172b, SentenceCat::Extend),
+    (0x11730, 0x11739, SentenceCat::Numeric),
+    (0x1173c, 0x1173e, SentenceCat::STerm),
+    (0x11740, 0x11746, SentenceCat::OLetter),
+    (0x11800, 0x1182b, SentenceCat::OLetter),
+    (0x1182c, 0x1183a, SentenceCat::Extend),
+    (0x118a0, 0x118bf, SentenceCat::Upper),
+    (0x118c0, 0x118df, SentenceCat::Lower),
+    (0x118e0, 0x118e9, SentenceCat::Numeric),
+    (0x118ff, 0x11906, SentenceCat::OLetter),
+    (0x11909, 0x11909, SentenceCat::OLetter),
+    (0x1190c, 0x11913, SentenceCat::OLetter),
+    (0x11915, 0x11916, SentenceCat::OLetter),
+    (0x11918, 0x1192f, SentenceCat::OLetter),
+    (0x11930, 0x11935, SentenceCat::Extend),
+    (0x11937, 0x11938, SentenceCat::Extend),
+    (0x1193b, 0x1193e, SentenceCat::Extend),
+    (0x1193f, 0x1193f, SentenceCat::OLetter),
+    (0x11940, 0x11940, SentenceCat::Extend),
+    (0x11941, 0x11941, SentenceCat::OLetter),
+    (0x11942, 0x11943, SentenceCat::Extend),
+    (0x11944, 0x11944, SentenceCat::STerm),
+    (0x11946, 0x11946, SentenceCat::STerm),
+    (0x11950, 0x11959, SentenceCat::Numeric),
+    (0x119a0, 0x119a7, SentenceCat::OLetter),
+    (0x119aa, 0x119d0, SentenceCat::OLetter),
+    (0x119d1, 0x119d7, SentenceCat::Extend),
+    (0x119da, 0x119e0, SentenceCat::Extend),
+    (0x119e1, 0x119e1, SentenceCat::OLetter),
+    (0x119e3, 0x119e3, SentenceCat::OLetter),
+    (0x119e4, 0x119e4, SentenceCat::Extend),
+    (0x11a00, 0x11a00, SentenceCat::OLetter),
+    (0x11a01, 0x11a0a, SentenceCat::Extend),
+    (0x11a0b, 0x11a32, SentenceCat::OLetter),
+    (0x11a33, 0x11a39, SentenceCat::Extend),
+    (0x11a3a, 0x11a3a, SentenceCat::OLetter),
+    (0x11a3b, 0x11a3e, SentenceCat::Extend),
+    (0x11a42, 0x11a43, SentenceCat::STerm),
+    (0x11a47, 0x11a47, SentenceCat::Extend),
+    (0x11a50, 0x11a50, SentenceCat::OLetter),
+    (0x11a51, 0x11a5b, SentenceCat::Extend),
+    (0x11a5c, 0x11a89, SentenceCat::OLetter),
+    (0x11a8a, 0x11a99, SentenceCat::Extend),
+    (0x11a9b, 0x11a9c, SentenceCat::STerm),
+    (0x11a9d, 0x11a9d, SentenceCat::OLetter),
+    (0x11ab0, 0x11af8, SentenceCat::OLetter),
+    (0x11b60, 0x11b67, SentenceCat::Extend),
+    (0x11bc0, 0x11be0, SentenceCat::OLetter),
+    (0x11bf0, 0x11bf9, SentenceCat::Numeric),
+    (0x11c00, 0x11c08, SentenceCat::OLetter),
+    (0x11c0a, 0x11c2e, SentenceCat::OLetter),
+    (0x11c2f, 0x11c36, SentenceCat::Extend),
+    (0x11c38, 0x11c3f, SentenceCat::Extend),
+    (0x11c40, 0x11c40, SentenceCat::OLetter),
+    (0x11c41, 0x11c42, SentenceCat::STerm),
+    (0x11c50, 0x11c59, SentenceCat::Numeric),
+    (0x11c72, 0x11c8f, SentenceCat::OLetter),
+    (0x11c92, 0x11ca7, SentenceCat::Extend),
+    (0x11ca9, 0x11cb6, SentenceCat::Extend),
+    (0x11d00, 0x11d06, SentenceCat::OLetter),
+    (0x11d08, 0x11d09, SentenceCat::OLetter),
+    (0x11d0b, 0x11d30, SentenceCat::OLetter),
+    (0x11d31, 0x11d36, SentenceCat::Extend),
+    (0x11d3a, 0x11d3a, SentenceCat::Extend),
+    (0x11d3c, 0x11d3d, SentenceCat::Extend),
+    (0x11d3f, 0x11d45, SentenceCat::Extend),
+    (0x11d46, 0x11d46, SentenceCat::OLetter),
+    (0x11d47, 0x11d47, SentenceCat::Extend),
+    (0x11d50, 0x11d59, SentenceCat::Numeric),
+    (0x11d60, 0x11d65, SentenceCat::OLetter),
+    (0x11d67, 0x11d68, SentenceCat::OLetter),
+    (0x11d6a, 0x11d89, SentenceCat::OLetter),
+    (0x11d8a, 0x11d8e, SentenceCat::Extend),
+    (0x11d90, 0x11d91, SentenceCat::Extend),
+    (0x11d93, 0x11d97, SentenceCat::Extend),
+    (0x11d98, 0x11d98, SentenceCat::OLetter),
+    (0x11da0, 0x11da9, SentenceCat::Numeric),
+    (0x11db0, 0x11ddb, SentenceCat::OLetter),
+    (0x11de0, 0x11de9, SentenceCat::Numeric),
+    (0x11ee0, 0x11ef2, SentenceCat::OLetter),
+    (0x11ef3, 0x11ef6, SentenceCat::Extend),
+    (0x11ef7, 0x11ef8, SentenceCat::STerm),
+    (0x11f00, 0x11f01, SentenceCat::Extend),
+    (0x11f02, 0x11f02, SentenceCat::OLetter),
+    (0x11f03, 0x11f03, SentenceCat::Extend),
+    (0x11f04, 0x11f10, SentenceCat::OLetter),
+    (0x11f12, 0x11f33, SentenceCat::OLetter),
+    (0x11f34, 0x11f3a, SentenceCat::Extend),
+    (0x11f3e, 0x11f42, SentenceCat::Extend),
+    (0x11f43, 0x11f44, SentenceCat::STerm),
+    (0x11f50, 0x11f59, SentenceCat::Numeric),
+    (0x11f5a, 0x11f5a, SentenceCat::Extend),
+    (0x11fb0, 0x11fb0, SentenceCat::OLetter),
+    (0x12000, 0x12399, SentenceCat::OLetter),
+    (0x12400, 0x1246e, SentenceCat::OLetter),
+    (0x12480, 0x12543, SentenceCat::OLetter),
+    (0x12f90, 0x12ff0, SentenceCat::OLetter),
+    (0x13000, 0x1342f, SentenceCat::OLetter),
+    (0x13430, 0x1343f, SentenceCat::Format),
+    (0x13440, 0x13440, SentenceCat::Extend),
+    (0x13441, 0x13446, SentenceCat::OLetter),
+    (0x13447, 0x13455, SentenceCat::Extend),
+    (0x13460, 0x143fa, SentenceCat::OLetter),
+    (0x14400, 0x14646, SentenceCat::OLetter),
+    (0x16100, 0x1611d, SentenceCat::OLetter),
+    (0x1611e, 0x1612f, SentenceCat::Extend),
+    (0x16130, 0x16139, SentenceCat::Numeric),
+    (0x16800, 0x16a38, SentenceCat::OLetter),
+    (0x16a40, 0x16a5e, SentenceCat::OLetter),
+    (0x16a60, 0x16a69, SentenceCat::Numeric),
+    (0x16a6e, 0x16a6f, SentenceCat::STerm),
+    (0x16a70, 0x16abe, SentenceCat::OLetter),
+    (0x16ac0, 0x16ac9, SentenceCat::Numeric),
+    (0x16ad0, 0x16aed, SentenceCat::OLetter),
+    (0x16af0, 0x16af4, SentenceCat::Extend),
+    (0x16af5, 0x16af5, SentenceCat::STerm),
+    (0x16b00, 0x16b2f, SentenceCat::OLetter),
+    (0x16b30, 0x16b36, SentenceCat::Extend),
+    (0x16b37, 0x16b38, SentenceCat::STerm),
+    (0x16b40, 0x16b43, SentenceCat::OLetter),
+    (0x16b44, 0x16b44, SentenceCat::STerm),
+    (0x16b50, 0x16b59, SentenceCat::Numeric),
+    (0x16b63, 0x16b77, SentenceCat::OLetter),
+    (0x16b7d, 0x16b8f, SentenceCat::OLetter),
+    (0x16d40, 0x16d6c, SentenceCat::OLetter),
+    (0x16d6e, 0x16d6f, SentenceCat::STerm),
+    (0x16d70, 0x16d79, SentenceCat::Numeric),
+    (0x16e40, 0x16e5f, SentenceCat::Upper),
+    (0x16e60, 0x16e7f, SentenceCat::Lower),
+    (0x16e98, 0x16e98, SentenceCat::STerm),
+    (0x16ea0, 0x16eb8, SentenceCat::Upper),
+    (0x16ebb, 0x16ed3, SentenceCat::Lower),
+    (0x16f00, 0x16f4a, SentenceCat::OLetter),
+    (0x16f4f, 0x16f4f, SentenceCat::Extend),
+    (0x16f50, 0x16f50, SentenceCat::OLetter),
+    (0x16f51, 0x16f87, SentenceCat::Extend),
+    (0x16f8f, 0x16f92, SentenceCat::Extend),
+    (0x16f93, 0x16f9f, SentenceCat::OLetter),
+    (0x16fe0, 0x16fe1, SentenceCat::OLetter),
+    (0x16fe3, 0x16fe3, SentenceCat::OLetter),
+    (0x16fe4, 0x16fe4, SentenceCat::Extend),
+    (0x16ff0, 0x16ff1, SentenceCat::Extend),
+    (0x16ff2, 0x16ff6, SentenceCat::OLetter),
+    (0x17000, 0x18cd5, SentenceCat::OLetter),
+    (0x18cff, 0x18d1e, SentenceCat::OLetter),
+    (0x18d80, 0x18df2, SentenceCat::OLetter),
+    (0x1aff0, 0x1aff3, SentenceCat::OLetter),
+    (0x1aff5, 0x1affb, SentenceCat::OLetter),
+    (0x1affd, 0x1affe, SentenceCat::OLetter),
+    (0x1b000, 0x1b122, SentenceCat::OLetter),
+    (0x1b132, 0x1b132, SentenceCat::OLetter),
+    (0x1b150, 0x1b152, SentenceCat::OLetter),
+    (0x1b155, 0x1b155, SentenceCat::OLetter),
+    (0x1b164, 0x1b167, SentenceCat::OLetter),
+    (0x1b170, 0x1b2fb, SentenceCat::OLetter),
+    (0x1bc00, 0x1bc6a, SentenceCat::OLetter),
+    (0x1bc70, 0x1bc7c, SentenceCat::OLetter),
+    (0x1bc80, 0x1bc88, SentenceCat::OLetter),
+    (0x1bc90, 0x1bc99, SentenceCat::OLetter),
+    (0x1bc9d, 0x1bc9e, SentenceCat::Extend),
+    (0x1bc9f, 0x1bc9f, SentenceCat::STerm),
+    (0x1bca0, 0x1bca3, SentenceCat::Format),
+    (0x1ccf0, 0x1ccf9, SentenceCat::Numeric),
+    (0x1cf00, 0x1cf2d, SentenceCat::Extend),
+    (0x1cf30, 0x1cf46, SentenceCat::Extend),
+    (0x1d165, 0x1d169, SentenceCat::Extend),
+    (0x1d16d, 0x1d172, SentenceCat::Extend),
+    (0x1d173, 0x1d17a, SentenceCat::Format),
+    (0x1d17b, 0x1d182, SentenceCat::Extend),
+    (0x1d185, 0x1d18b, SentenceCat::Extend),
+    (0x1d1aa, 0x1d1ad, SentenceCat::Extend),
+    (0x1d242, 0x1d244, SentenceCat::Extend),
+    (0x1d400, 0x1d419, SentenceCat::Upper),
+    (0x1d41a, 0x1d433, SentenceCat::Lower),
+    (0x1d434, 0x1d44d, SentenceCat::Upper),
+    (0x1d44e, 0x1d454, SentenceCat::Lower),
+    (0x1d456, 0x1d467, SentenceCat::Lower),
+    (0x1d468, 0x1d481, SentenceCat::Upper),
+    (0x1d482, 0x1d49b, SentenceCat::Lower),
+    (0x1d49c, 0x1d49c, SentenceCat::Upper),
+    (0x1d49e, 0x1d49f, SentenceCat::Upper),
+    (0x1d4a2, 0x1d4a2, SentenceCat::Upper),
+    (0x1d4a5, 0x1d4a6, SentenceCat::Upper),
+    (0x1d4a9, 0x1d4ac, SentenceCat::Upper),
+    (0x1d4ae, 0x1d4b5, SentenceCat::Upper),
+    (0x1d4b6, 0x1d4b9, SentenceCat::Lower),
+    (0x1d4bb, 0x1d4bb, SentenceCat::Lower),
+    (0x1d4bd, 0x1d4c3, SentenceCat::Lower),
+    (0x1d4c5, 0x1d4cf, SentenceCat::Lower),
+    (0x1d4d0, 0x1d4e9, SentenceCat::Upper),
+    (0x1d4ea, 0x1d503, SentenceCat::Lower),
+    (0x1d504, 0x1d505, SentenceCat::Upper),
+    (0x1d507, 0x1d50a, SentenceCat::Upper),
+    (0x1d50d, 0x1d514, SentenceCat::Upper),
+    (0x1d516, 0x1d51c, SentenceCat::Upper),
+    (0x1d51e, 0x1d537, SentenceCat::Lower),
+    (0x1d538, 0x1d539, SentenceCat::Upper),
+    (0x1d53b, 0x1d53e, SentenceCat::Upper),
+    (0x1d540, 0x1d544, SentenceCat::Upper),
+    (0x1d546, 0x1d546, SentenceCat::Upper),
+    (0x1d54a, 0x1d550, SentenceCat::Upper),
+    (0x1d552, 0x1d56b, SentenceCat::Lower),
+    (0x1d56c, 0x1d585, SentenceCat::Upper),
+    (0x1d586, 0x1d59f, SentenceCat::Lower),
+    (0x1d5a0, 0x1d5b9, SentenceCat::Upper),
+    (0x1d5ba, 0x1d5d3, SentenceCat::Lower),
+    (0x1d5d4, 0x1d5ed, SentenceCat::Upper),
+    (0x1d5ee, 0x1d607, SentenceCat::Lower),
+    (0x1d608, 0x1d621, SentenceCat::Upper),
+    (0x1d622, 0x1d63b, SentenceCat::Lower),
+    (0x1d63c, 0x1d655, SentenceCat::Upper),
+    (0x1d656, 0x1d66f, SentenceCat::Lower),
+    (0x1d670, 0x1d689, SentenceCat::Upper),
+    (0x1d68a, 0x1d6a5, SentenceCat::Lower),
+    (0x1d6a8, 0x1d6c0, SentenceCat::Upper),
+    (0x1d6c2, 0x1d6da, SentenceCat::Lower),
+    (0x1d6dc, 0x1d6e1, SentenceCat::Lower),
+    (0x1d6e2, 0x1d6fa, SentenceCat::Upper),
+    (0x1d6fc, 0x1d714, SentenceCat::Lower),
+    (0x1d716, 0x1d71b, SentenceCat::Lower),
+    (0x1d71c, 0x1d734, SentenceCat::Upper),
+    (0x1d736, 0x1d74e, SentenceCat::Lower),
+    (0x1d750, 0x1d755, SentenceCat::Lower),
+    (0x1d756, 0x1d76e, SentenceCat::Upper),
+    (0x1d770, 0x1d788, SentenceCat::Lower),
+    (0x1d78a, 0x1d78f, SentenceCat::Lower),
+    (0x1d790, 0x1d7a8, SentenceCat::Upper),
+    (0x1d7aa, 0x1d7c2, SentenceCat::Lower),
+    (0x1d7c4, 0x1d7c9, SentenceCat::Lower),
+    (0x1d7ca, 0x1d7ca, SentenceCat::Upper),
+    (0x1d7cb, 0x1d7cb, SentenceCat::Lower),
+    (0x1d7ce, 0x1d7ff, SentenceCat::Numeric),
+    (0x1da00, 0x1da36, SentenceCat::Extend),
+    (0x1da3b, 0x1da6c, SentenceCat::Extend),
+    (0x1da75, 0x1da75, SentenceCat::Extend),
+    (0x1da84, 0x1da84, SentenceCat::Extend),
+    (0x1da88, 0x1da88, SentenceCat::STerm),
+    (0x1da9b, 0x1da9f, SentenceCat::Extend),
+    (0x1daa1, 0x1daaf, SentenceCat::Extend),
+    (0x1df00, 0x1df09, SentenceCat::Lower),
+    (0x1df0a, 0x1df0a, SentenceCat::OLetter),
+    (0x1df0b, 0x1df1e, SentenceCat::Lower),
+    (0x1df25, 0x1df2a, SentenceCat::Lower),
+    (0x1e000, 0x1e006, SentenceCat::Extend),
+    (0x1e008, 0x1e018, SentenceCat::Extend),
+    (0x1e01b, 0x1e021, SentenceCat::Extend),
+    (0x1e023, 0x1e024, SentenceCat::Extend),
+    (0x1e026, 0x1e02a, SentenceCat::Extend),
+    (0x1e030, 0x1e06d, SentenceCat::Lower),
+    (0x1e08f, 0x1e08f, SentenceCat::Extend),
+    (0x1e100, 0x1e12c, SentenceCat::OLetter),
+    (0x1e130, 0x1e136, SentenceCat::Extend),
+    (0x1e137, 0x1e13d, SentenceCat::OLetter),
+    (0x1e140, 0x1e149, SentenceCat::Numeric),
+    (0x1e14e, 0x1e14e, SentenceCat::OLetter),
+    (0x1e290, 0x1e2ad, SentenceCat::OLetter),
+    (0x1e2ae, 0x1e2ae, SentenceCat::Extend),
+    (0x1e2c0, 0x1e2eb, SentenceCat::OLetter),
+    (0x1e2ec, 0x1e2ef, SentenceCat::Extend),
+    (0x1e2f0, 0x1e2f9, SentenceCat::Numeric),
+    (0x1e4d0, 0x1e4eb, SentenceCat::OLetter),
+    (0x1e4ec, 0x1e4ef, SentenceCat::Extend),
+    (0x1e4f0, 0x1e4f9, SentenceCat::Numeric),
+    (0x1e5d0, 0x1e5ed, SentenceCat::OLetter),
+    (0x1e5ee, 0x1e5ef, SentenceCat::Extend),
+    (0x1e5f0, 0x1e5f0, SentenceCat::OLetter),
+    (0x1e5f1, 0x1e5fa, SentenceCat::Numeric),
+    (0x1e6c0, 0x1e6de, SentenceCat::OLetter),
+    (0x1e6e0, 0x1e6e2, SentenceCat::OLetter),
+    (0x1e6e3, 0x1e6e3, SentenceCat::Extend),
+    (0x1e6e4, 0x1e6e5, SentenceCat::OLetter),
+    (0x1e6e6, 0x1e6e6, SentenceCat::Extend),
+    (0x1e6e7, 0x1e6ed, SentenceCat::OLetter),
+    (0x1e6ee, 0x1e6ef, SentenceCat::Extend),
+    (0x1e6f0, 0x1e6f4, SentenceCat::OLetter),
+    (0x1e6f5, 0x1e6f5, SentenceCat::Extend),
+    (0x1e6fe, 0x1e6ff, SentenceCat::OLetter),
+    (0x1e7e0, 0x1e7e6, SentenceCat::OLetter),
+    (0x1e7e8, 0x1e7eb, SentenceCat::OLetter),
+    (0x1e7ed, 0x1e7ee, SentenceCat::OLetter),
+    (0x1e7f0, 0x1e7fe, SentenceCat::OLetter),
+    (0x1e800, 0x1e8c4, SentenceCat::OLetter),
+    (0x1e8d0, 0x1e8d6, SentenceCat::Extend),
+    (0x1e900, 0x1e921, SentenceCat::Upper),
+    (0x1e922, 0x1e943, SentenceCat::Lower),
+    (0x1e944, 0x1e94a, SentenceCat::Extend),
+    (0x1e94b, 0x1e94b, SentenceCat::OLetter),
+    (0x1e950, 0x1e959, SentenceCat::Numeric),
+    (0x1ee00, 0x1ee03, SentenceCat::OLetter),
+    (0x1ee05, 0x1ee1f, SentenceCat::OLetter),
+    (0x1ee21, 0x1ee22, SentenceCat::OLetter),
+    (0x1ee24, 0x1ee24, SentenceCat::OLetter),
+    (0x1ee27, 0x1ee27, SentenceCat::OLetter),
+    (0x1ee29, 0x1ee32, SentenceCat::OLetter),
+    (0x1ee34, 0x1ee37, SentenceCat::OLetter),
+    (0x1ee39, 0x1ee39, SentenceCat::OLetter),
+    (0x1ee3b, 0x1ee3b, SentenceCat::OLetter),
+    (0x1ee42, 0x1ee42, SentenceCat::OLetter),
+    (0x1ee47, 0x1ee47, SentenceCat::OLetter),
+    (0x1ee49, 0x1ee49, SentenceCat::OLetter),
+    (0x1ee4b, 0x1ee4b, SentenceCat::OLetter),
+    (0x1ee4d, 0x1ee4f, SentenceCat::OLetter),
+    (0x1ee51, 0x1ee52, SentenceCat::OLetter),
+    (0x1ee54, 0x1ee54, SentenceCat::OLetter),
+    (0x1ee57, 0x1ee57, SentenceCat::OLetter),
+    (0x1ee59, 0x1ee59, SentenceCat::OLetter),
+    (0x1ee5b, 0x1ee5b, SentenceCat::OLetter),
+    (0x1ee5d, 0x1ee5d, SentenceCat::OLetter),
+    (0x1ee5f, 0x1ee5f, SentenceCat::OLetter),
+    (0x1ee61, 0x1ee62, SentenceCat::OLetter),
+    (0x1ee64, 0x1ee64, SentenceCat::OLetter),
+    (0x1ee67, 0x1ee6a, SentenceCat::OLetter),
+    (0x1ee6c, 0x1ee72, SentenceCat::OLetter),
+    (0x1ee74, 0x1ee77, SentenceCat::OLetter),
+    (0x1ee79, 0x1ee7c, SentenceCat::OLetter),
+    (0x1ee7e, 0x1ee7e, SentenceCat::OLetter),
+    (0x1ee80, 0x1ee89, SentenceCat::OLetter),
+    (0x1ee8b, 0x1ee9b, SentenceCat::OLetter),
+    (0x1eea1, 0x1eea3, SentenceCat::OLetter),
+    (0x1eea5, 0x1eea9, SentenceCat::OLetter),
+    (0x1eeab, 0x1eebb, SentenceCat::OLetter),
+    (0x1f130, 0x1f149, SentenceCat::Upper),
+    (0x1f150, 0x1f169, SentenceCat::Upper),
+    (0x1f170, 0x1f189, SentenceCat::Upper),
+    (0x1f676, 0x1f678, SentenceCat::Close),
+    (0x1fbf0, 0x1fbf9, SentenceCat::Numeric),
+    (0x20000, 0x2a6df, SentenceCat::OLetter),
+    (0x2a700, 0x2b81d, SentenceCat::OLetter),
+    (0x2b820, 0x2cead, SentenceCat::OLetter),
+    (0x2ceb0, 0x2ebe0, SentenceCat::OLetter),
+    (0x2ebf0, 0x2ee5d, SentenceCat::OLetter),
+    (0x2f800, 0x2fa1d, SentenceCat::OLetter),
+    (0x30000, 0x3134a, SentenceCat::OLetter),
+    (0x31350, 0x33479, SentenceCat::OLetter),
+    (0xe0001, 0xe0001, SentenceCat::Format),
+    (0xe0020, 0xe007f, SentenceCat::Extend),
+    (0xe0100, 0xe01ef, SentenceCat::Extend),
+];
+
+/// East_Asian_Width `W` and `F` ranges.
+pub(crate) const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115f),
+    (0x231a, 0x231b),
+    (0x2329, 0x232a),
+    (0x23e9, 0x23ec),
+    (0x23f0, 0x23f0),
+    (0x23f3, 0x23f3),
+    (0x25fd, 0x25fe),
+    (0x2614, 0x2615),
+    (0x2630, 0x2637),
+    (0x2648, 0x2653),
+    (0x267f, 0x267f),
+    (0x268a, 0x268f),
+    (0x2693, 0x2693),
+    (0x26a1, 0x26a1),
+    (0x26aa, 0x26ab),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26ce, 0x26ce),
+    (0x26d4, 0x26d4),
+    (0x26ea, 0x26ea),
+    (0x26f2, 0x26f3),
+    (0x26f5, 0x26f5),
+    (0x26fa, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2705, 0x2705),
+    (0x270a, 0x270b),
+    (0x2728, 0x2728),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x2e80, 0x2e99),
+    (0x2e9b, 0x2ef3),
+    (0x2f00, 0x2fd5),
+    (0x2ff0, 0x303e),
+    (0x3041, 0x3096),
+    (0x3099, 0x30ff),
+    (0x3105, 0x312f),
+    (0x3131, 0x318e),
+    (0x3190, 0x31e5),
+    (0x31ef, 0x321e),
+    (0x3220, 0x3247),
+    (0x3250, 0xa48c),
+    (0xa490, 0xa4c6),
+    (0xa960, 0xa97c),
+    (0xac00, 0xd7a3),
+    (0xf900, 0xfaff),
+    (0xfe10, 0xfe19),
+    (0xfe30, 0xfe52),
+    (0xfe54, 0xfe66),
+    (0xfe68, 0xfe6b),
+    (0xff01, 0xff60),
+    (0xffe0, 0xffe6),
+    (0x16fe0, 0x16fe4),
+    (0x16ff0, 0x16ff6),
+    (0x17000, 0x18cd5),
+    (0x18cff, 0x18d1e),
+    (0x18d80, 0x18df2),
+    (0x1aff0, 0x1aff3),
+    (0x1aff5, 0x1affb),
+    (0x1affd, 0x1affe),
+    (0x1b000, 0x1b122),
+    (0x1b132, 0x1b132),
+    (0x1b150, 0x1b152),
+    (0x1b155, 0x1b155),
+    (0x1b164, 0x1b167),
+    (0x1b170, 0x1b2fb),
+    (0x1d300, 0x1d356),
+    (0x1d360, 0x1d376),
+    (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f200, 0x1f202),
+    (0x1f210, 0x1f23b),
+    (0x1f240, 0x1f248),
+    (0x1f250, 0x1f251),
+    (0x1f260, 0x1f265),
+    (0x1f300, 0x1f320),
+    (0x1f32d, 0x1f335),
+    (0x1f337, 0x1f37c),
+    (0x1f37e, 0x1f393),
+    (0x1f3a0, 0x1f3ca),
+    (0x1f3cf, 0x1f3d3),
+    (0x1f3e0, 0x1f3f0),
+    (0x1f3f4, 0x1f3f4),
+    (0x1f3f8, 0x1f43e),
+    (0x1f440, 0x1f440),
+    (0x1f442, 0x1f4fc),
+    (0x1f4ff, 0x1f53d),
+    (0x1f54b, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f57a, 0x1f57a),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a4),
+    (0x1f5fb, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cc, 0x1f6cc),
+    (0x1f6d0, 0x1f6d2),
+    (0x1f6d5, 0x1f6d8),
+    (0x1f6dc, 0x1f6df),
+    (0x1f6eb, 0x1f6ec),
+    (0x1f6f4, 0x1f6fc),
+    (0x1f7e0, 0x1f7eb),
+    (0x1f7f0, 0x1f7f0),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa70, 0x1fa7c),
+    (0x1fa80, 0x1fa8a),
+    (0x1fa8e, 0x1fac6),
+    (0x1fac8, 0x1fac8),
+    (0x1facd, 0x1fadc),
+    (0x1fadf, 0x1faea),
+    (0x1faef, 0x1faf8),
+    (0x20000, 0x2fffd),
+    (0x30000, 0x3fffd),
+];
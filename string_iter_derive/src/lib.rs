@@ -0,0 +1,125 @@
+//! Derive macro for `string_iter::StringIterToken`.
+//!
+//! Use it through `string_iter` with the `derive` feature,
+//! which re-exports [`StringIterToken`](macro@StringIterToken).
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, LitStr};
+
+mod regex_like;
+
+/// Derive `StringIterToken` for an enum of unit variants,
+/// which turns variant attributes into `Rule`s of a `Lexer`.
+///
+/// * `#[token("...")]` matches a literal [`str`].
+/// * `#[regex_like("...")]` matches a regex-like expression, see below.
+/// * `#[pattern(...)]` matches a run of a `Pattern`, like `pat!('a'..='z')`.
+/// * `#[priority(n)]` breaks ties between matches of the same length, default `0`.
+/// * `#[skip]` discards the matched tokens, like whitespace.
+///
+/// A variant can have multiple matching attributes, and variants without any are ignored.
+/// The rules are added in the order of the variants, then of the attributes.
+///
+/// # Regex-like expressions
+///
+/// A `regex_like` expression is a sequence of atoms,
+/// compiled to code that walks a `StringIter` with `peek()`.
+///
+/// * Atoms are a [`char`], `.` (any [`char`] but `\n`),
+///   `\d`, `\w`, `\s`, their negations `\D`, `\W`, `\S`,
+///   an escaped [`char`] like `\.`, or a class like `[a-z_]` or `[^"\\]`.
+/// * Quantifiers are `?`, `*`, `+`, `{n}`, `{n,}` and `{n,m}`.
+///
+/// Quantifiers are greedy and never backtrack, so `a*a` never matches.
+/// Groups, alternation and anchors are not supported.
+///
+/// # Example
+///
+/// ```
+/// use string_iter::{prelude::*, StringIterToken};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, StringIterToken)]
+/// enum Token {
+///     #[token("let")]
+///     #[priority(1)]
+///     Let,
+///     #[regex_like("[a-zA-Z_][a-zA-Z0-9_]*")]
+///     Ident,
+///     #[pattern(pat!('0'..='9'))]
+///     Int,
+///     #[regex_like(r"\d+\.\d+")]
+///     Float,
+///     #[token("=")]
+///     Eq,
+///     #[regex_like(r"\s+")]
+///     #[skip]
+///     Space,
+/// }
+///
+/// let tokens: Vec<_> = Token::lexer("let x = 1.5")
+///     .map(|token| token.map(|token| (token.kind, token.text)))
+///     .collect();
+/// assert_eq!(tokens, [
+///     Ok((Token::Let, "let")),
+///     Ok((Token::Ident, "x")),
+///     Ok((Token::Eq, "=")),
+///     Ok((Token::Float, "1.5")),
+/// ]);
+/// ```
+#[proc_macro_derive(StringIterToken, attributes(token, regex_like, pattern, priority, skip))]
+pub fn derive_string_iter_token(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(&input.ident, "`StringIterToken` can only be derived for enums"));
+    };
+    let mut rules = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let mut matchers = Vec::new();
+        let mut priority = None;
+        let mut skip = None;
+        for attr in &variant.attrs {
+            let path = attr.path();
+            if path.is_ident("token") {
+                let literal: LitStr = attr.parse_args()?;
+                matchers.push(quote!(::string_iter::Rule::literal(#literal, Self::#ident)));
+            } else if path.is_ident("regex_like") {
+                let regex: LitStr = attr.parse_args()?;
+                let matcher = regex_like::compile(&regex)?;
+                matchers.push(quote!(::string_iter::Rule::new(#matcher, Self::#ident)));
+            } else if path.is_ident("pattern") {
+                let pattern: Expr = attr.parse_args()?;
+                matchers.push(quote!(::string_iter::Rule::pattern(#pattern, Self::#ident)));
+            } else if path.is_ident("priority") {
+                let value: Expr = attr.parse_args()?;
+                priority = Some(quote!(.priority(#value)));
+            } else if path.is_ident("skip") {
+                attr.meta.require_path_only()?;
+                skip = Some(quote!(.skip()));
+            }
+        }
+        if !matchers.is_empty() && !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(&variant.fields, "token variants cannot have fields"));
+        }
+        if matchers.is_empty() && (priority.is_some() || skip.is_some()) {
+            return Err(Error::new_spanned(ident,
+                "expected a `#[token]`, `#[regex_like]` or `#[pattern]` attribute"));
+        }
+        rules.extend(matchers.into_iter().map(|matcher| quote!(#matcher #priority #skip)));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::string_iter::StringIterToken for #name #ty_generics #where_clause {
+            fn rules<'t>() -> ::std::vec::Vec<::string_iter::Rule<'t, Self>> {
+                ::std::vec![#(#rules),*]
+            }
+        }
+    })
+}
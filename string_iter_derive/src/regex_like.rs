@@ -0,0 +1,198 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{Error, LitStr};
+
+/// A set of [`char`]s in an [`Atom`].
+enum Class {
+    Char(char),
+    Range(char, char),
+    Digit,
+    Word,
+    Space,
+}
+
+impl Class {
+    fn condition(&self) -> TokenStream {
+        match *self {
+            Class::Char(c) => {
+                let c = Literal::character(c);
+                quote!(c == #c)
+            }
+            Class::Range('a', 'z') => quote!(c.is_ascii_lowercase()),
+            Class::Range('A', 'Z') => quote!(c.is_ascii_uppercase()),
+            Class::Range('0', '9') => quote!(c.is_ascii_digit()),
+            Class::Range(start, end) => {
+                let (start, end) = (Literal::character(start), Literal::character(end));
+                quote!((#start..=#end).contains(&c))
+            }
+            Class::Digit => quote!(c.is_ascii_digit()),
+            Class::Word => quote!((c.is_alphanumeric() || c == '_')),
+            Class::Space => quote!(c.is_whitespace()),
+        }
+    }
+}
+
+/// A [`char`] matching any of `classes`, or none if `negated`, repeated `min..=max` times.
+struct Atom {
+    classes: Vec<Class>,
+    negated: bool,
+    min: usize,
+    max: Option<usize>,
+}
+
+impl Atom {
+    fn matcher(&self) -> TokenStream {
+        let classes = self.classes.iter().map(Class::condition);
+        let condition = match self.negated {
+            false => quote!((#(#classes)||*)),
+            true => quote!(!(#(#classes)||*)),
+        };
+        let matches = quote!(iter.peek().is_some_and(|(c, _)| #condition));
+        match (self.min, self.max) {
+            (_, Some(0)) => TokenStream::new(),
+            (1, Some(1)) => quote!(if !#matches { return false; } iter.next();),
+            (0, Some(1)) => quote!(if #matches { iter.next(); }),
+            (0, None) => quote!(while #matches { iter.next(); }),
+            (min, max) => {
+                let bound = max.map(|max| quote!(n < #max &&));
+                let check = (min > 0).then(|| quote!(if n < #min { return false; }));
+                quote!({
+                    let mut n = 0usize;
+                    while #bound #matches {
+                        iter.next();
+                        n += 1;
+                    }
+                    #check
+                })
+            }
+        }
+    }
+}
+
+/// Compile a regex-like expression into a closure for `Rule::new`.
+pub(crate) fn compile(regex: &LitStr) -> syn::Result<TokenStream> {
+    let atoms = parse(&regex.value()).map_err(|message| Error::new(regex.span(), message))?;
+    let matchers = atoms.iter().map(Atom::matcher);
+    Ok(quote! {
+        |iter: &mut ::string_iter::StringIter<'t>| -> bool {
+            #(#matchers)*
+            true
+        }
+    })
+}
+
+fn parse(regex: &str) -> Result<Vec<Atom>, String> {
+    let mut chars = regex.chars().peekable();
+    let mut atoms = Vec::new();
+    while let Some(c) = chars.next() {
+        let (classes, negated) = match c {
+            '.' => (vec![Class::Char('\n')], true),
+            '[' => parse_class(&mut chars)?,
+            '\\' => {
+                let (class, negated) = parse_escape(&mut chars)?;
+                (vec![class], negated)
+            }
+            '?' | '*' | '+' | '{' => return Err(format!("`{}` does not follow an atom", c)),
+            '(' | ')' | '|' | '^' | '$' => return Err(format!(
+                "groups, alternation and anchors are not supported, use `\\{}` to match `{}`", c, c)),
+            c => (vec![Class::Char(c)], false),
+        };
+        let (min, max) = parse_quantifier(&mut chars)?;
+        atoms.push(Atom { classes, negated, min, max });
+    }
+    match atoms.is_empty() {
+        true => Err("empty expression".to_owned()),
+        false => Ok(atoms),
+    }
+}
+
+/// Parse an escape after `\`, returns the [`Class`] and whether it is negated.
+fn parse_escape(chars: &mut Peekable<Chars>) -> Result<(Class, bool), String> {
+    Ok(match chars.next().ok_or("trailing `\\`")? {
+        'd' => (Class::Digit, false),
+        'D' => (Class::Digit, true),
+        'w' => (Class::Word, false),
+        'W' => (Class::Word, true),
+        's' => (Class::Space, false),
+        'S' => (Class::Space, true),
+        'n' => (Class::Char('\n'), false),
+        'r' => (Class::Char('\r'), false),
+        't' => (Class::Char('\t'), false),
+        c if c.is_ascii_punctuation() => (Class::Char(c), false),
+        c => return Err(format!("unknown escape `\\{}`", c)),
+    })
+}
+
+/// Parse a class after `[`, returns the [`Class`]es and whether they are negated.
+fn parse_class(chars: &mut Peekable<Chars>) -> Result<(Vec<Class>, bool), String> {
+    let negated = chars.next_if_eq(&'^').is_some();
+    let mut classes = Vec::new();
+    loop {
+        let start = match chars.next().ok_or("unterminated `[`")? {
+            ']' if classes.is_empty() => return Err("empty class `[]`".to_owned()),
+            ']' => return Ok((classes, negated)),
+            '\\' => match parse_escape(chars)? {
+                (Class::Char(c), _) => c,
+                (class, false) => {
+                    classes.push(class);
+                    continue;
+                }
+                (_, true) => return Err("negated escapes are not supported in a class".to_owned()),
+            },
+            c => c,
+        };
+        let mut ahead = chars.clone();
+        if ahead.next() != Some('-') || matches!(ahead.peek(), None | Some(']')) {
+            classes.push(Class::Char(start));
+            continue;
+        }
+        chars.next();
+        let end = match chars.next().ok_or("unterminated `[`")? {
+            '\\' => match parse_escape(chars)? {
+                (Class::Char(c), _) => c,
+                _ => return Err("a range cannot end with a class".to_owned()),
+            },
+            c => c,
+        };
+        if end < start {
+            return Err(format!("invalid range `{}-{}`", start, end));
+        }
+        classes.push(Class::Range(start, end));
+    }
+}
+
+/// Parse an optional quantifier, returns the minimum and maximum repetitions.
+fn parse_quantifier(chars: &mut Peekable<Chars>) -> Result<(usize, Option<usize>), String> {
+    let quantifier = match chars.peek() {
+        Some('?') => (0, Some(1)),
+        Some('*') => (0, None),
+        Some('+') => (1, None),
+        Some('{') => {
+            chars.next();
+            let mut inner = String::new();
+            loop {
+                match chars.next().ok_or("unterminated `{`")? {
+                    '}' => break,
+                    c => inner.push(c),
+                }
+            }
+            let invalid = || format!("invalid quantifier `{{{}}}`", inner);
+            let number = |s: &str| s.trim().parse::<usize>().map_err(|_| invalid());
+            let (min, max) = match inner.split_once(',') {
+                None => (number(&inner)?, Some(number(&inner)?)),
+                Some((min, max)) if max.trim().is_empty() => (number(min)?, None),
+                Some((min, max)) => (number(min)?, Some(number(max)?)),
+            };
+            if max.is_some_and(|max| max < min) {
+                return Err(invalid());
+            }
+            return Ok((min, max));
+        }
+        _ => return Ok((1, Some(1))),
+    };
+    chars.next();
+    Ok(quantifier)
+}
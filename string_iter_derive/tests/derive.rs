@@ -0,0 +1,96 @@
+use string_iter::{prelude::*, StringIterToken, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StringIterToken)]
+enum Token {
+    #[token("fn")]
+    #[token("let")]
+    #[priority(1)]
+    Keyword,
+    #[regex_like("[a-zA-Z_][a-zA-Z0-9_]*")]
+    Ident,
+    #[pattern(pat!('0'..='9'))]
+    Int,
+    #[regex_like(r"\d+\.\d+")]
+    #[regex_like(r"\d+\.\d+[eE][+\-]?\d{1,3}")]
+    Float,
+    #[regex_like(r#""[^"\\\n]*""#)]
+    Str,
+    #[token("+")]
+    Plus,
+    #[token("+=")]
+    PlusEq,
+    #[regex_like(r"//.*")]
+    #[skip]
+    Comment,
+    #[regex_like(r"\s+")]
+    #[skip]
+    Space,
+}
+
+type Tokens<'t> = Vec<Result<(Token, &'t str), &'t str>>;
+
+fn lex(source: &str) -> Tokens<'_> {
+    Token::lexer(source).map(|token| match token {
+        Ok(token) => Ok((token.kind, token.text)),
+        Err(err) => Err(err.as_str()),
+    }).collect()
+}
+
+#[test]
+fn derive_test(){
+    use Token::*;
+    let cases: [(&str, Tokens); 7] = [
+        ("", vec![]),
+        ("let lets fn_", vec![Ok((Keyword, "let")), Ok((Ident, "lets")), Ok((Ident, "fn_"))]),
+        ("1 1.5 2.0e10 3.", vec![
+            Ok((Int, "1")), Ok((Float, "1.5")), Ok((Float, "2.0e10")), Ok((Int, "3")), Err("."),
+        ]),
+        ("x+=1+2 // sum\ny", vec![
+            Ok((Ident, "x")), Ok((PlusEq, "+=")), Ok((Int, "1")), Ok((Plus, "+")), Ok((Int, "2")),
+            Ok((Ident, "y")),
+        ]),
+        (r#""a b" "c"#, vec![Ok((Str, "\"a b\"")), Err("\""), Ok((Ident, "c"))]),
+        ("a @# b", vec![Ok((Ident, "a")), Err("@"), Err("#"), Ok((Ident, "b"))]),
+        ("蟹 a", vec![Err("蟹"), Ok((Ident, "a"))]),
+    ];
+    for (source, tokens) in cases {
+        assert_eq!(lex(source), tokens, "{source:?}");
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StringIterToken)]
+enum Repeat {
+    #[regex_like("a{2}")]
+    Two,
+    #[regex_like("b{2,}")]
+    Many,
+    #[regex_like("c{1,2}")]
+    UpToTwo,
+    #[regex_like("x?y")]
+    MaybeX,
+    #[regex_like(r"[\d\-.]+")]
+    Class,
+    // not a token, ignored by the derive
+    #[allow(dead_code)]
+    Other(usize),
+}
+
+#[test]
+fn quantifier_test(){
+    let tokens: Vec<_> = Repeat::lexer("aaabbbcccy-1.2xy").collect();
+    let kinds: Vec<_> = tokens.iter().map(|token| match token {
+        Ok(token) => Ok((token.kind, token.text)),
+        Err(err) => Err(err.as_str()),
+    }).collect();
+    assert_eq!(kinds, [
+        Ok((Repeat::Two, "aa")),
+        Err("a"),
+        Ok((Repeat::Many, "bbb")),
+        Ok((Repeat::UpToTwo, "cc")),
+        Ok((Repeat::UpToTwo, "c")),
+        Ok((Repeat::MaybeX, "y")),
+        Ok((Repeat::Class, "-1.2")),
+        Ok((Repeat::MaybeX, "xy")),
+    ]);
+    assert_eq!(tokens[1].unwrap_err().span(), Span::new(2, 3));
+}
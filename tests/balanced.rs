@@ -0,0 +1,130 @@
+use std::num::NonZeroUsize;
+
+use string_iter::{prelude::*, Pattern, patterns::{Balanced, BalanceError, BalanceErrorKind}};
+
+/// Disables the `search` fast paths.
+struct SlowPattern<P>(P);
+
+impl<P: Pattern> Pattern for SlowPattern<P> {
+    type Err = P::Err;
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Self::Err> { self.0.matches(c, s) }
+    fn len(&self) -> NonZeroUsize { self.0.len() }
+    fn match_len(&self, c: char, s: &str) -> usize { self.0.match_len(c, s) }
+    fn sep(&self) -> Sep { self.0.sep() }
+}
+
+fn code() -> Balanced<'static> {
+    Balanced::new(Balanced::ALL_BRACKETS)
+        .quotes(&['"', '\''])
+        .comments(&[("//", "\n"), ("/*", "*/")])
+}
+
+#[test]
+fn balanced_test(){
+    let cases = [
+        ("(a)b", "(a)"),
+        ("x(a)b", "x(a)"),
+        ("([{<>}])!", "([{<>}])"),
+        ("f(a, g(b), [c]) + 1", "f(a, g(b), [c])"),
+        (r#"("(", ')') x"#, r#"("(", ')')"#),
+        (r#"("\")", x)y"#, r#"("\")", x)"#),
+        ("(a /* ) */ b)c", "(a /* ) */ b)"),
+        ("(a // )\n b)c", "(a // )\n b)"),
+        ("(a /*/ ) */)c", "(a /*/ ) */)"),
+        ("(é🦀)ü", "(é🦀)"),
+        ("no delimiters", "no delimiters"),
+    ];
+    for (input, output) in cases {
+        let mut iter = input.str_iter();
+        assert_eq!(iter.try_next_slice(code().sep_with(Sep::Yield)), Ok(Some(output)), "{input}");
+        assert_eq!(iter.as_str(), &input[output.len()..]);
+        let mut iter = input.str_iter();
+        assert_eq!(iter.try_next_slice(SlowPattern(code().sep_with(Sep::Yield))), Ok(Some(output)), "{input}");
+    }
+
+    let mut iter = "{a}b".str_iter();
+    assert_eq!(iter.try_next_slice(Balanced::brackets()), Ok(Some("{a")));
+    assert_eq!(iter.as_str(), "}b");
+}
+
+#[test]
+fn balanced_back_test(){
+    let cases = [
+        ("a(b)", "(b)"),
+        ("a(b)c", "(b)c"),
+        ("(a)(b(c))d", "(b(c))d"),
+        (r#"x("(")"#, r#"("(")"#),
+        ("x(a) // (b)", "(a) // (b)"),
+        ("no delimiters", "no delimiters"),
+    ];
+    for (input, output) in cases {
+        let mut iter = input.str_iter();
+        assert_eq!(iter.try_next_slice_back(code().sep_with(Sep::Yield)), Ok(Some(output)), "{input}");
+        assert_eq!(iter.as_str(), &input[..input.len() - output.len()]);
+    }
+    let mut iter = "a(b)c".str_iter();
+    assert_eq!(iter.try_next_slice_back(Balanced::brackets()), Ok(Some("b)c")));
+    assert_eq!(iter.as_str(), "a(");
+}
+
+#[test]
+fn balanced_stop_test(){
+    let arg = || Balanced::brackets().quotes(&['"']).stop_at(&[',']).sep_with(Sep::Split);
+    let mut iter = r#"a, f(b, c), "d, e", [g, h]"#.str_iter();
+    let mut args = Vec::new();
+    while let Some(s) = iter.try_next_slice(arg()).unwrap() {
+        args.push(s.trim());
+    }
+    assert_eq!(args, ["a", "f(b, c)", r#""d, e""#, "[g, h]"]);
+
+    let mut iter = r#"a, f(b, c), "d, e", [g, h]"#.str_iter();
+    let mut args = Vec::new();
+    while let Some(s) = iter.try_next_slice_back(arg()).unwrap() {
+        args.push(s.trim());
+    }
+    assert_eq!(args, ["[g, h]", r#""d, e""#, "f(b, c)", "a"]);
+}
+
+#[test]
+fn balanced_error_test(){
+    let cases = [
+        (")", BalanceErrorKind::Unmatched(')'), 0),
+        ("a]", BalanceErrorKind::Unmatched(']'), 1),
+        ("(]", BalanceErrorKind::Mismatched { expected: ')', found: ']' }, 1),
+        ("{(})", BalanceErrorKind::Mismatched { expected: ')', found: '}' }, 2),
+        ("(a", BalanceErrorKind::Unclosed { expected: ')' }, 2),
+        ("([a]", BalanceErrorKind::Unclosed { expected: ')' }, 4),
+        ("(\"a)", BalanceErrorKind::Unclosed { expected: ')' }, 4),
+        ("(/* a)", BalanceErrorKind::Unclosed { expected: ')' }, 6),
+    ];
+    for (input, kind, offset) in cases {
+        let mut iter = input.str_iter();
+        let err = iter.try_next_slice(code()).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (kind, offset), "{input}");
+        assert_eq!(iter.as_str(), input);
+        let err = iter.try_next_slice_back(code()).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (kind, offset), "{input}");
+        assert_eq!(iter.as_str(), input);
+    }
+
+    // without the fast path, errors are still found front to back
+    let err = "(]".str_iter().try_next_slice(SlowPattern(code())).unwrap_err();
+    assert_eq!(err.kind(), BalanceErrorKind::Mismatched { expected: ')', found: ']' });
+
+    // unclosed groups are found at the end of the string
+    let semicolon = |c: char| Ok::<_, BalanceError>(c == ';');
+    for input in ["(a", "x(/* ) */", "(é🦀"] {
+        let err = input.str_iter().try_next_slice(SlowPattern(code())).unwrap_err();
+        assert_eq!(err.kind(), BalanceErrorKind::Unclosed { expected: ')' }, "{input}");
+        assert_eq!(err.offset(), input.len(), "{input}");
+        let err = input.str_iter().try_next_slice(code().or(semicolon)).unwrap_err();
+        assert_eq!(err.kind(), BalanceErrorKind::Unclosed { expected: ')' }, "{input}");
+    }
+    let mut iter = "(a; b".str_iter();
+    assert_eq!(iter.try_next_slice(code().or(semicolon)), Ok(Some("(a")));
+
+    let deep = "(".repeat(Balanced::MAX_DEPTH + 1);
+    let err = deep.str_iter().try_next_slice(code()).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (BalanceErrorKind::TooDeep, Balanced::MAX_DEPTH));
+    assert_eq!(err.to_string(), "delimiters nested too deep at byte 64");
+}
@@ -0,0 +1,41 @@
+use string_iter::{prelude::*, Case, StringIter};
+
+fn words(s: &str) -> Vec<&str> {
+    StringIter::new(s).case_words().collect()
+}
+
+#[test]
+fn case_words_test(){
+    assert_eq!(words("thisIsCamelCase"), ["this", "Is", "Camel", "Case"]);
+    assert_eq!(words("HTTPServer"), ["HTTP", "Server"]);
+    assert_eq!(words("getHTTPResponseCode"), ["get", "HTTP", "Response", "Code"]);
+    assert_eq!(words("IOError"), ["IO", "Error"]);
+    assert_eq!(words("ABC"), ["ABC"]);
+    assert_eq!(words("utf8Decoder"), ["utf8", "Decoder"]);
+    assert_eq!(words("Base64Encode"), ["Base64", "Encode"]);
+    assert_eq!(words("snake_case-and kebab"), ["snake", "case", "and", "kebab"]);
+    assert_eq!(words("__leading__trailing__"), ["leading", "trailing"]);
+    assert_eq!(words("ÉtéÀParis"), ["Été", "À", "Paris"]);
+    assert_eq!(words(""), Vec::<&str>::new());
+    assert_eq!(words("--"), Vec::<&str>::new());
+}
+
+#[test]
+fn to_case_test(){
+    let cases = [
+        ("HTTPServer", ["http_server", "http-server", "httpServer", "HttpServer", "HTTP_SERVER", "Http Server"]),
+        ("user_id", ["user_id", "user-id", "userId", "UserId", "USER_ID", "User Id"]),
+        ("Title Case words", ["title_case_words", "title-case-words", "titleCaseWords", "TitleCaseWords", "TITLE_CASE_WORDS", "Title Case Words"]),
+        ("version2Beta", ["version2_beta", "version2-beta", "version2Beta", "Version2Beta", "VERSION2_BETA", "Version2 Beta"]),
+        ("SCREAMING_CASE", ["screaming_case", "screaming-case", "screamingCase", "ScreamingCase", "SCREAMING_CASE", "Screaming Case"]),
+    ];
+    let styles = [Case::Snake, Case::Kebab, Case::Camel, Case::Pascal, Case::ScreamingSnake, Case::Title];
+    for (input, outputs) in cases {
+        for (case, output) in styles.into_iter().zip(outputs) {
+            assert_eq!(input.str_iter().to_case(case).to_string(), output);
+            assert_eq!(case.convert(input), output);
+        }
+    }
+    // the adapter works with any formatter
+    assert_eq!(format!("[{}]", "fooBar".str_iter().to_case(Case::Kebab)), "[foo-bar]");
+}
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use string_iter::{prelude::*, CsvReader, CsvErrorKind};
+
+fn records(reader: CsvReader) -> Vec<Vec<String>> {
+    reader.map(|record| record.unwrap().map(|field| field.into_owned()).collect()).collect()
+}
+
+#[test]
+fn csv_test(){
+    let cases: [(&str, &[&[&str]]); 11] = [
+        ("a,b,c", &[&["a", "b", "c"]]),
+        ("a,b\nc,d\n", &[&["a", "b"], &["c", "d"]]),
+        ("a,b\r\nc,d\r\n", &[&["a", "b"], &["c", "d"]]),
+        ("a\n\n\r\nb\n", &[&["a"], &[""], &[""], &["b"]]),
+        (",\n,", &[&["", ""], &["", ""]]),
+        ("\"\"\n", &[&[""]]),
+        ("a,,\n,,b", &[&["a", "", ""], &["", "", "b"]]),
+        ("\"a,b\",\"c\nd\",\"e\"\"f\"", &[&["a,b", "c\nd", "e\"f"]]),
+        ("\"\",\"\"\"\"", &[&["", "\""]]),
+        ("a\rb,c", &[&["a\rb", "c"]]),
+        ("ü,\"🦀,é\"", &[&["ü", "🦀,é"]]),
+    ];
+    for (input, output) in cases {
+        assert_eq!(records(CsvReader::new(input)), output, "{input:?}");
+        // fields in reverse
+        let reversed: Vec<Vec<String>> = CsvReader::new(input)
+            .map(|record| record.unwrap().rev().map(|field| field.into_owned()).collect())
+            .collect();
+        let expected: Vec<Vec<&str>> = output.iter().map(|r| r.iter().rev().copied().collect()).collect();
+        assert_eq!(reversed, expected, "{input:?}");
+    }
+}
+
+#[test]
+fn csv_borrow_test(){
+    let input = "plain,\"quoted\",\"dou\"\"bled\"";
+    let mut record = input.str_iter().into_csv().next().unwrap().unwrap();
+    assert_eq!(record.len(), 3);
+    assert_eq!(record.as_str(), input);
+    assert!(matches!(record.next(), Some(Cow::Borrowed("plain"))));
+    assert!(matches!(record.next(), Some(Cow::Borrowed("quoted"))));
+    assert!(matches!(record.next(), Some(Cow::Owned(s)) if s == "dou\"bled"));
+    assert_eq!(record.next(), None);
+
+    let record = CsvReader::new(input).next().unwrap().unwrap();
+    let raw: Vec<_> = record.into_raw_fields().collect();
+    assert_eq!(raw, ["plain", "\"quoted\"", "\"dou\"\"bled\""]);
+}
+
+#[test]
+fn csv_config_test(){
+    assert_eq!(records(CsvReader::tsv("a\tb,c\n1\t2")), [["a", "b,c"], ["1", "2"]]);
+    assert_eq!(records(CsvReader::new("a;b\n1;2").delimiter(';')), [["a", "b"], ["1", "2"]]);
+    assert_eq!(records(CsvReader::new("aΣb\n1Σ2").delimiter('Σ')), [["a", "b"], ["1", "2"]]);
+    assert_eq!(records(CsvReader::new("a,b\n\n\r\nc,d").skip_blank_lines(true)), [["a", "b"], ["c", "d"]]);
+
+    let mut reader = CsvReader::new("id,name\n1,ferris\n2,corro").has_headers(true);
+    assert_eq!(reader.header_index("name"), Some(1));
+    assert_eq!(reader.header_index("age"), None);
+    let headers: Vec<_> = reader.headers().unwrap().unwrap().collect();
+    assert_eq!(headers, ["id", "name"]);
+    assert_eq!(records(reader), [["1", "ferris"], ["2", "corro"]]);
+
+    assert!(CsvReader::new("").has_headers(true).headers().unwrap().is_none());
+    assert!(CsvReader::new("a,b").headers().unwrap().is_none());
+
+    // flexible
+    let rows = records(CsvReader::new("a,b\nc\nd,e,f").flexible(true));
+    assert_eq!(rows, [vec!["a", "b"], vec!["c"], vec!["d", "e", "f"]]);
+
+    // strict mode reports and skips the record
+    let mut reader = CsvReader::new("a,b\nc\nd,e");
+    assert!(reader.next().unwrap().is_ok());
+    let err = reader.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), CsvErrorKind::ColumnCount { expected: 2, found: 1 });
+    assert_eq!((err.position().line, err.position().column), (2, 1));
+    assert_eq!(err.to_string(), "expected 2 fields, found 1 at 2:1");
+    assert_eq!(reader.next().unwrap().unwrap().collect::<Vec<_>>(), ["d", "e"]);
+    assert!(reader.next().is_none());
+
+    // a blank line is a record with one field
+    let mut reader = CsvReader::new("a,b\n\nc,d");
+    assert!(reader.next().unwrap().is_ok());
+    let err = reader.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), CsvErrorKind::ColumnCount { expected: 2, found: 1 });
+    assert_eq!(err.position().line, 2);
+}
+
+#[test]
+fn csv_error_test(){
+    let cases: [(&str, CsvErrorKind, usize, usize, &[&str]); 6] = [
+        ("a,\"b", CsvErrorKind::UnterminatedQuote, 1, 3, &[]),
+        ("a,b\nc,\"d\ne", CsvErrorKind::UnterminatedQuote, 2, 3, &["e"]),
+        ("a,b\"c\r\nd", CsvErrorKind::UnexpectedQuote, 1, 4, &["d"]),
+        ("x\n\"a\"b", CsvErrorKind::UnexpectedChar, 2, 4, &[]),
+        ("\"a\nb\" c\nd,e", CsvErrorKind::UnexpectedChar, 2, 3, &["d", "e"]),
+        ("a\rb,\"c\"d\ne", CsvErrorKind::UnexpectedChar, 1, 8, &["e"]),
+    ];
+    for (input, kind, line, column, next) in cases {
+        let mut reader = CsvReader::new(input).flexible(true);
+        let err = reader.find_map(|r| r.err()).unwrap();
+        assert_eq!((err.kind(), err.position().line, err.position().column), (kind, line, column), "{input:?}");
+        // the reader continues with the next line
+        let rest: Vec<_> = reader.flatten().flatten().collect();
+        assert_eq!(rest, next, "{input:?}");
+    }
+
+    // a lone `\r` is not a line break
+    let mut reader = CsvReader::new("a\rb\nc,d\ne");
+    assert_eq!(reader.next().unwrap().unwrap().collect::<Vec<_>>(), ["a\rb"]);
+    let err = reader.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), CsvErrorKind::ColumnCount { expected: 1, found: 2 });
+    assert_eq!((err.position().line, err.position().column, err.position().byte), (2, 1, 4));
+    let record = reader.next().unwrap().unwrap();
+    assert_eq!((record.position().line, record.position().column), (3, 1));
+}
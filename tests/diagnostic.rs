@@ -0,0 +1,105 @@
+use string_iter::{prelude::*, Diagnostic, Label, Level, Span};
+
+#[test]
+fn diagnostic_alignment_test(){
+    // tabs expand to tab stops, wide characters take 2 columns
+    let source = "\tx = '🦀' + 数字;";
+    let crab = &source[source.find('\'').unwrap()..][..6];
+    let digits = source.substr_back(1..3);
+    let labels = [Label::primary(digits, "not a number"), Label::secondary(crab, "")];
+    let rendered = Diagnostic::new(Level::Warning, "mismatched types", source)
+        .labels(&labels)
+        .to_string();
+    assert_eq!(rendered, "\
+warning: mismatched types
+ --> 1:12
+  |
+1 |     x = '🦀' + 数字;
+  |                ^^^^ not a number
+  |         ----
+");
+    let rendered = Diagnostic::new(Level::Warning, "mismatched types", source)
+        .labels(&labels[..1])
+        .tab_width(2)
+        .to_string();
+    assert!(rendered.ends_with("1 |   x = '🦀' + 数字;\n  |              ^^^^ not a number\n"), "{rendered}");
+}
+
+#[test]
+fn diagnostic_lines_test(){
+    let source = "a\r\nb\nc\nd\ne\nf\ng\nh\ni\nj\nfoo bar\n";
+    let foo = source.find("foo").unwrap();
+    let labels = [
+        Label::secondary(0..1, "first"),
+        Label::primary(Span::new(foo + 4, foo + 7), "bar"),
+        Label::secondary(foo..foo + 3, "foo"),
+        Label::secondary(3..4, "second"),
+        // multiple lines are underlined to the end of the first line
+        Label::secondary(7..11, "multiline"),
+        // ignored, out of bounds
+        Label::primary(100..101, "ignored"),
+        Label::primary("foo", "ignored"),
+    ];
+    let rendered = Diagnostic::new(Level::Error, "several lines", source)
+        .file("f.txt")
+        .labels(&labels)
+        .notes(&["one", "two"])
+        .to_string();
+    assert_eq!(rendered, "\
+error: several lines
+  --> f.txt:11:5
+   |
+ 1 | a
+   | - first
+ 2 | b
+   | - second
+...
+ 4 | d
+   | - multiline
+...
+11 | foo bar
+   |     ^^^ bar
+   | --- foo
+   |
+   = note: one
+   = note: two
+");
+}
+
+#[test]
+fn diagnostic_edge_test(){
+    let source = "abc";
+    // empty spans and spans at the end of the source are one caret wide
+    let labels = [Label::primary(3..3, "end"), Label::secondary(1..1, "")];
+    assert_eq!(Diagnostic::new(Level::Help, "h", source).labels(&labels).to_string(), "\
+help: h
+ --> 1:4
+  |
+1 | abc
+  |    ^ end
+  |  -
+");
+    // without labels
+    assert_eq!(
+        Diagnostic::new(Level::Note, "n", source).notes(&["x"]).to_string(),
+        "note: n\n = note: x\n",
+    );
+    let mut buf = String::new();
+    Diagnostic::new(Level::Error, "e", "").render(&mut buf).unwrap();
+    assert_eq!(buf, "error: e\n");
+
+    // `䷀` is wide since Unicode 16
+    let labels = [Label::primary(3..4, "x")];
+    let rendered = Diagnostic::new(Level::Error, "e", "䷀x").labels(&labels).to_string();
+    assert!(rendered.ends_with("1 | ䷀x\n  |   ^ x\n"), "{rendered}");
+
+    // a lone `\r` is a line break, like in `Position`
+    let labels = [Label::primary(4..5, "c")];
+    assert_eq!(Diagnostic::new(Level::Error, "e", "a\rb c").labels(&labels).to_string(), "\
+error: e
+ --> 2:3
+  |
+2 | b c
+  |   ^ c
+");
+}
@@ -0,0 +1,135 @@
+use string_iter::{prelude::*, StringIter, Expected, Position, ParseError, NumberErrorKind, QuoteStyle, QuoteErrorKind};
+use string_iter::{CsvReader, Lexer, Rule};
+use string_iter::patterns::{Balanced, BalanceErrorKind};
+
+#[test]
+fn trim_by_test(){
+    let cases = [
+        ("", "", ""),
+        ("c", "", ""),
+        ("abc", "abc", "ab"),
+        ("ccabcc", "abcc", "ccab"),
+        ("cccc", "", ""),
+        ("éc🦀c", "éc🦀c", "éc🦀"),
+        ("cé", "é", "cé"),
+    ];
+    for (input, start, end) in cases {
+        let mut iter = input.str_iter();
+        iter.trim_start_by('c');
+        assert_eq!(iter.as_str(), start, "{input:?}");
+        let mut iter = input.str_iter();
+        iter.trim_end_by('c');
+        assert_eq!(iter.as_str(), end, "{input:?}");
+    }
+    // multi-byte chars from the back
+    for input in ["a", "aé", "a🦀🦀", "aé字🦀", "é"] {
+        let mut iter = input.str_iter();
+        iter.trim_end_by(|c: char| !c.is_ascii());
+        assert_eq!(iter.as_str(), input.trim_end_matches(|c: char| !c.is_ascii()), "{input:?}");
+    }
+}
+
+#[test]
+fn try_trim_by_test(){
+    let digit = |c: char| match c {
+        '0'..='9' => Ok(true),
+        '!' => Err(c),
+        _ => Ok(false),
+    };
+    let mut iter = "12ab34".str_iter();
+    assert_eq!(iter.try_trim_by(digit), Ok(()));
+    assert_eq!(iter.as_str(), "ab");
+
+    let mut iter = "12!34".str_iter();
+    assert_eq!(iter.try_trim_start_by(digit), Err('!'));
+    assert_eq!(iter.try_trim_end_by(digit), Err('!'));
+    assert_eq!(iter.as_str(), "12!34");
+
+    // unchanged if the end fails after the start succeeds
+    let mut iter = "12a!".str_iter();
+    assert_eq!(iter.try_trim_by(digit), Err('!'));
+    assert_eq!(iter.as_str(), "12a!");
+}
+
+#[test]
+fn expect_test(){
+    let source = "fn 🦀(x)\n-> y";
+    let mut iter = source.str_iter();
+    assert_eq!(iter.expect_str("fn"), Ok("fn"));
+    assert_eq!(iter.expect_char(' '), Ok(" "));
+    assert_eq!(iter.expect_char('🦀'), Ok("🦀"));
+    assert_eq!(iter.expect(['(', '[']), Ok("("));
+    assert_eq!(iter.expect(char::is_alphabetic), Ok("x"));
+    assert_eq!(iter.expect_chars(2), Ok(")\n"));
+    assert_eq!(iter.expect("->"), Ok("->"));
+
+    let errors = [
+        (iter.clone().expect_char('x').unwrap_err(), Expected::Char('x'), "expected 'x', found ' '"),
+        (iter.clone().expect_str(" z").unwrap_err(), Expected::Str(" z"), "expected \" z\", found ' '"),
+        (iter.clone().expect('0'..='9').unwrap_err(), Expected::Pattern, "expected a match of the pattern, found ' '"),
+        (iter.clone().expect_chars(3).unwrap_err(), Expected::Chars(3), "expected 3 chars, found 2 chars"),
+        (iter.clone().expect_named('0'..='9', "a digit").unwrap_err(), Expected::Named("a digit"), "expected a digit, found ' '"),
+    ];
+    for (err, expected, message) in errors {
+        assert_eq!(err.expected(), expected);
+        assert_eq!(err.found(), Some(' '));
+        assert_eq!(err.as_str(), " y");
+        assert_eq!(err.offset_in(source), Some(13));
+        assert_eq!(err.position_in(source), Some(Position { byte: 13, char: 10, line: 2, column: 3 }));
+        assert_eq!(err.to_string(), message);
+    }
+    assert_eq!(iter.as_str(), " y");
+
+    iter.drain();
+    let err = iter.expect_char('x').unwrap_err();
+    assert_eq!(err.found(), None);
+    assert_eq!(err.to_string(), "expected 'x', found end of input");
+    assert_eq!(err.offset_in(source), Some(source.len()));
+    assert_eq!(err.offset_in("y"), None);
+    assert_eq!(iter.expect('x').unwrap_err().to_string(), "expected a match of the pattern, found end of input");
+    assert_eq!(iter.expect_chars(0), Ok(""));
+}
+
+#[test]
+fn parse_error_conversion_test(){
+    fn parse(source: &str) -> Result<(i32, String), ParseError<'_>> {
+        let mut iter = StringIter::new(source);
+        let (n, _) = iter.next_int::<i32>().map_err(|err| err.into_parse_error(iter.as_str()))?;
+        iter.expect_char(' ')?;
+        let s = iter.next_quoted(QuoteStyle::Single).map_err(|err| err.into_parse_error(iter.as_str()))?;
+        Ok((n, s.decoded().into_owned()))
+    }
+    assert_eq!(parse("12 'a'"), Ok((12, "a".to_owned())));
+
+    let source = "x";
+    let err = parse(source).unwrap_err();
+    assert_eq!(err.expected(), Expected::Number(NumberErrorKind::Empty));
+    assert_eq!((err.found(), err.offset_in(source)), (Some('x'), Some(0)));
+    assert_eq!(err.to_string(), "expected a number, found 'x'");
+
+    let source = "1 2";
+    let err = parse(source).unwrap_err();
+    assert_eq!(err.expected(), Expected::Quoted(QuoteErrorKind::NotQuoted));
+    assert_eq!((err.found(), err.offset_in(source)), (Some('2'), Some(2)));
+
+    let source = "a(]";
+    let mut iter = source.str_iter();
+    iter.skip_front(1);
+    let err = iter.try_next_slice(Balanced::brackets()).unwrap_err().into_parse_error(iter.as_str());
+    assert_eq!(err.expected(), Expected::Balanced(BalanceErrorKind::Mismatched { expected: ')', found: ']' }));
+    assert_eq!((err.found(), err.offset_in(source)), (Some(']'), Some(2)));
+
+    let source = "a 'b";
+    let err = source.str_iter().shell_words().nth(1).unwrap().unwrap_err().into_parse_error(source);
+    assert_eq!((err.expected(), err.as_str()), (Expected::Named("a shell word"), "'b"));
+    assert_eq!(err.to_string(), "expected a shell word, found '\\''");
+
+    let source = "a,b\nc,\"d";
+    let err = CsvReader::new(source).find_map(|r| r.err()).unwrap().into_parse_error(source);
+    assert_eq!((err.expected(), err.offset_in(source)), (Expected::Named("a CSV record"), Some(6)));
+
+    let source = "ab!";
+    let mut lexer = Lexer::new(source).rule(Rule::pattern('a'..='z', ()));
+    let err = lexer.find_map(|t| t.err()).unwrap().into_parse_error(source);
+    assert_eq!((err.expected(), err.as_str()), (Expected::Named("a token"), "!"));
+}
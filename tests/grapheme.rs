@@ -0,0 +1,72 @@
+use string_iter::prelude::*;
+
+/// Parse the official test format, `÷ 0061 × 0308 ÷`.
+fn test_cases() -> Vec<(String, Vec<String>)> {
+    include_str!("data/GraphemeBreakTest.txt").lines()
+        .filter(|line| line.starts_with('÷'))
+        .map(|line| {
+            let mut graphemes = vec![String::new()];
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" => graphemes.push(String::new()),
+                    "×" => (),
+                    hex => graphemes.last_mut().unwrap()
+                        .push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap()),
+                }
+            }
+            graphemes.retain(|s| !s.is_empty());
+            (graphemes.concat(), graphemes)
+        })
+        .collect()
+}
+
+#[test]
+fn grapheme_conformance_test(){
+    let cases = test_cases();
+    assert!(cases.len() > 700);
+    for (string, graphemes) in cases {
+        let forward: Vec<_> = string.str_iter().graphemes().collect();
+        assert_eq!(forward, graphemes, "{string:?}");
+
+        let mut backward: Vec<_> = string.str_iter().graphemes().rev().collect();
+        backward.reverse();
+        assert_eq!(backward, graphemes, "{string:?}");
+    }
+}
+
+#[test]
+fn grapheme_test(){
+    let family = "👨‍👩‍👧‍👦";
+    let string = format!("e\u{301}{family}🇯🇵🇫🇷x\r\nक्षि");
+    let mut iter = string.str_iter().graphemes();
+    assert_eq!(iter.next(), Some("e\u{301}"));
+    assert_eq!(iter.next_back(), Some("क्षि"));
+    assert_eq!(iter.next(), Some(family));
+    assert_eq!(iter.next_back(), Some("\r\n"));
+    assert_eq!(iter.next_back(), Some("x"));
+    assert_eq!(iter.next(), Some("🇯🇵"));
+    assert_eq!(iter.as_str(), "🇫🇷");
+
+    let iter = string.str_iter();
+    assert_eq!(iter.peekn_graphemes(2), Ok(&string[..3 + family.len()]));
+    assert_eq!(iter.peekn_graphemes_back(2), Ok("\r\nक्षि"));
+    assert_eq!(iter.peekn_graphemes(100), Err(string.as_str()));
+    assert_eq!(iter.peek_grapheme(), Some("e\u{301}"));
+
+    let mut iter = string.str_iter();
+    assert!(!iter.skip_front_graphemes(2));
+    assert!(!iter.skip_back_graphemes(3));
+    assert_eq!(iter.as_str(), "🇯🇵🇫🇷");
+    assert!(iter.skip_front_graphemes(3));
+
+    assert_eq!(string.substr_graphemes(1), family);
+    assert_eq!(string.substr_graphemes(2..4), "🇯🇵🇫🇷");
+    assert_eq!(string.try_substr_graphemes(5..9), Err("\r\nक्षि"));
+    assert_eq!(string.substr_graphemes_back(..2), "\r\nक्षि");
+    assert_eq!(string.substr_graphemes_back(2), "x");
+
+    // regional indicators pair from the start of the iterator
+    let mut iter = "🇯🇵🇫".str_iter();
+    assert_eq!(iter.next_grapheme_back(), Some("🇫"));
+    assert_eq!(iter.next_grapheme_back(), Some("🇯🇵"));
+}
@@ -38,8 +38,8 @@ fn empty_test() {
     assert_eq!(original.str_iter().peekn_back(1), Err(""));
     assert_eq!(original.str_iter().peekn(2), Err(""));
     assert_eq!(original.str_iter().peekn_back(2), Err(""));
-    assert_eq!(original.str_iter().skip_front(1), true);
-    assert_eq!(original.str_iter().skip_back(1), true);
+    assert!(original.str_iter().skip_front(1));
+    assert!(original.str_iter().skip_back(1));
 }
 
 #[test]
@@ -88,4 +88,23 @@ fn peekn_test(){
 
     assert!(iter.peekn(33).ok() == Some(&string.substring(18, 51)));
     assert!(iter.next_slice(33) == Some(&string.substring(18, 51)));
-}
\ No newline at end of file
+}
+#[test]
+fn checkpoint_test(){
+    let mut iter = "foo bar baz".str_iter();
+    let checkpoint = iter.checkpoint();
+    iter.next_slice(' '.sep_with(Sep::Split));
+    iter.next_back();
+    assert_eq!(iter.consumed_since(checkpoint), "foo ");
+    assert_eq!(iter.consumed_back_since(checkpoint), "z");
+    iter.rewind(checkpoint);
+    assert_eq!(iter.as_str(), "foo bar baz");
+
+    let result: Result<(), ()> = iter.transaction(|iter| {
+        iter.next_slice(' ');
+        iter.peek().filter(|(c, _)| *c == ':').ok_or(())?;
+        Ok(())
+    });
+    assert_eq!(result, Err(()));
+    assert_eq!(iter.as_str(), "foo bar baz");
+}
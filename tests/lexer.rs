@@ -0,0 +1,171 @@
+use string_iter::{prelude::*, Lexer, Rule, Span, Token, Trivia};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Ident,
+    If,
+    Number,
+    Op,
+    Quote,
+    Text,
+    Open,
+    Close,
+    Space,
+}
+
+type Tokens<'t> = Vec<Result<(Kind, &'t str), &'t str>>;
+
+fn lex<'t>(lexer: Lexer<'t, Kind>) -> Tokens<'t> {
+    lexer.map(|token| match token {
+        Ok(token) => Ok((token.kind, token.text)),
+        Err(err) => Err(err.as_str()),
+    }).collect()
+}
+
+fn basic(source: &str) -> Lexer<'_, Kind> {
+    Lexer::new(source)
+        .trivia(Trivia::C)
+        .rule(Rule::pattern(pat!('a'..='z' | '_'), Kind::Ident))
+        .rule(Rule::literal("if", Kind::If).priority(1))
+        .rule(Rule::pattern('0'..='9', Kind::Number))
+        .rule(Rule::literal("<", Kind::Op))
+        .rule(Rule::literal("<=", Kind::Op))
+        .rule(Rule::literal("<<=", Kind::Op))
+}
+
+#[test]
+fn longest_match_test(){
+    use Kind::*;
+    let cases: [(&str, Tokens); 6] = [
+        ("", vec![]),
+        ("if iffy", vec![Ok((If, "if")), Ok((Ident, "iffy"))]),
+        ("a<b<=c<<=1", vec![
+            Ok((Ident, "a")), Ok((Op, "<")), Ok((Ident, "b")), Ok((Op, "<=")),
+            Ok((Ident, "c")), Ok((Op, "<<=")), Ok((Number, "1")),
+        ]),
+        ("a /* x */ 12 // y", vec![Ok((Ident, "a")), Ok((Number, "12"))]),
+        ("a $$ b ?1", vec![
+            Ok((Ident, "a")), Err("$"), Err("$"), Ok((Ident, "b")), Err("?"), Ok((Number, "1")),
+        ]),
+        ("1 /* x", vec![Ok((Number, "1")), Err("/* x")]),
+    ];
+    for (source, tokens) in cases {
+        assert_eq!(lex(basic(source)), tokens, "{source:?}");
+    }
+}
+
+#[test]
+fn priority_test(){
+    // equal length and priority, the first rule wins
+    let lexer = Lexer::new("ab")
+        .rule(Rule::literal("ab", Kind::Text))
+        .rule(Rule::pattern(char::is_alphabetic, Kind::Ident));
+    assert_eq!(lex(lexer), [Ok((Kind::Text, "ab"))]);
+
+    let lexer = Lexer::new("ab")
+        .rule(Rule::literal("ab", Kind::Text))
+        .rule(Rule::pattern(char::is_alphabetic, Kind::Ident).priority(1));
+    assert_eq!(lex(lexer), [Ok((Kind::Ident, "ab"))]);
+
+    // priority does not beat a longer match
+    let lexer = Lexer::new("abc")
+        .rule(Rule::literal("ab", Kind::Text).priority(10))
+        .rule(Rule::pattern(char::is_alphabetic, Kind::Ident));
+    assert_eq!(lex(lexer), [Ok((Kind::Ident, "abc"))]);
+}
+
+#[test]
+fn span_test(){
+    let source = "x  🦀 yz";
+    let tokens: Vec<_> = Lexer::new(source)
+        .rule(Rule::pattern(char::is_whitespace, Kind::Space).skip())
+        .rule(Rule::pattern(char::is_alphabetic, Kind::Ident))
+        .collect();
+    let crab = tokens[1].unwrap_err();
+    assert_eq!(crab.span(), Span::new(3, 7));
+    assert_eq!(crab.to_string(), "unexpected \"🦀\" at byte 3");
+    assert_eq!(tokens[2], Ok(Token { kind: Kind::Ident, text: "yz", span: Span::new(8, 10) }));
+    for token in tokens.iter().flatten() {
+        assert_eq!(token.span.as_str(source), Some(token.text));
+    }
+}
+
+const STRING: usize = 1;
+
+fn interpolated(source: &str) -> Lexer<'_, Kind> {
+    Lexer::new(source)
+        .rule(Rule::pattern(' ', Kind::Space).skip())
+        .rule(Rule::pattern(char::is_alphabetic, Kind::Ident))
+        .rule(Rule::literal("\"", Kind::Quote).push_mode(STRING))
+        .rule(Rule::literal("}", Kind::Close).pop_mode())
+        .rule(Rule::literal("\"", Kind::Quote).in_mode(STRING).pop_mode())
+        .rule(Rule::literal("${", Kind::Open).in_mode(STRING).push_mode(0))
+        .rule(Rule::new(|iter| {
+            while !iter.as_str().starts_with(['"', '$']) {
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+            true
+        }, Kind::Text).in_mode(STRING))
+}
+
+#[test]
+fn mode_test(){
+    use Kind::*;
+    let tokens = lex(interpolated(r#"a "b ${c "d${e}"} f" g"#));
+    assert_eq!(tokens, [
+        Ok((Ident, "a")),
+        Ok((Quote, "\"")),
+        Ok((Text, "b ")),
+        Ok((Open, "${")),
+        Ok((Ident, "c")),
+        Ok((Quote, "\"")),
+        Ok((Text, "d")),
+        Ok((Open, "${")),
+        Ok((Ident, "e")),
+        Ok((Close, "}")),
+        Ok((Quote, "\"")),
+        Ok((Close, "}")),
+        Ok((Text, " f")),
+        Ok((Quote, "\"")),
+        Ok((Ident, "g")),
+    ]);
+
+    // a stray `}` in mode 0 does not pop the last mode
+    assert_eq!(lex(interpolated("} a")), [Ok((Close, "}")), Ok((Ident, "a"))]);
+    // `$` alone is not a token in a string
+    assert_eq!(lex(interpolated("\"$\"")), [Ok((Quote, "\"")), Err("$"), Ok((Quote, "\""))]);
+}
+
+#[test]
+fn trivia_mode_test(){
+    use Kind::*;
+    // trivia is only skipped in mode 0 by default
+    let tokens = lex(interpolated(r#"a "  x // y" /* z */ b"#).trivia(Trivia::C));
+    assert_eq!(tokens, [
+        Ok((Ident, "a")), Ok((Quote, "\"")), Ok((Text, "  x // y")), Ok((Quote, "\"")), Ok((Ident, "b")),
+    ]);
+    let tokens = lex(interpolated(r#""${ b /* c */ }""#).trivia(Trivia::C));
+    assert_eq!(tokens, [
+        Ok((Quote, "\"")), Ok((Open, "${")), Ok((Ident, "b")), Ok((Close, "}")), Ok((Quote, "\"")),
+    ]);
+
+    let lexer = interpolated(r#""  a ""#).trivia_in_mode(STRING, Trivia::WHITESPACE);
+    assert_eq!(lex(lexer), [Ok((Quote, "\"")), Ok((Text, "a ")), Ok((Quote, "\""))]);
+}
+
+#[test]
+fn manual_mode_test(){
+    let mut lexer = interpolated("a b");
+    assert_eq!(lexer.mode(), 0);
+    assert_eq!(lexer.pop_mode(), None);
+    assert_eq!(lexer.next().unwrap().unwrap().text, "a");
+    lexer.push_mode(STRING);
+    assert_eq!(lexer.mode(), STRING);
+    assert_eq!(lexer.offset(), 1);
+    assert_eq!(lexer.next().unwrap().unwrap().kind, Kind::Text);
+    assert_eq!(lexer.pop_mode(), Some(STRING));
+    assert_eq!(lexer.as_str(), "");
+    assert!(lexer.next().is_none());
+}
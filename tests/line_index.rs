@@ -0,0 +1,96 @@
+use string_iter::{prelude::*, LineIndex, LineColumn, ColumnUnit};
+
+const UNITS: [ColumnUnit; 3] = [ColumnUnit::Byte, ColumnUnit::Char, ColumnUnit::Utf16];
+
+#[test]
+fn line_index_test(){
+    let source = "aé🦀\r\nb\rc\n\nd";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 5);
+    let lines: Vec<_> = (1..=5).map(|i| index.line(i).unwrap()).collect();
+    assert_eq!(lines, ["aé🦀", "b", "c", "", "d"]);
+    assert_eq!(index.line(0), None);
+    assert_eq!(index.line(6), None);
+
+    // (offset, line, byte column, char column, utf16 column)
+    let cases = [
+        (0, 1, 1, 1, 1),
+        (1, 1, 2, 2, 2),
+        (3, 1, 4, 3, 3),
+        (7, 1, 8, 4, 5),
+        (9, 2, 1, 1, 1),
+        (11, 3, 1, 1, 1),
+        (13, 4, 1, 1, 1),
+        (14, 5, 1, 1, 1),
+        (15, 5, 2, 2, 2),
+    ];
+    for (offset, line, byte, char, utf16) in cases {
+        for (unit, column) in UNITS.into_iter().zip([byte, char, utf16]) {
+            let position = LineColumn::new(line, column);
+            assert_eq!(index.offset_to_position(offset, unit), Some(position), "{offset} {unit:?}");
+            assert_eq!(index.position_to_offset(position, unit), Some(offset), "{position} {unit:?}");
+        }
+    }
+}
+
+#[test]
+fn line_index_invalid_test(){
+    let source = "a🦀\nb";
+    let index = LineIndex::new(source);
+    // out of bounds or not on a char boundary
+    assert_eq!(index.offset_to_position(2, ColumnUnit::Byte), None);
+    assert_eq!(index.offset_to_position(8, ColumnUnit::Byte), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(1, 3), ColumnUnit::Byte), None);
+    // between a surrogate pair
+    assert_eq!(index.position_to_offset(LineColumn::new(1, 3), ColumnUnit::Utf16), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(1, 4), ColumnUnit::Utf16), Some(5));
+    // past the end of a line
+    assert_eq!(index.position_to_offset(LineColumn::new(1, 4), ColumnUnit::Char), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(2, 3), ColumnUnit::Char), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(3, 1), ColumnUnit::Char), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(0, 1), ColumnUnit::Char), None);
+    assert_eq!(index.position_to_offset(LineColumn::new(1, 0), ColumnUnit::Char), None);
+}
+
+#[test]
+fn line_index_range_test(){
+    let source = "fn main() {\n    let 🦀 = 1;\n}\n";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.offset_to_position(source.len(), ColumnUnit::Char), Some(LineColumn::new(4, 1)));
+
+    let mut iter = source.str_iter();
+    iter.next_slice("let");
+    let statement = iter.next_slice(';'.sep_with(Sep::Yield)).unwrap();
+    assert_eq!(statement, "let 🦀 = 1;");
+    let expected = [(5, 18), (5, 15), (5, 16)];
+    for (unit, (start, end)) in UNITS.into_iter().zip(expected) {
+        let range = index.range_of(statement, unit).unwrap();
+        assert_eq!(range, LineColumn::new(2, start)..LineColumn::new(2, end), "{unit:?}");
+    }
+    let whole = index.range_of(source, ColumnUnit::Utf16).unwrap();
+    assert_eq!(whole, LineColumn::new(1, 1)..LineColumn::new(4, 1));
+    assert_eq!(index.range_of("let", ColumnUnit::Byte), None);
+    assert_eq!(LineColumn::new(2, 5).to_string(), "2:5");
+
+    let empty = LineIndex::new("");
+    assert_eq!(empty.line_count(), 1);
+    assert_eq!(empty.offset_to_position(0, ColumnUnit::Byte), Some(LineColumn::new(1, 1)));
+}
+
+#[test]
+fn line_index_tracked_test(){
+    for source in ["a\rb", "a\r\nb\n", "\r\r\n\n", "a\n\rb\r", "🦀\rc\r\nd"] {
+        let index = LineIndex::new(source);
+        let mut iter = source.str_iter().tracked();
+        loop {
+            let position = iter.position();
+            assert_eq!(index.offset_to_position(position.byte, ColumnUnit::Char), Some(position.into()), "{source:?}");
+            if iter.next().is_none() {
+                break;
+            }
+        }
+        let trailing = usize::from(source.ends_with(['\n', '\r']));
+        assert_eq!(index.line_count(), source.str_iter().lines().count() + trailing, "{source:?}");
+    }
+}
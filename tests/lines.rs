@@ -0,0 +1,93 @@
+use string_iter::{prelude::*, StringIter};
+
+/// `(number, byte_offset, content, terminator)`
+type Row<'t> = (usize, usize, &'t str, &'t str);
+/// `(number, content, joined)`
+type Joined<'t> = (usize, &'t str, &'t str);
+
+fn lines(s: &str) -> Vec<Row<'_>> {
+    StringIter::new(s).lines()
+        .map(|line| (line.number(), line.byte_offset(), line.content(), line.terminator()))
+        .collect()
+}
+
+fn lines_back(s: &str) -> Vec<Row<'_>> {
+    let mut result: Vec<_> = StringIter::new(s).lines().rev()
+        .map(|line| (line.number(), line.byte_offset(), line.content(), line.terminator()))
+        .collect();
+    result.reverse();
+    result
+}
+
+#[test]
+fn lines_test(){
+    let cases: [(&str, &[Row]); 9] = [
+        ("", &[]),
+        ("a", &[(1, 0, "a", "")]),
+        ("a\n", &[(1, 0, "a", "\n")]),
+        ("\n", &[(1, 0, "", "\n")]),
+        ("\n\n", &[(1, 0, "", "\n"), (2, 1, "", "\n")]),
+        ("a\r\nb", &[(1, 0, "a", "\r\n"), (2, 3, "b", "")]),
+        ("a\rb\r", &[(1, 0, "a", "\r"), (2, 2, "b", "\r")]),
+        ("\r\r\n\n", &[(1, 0, "", "\r"), (2, 1, "", "\r\n"), (3, 3, "", "\n")]),
+        ("ü🦀\né", &[(1, 0, "ü🦀", "\n"), (2, 7, "é", "")]),
+    ];
+    for (input, output) in cases {
+        assert_eq!(lines(input), output, "{input:?}");
+        assert_eq!(lines_back(input), output, "{input:?}");
+        // lines round trip
+        let joined: String = StringIter::new(input).lines().map(|line| line.as_str()).collect();
+        assert_eq!(joined, input);
+    }
+}
+
+#[test]
+fn lines_mixed_test(){
+    let mut iter = "a\nb\nc\nd".str_iter().lines();
+    assert_eq!(iter.next_back().map(|l| (l.number(), l.content())), Some((4, "d")));
+    assert_eq!(iter.next().map(|l| (l.number(), l.content())), Some((1, "a")));
+    assert_eq!(iter.next_back().map(|l| (l.number(), l.content())), Some((3, "c")));
+    assert_eq!(iter.next().map(|l| (l.number(), l.content())), Some((2, "b")));
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn continuation_test(){
+    let cases: [(&str, &[Joined]); 7] = [
+        ("a\\\nb", &[(1, "a\\\nb", "ab")]),
+        ("a\\\nb\\\r\nc\nd", &[(1, "a\\\nb\\\r\nc", "abc"), (4, "d", "d")]),
+        ("a\\\n", &[(1, "a\\", "a\\")]),
+        ("a\\", &[(1, "a\\", "a\\")]),
+        ("a\\\n\nb", &[(1, "a\\\n", "a"), (3, "b", "b")]),
+        ("a\n\\\nb", &[(1, "a", "a"), (2, "\\\nb", "b")]),
+        ("a\\b\nc", &[(1, "a\\b", "a\\b"), (2, "c", "c")]),
+    ];
+    for (input, output) in cases {
+        let forward: Vec<_> = StringIter::new(input).lines_with_continuations('\\')
+            .map(|line| (line.number(), line.content(), line.joined().into_owned()))
+            .collect();
+        let mut backward: Vec<_> = StringIter::new(input).lines_with_continuations('\\').rev()
+            .map(|line| (line.number(), line.content(), line.joined().into_owned()))
+            .collect();
+        backward.reverse();
+        let expected: Vec<_> = output.iter().map(|(n, c, j)| (*n, *c, j.to_string())).collect();
+        assert_eq!(forward, expected, "{input:?}");
+        assert_eq!(backward, expected, "{input:?}");
+    }
+}
+
+#[test]
+fn physical_lines_test(){
+    let source = "x\ny \\\r\nz \\\nw\n";
+    let line = source.str_iter().lines_with_continuations('\\').nth(1).unwrap();
+    assert_eq!((line.number(), line.byte_offset(), line.terminator()), (2, 2, "\n"));
+    let physical: Vec<_> = line.physical_lines()
+        .map(|line| (line.number(), line.byte_offset(), line.content(), line.terminator()))
+        .collect();
+    assert_eq!(physical, [(2, 2, "y \\", "\r\n"), (3, 7, "z \\", "\n"), (4, 11, "w", "\n")]);
+    for (number, offset, content, _) in physical {
+        assert_eq!(&source[offset..offset + content.len()], content);
+        assert_eq!(lines(source)[number - 1].2, content);
+    }
+}
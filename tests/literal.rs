@@ -0,0 +1,88 @@
+use rand::Rng;
+use string_iter::{prelude::*, patterns::LiteralSet, Pattern, Never};
+
+fn gen_string(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| match rng.gen_range(0..6) {
+        0 => '=',
+        1 => '!',
+        2 => '<',
+        3 => 'é',
+        _ => rng.gen_range('a'..='c'),
+    }).collect()
+}
+
+#[test]
+fn literal_set_test(){
+    let ops = LiteralSet::new(["==", "!=", "<=", "<", "=", "&&"]);
+    let mut iter = "a<=b==c&&d".str_iter().into_splits(&ops);
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(ops.last_match().map(|m| (m.literal, m.len)), Some((2, 2)));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(ops.last_match().map(|m| &ops[m.literal]), Some("=="));
+    assert_eq!(iter.next(), Some("c"));
+    assert_eq!(iter.next(), Some("d"));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "a<=b==c".str_iter();
+    assert_eq!(iter.next_slice_back((&ops).sep_with(Sep::Split)), Some("c"));
+    // in reverse, the last starting literal is matched
+    assert_eq!(ops.last_match().map(|m| &ops[m.literal]), Some("="));
+    assert_eq!(iter.as_str(), "a<=b=");
+
+    let keywords = LiteralSet::new(["for", "fn", "f"]);
+    let mut iter = "fn for fox".str_iter();
+    assert_eq!(iter.next_literal(&keywords), Some((1, "fn")));
+    assert_eq!(iter.next_literal(&keywords), None);
+    iter.trim_start();
+    assert_eq!(iter.next_literal(&keywords), Some((0, "for")));
+    iter.trim_start();
+    assert_eq!(iter.next_literal(&keywords), Some((2, "f")));
+    assert_eq!(iter.as_str(), "ox");
+}
+
+#[test]
+fn literal_set_search_test(){
+    let set = LiteralSet::new(["==", "=", "!=", "<", "<=", "a=", "=é", "ab", "abc", "é"]);
+    let mut slow_set = set.clone();
+    for _ in 0..200 {
+        let string = gen_string(100);
+
+        let fast: Vec<_> = string.str_iter().into_splits(&set).collect();
+        let slow: Vec<_> = string.str_iter().into_splits(SlowPattern(&mut slow_set)).collect();
+        assert_eq!(fast, slow);
+
+        let fast: Vec<_> = string.str_iter().into_splits(&set).rev().collect();
+        let slow: Vec<_> = string.str_iter().into_splits(SlowPattern(&mut slow_set)).rev().collect();
+        assert_eq!(fast, slow);
+
+        let fast: Vec<_> = string.str_iter().into_substrs(&set).collect();
+        let slow: Vec<_> = string.str_iter().into_substrs(SlowPattern(&mut slow_set)).collect();
+        assert_eq!(fast, slow);
+
+        let fast: Vec<_> = string.str_iter().into_substrs(&set).rev().collect();
+        let slow: Vec<_> = string.str_iter().into_substrs(SlowPattern(&mut slow_set)).rev().collect();
+        assert_eq!(fast, slow);
+
+        // the first match is the leftmost, longest literal
+        let literals = ["==", "=", "!=", "<", "<=", "a=", "=é", "ab", "abc", "é"];
+        let first = (0..string.len())
+            .filter(|i| string.is_char_boundary(*i))
+            .find_map(|i| literals.iter()
+                .filter(|l| string[i..].starts_with(*l))
+                .max_by_key(|l| l.len())
+                .map(|l| (i, *l)));
+        let found = set.find(&string).map(|(i, m)| (i, &set[m.literal]));
+        assert_eq!(found, first);
+    }
+}
+
+/// Hides the search fast path of the inner pattern.
+struct SlowPattern<'t>(&'t mut LiteralSet);
+
+impl Pattern for SlowPattern<'_> {
+    type Err = Never;
+    fn matches(&mut self, c: char, s: &str) -> Result<bool, Never> { self.0.matches(c, s) }
+    fn len(&self) -> std::num::NonZeroUsize { Pattern::len(self.0) }
+    fn match_len(&self, c: char, s: &str) -> usize { self.0.match_len(c, s) }
+}
@@ -0,0 +1,143 @@
+use string_iter::{prelude::*, NumberErrorKind};
+
+#[test]
+fn int_test(){
+    let mut iter = "42 -17 +8 1_000_000 12ab".str_iter();
+    assert_eq!(iter.next_int::<i32>(), Ok((42, "42")));
+    iter.trim_start();
+    assert_eq!(iter.next_int::<i64>(), Ok((-17, "-17")));
+    iter.trim_start();
+    assert_eq!(iter.next_int::<u8>(), Ok((8, "+8")));
+    iter.trim_start();
+    assert_eq!(iter.next_int::<u32>(), Ok((1_000_000, "1_000_000")));
+    iter.trim_start();
+    assert_eq!(iter.next_int::<u32>(), Ok((12, "12")));
+    assert_eq!(iter.as_str(), "ab");
+
+    // bounds
+    assert_eq!("127".str_iter().next_int::<i8>(), Ok((127, "127")));
+    assert_eq!("-128".str_iter().next_int::<i8>(), Ok((-128, "-128")));
+    assert_eq!("340282366920938463463374607431768211455".str_iter().next_uint::<u128>(), Ok((u128::MAX, "340282366920938463463374607431768211455")));
+    assert_eq!("-0".str_iter().next_int::<u8>(), Ok((0, "-0")));
+
+    // unsigned does not accept a sign
+    assert_eq!("-1".str_iter().next_uint::<i32>().unwrap_err().kind(), NumberErrorKind::Empty);
+    assert_eq!("+1".str_iter().next_uint::<i32>().unwrap_err().kind(), NumberErrorKind::Empty);
+    assert_eq!("1".str_iter().next_uint::<i32>(), Ok((1, "1")));
+
+    // separators must follow a digit
+    assert_eq!("_1".str_iter().next_int::<i32>().unwrap_err().kind(), NumberErrorKind::Empty);
+    assert_eq!("-_1".str_iter().next_int::<i32>().unwrap_err().offset(), 1);
+    assert_eq!("1__".str_iter().next_int::<i32>(), Ok((1, "1__")));
+}
+
+#[test]
+fn int_error_test(){
+    let cases = [
+        ("", NumberErrorKind::Empty, 0),
+        ("abc", NumberErrorKind::Empty, 0),
+        ("-", NumberErrorKind::Empty, 1),
+        ("128", NumberErrorKind::PosOverflow, 2),
+        ("-129", NumberErrorKind::NegOverflow, 3),
+        ("1_000", NumberErrorKind::PosOverflow, 4),
+    ];
+    for (input, kind, offset) in cases {
+        let mut iter = input.str_iter();
+        let err = iter.next_int::<i8>().unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (kind, offset), "{input}");
+        assert_eq!(iter.as_str(), input);
+    }
+    assert_eq!("-1".str_iter().next_int::<u32>().unwrap_err().kind(), NumberErrorKind::NegOverflow);
+    assert_eq!("128".str_iter().next_int::<i8>().unwrap_err().to_string(),
+        "number too large to fit in target type at byte 2");
+}
+
+#[test]
+fn radix_test(){
+    let cases = [
+        ("0x7f", 127, "0x7f"),
+        ("0X7F", 127, "0X7F"),
+        ("0o777", 511, "0o777"),
+        ("0b1111_0000", 240, "0b1111_0000"),
+        ("0x_ff", 255, "0x_ff"),
+        ("-0b11", -3, "-0b11"),
+        ("+0o7", 7, "+0o7"),
+        ("0b102", 2, "0b10"),
+        ("09", 9, "09"),
+        ("0", 0, "0"),
+        ("0.5", 0, "0"),
+    ];
+    for (input, value, lexeme) in cases {
+        assert_eq!(input.str_iter().next_int_prefixed::<i32>(), Ok((value, lexeme)), "{input}");
+    }
+    for input in ["0x", "0xg", "0b2", "-0o_"] {
+        let mut iter = input.str_iter();
+        assert_eq!(iter.next_int_prefixed::<i32>().unwrap_err().kind(), NumberErrorKind::InvalidDigit, "{input}");
+        assert_eq!(iter.as_str(), input);
+    }
+    assert_eq!("0xff".str_iter().next_int::<i32>(), Ok((0, "0")));
+    assert_eq!("zz".str_iter().next_int_radix::<u32>(36), Ok((1295, "zz")));
+    assert_eq!("777".str_iter().next_int_radix::<u8>(8).unwrap_err().kind(), NumberErrorKind::PosOverflow);
+    assert_eq!("102".str_iter().next_int_radix::<u8>(2), Ok((2, "10")));
+}
+
+#[test]
+#[should_panic]
+fn radix_panic_test(){
+    let _ = "1".str_iter().next_int_radix::<u8>(37);
+}
+
+#[test]
+fn float_test(){
+    let cases = [
+        ("1", 1.0, "1"),
+        ("-2.5", -2.5, "-2.5"),
+        ("+3.25e2", 325.0, "+3.25e2"),
+        ("1E-3", 0.001, "1E-3"),
+        ("6.02e+23", 6.02e23, "6.02e+23"),
+        ("1_000.000_1", 1000.0001, "1_000.000_1"),
+        ("1e1_0", 1e10, "1e1_0"),
+        ("1.", 1.0, "1"),
+        ("1..2", 1.0, "1"),
+        ("1.e3", 1.0, "1"),
+        ("2em", 2.0, "2"),
+        ("2e-x", 2.0, "2"),
+        ("3.5.6", 3.5, "3.5"),
+        ("0x10", 0.0, "0"),
+    ];
+    for (input, value, lexeme) in cases {
+        assert_eq!(input.str_iter().next_float::<f64>(), Ok((value, lexeme)), "{input}");
+    }
+    assert_eq!("0.1".str_iter().next_float::<f32>(), Ok((0.1f32, "0.1")));
+
+    // separated floats of any length round like `str::parse`
+    let long = [
+        format!("0.{}1e300", "0_".repeat(200)),
+        format!("{}.5", "9_".repeat(300).trim_end_matches('_')),
+        format!("1.{}_1e-1_0", "0".repeat(1000)),
+        format!("{}e-1_000", "1_2".repeat(400)),
+        format!("-0_0.{}", "0_".repeat(500).trim_end_matches('_')),
+        format!("2.{}_1e-3_0_8", "4".repeat(900)),
+    ];
+    for input in long {
+        let expected: f64 = input.replace('_', "").parse().unwrap();
+        let (value, lexeme) = input.str_iter().next_float::<f64>().unwrap();
+        assert_eq!((value.to_bits(), lexeme), (expected.to_bits(), input.as_str()), "{input}");
+    }
+
+    for (input, kind) in [
+        ("", NumberErrorKind::Empty),
+        (".5", NumberErrorKind::Empty),
+        ("-", NumberErrorKind::Empty),
+        ("inf", NumberErrorKind::Empty),
+        ("NaN", NumberErrorKind::Empty),
+        ("1e400", NumberErrorKind::PosOverflow),
+        ("-1e400", NumberErrorKind::NegOverflow),
+    ] {
+        let mut iter = input.str_iter();
+        assert_eq!(iter.next_float::<f64>().unwrap_err().kind(), kind, "{input}");
+        assert_eq!(iter.as_str(), input);
+    }
+    assert_eq!("1e39".str_iter().next_float::<f32>().unwrap_err().kind(), NumberErrorKind::PosOverflow);
+    assert_eq!("1e39".str_iter().next_float::<f64>(), Ok((1e39, "1e39")));
+}
@@ -0,0 +1,117 @@
+use string_iter::{prelude::*, ParseError, StringIter};
+
+#[derive(Debug, PartialEq)]
+enum Value<'t> {
+    Number(&'t str),
+    List(Vec<Value<'t>>),
+}
+
+#[derive(Debug, PartialEq)]
+struct Error(usize);
+
+impl From<ParseError<'_>> for Error {
+    fn from(value: ParseError<'_>) -> Self {
+        Error(value.as_str().len())
+    }
+}
+
+fn value<'t>(iter: &mut StringIter<'t>) -> Result<Value<'t>, Error> {
+    iter.trim_start();
+    let value = number_or_list(iter);
+    iter.trim_start();
+    value
+}
+
+fn number_or_list<'t>(iter: &mut StringIter<'t>) -> Result<Value<'t>, Error> {
+    if let Some(number) = iter.optional(|iter| {
+        let start = iter.checkpoint();
+        iter.eat('-');
+        iter.many1::<(), _, _>(|iter| iter.expect('0'..='9').map(|_| ()))?;
+        Ok::<_, ParseError>(iter.consumed_since(start))
+    }) {
+        return Ok(Value::Number(number));
+    }
+    iter.delimited('[', |iter| {
+        let list = iter.separated_list(value, ',');
+        iter.trim_start();
+        Ok(Value::List(list))
+    }, ']')
+}
+
+#[test]
+fn grammar_test(){
+    let mut iter = "[1, [-20 ,[]], 3]".str_iter();
+    assert_eq!(value(&mut iter), Ok(Value::List(vec![
+        Value::Number("1"),
+        Value::List(vec![Value::Number("-20"), Value::List(vec![])]),
+        Value::Number("3"),
+    ])));
+    assert!(iter.is_empty());
+
+    for input in ["[1, 2", "[1,]", "-", "[[1 2]]", "", "x"] {
+        let mut iter = StringIter::new(input);
+        assert!(value(&mut iter).is_err(), "{input:?}");
+        assert_eq!(iter.as_str(), input, "{input:?}");
+    }
+    let mut iter = "[1, x]".str_iter();
+    // the list stops before `,` and expects `]`
+    assert_eq!(value(&mut iter), Err(Error(4)));
+}
+
+#[test]
+fn eat_test(){
+    let mut iter = "->x".str_iter();
+    assert!(!iter.eat_str("=>"));
+    assert!(iter.eat_str("->"));
+    assert!(!iter.eat(char::is_numeric));
+    assert!(iter.eat(char::is_alphabetic));
+    assert!(!iter.eat_str("x"));
+    assert!(iter.eat_str(""));
+}
+
+#[test]
+fn many_test(){
+    // empty runs stop the loop
+    let mut iter = "aa".str_iter();
+    let mut count = 0;
+    let result: Vec<_> = iter.many0(|iter| {
+        count += 1;
+        Ok::<_, ()>(iter.next_slice('b'))
+    });
+    assert_eq!(result, [Some("aa")]);
+    assert_eq!(count, 2);
+
+    // failed runs are rewound
+    let mut iter = "abab!".str_iter();
+    let pairs: Vec<_> = iter.many0(|iter| {
+        iter.expect('a')?;
+        iter.expect('b')
+    });
+    assert_eq!(pairs, ["b", "b"]);
+    assert_eq!(iter.as_str(), "!");
+
+    let mut iter = "ac".str_iter();
+    let pairs: Result<Vec<_>, _> = iter.many1(|iter| {
+        iter.expect('a')?;
+        iter.expect('b')
+    });
+    assert_eq!(pairs.unwrap_err().as_str(), "c");
+    assert_eq!(iter.as_str(), "ac");
+
+    // an empty first run is returned alone
+    let mut count = 0;
+    let result: Result<Vec<_>, ()> = iter.many1(|iter| {
+        count += 1;
+        Ok(iter.next_slice('a'))
+    });
+    assert_eq!(result.unwrap(), [Some("")]);
+    assert_eq!(count, 1);
+    assert_eq!(iter.as_str(), "ac");
+
+    let mut iter = "x".str_iter();
+    let list: Vec<_> = iter.separated_list(|iter| iter.expect('y'), ',');
+    assert!(list.is_empty());
+    assert_eq!(iter.as_str(), "x");
+    let list: Vec<_> = "x;y;;z".str_iter().separated_list(|iter| iter.expect(char::is_alphabetic), ';');
+    assert_eq!(list, ["x", "y"]);
+}
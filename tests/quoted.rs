@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+
+use string_iter::{prelude::*, QuoteStyle, QuoteErrorKind};
+
+#[test]
+fn quoted_test(){
+    let cases = [
+        (QuoteStyle::Double, r#""foo" bar"#, r#""foo""#, "foo"),
+        (QuoteStyle::Double, r#""" bar"#, r#""""#, ""),
+        (QuoteStyle::Double, r#""it's \"quoted\"""#, r#""it's \"quoted\"""#, r#"it's "quoted""#),
+        (QuoteStyle::Single, r#"'don\'t' x"#, r#"'don\'t'"#, "don't"),
+        (QuoteStyle::Backtick, r#"`a\`b`c"#, r#"`a\`b`"#, "a`b"),
+        (QuoteStyle::Double, "\"a\\n\\t\\r\\0\\\\b\"", "\"a\\n\\t\\r\\0\\\\b\"", "a\n\t\r\0\\b"),
+        (QuoteStyle::Double, r#""\x41\x7F""#, r#""\x41\x7F""#, "A\x7f"),
+        (QuoteStyle::Double, r#""\u{e9}\u{1F980}\u{0}""#, r#""\u{e9}\u{1F980}\u{0}""#, "é🦀\0"),
+        (QuoteStyle::Double, r#""\u00e9\uD83E\uDD80""#, r#""\u00e9\uD83E\uDD80""#, "é🦀"),
+        (QuoteStyle::Double, "\"multi\nline\"", "\"multi\nline\"", "multi\nline"),
+        (QuoteStyle::Double, "\"ünï\\tcödé\"", "\"ünï\\tcödé\"", "ünï\tcödé"),
+        (QuoteStyle::Raw, r#"r"C:\path" x"#, r#"r"C:\path""#, r"C:\path"),
+        (QuoteStyle::Raw, r###"r#"a "b" c"# x"###, r###"r#"a "b" c"#"###, r#"a "b" c"#),
+        (QuoteStyle::Raw, r###"r##"a "# b"## x"###, r###"r##"a "# b"##"###, r##"a "# b"##),
+    ];
+    for (style, input, raw, decoded) in cases {
+        let mut iter = input.str_iter();
+        let quoted = iter.next_quoted(style).unwrap();
+        assert_eq!(quoted.raw(), raw);
+        assert_eq!(quoted.style(), style);
+        assert_eq!(quoted.decoded(), decoded);
+        assert_eq!(matches!(quoted.decoded(), Cow::Borrowed(_)), !quoted.has_escapes());
+        assert_eq!(iter.as_str(), &input[raw.len()..]);
+        assert!(raw.contains(quoted.content()));
+    }
+}
+
+#[test]
+fn quoted_borrow_test(){
+    let source = String::from(r#""plain" "esc\n""#);
+    let mut iter = source.str_iter();
+    let plain = iter.next_quoted(QuoteStyle::Double).unwrap();
+    assert!(matches!(plain.decoded(), Cow::Borrowed("plain")));
+    iter.trim_start();
+    let escaped = iter.next_quoted(QuoteStyle::Double).unwrap();
+    assert_eq!(escaped.content(), r"esc\n");
+    assert!(matches!(escaped.decoded(), Cow::Owned(s) if s == "esc\n"));
+}
+
+#[test]
+fn quoted_error_test(){
+    let cases = [
+        (QuoteStyle::Double, "foo", QuoteErrorKind::NotQuoted, 0),
+        (QuoteStyle::Double, "'foo'", QuoteErrorKind::NotQuoted, 0),
+        (QuoteStyle::Single, "", QuoteErrorKind::NotQuoted, 0),
+        (QuoteStyle::Raw, "\"foo\"", QuoteErrorKind::NotQuoted, 0),
+        (QuoteStyle::Raw, "r#foo", QuoteErrorKind::NotQuoted, 0),
+        (QuoteStyle::Double, "\"foo", QuoteErrorKind::Unterminated, 0),
+        (QuoteStyle::Double, r#""foo\""#, QuoteErrorKind::Unterminated, 0),
+        (QuoteStyle::Raw, r##"r#"foo""##, QuoteErrorKind::Unterminated, 0),
+        (QuoteStyle::Double, r#""ab\q""#, QuoteErrorKind::InvalidEscape, 3),
+        (QuoteStyle::Double, r#""\x4""#, QuoteErrorKind::InvalidHexEscape, 1),
+        (QuoteStyle::Double, r#""\x80""#, QuoteErrorKind::InvalidHexEscape, 1),
+        (QuoteStyle::Double, r#""\u{}""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""\u{1234567}""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""\u{D800}""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""\u{110000}""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""\u{41""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""\u12""#, QuoteErrorKind::InvalidUnicodeEscape, 1),
+        (QuoteStyle::Double, r#""x\uD83E""#, QuoteErrorKind::LoneSurrogate, 2),
+        (QuoteStyle::Double, r#""\uDD80""#, QuoteErrorKind::LoneSurrogate, 1),
+        (QuoteStyle::Double, r#""\uD83E\u0041""#, QuoteErrorKind::LoneSurrogate, 1),
+    ];
+    for (style, input, kind, offset) in cases {
+        let mut iter = input.str_iter();
+        let err = iter.next_quoted(style).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (kind, offset), "{input}");
+        assert_eq!(iter.as_str(), input);
+    }
+}
@@ -0,0 +1,131 @@
+use rand::Rng;
+use string_iter::prelude::*;
+
+/// this generates a string with mostly separators and some unicode
+fn gen_string(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| match rng.gen_range(0..8) {
+        0 => ' ',
+        1 => ',',
+        2 => ':',
+        3 => 'é',
+        4 => '🦀',
+        5 => '\n',
+        _ => rng.gen_range('a'..='e'),
+    }).collect()
+}
+
+#[test]
+fn char_search_test(){
+    for _ in 0..100 {
+        let string = gen_string(200);
+        for c in [' ', ':', 'é', '🦀'] {
+            let fast: Vec<_> = string.str_iter().into_splits(c).collect();
+            let slow: Vec<_> = string.str_iter().into_splits(|x: char| x == c).collect();
+            assert_eq!(fast, slow);
+
+            let fast: Vec<_> = string.str_iter().into_splits(c).rev().collect();
+            let slow: Vec<_> = string.str_iter().into_splits(|x: char| x == c).rev().collect();
+            assert_eq!(fast, slow);
+
+            let fast: Vec<_> = string.str_iter().into_substrs(c).collect();
+            let slow: Vec<_> = string.str_iter().into_substrs(|x: char| x == c).collect();
+            assert_eq!(fast, slow);
+
+            let fast: Vec<_> = string.str_iter().into_substrs(c).rev().collect();
+            let slow: Vec<_> = string.str_iter().into_substrs(|x: char| x == c).rev().collect();
+            assert_eq!(fast, slow);
+        }
+    }
+}
+
+#[test]
+fn set_search_test(){
+    for _ in 0..100 {
+        let string = gen_string(200);
+        let fast: Vec<_> = string.str_iter().into_splits([' ', ',']).collect();
+        let slow: Vec<_> = string.str_iter().into_splits(pat!(' '|',')).collect();
+        assert_eq!(fast, slow);
+
+        let set = [' ', ',', ':', '\n', 'a', 'b'];
+        let fast: Vec<_> = string.str_iter().into_substrs(set).rev().collect();
+        let slow: Vec<_> = string.str_iter().into_substrs(|c| set.contains(&c)).rev().collect();
+        assert_eq!(fast, slow);
+
+        let set: &[char] = &['é', 'a'];
+        let fast: Vec<_> = string.str_iter().into_splits(set).collect();
+        let slow: Vec<_> = string.str_iter().into_splits(pat!('é'|'a')).collect();
+        assert_eq!(fast, slow);
+    }
+}
+
+#[test]
+fn str_search_test(){
+    for _ in 0..100 {
+        let string = gen_string(200);
+        for pat in [": ", "a,", "é🦀", "\n\n"] {
+            let slow = || (|s: &str| s == pat).expecting(2);
+
+            let fast: Vec<_> = string.str_iter().into_splits(pat).collect();
+            let slow_split: Vec<_> = string.str_iter().into_splits(slow()).collect();
+            assert_eq!(fast, slow_split);
+
+            let fast: Vec<_> = string.str_iter().into_splits(pat).rev().collect();
+            let slow_split: Vec<_> = string.str_iter().into_splits(slow()).rev().collect();
+            assert_eq!(fast, slow_split);
+
+            let fast: Vec<_> = string.str_iter().into_substrs(pat).collect();
+            let slow_split: Vec<_> = string.str_iter().into_substrs(slow()).collect();
+            assert_eq!(fast, slow_split);
+
+            let fast: Vec<_> = string.str_iter().into_substrs(pat).rev().collect();
+            let slow_split: Vec<_> = string.str_iter().into_substrs(slow()).rev().collect();
+            assert_eq!(fast, slow_split);
+        }
+    }
+}
+
+/// small alphabets produce periodic needles and many partial matches
+fn gen_periodic(len: usize, alphabet: &[char]) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| alphabet[rng.gen_range(0..alphabet.len())]).collect()
+}
+
+#[test]
+fn two_way_search_test(){
+    let mut rng = rand::thread_rng();
+    for _ in 0..2000 {
+        let alphabet: &[char] = if rng.gen() { &['a', 'b'] } else { &['a', 'b', 'é'] };
+        let string = gen_periodic(rng.gen_range(0..64), alphabet);
+        let pat = gen_periodic(rng.gen_range(2..8), alphabet);
+
+        let mut iter = string.str_iter();
+        let front = iter.next_slice(pat.as_str().sep_with(Sep::Split));
+        match string.find(pat.as_str()) {
+            Some(i) => assert_eq!(front, Some(&string[..i]), "{string:?} {pat:?}"),
+            None => assert_eq!(front, Some(string.as_str()).filter(|s| !s.is_empty())),
+        }
+
+        let mut iter = string.str_iter();
+        let back = iter.next_slice_back(pat.as_str().sep_with(Sep::Split));
+        match string.rfind(pat.as_str()) {
+            Some(i) => assert_eq!(back, Some(&string[i + pat.len()..]), "{string:?} {pat:?}"),
+            None => assert_eq!(back, Some(string.as_str()).filter(|s| !s.is_empty())),
+        }
+    }
+}
+
+#[test]
+fn two_way_worst_case_test(){
+    let string = "a".repeat(10000) + "b";
+    let pat = "a".repeat(100) + "b";
+    let mut iter = string.str_iter();
+    assert_eq!(iter.next_slice(pat.as_str()), Some(&string[..9900]));
+    let mut iter = string.str_iter();
+    assert_eq!(iter.next_slice_back(pat.as_str()), Some(""));
+
+    let string = "ab".repeat(5000);
+    let pat = "ab".repeat(50) + "b";
+    assert_eq!(string.str_iter().next_slice(pat.as_str()), Some(string.as_str()));
+    assert_eq!(string.str_iter().next_slice_back(pat.as_str()), Some(string.as_str()));
+}
@@ -0,0 +1,74 @@
+use string_iter::{prelude::*, StringIter, patterns::{WordBounds, SentenceBounds}};
+
+/// Parse the official test format, `÷ 0061 × 0308 ÷`.
+fn test_cases(data: &str) -> Vec<(String, Vec<String>)> {
+    data.lines()
+        .filter(|line| line.starts_with('÷'))
+        .map(|line| {
+            let mut segments = vec![String::new()];
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" => segments.push(String::new()),
+                    "×" => (),
+                    hex => segments.last_mut().unwrap()
+                        .push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap()),
+                }
+            }
+            segments.retain(|s| !s.is_empty());
+            (segments.concat(), segments)
+        })
+        .collect()
+}
+
+#[test]
+fn word_conformance_test(){
+    let cases = test_cases(include_str!("data/WordBreakTest.txt"));
+    assert!(cases.len() > 1000);
+    for (string, words) in cases {
+        let forward: Vec<_> = string.str_iter().words().collect();
+        assert_eq!(forward, words, "{string:?}");
+
+        let mut backward: Vec<_> = string.str_iter().words().rev().collect();
+        backward.reverse();
+        assert_eq!(backward, words, "{string:?}");
+
+        let pattern: Vec<_> = string.str_iter().into_substrs(WordBounds::new(&string)).collect();
+        assert_eq!(pattern, words, "{string:?}");
+    }
+}
+
+#[test]
+fn sentence_conformance_test(){
+    let cases = test_cases(include_str!("data/SentenceBreakTest.txt"));
+    assert!(cases.len() > 500);
+    for (string, sentences) in cases {
+        let forward: Vec<_> = string.str_iter().sentences().collect();
+        assert_eq!(forward, sentences, "{string:?}");
+
+        let mut backward: Vec<_> = string.str_iter().sentences().rev().collect();
+        backward.reverse();
+        assert_eq!(backward, sentences, "{string:?}");
+
+        let pattern: Vec<_> = string.str_iter().into_substrs(SentenceBounds::new(&string)).collect();
+        assert_eq!(pattern, sentences, "{string:?}");
+    }
+}
+
+#[test]
+fn word_test(){
+    fn words(s: &str) -> Vec<&str> {
+        StringIter::new(s).words().filter(|w| !w.trim().is_empty()).collect()
+    }
+    assert_eq!(words("it's 1,000.5 o'clock"), ["it's", "1,000.5", "o'clock"]);
+    assert_eq!(words("snake_case and camelCase"), ["snake_case", "and", "camelCase"]);
+    assert_eq!(words("東京タワー에 갑니다"), ["東", "京", "タワー", "에", "갑니다"]);
+    assert_eq!(words("👨‍👩‍👧 🇯🇵🇫🇷"), ["👨‍👩‍👧", "🇯🇵", "🇫🇷"]);
+
+    // next_slice stops at the next boundary
+    let text = "foo bar";
+    let mut iter = text.str_iter();
+    iter.next();
+    assert_eq!(iter.next_slice(WordBounds::new(text)), Some("oo"));
+    assert_eq!(iter.next_slice(WordBounds::new(text)), Some(""));
+    assert_eq!(iter.as_str(), " bar");
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use string_iter::{prelude::*, ShellErrorKind};
+
+fn words(s: &str) -> Vec<String> {
+    s.str_iter().shell_words().map(|word| word.unwrap().into_owned()).collect()
+}
+
+#[test]
+fn shell_words_test(){
+    let cases: [(&str, &[&str]); 17] = [
+        ("", &[]),
+        ("  \t\n ", &[]),
+        ("a b  c", &["a", "b", "c"]),
+        ("'a b' c", &["a b", "c"]),
+        ("a'b c'd", &["ab cd"]),
+        ("''", &[""]),
+        ("\"\" ''", &["", ""]),
+        (r#""a \"b\" \$c \\ \n""#, &[r#"a "b" $c \ \n"#]),
+        (r"'a \n \'", &[r"a \n \"]),
+        (r"a\ b \'c", &["a b", "'c"]),
+        ("a\\\nb c\\\n d", &["ab", "c", "d"]),
+        ("\"a\\\nb\"", &["ab"]),
+        ("\"a\nb\"", &["a\nb"]),
+        ("a # comment\nb#c #", &["a", "b#c"]),
+        ("#only a comment", &[]),
+        ("ü '🦀 é'", &["ü", "🦀 é"]),
+        ("a \"\\é\" b", &["a", "\\é", "b"]),
+    ];
+    for (input, output) in cases {
+        assert_eq!(words(input), output, "{input:?}");
+    }
+}
+
+#[test]
+fn shell_borrow_test(){
+    let mut iter = "plain 'single' \"double\" es\\cape".str_iter().shell_words();
+    assert!(matches!(iter.next(), Some(Ok(Cow::Borrowed("plain")))));
+    assert!(matches!(iter.next(), Some(Ok(Cow::Borrowed("single")))));
+    assert!(matches!(iter.next(), Some(Ok(Cow::Owned(_)))));
+    assert!(matches!(iter.next(), Some(Ok(Cow::Owned(_)))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn shell_error_test(){
+    let cases = [
+        ("a 'b", ShellErrorKind::UnterminatedSingleQuote, 2),
+        ("a \"b\\\"", ShellErrorKind::UnterminatedDoubleQuote, 2),
+        ("a \"b\\", ShellErrorKind::UnterminatedDoubleQuote, 2),
+        ("a b\\", ShellErrorKind::TrailingBackslash, 3),
+    ];
+    for (input, kind, offset) in cases {
+        let mut iter = input.str_iter().shell_words();
+        assert_eq!(iter.next().unwrap().unwrap(), "a");
+        let err = iter.next().unwrap().unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (kind, offset), "{input:?}");
+        assert!(iter.next().is_none());
+    }
+    assert_eq!(
+        "x 'y".str_iter().shell_words().nth(1).unwrap().unwrap_err().to_string(),
+        "unterminated single quote at byte 2",
+    );
+}
+
+#[test]
+fn shell_quote_test(){
+    let cases = [
+        "", "a", "a b", "it's", "'", "''", "\\", "\"$HOME\"", "a\nb", "#", "~", "*.rs",
+        "-n", "ü🦀", " leading", "trailing\\", "a'\"b\"'c",
+    ];
+    for s in cases {
+        assert_eq!(words(&s.shell_quote()), [s], "{s:?}");
+    }
+    let joined = cases.map(|s| s.shell_quote().into_owned()).join(" ");
+    assert_eq!(words(&joined), cases);
+
+    assert!(matches!("--flag=a.b/c".shell_quote(), Cow::Borrowed(_)));
+    assert_eq!("a b".shell_quote(), "'a b'");
+}
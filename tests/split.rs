@@ -66,4 +66,190 @@ fn interval_test(){
     assert!(iter.next().unwrap() == "dd");
     assert!(iter.next().unwrap() == "eee");
     assert!(iter.next().is_none());
-}
\ No newline at end of file
+}
+#[test]
+fn multichar_split_test(){
+    let mut iter = "a::b::c".str_iter().into_splits("::");
+    assert!(iter.next().unwrap() == "a");
+    assert!(iter.next().unwrap() == "b");
+    assert!(iter.next().unwrap() == "c");
+    assert!(iter.next().is_none());
+
+    let mut iter = "a::b::c".str_iter().into_splits("::");
+    assert!(iter.next_back().unwrap() == "c");
+    assert!(iter.next_back().unwrap() == "b");
+    assert!(iter.next_back().unwrap() == "a");
+    assert!(iter.next_back().is_none());
+
+    let mut iter = "foo\r\nbar\r\n".str_iter().into_substrs("\r\n".sep_with(Sep::Yield));
+    assert!(iter.next().unwrap() == "foo\r\n");
+    assert!(iter.next().unwrap() == "bar\r\n");
+    assert!(iter.next().is_none());
+
+    let mut iter = "a-->b-->c".str_iter().into_substrs("-->");
+    assert!(iter.next().unwrap() == "a");
+    assert!(iter.next().unwrap() == "-->b");
+    assert!(iter.next().unwrap() == "-->c");
+    assert!(iter.next().is_none());
+
+    let mut iter = "a-->b-->c".str_iter().into_substrs("-->");
+    assert!(iter.next_back().unwrap() == "c");
+    assert!(iter.next_back().unwrap() == "b-->");
+    assert!(iter.next_back().unwrap() == "a-->");
+    assert!(iter.next_back().is_none());
+
+    let mut iter = "a::b".str_iter();
+    assert_eq!(iter.next_slice("::".sep_with(Sep::Conjoin)), Some("a::"));
+    assert_eq!(iter.as_str(), "::b");
+
+    let mut iter = "a::b::c".str_iter();
+    assert_eq!(iter.next_slice_back("::".sep_with(Sep::Yield)), Some("::c"));
+    assert_eq!(iter.next_slice_back("::".sep_with(Sep::Split)), Some("b"));
+    assert_eq!(iter.as_str(), "a");
+    assert_eq!(iter.next_slice_back("::"), Some("a"));
+    assert_eq!(iter.next_slice_back("::"), None);
+
+    let mut iter = "ab12cd".str_iter();
+    let digits = (|s: &str| s.chars().all(|c| c.is_ascii_digit())).expecting(2);
+    assert_eq!(iter.next_slice(digits.sep_with(Sep::Split)), Some("ab"));
+    assert_eq!(iter.as_str(), "cd");
+}
+
+#[test]
+fn combinator_test(){
+    let mut iter = "foo_bar123 baz".str_iter();
+    let ident = ('a'..='z').or('0'..='9').or('_');
+    assert_eq!(iter.next_slice(ident.not()), Some("foo_bar123"));
+
+    let mut iter = "a<=b>=c<d".str_iter().into_splits(['<', '>'].followed_by('='));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.next(), Some("c<d"));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "a\r\nb\nc".str_iter().into_splits("\r\n".or('\n'));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.next(), Some("c"));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "a  b c    d".str_iter().into_splits(' '.repeated(1..=8));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.next(), Some("c"));
+    assert_eq!(iter.next(), Some("d"));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "a b          c".str_iter().into_splits(' '.repeated(1..));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.next(), Some("c"));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = "x1y22z3333333333".str_iter();
+    assert_eq!(iter.next_slice(('0'..='9').repeated(3..).sep_with(Sep::Yield)), Some("x1y22z3333333333"));
+    assert_eq!(iter.as_str(), "");
+
+    let mut iter = "a1b2222c".str_iter();
+    assert_eq!(iter.next_slice_back(('0'..='9').repeated(2..).sep_with(Sep::Yield)), Some("22c"));
+    assert_eq!(iter.as_str(), "a1b22");
+
+    let mut iter = "zaab".str_iter();
+    assert_eq!(iter.next_slice('x'.or('a'.repeated(2..=2)).sep_with(Sep::Yield)), Some("zaa"));
+    assert_eq!(iter.as_str(), "b");
+
+    // in reverse, the right-most start of a match
+    let mut iter = "baaa".str_iter();
+    assert_eq!(iter.next_slice_back('a'.repeated(2..).sep_with(Sep::Yield)), Some("aa"));
+    assert_eq!(iter.as_str(), "ba");
+
+    let mut iter = "aaab".str_iter();
+    assert!(iter.expect('a'.repeated(4..)).is_err());
+    assert_eq!(iter.expect('a'.repeated(2..=2)), Ok("aa"));
+    assert_eq!(iter.expect('a'.repeated(1..)), Ok("a"));
+    assert_eq!("aaaab".str_iter().expect('a'.repeated(2..)), Ok("aaaa"));
+
+    let mut iter = "aabab".str_iter();
+    iter.trim_start_by('a'.repeated(2..));
+    assert_eq!(iter.as_str(), "bab");
+    iter.trim_start_by("ba");
+    assert_eq!(iter.as_str(), "b");
+    iter.trim_start_by('b'.repeated(2..));
+    assert_eq!(iter.as_str(), "b");
+
+    let mut iter = "x1y22z333".str_iter();
+    assert_eq!(iter.next_slice(('0'..='9').repeated(2..4).sep_with(Sep::Yield)), Some("x1y22"));
+    assert_eq!(iter.as_str(), "z333");
+
+    let mut iter = "Abc".str_iter();
+    assert_eq!(iter.next_slice(('a'..='z').and('b'.not())), Some("Ab"));
+    assert_eq!(iter.as_str(), "c");
+}
+
+#[test]
+fn quoted_split_test(){
+    const QUOTES: &[(char, char)] = &[('"', '"'), ('\'', '\''), ('[', ']')];
+    let sep = || ','.outside_quotes(QUOTES).escape('\\');
+    let cases: [(&str, &[&str]); 9] = [
+        (r#"a,"b,c",d"#, &["a", r#""b,c""#, "d"]),
+        (r#"a,'b,"c',d"#, &["a", r#"'b,"c'"#, "d"]),
+        (r"a,[b,c],d", &["a", "[b,c]", "d"]),
+        (r#"a\,b,"c\",d",e"#, &[r"a\,b", r#""c\",d""#, "e"]),
+        (r#"x"a,b"y,z"#, &[r#"x"a,b"y"#, "z"]),
+        // like `into_splits`, a trailing empty field is not yielded
+        (",,", &["", ""]),
+        ("", &[]),
+        (r#""unterminated,a"#, &[r#""unterminated,a"#]),
+        ("é,\"ü,🦀\",ß", &["é", "\"ü,🦀\"", "ß"]),
+    ];
+    for (input, fields) in cases {
+        let forward: Vec<_> = input.str_iter().into_quoted_splits(sep()).collect();
+        assert_eq!(forward, fields, "{input}");
+        let mut backward: Vec<_> = input.str_iter().into_quoted_splits(sep()).rev().collect();
+        backward.reverse();
+        assert_eq!(backward, fields, "{input}");
+        let plain: Vec<_> = input.str_iter().into_splits(sep()).collect();
+        assert_eq!(plain, fields, "{input}");
+    }
+
+    let mut iter = r#"a,"b,c",d,e"#.str_iter().into_quoted_splits(sep());
+    assert_eq!(iter.next_back(), Some("e"));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.as_str(), r#""b,c",d"#);
+    assert_eq!(iter.next_back(), Some("d"));
+    assert_eq!(iter.next_back(), Some(r#""b,c""#));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    // multi char separators
+    let fields: Vec<_> = r#"a::"b::c"::d"#.str_iter().into_quoted_splits("::".outside_quotes(QUOTES)).collect();
+    assert_eq!(fields, ["a", r#""b::c""#, "d"]);
+
+    // errors of the inner pattern are reported by the fast paths
+    let comma = |c: char| match c {
+        ',' => Ok(true),
+        '!' => Err(c),
+        _ => Ok(false),
+    };
+    let mut iter = r#"a"!"b!,c"#.str_iter();
+    assert_eq!(iter.try_next_slice(comma.outside_quotes(QUOTES)), Err('!'));
+    assert_eq!(iter.try_next_slice_back(comma.outside_quotes(QUOTES)), Err('!'));
+    assert_eq!(iter.as_str(), r#"a"!"b!,c"#);
+}
+
+#[test]
+fn decoded_split_test(){
+    use std::borrow::Cow;
+    let sep = || ','.outside_quotes(&[('"', '"'), ('\'', '\'')]).escape('\\');
+    let input = r#"plain,"quoted",x"a,b"y,"esc\"aped",a\,b,'it''s'"#;
+    let fields: Vec<_> = input.str_iter().into_quoted_splits(sep()).decoded().collect();
+    assert_eq!(fields, ["plain", "quoted", "xa,by", "esc\"aped", "a,b", "its"]);
+    assert!(matches!(fields[0], Cow::Borrowed(_)));
+    assert!(matches!(fields[1], Cow::Borrowed(_)));
+    assert!(matches!(fields[2], Cow::Owned(_)));
+    assert!(matches!(fields[3], Cow::Owned(_)));
+
+    let mut backward: Vec<_> = input.str_iter().into_quoted_splits(sep()).decoded().rev().collect();
+    backward.reverse();
+    assert_eq!(backward, fields);
+}
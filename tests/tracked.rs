@@ -0,0 +1,63 @@
+use string_iter::{prelude::*, Position};
+
+#[test]
+fn tracked_test(){
+    let mut iter = "ab\ncд\r\n🦀f".str_iter().tracked();
+    assert_eq!(iter.position(), Position::START);
+    iter.next_slice('\n'.sep_with(Sep::Split));
+    assert_eq!(iter.position(), Position { byte: 3, char: 3, line: 2, column: 1 });
+    iter.next();
+    iter.next();
+    assert_eq!(iter.position(), Position { byte: 6, char: 5, line: 2, column: 3 });
+    iter.skip_front(2);
+    assert_eq!(iter.position(), Position { byte: 8, char: 7, line: 3, column: 1 });
+    assert_eq!(iter.back_position(), Position { byte: 13, char: 9, line: 3, column: 3 });
+    iter.next_back();
+    assert_eq!(iter.back_position(), Position { byte: 12, char: 8, line: 3, column: 2 });
+    iter.next_back();
+    iter.next_back();
+    assert_eq!(iter.back_position(), Position { byte: 8, char: 7, line: 3, column: 1 });
+    assert_eq!(iter.to_string(), "");
+
+    let mut iter = "ab\ncд\r\n🦀f".str_iter().tracked();
+    assert_eq!(iter.back_position(), Position { byte: 13, char: 9, line: 3, column: 3 });
+    iter.next_slice_back('\n'.sep_with(Sep::Split));
+    assert_eq!(iter.back_position(), Position { byte: 7, char: 6, line: 2, column: 4 });
+    iter.next_slice_back('\n');
+    assert_eq!(iter.back_position(), Position { byte: 3, char: 3, line: 2, column: 1 });
+
+    let origin = iter.origin();
+    assert_eq!(iter.position_of(&origin[3..]), Some(Position { byte: 3, char: 3, line: 2, column: 1 }));
+    assert_eq!(iter.position_of("cд"), None);
+}
+
+#[test]
+fn tracked_line_break_test(){
+    let source = "a\rb\r\n\ncд\n\r🦀";
+    let mut iter = source.str_iter().tracked();
+    iter.skip_front(2);
+    assert_eq!(iter.position(), Position { byte: 2, char: 2, line: 2, column: 1 });
+    iter.skip_front(2);
+    assert_eq!(iter.position(), Position { byte: 4, char: 4, line: 2, column: 3 });
+
+    // walking backward agrees with walking forward
+    let mut forward = Vec::new();
+    let mut iter = source.str_iter().tracked();
+    loop {
+        forward.push(iter.position());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+    let mut backward = Vec::new();
+    let mut iter = source.str_iter().tracked();
+    loop {
+        backward.push(iter.back_position());
+        if iter.next_back().is_none() {
+            break;
+        }
+    }
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(forward.last(), Some(&Position { byte: 15, char: 11, line: 6, column: 2 }));
+}
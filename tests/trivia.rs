@@ -0,0 +1,83 @@
+use string_iter::{prelude::*, StringIter, Trivia};
+
+fn skip<'t>(s: &'t str, trivia: &Trivia) -> (Vec<&'t str>, &'t str) {
+    let mut iter = StringIter::new(s);
+    let comments = iter.skip_trivia(trivia).unwrap().collect();
+    (comments, iter.as_str())
+}
+
+#[test]
+fn skip_trivia_test(){
+    let cases: [(&str, Trivia, &[&str], &str); 9] = [
+        ("", Trivia::C, &[], ""),
+        ("  \t\n x", Trivia::WHITESPACE, &[], "x"),
+        ("// a\r\n/* b */x", Trivia::C, &["// a", "/* b */"], "x"),
+        ("/* a /* b */ c */", Trivia::C, &["/* a /* b */"], "c */"),
+        ("/* a /* b */ c */", Trivia::RUST, &["/* a /* b */ c */"], ""),
+        ("/**/ /*/ */x", Trivia::RUST, &["/**/", "/*/ */"], "x"),
+        ("# a\n#b", Trivia::HASH, &["# a", "#b"], ""),
+        ("// a", Trivia::HASH, &[], "// a"),
+        (" # a\n  b", Trivia::HASH.significant_newlines(true), &["# a"], "\n  b"),
+    ];
+    for (input, trivia, comments, rest) in cases {
+        assert_eq!(skip(input, &trivia), (comments.to_vec(), rest), "{input:?}");
+    }
+
+    let trivia = Trivia::C.whitespace(|c| c == ' ');
+    assert_eq!(skip(" /**/ \tx", &trivia), (vec!["/**/"], "\tx"));
+
+    let trivia = Trivia::new().line_comments(&["--", ";"]).block_comments(&[("(*", "*)"), ("{-", "-}")]);
+    assert_eq!(skip("; a\n{- b -} (* c *) -- d", &trivia), (vec!["; a", "{- b -}", "(* c *)", "-- d"], ""));
+}
+
+#[test]
+fn unterminated_test(){
+    for (input, trivia, at) in [
+        ("/* a", Trivia::C, "/* a"),
+        ("// a\n /* /* */", Trivia::RUST, "/* /* */"),
+    ] {
+        let mut iter = input.str_iter();
+        let err = iter.skip_trivia(&trivia).unwrap_err();
+        assert_eq!(err.as_str(), at);
+        assert_eq!(err.to_string(), "expected \"*/\", found '/'");
+        assert_eq!(iter.as_str(), input);
+    }
+}
+
+#[test]
+fn comments_as_str_test(){
+    let mut iter = " // a\n /* b */ c".str_iter();
+    let mut comments = iter.skip_trivia(&Trivia::C).unwrap();
+    assert_eq!(comments.as_str(), " // a\n /* b */ ");
+    assert_eq!(comments.next(), Some("// a"));
+    assert_eq!(comments.as_str(), "\n /* b */ ");
+    assert_eq!(comments.next(), Some("/* b */"));
+    assert_eq!(comments.next(), None);
+    assert_eq!(comments.as_str(), "");
+}
+
+fn tokens<'t>(s: &'t str, trivia: &Trivia) -> Vec<&'t str> {
+    let mut iter = StringIter::new(s);
+    let mut tokens = Vec::new();
+    while let Some(token) = iter.next_token_after_trivia(trivia).unwrap() {
+        tokens.push(token);
+    }
+    assert!(iter.is_empty());
+    tokens
+}
+
+#[test]
+fn next_token_after_trivia_test(){
+    assert_eq!(tokens("", &Trivia::C), Vec::<&str>::new());
+    assert_eq!(tokens("a/* b */c // d\n e", &Trivia::C), ["a", "c", "e"]);
+    assert_eq!(tokens("a#b c\nd", &Trivia::HASH), ["a", "d"]);
+    assert_eq!(tokens("蟹 🦀\n", &Trivia::WHITESPACE), ["蟹", "🦀"]);
+
+    let trivia = Trivia::HASH.significant_newlines(true);
+    assert_eq!(tokens("\n\na\rb # c\n", &trivia), ["\n", "\n", "a", "\r", "b", "\n"]);
+
+    let mut iter = "a /* b".str_iter();
+    assert_eq!(iter.next_token_after_trivia(&Trivia::C), Ok(Some("a")));
+    assert!(iter.next_token_after_trivia(&Trivia::C).is_err());
+    assert_eq!(iter.as_str(), " /* b");
+}